@@ -23,7 +23,9 @@ use std::fmt;
 pub enum HibouCoreError {
     UnknownLifeline(usize),
     UnknownMessage(usize),
-    UnknownGate(usize)
+    UnknownGate(usize),
+    InvalidWitnessStep(usize,String),
+    IncompleteWitness(String)
 }
 
 impl fmt::Display for HibouCoreError {
@@ -37,6 +39,12 @@ impl fmt::Display for HibouCoreError {
             },
             HibouCoreError::UnknownGate( gt_id ) => {
                 return write!(f, "{}", format!("context error ; unknown gate : {:}", gt_id));
+            },
+            HibouCoreError::InvalidWitnessStep( step_id, sub_e ) => {
+                return write!(f, "{}", format!("witness error ; invalid step {:} : {:}", step_id, sub_e));
+            },
+            HibouCoreError::IncompleteWitness( sub_e ) => {
+                return write!(f, "{}", format!("witness error ; incomplete witness : {:}", sub_e));
             }
         }
     }
@@ -0,0 +1,202 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use autour_core::nfa::nfa::AutNFA;
+
+use crate::core::execution::semantics::execute::execute_interaction;
+use crate::core::execution::semantics::frontier::global_frontier;
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+/**
+An automaton whose states are the interactions reachable from an initial interaction
+and whose letters index the (possibly symbolic) trace actions labelling the transitions.
+If the number of states is bounded, the automaton may not be complete,
+in which case some reachable states are not explored and have no outgoing transitions.
+**/
+pub struct InteractionAutomaton {
+    pub nfa : AutNFA<usize>,
+    pub letters : Vec<TraceAction>,
+    pub states : Vec<Interaction>,
+    pub complete : bool
+}
+
+
+pub fn interaction_as_automaton(gen_ctx : &GeneralContext,
+                                interaction : &Interaction,
+                                max_states : usize) -> InteractionAutomaton {
+    let mut letters : Vec<TraceAction> = vec![];
+    let mut letters_ids : HashMap<TraceAction,usize> = HashMap::new();
+    let mut states : Vec<Interaction> = vec![interaction.clone()];
+    let mut states_ids : HashMap<Interaction,usize> = hashmap!{interaction.clone() => 0};
+    let mut transitions : Vec<HashMap<usize,HashSet<usize>>> = vec![hashmap!{}];
+    let mut complete = true;
+    // ***
+    let mut queue : VecDeque<usize> = VecDeque::from(vec![0]);
+    while let Some(state_id) = queue.pop_front() {
+        let state_int = states.get(state_id).unwrap().clone();
+        for frt_elt in global_frontier(&state_int,gen_ctx,&None) {
+            let new_int = execute_interaction(&state_int,
+                                              &frt_elt.position,
+                                              &frt_elt.target_action,
+                                              gen_ctx);
+            let target_id = match states_ids.get(&new_int) {
+                Some( got_id ) => {
+                    *got_id
+                },
+                None => {
+                    if states.len() >= max_states {
+                        complete = false;
+                        continue;
+                    }
+                    let new_id = states.len();
+                    states_ids.insert(new_int.clone(),new_id);
+                    states.push(new_int);
+                    transitions.push(hashmap!{});
+                    queue.push_back(new_id);
+                    new_id
+                }
+            };
+            let letter = match letters_ids.get(&frt_elt.target_action) {
+                Some( got_letter ) => {
+                    *got_letter
+                },
+                None => {
+                    let new_letter = letters.len();
+                    letters_ids.insert(frt_elt.target_action.clone(),new_letter);
+                    letters.push(frt_elt.target_action);
+                    new_letter
+                }
+            };
+            transitions.get_mut(state_id).unwrap()
+                .entry(letter)
+                .or_insert_with(HashSet::new)
+                .insert(target_id);
+        }
+    }
+    // ***
+    let finals : HashSet<usize> = states.iter().enumerate()
+        .filter(|(_,state_int)| state_int.express_empty())
+        .map(|(state_id,_)| state_id)
+        .collect();
+    let nfa = AutNFA{alphabet:(0..letters.len()).collect(),
+                     initials:hashset!{0},
+                     finals,
+                     transitions};
+    InteractionAutomaton{nfa,letters,states,complete}
+}
+
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::eliminate_lf::eliminable::LifelineEliminable;
+    use crate::core::language::syntax::action::BroadcastPrimitive;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use super::interaction_as_automaton;
+
+    fn message(orig_lf_id : usize, ms_id : usize, tar_lf_id : usize) -> Box<Interaction> {
+        Box::new(Interaction::Action(BroadcastPrimitive::new(Some(orig_lf_id),
+                                                             MessageTypeExpression::Singleton(ms_id),
+                                                             vec![tar_lf_id])))
+    }
+
+    fn action(lf_id : usize, act_kind : TraceActionKind, ms_id : usize) -> TraceAction {
+        TraceAction::new(lf_id,act_kind,MessageTypeExpression::Singleton(ms_id))
+    }
+
+    fn get_context() -> GeneralContext {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        gen_ctx.add_msg("m3".to_string());
+        gen_ctx
+    }
+
+    #[test]
+    fn projected_automaton_test() {
+        let gen_ctx = get_context();
+        // seq(l1 -- m1 -> l2, alt(l2 -- m2 -> l1, o)) projected onto l1
+        let int = Interaction::CoReg(vec![],
+                                     message(0,0,1),
+                                     Box::new(Interaction::Alt(message(1,1,0),
+                                                               Box::new(Interaction::Empty))));
+        let local_int = int.eliminate_lifelines(&hashset!{1},&gen_ctx);
+        let expected = Interaction::CoReg(vec![],
+                                          Box::new(Interaction::Action(BroadcastPrimitive::new(Some(0),
+                                                                                               MessageTypeExpression::Singleton(0),
+                                                                                               vec![]))),
+                                          Box::new(Interaction::Alt(Box::new(Interaction::Action(BroadcastPrimitive::new(None,
+                                                                                                                         MessageTypeExpression::Singleton(1),
+                                                                                                                         vec![0]))),
+                                                                    Box::new(Interaction::Empty))));
+        assert_eq!(local_int,expected);
+        // ***
+        let automaton = interaction_as_automaton(&gen_ctx,&local_int,10);
+        assert!(automaton.complete);
+        assert_eq!(automaton.states.len(),3);
+        assert_eq!(automaton.letters,vec![action(0,TraceActionKind::Emission,0),
+                                          action(0,TraceActionKind::Reception,1)]);
+        // l1!m1 from the initial state then optionally l1?m2
+        assert_eq!(automaton.nfa.initials,hashset!{0});
+        assert_eq!(automaton.nfa.transitions,vec![hashmap!{0 => hashset!{1}},
+                                                  hashmap!{1 => hashset!{2}},
+                                                  hashmap!{}]);
+        assert_eq!(automaton.nfa.finals,hashset!{1,2});
+        assert_eq!(automaton.states.get(2).unwrap(),&Interaction::Empty);
+    }
+
+    #[test]
+    fn truncated_automaton_test() {
+        let gen_ctx = get_context();
+        let emission = |ms_id : usize| Box::new(Interaction::Action(BroadcastPrimitive::new(Some(0),
+                                                                                           MessageTypeExpression::Singleton(ms_id),
+                                                                                           vec![])));
+        // seq(l1 -- m1 -> |, l1 -- m2 -> |, l1 -- m3 -> |) has 4 reachable states
+        let int = Interaction::CoReg(vec![],
+                                     emission(0),
+                                     Box::new(Interaction::CoReg(vec![],
+                                                                 emission(1),
+                                                                 emission(2))));
+        let automaton = interaction_as_automaton(&gen_ctx,&int,10);
+        assert!(automaton.complete);
+        assert_eq!(automaton.states.len(),4);
+        // ***
+        let automaton = interaction_as_automaton(&gen_ctx,&int,3);
+        assert!(!automaton.complete);
+        assert_eq!(automaton.states.len(),3);
+        // the last state is not explored and has no outgoing transitions
+        assert_eq!(automaton.nfa.transitions,vec![hashmap!{0 => hashset!{1}},
+                                                  hashmap!{1 => hashset!{2}},
+                                                  hashmap!{}]);
+        assert!(automaton.nfa.finals.is_empty());
+    }
+
+}
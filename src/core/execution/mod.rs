@@ -15,4 +15,7 @@ limitations under the License.
 */
 
 pub mod trace;
-pub mod semantics;
\ No newline at end of file
+pub mod semantics;
+pub mod witness;
+pub mod automaton;
+pub mod realizability;
\ No newline at end of file
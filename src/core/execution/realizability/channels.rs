@@ -0,0 +1,182 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+/**
+Asynchronous communications between the components of a composition.
+There is a FIFO channel for each pair of an emitting and a receiving lifeline which communicate in the interaction.
+An emission puts a copy of the message in the channels towards the lifelines it is sent to by one of the corresponding actions of the interaction
+(there are as many possible outcomes as there are distinct sets of targets for such actions)
+and a reception can only occur if the message is at the head of a channel towards the receiving lifeline
+unless the message may also be received from the environment (i.e. a found message).
+**/
+pub struct CompositionChannels {
+    channels_ids : HashMap<(usize,usize),usize>,
+    emitters : HashSet<(usize,usize,BTreeSet<usize>)>,
+    found : HashSet<(usize,usize)>
+}
+
+impl CompositionChannels {
+
+    pub fn from_interaction(gen_ctx : &GeneralContext,
+                            interaction : &Interaction) -> CompositionChannels {
+        let mut channels = CompositionChannels{channels_ids:hashmap!{},
+                                               emitters:hashset!{},
+                                               found:hashset!{}};
+        channels.emitters = channels.collect(gen_ctx,interaction);
+        for (orig_lf_id,_,targets) in &channels.emitters {
+            for tar_lf_id in targets {
+                let new_id = channels.channels_ids.len();
+                channels.channels_ids.entry((*orig_lf_id,*tar_lf_id)).or_insert(new_id);
+            }
+        }
+        return channels;
+    }
+
+    /**
+    Collects the found messages and returns the emissions of the interaction
+    as triplets of an emitting lifeline, a message and the set of its targets.
+    Emissions which synchronize in a 'sync' operator are merged.
+    **/
+    fn collect(&mut self,
+               gen_ctx : &GeneralContext,
+               interaction : &Interaction) -> HashSet<(usize,usize,BTreeSet<usize>)> {
+        match interaction {
+            Interaction::Empty => {
+                hashset!{}
+            },
+            Interaction::Action(act) => {
+                let targets : BTreeSet<usize> = act.targets.iter().cloned().collect();
+                let mut emitters = hashset!{};
+                for ms_id in act.message_type.resolve(gen_ctx) {
+                    match act.origin_on_emission {
+                        None => {
+                            for tar_lf_id in &targets {
+                                self.found.insert( (*tar_lf_id,ms_id) );
+                            }
+                        },
+                        Some( orig_lf_id ) => {
+                            emitters.insert( (orig_lf_id,ms_id,targets.clone()) );
+                        }
+                    }
+                }
+                emitters
+            },
+            Interaction::CoReg(_, i1, i2) => {
+                let mut emitters = self.collect(gen_ctx,i1);
+                emitters.extend(self.collect(gen_ctx,i2));
+                emitters
+            },
+            Interaction::Alt(i1, i2) => {
+                let mut emitters = self.collect(gen_ctx,i1);
+                emitters.extend(self.collect(gen_ctx,i2));
+                emitters
+            },
+            Interaction::Sync(sync_acts, i1, i2) => {
+                let is_synchronized = |orig_lf_id : usize, ms_id : usize| {
+                    match sync_acts.get(&(orig_lf_id,TraceActionKind::Emission)) {
+                        None => {
+                            false
+                        },
+                        Some( ms_type ) => {
+                            ms_type.resolve(gen_ctx).contains(&ms_id)
+                        }
+                    }
+                };
+                let emitters1 = self.collect(gen_ctx,i1);
+                let emitters2 = self.collect(gen_ctx,i2);
+                let mut emitters = hashset!{};
+                for (orig_lf_id,ms_id,targets) in emitters1.iter().chain(emitters2.iter()) {
+                    if !is_synchronized(*orig_lf_id,*ms_id) {
+                        emitters.insert( (*orig_lf_id,*ms_id,targets.clone()) );
+                    }
+                }
+                for (orig1,ms1,targets1) in &emitters1 {
+                    if is_synchronized(*orig1,*ms1) {
+                        for (orig2,ms2,targets2) in &emitters2 {
+                            if orig1 == orig2 && ms1 == ms2 {
+                                let merged : BTreeSet<usize> = targets1.union(targets2).cloned().collect();
+                                emitters.insert( (*orig1,*ms1,merged) );
+                            }
+                        }
+                    }
+                }
+                emitters
+            },
+            Interaction::Loop(_, i1) => {
+                self.collect(gen_ctx,i1)
+            }
+        }
+    }
+
+    pub fn channels_num(&self) -> usize {
+        self.channels_ids.len()
+    }
+
+    /**
+    Returns the possible contents of the channels after the occurrence of a concrete action
+    (none if the action is a reception which cannot occur).
+    **/
+    pub fn update(&self,
+                  gen_ctx : &GeneralContext,
+                  contents : &Vec<Vec<usize>>,
+                  action : &TraceAction) -> Vec<Vec<Vec<usize>>> {
+        let resolved = action.message.resolve(gen_ctx);
+        assert!(resolved.len() == 1);
+        let ms_id = *resolved.iter().next().unwrap();
+        let mut outcomes = vec![];
+        match action.act_kind {
+            TraceActionKind::Emission => {
+                let targets_sets : HashSet<&BTreeSet<usize>> = self.emitters.iter()
+                    .filter(|(orig_lf_id,emitted_ms_id,_)| *orig_lf_id == action.lf_id && *emitted_ms_id == ms_id)
+                    .map(|(_,_,targets)| targets)
+                    .collect();
+                if targets_sets.is_empty() {
+                    outcomes.push(contents.clone());
+                }
+                for targets in targets_sets {
+                    let mut new_contents = contents.clone();
+                    for tar_lf_id in targets {
+                        let channel_id = *self.channels_ids.get(&(action.lf_id,*tar_lf_id)).unwrap();
+                        new_contents.get_mut(channel_id).unwrap().push(ms_id);
+                    }
+                    outcomes.push(new_contents);
+                }
+            },
+            TraceActionKind::Reception => {
+                for ((_,tar_lf_id),channel_id) in &self.channels_ids {
+                    if *tar_lf_id == action.lf_id && contents.get(*channel_id).unwrap().first() == Some(&ms_id) {
+                        let mut new_contents = contents.clone();
+                        new_contents.get_mut(*channel_id).unwrap().remove(0);
+                        outcomes.push(new_contents);
+                    }
+                }
+                if self.found.contains(&(action.lf_id,ms_id)) {
+                    outcomes.push(contents.clone());
+                }
+            }
+        }
+        outcomes
+    }
+
+}
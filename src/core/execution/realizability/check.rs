@@ -0,0 +1,344 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::realizability::channels::CompositionChannels;
+use crate::core::execution::realizability::verdict::{RealizabilityVerdict, RealizabilityViolation, RealizabilityViolationKind};
+use crate::core::execution::realizability::views::{are_local_views_jointly_observable, trace_local_views};
+use crate::core::execution::semantics::execute::execute_interaction;
+use crate::core::execution::semantics::frontier::{concretize_frontier, global_frontier};
+use crate::core::execution::trace::multitrace::Trace;
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::eliminate_lf::eliminable::LifelineEliminable;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+/**
+A state of the composition :
+the current local interactions of the components, the messages in transit in the channels
+and the set of global interactions which can be reached by the same global trace.
+**/
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+struct CompositionState {
+    locals : Vec<Interaction>,
+    channels : Vec<Vec<usize>>,
+    globals : BTreeSet<usize>
+}
+
+
+/**
+Memoizes the global interactions and their outgoing transitions.
+**/
+struct GlobalBehaviour<'a> {
+    gen_ctx : &'a GeneralContext,
+    states : Vec<Interaction>,
+    states_ids : HashMap<Interaction,usize>,
+    transitions : HashMap<usize,Vec<(TraceAction,usize)>>
+}
+
+impl<'a> GlobalBehaviour<'a> {
+
+    fn new(gen_ctx : &'a GeneralContext, interaction : &Interaction) -> GlobalBehaviour<'a> {
+        GlobalBehaviour{gen_ctx,
+                        states:vec![interaction.clone()],
+                        states_ids:hashmap!{interaction.clone() => 0},
+                        transitions:hashmap!{}}
+    }
+
+    fn get_transitions(&mut self, state_id : usize) -> &Vec<(TraceAction,usize)> {
+        if !self.transitions.contains_key(&state_id) {
+            let state_int = self.states.get(state_id).unwrap().clone();
+            let mut transitions = vec![];
+            for frt_elt in concretize_frontier(global_frontier(&state_int,self.gen_ctx,&None),self.gen_ctx) {
+                let new_int = execute_interaction(&state_int,
+                                                  &frt_elt.position,
+                                                  &frt_elt.target_action,
+                                                  self.gen_ctx);
+                let target_id = match self.states_ids.get(&new_int) {
+                    Some( got_id ) => {
+                        *got_id
+                    },
+                    None => {
+                        let new_id = self.states.len();
+                        self.states_ids.insert(new_int.clone(),new_id);
+                        self.states.push(new_int);
+                        new_id
+                    }
+                };
+                transitions.push( (frt_elt.target_action,target_id) );
+            }
+            self.transitions.insert(state_id,transitions);
+        }
+        self.transitions.get(&state_id).unwrap()
+    }
+
+    fn step(&mut self, globals : &BTreeSet<usize>, action : &TraceAction) -> BTreeSet<usize> {
+        let mut new_globals = btreeset!{};
+        for state_id in globals {
+            for (trans_action,target_id) in self.get_transitions(*state_id) {
+                if trans_action == action {
+                    new_globals.insert(*target_id);
+                }
+            }
+        }
+        new_globals
+    }
+
+    fn may_terminate(&self, globals : &BTreeSet<usize>) -> bool {
+        globals.iter().any(|state_id| self.states.get(*state_id).unwrap().express_empty())
+    }
+
+}
+
+
+/**
+Checks whether the composition of the projections of the interaction onto the co-localizations,
+communicating asynchronously through FIFO channels, exhibits exactly the behaviour of the interaction.
+Every behaviour of the interaction projects onto behaviours of the components
+so that one searches for behaviours of the composition which are not behaviours of the interaction
+and for states in which the composition is stuck.
+Actions are concretized so that components and channels exchange concrete messages.
+The composition and the global interaction are explored jointly in a breadth-first manner
+so that the first violation which is found has a shortest witness.
+At most 'max_states' states of the composition are explored.
+**/
+pub fn check_realizability(gen_ctx : &GeneralContext,
+                           co_localizations : &CoLocalizations,
+                           interaction : &Interaction,
+                           max_states : usize) -> (RealizabilityVerdict,usize) {
+    let channels = CompositionChannels::from_interaction(gen_ctx,interaction);
+    let mut global = GlobalBehaviour::new(gen_ctx,interaction);
+    // ***
+    let mut init_locals = vec![];
+    for coloc_lfs in &co_localizations.locs_lf_ids {
+        let mut lfs_to_remove : HashSet<usize> = gen_ctx.get_all_lfs_ids();
+        for lf_id in coloc_lfs {
+            lfs_to_remove.remove( lf_id );
+        }
+        init_locals.push( interaction.clone().eliminate_lifelines(&lfs_to_remove,gen_ctx) );
+    }
+    let init_state = CompositionState{locals:init_locals,
+                                      channels:vec![vec![];channels.channels_num()],
+                                      globals:btreeset!{0}};
+    // ***
+    let mut states : Vec<(CompositionState,Option<(usize,TraceAction)>)> = vec![(init_state.clone(),None)];
+    let mut states_ids : HashMap<CompositionState,usize> = hashmap!{init_state => 0};
+    let mut queue : VecDeque<usize> = VecDeque::from(vec![0]);
+    let mut truncated = false;
+    while let Some(state_id) = queue.pop_front() {
+        let state = states.get(state_id).unwrap().0.clone();
+        let terminated = state.locals.iter().all(|local_int| local_int.express_empty())
+            && state.channels.iter().all(|content| content.is_empty());
+        if terminated && !global.may_terminate(&state.globals) {
+            let witness = get_witness(&states,state_id,None);
+            let violation = classify_violation(gen_ctx,co_localizations,interaction,witness,true);
+            return (RealizabilityVerdict::NotRealizable(violation),states.len());
+        }
+        // ***
+        let mut has_successor = false;
+        for (coloc_id,local_int) in state.locals.iter().enumerate() {
+            for frt_elt in concretize_frontier(global_frontier(local_int,gen_ctx,&None),gen_ctx) {
+                let outcomes = channels.update(gen_ctx,&state.channels,&frt_elt.target_action);
+                if outcomes.is_empty() {
+                    continue;
+                }
+                has_successor = true;
+                let new_globals = global.step(&state.globals,&frt_elt.target_action);
+                if new_globals.is_empty() {
+                    let witness = get_witness(&states,state_id,Some(frt_elt.target_action));
+                    let violation = classify_violation(gen_ctx,co_localizations,interaction,witness,false);
+                    return (RealizabilityVerdict::NotRealizable(violation),states.len());
+                }
+                let mut new_locals = state.locals.clone();
+                *new_locals.get_mut(coloc_id).unwrap() = execute_interaction(local_int,
+                                                                             &frt_elt.position,
+                                                                             &frt_elt.target_action,
+                                                                             gen_ctx);
+                for new_channels in outcomes {
+                    let new_state = CompositionState{locals:new_locals.clone(),
+                                                     channels:new_channels,
+                                                     globals:new_globals.clone()};
+                    if !states_ids.contains_key(&new_state) {
+                        if states.len() >= max_states {
+                            truncated = true;
+                            continue;
+                        }
+                        states_ids.insert(new_state.clone(),states.len());
+                        queue.push_back(states.len());
+                        states.push( (new_state,Some((state_id,frt_elt.target_action.clone()))) );
+                    }
+                }
+            }
+        }
+        if !has_successor && !terminated {
+            let witness = get_witness(&states,state_id,None);
+            let violation = RealizabilityViolation::new(RealizabilityViolationKind::Deadlock,witness,false);
+            return (RealizabilityVerdict::NotRealizable(violation),states.len());
+        }
+    }
+    // ***
+    if truncated {
+        (RealizabilityVerdict::Inconclusive,states.len())
+    } else {
+        (RealizabilityVerdict::Realizable,states.len())
+    }
+}
+
+
+fn get_witness(states : &Vec<(CompositionState,Option<(usize,TraceAction)>)>,
+               state_id : usize,
+               last_action : Option<TraceAction>) -> Trace {
+    let mut witness : Trace = vec![];
+    if let Some(action) = last_action {
+        witness.push(action);
+    }
+    let mut current_id = state_id;
+    while let Some((parent_id,action)) = &states.get(current_id).unwrap().1 {
+        witness.push(action.clone());
+        current_id = *parent_id;
+    }
+    witness.reverse();
+    witness
+}
+
+
+fn classify_violation(gen_ctx : &GeneralContext,
+                      co_localizations : &CoLocalizations,
+                      interaction : &Interaction,
+                      witness : Trace,
+                      on_termination : bool) -> RealizabilityViolation {
+    let views = trace_local_views(co_localizations,&witness);
+    let kind = if are_local_views_jointly_observable(gen_ctx,co_localizations,interaction,&views,!on_termination) {
+        RealizabilityViolationKind::Race
+    } else {
+        RealizabilityViolationKind::NonLocalChoice
+    };
+    RealizabilityViolation::new(kind,witness,on_termination)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::core::execution::realizability::verdict::{RealizabilityVerdict, RealizabilityViolationKind};
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::BroadcastPrimitive;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use super::check_realizability;
+
+    fn get_test_context() -> GeneralContext {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_lf("l3".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        return gen_ctx;
+    }
+
+    fn message(orig_lf_id : usize, ms_id : usize, tar_lf_id : usize) -> Box<Interaction> {
+        Box::new(Interaction::Action(BroadcastPrimitive::new(Some(orig_lf_id),
+                                                             MessageTypeExpression::Singleton(ms_id),
+                                                             vec![tar_lf_id])))
+    }
+
+    fn action(lf_id : usize, act_kind : TraceActionKind, ms_id : usize) -> TraceAction {
+        TraceAction::new(lf_id,act_kind,MessageTypeExpression::Singleton(ms_id))
+    }
+
+    #[test]
+    fn realizable_test() {
+        let gen_ctx = get_test_context();
+        // seq(l1 -- m1 -> l2, l2 -- m2 -> l3)
+        let int = Interaction::CoReg(vec![],message(0,0,1),message(1,1,2));
+        let (verdict,_) = check_realizability(&gen_ctx,&CoLocalizations::get_discrete_partition(3),&int,100);
+        assert_eq!(verdict,RealizabilityVerdict::Realizable);
+    }
+
+    #[test]
+    fn non_local_choice_test() {
+        let gen_ctx = get_test_context();
+        // alt(l1 -- m1 -> l2, l3 -- m2 -> l2)
+        let int = Interaction::Alt(message(0,0,1),message(2,1,1));
+        let (verdict,_) = check_realizability(&gen_ctx,&CoLocalizations::get_discrete_partition(3),&int,100);
+        match verdict {
+            RealizabilityVerdict::NotRealizable(violation) => {
+                assert_eq!(violation.kind,RealizabilityViolationKind::NonLocalChoice);
+                assert_eq!(violation.witness.len(),2);
+                assert!(!violation.on_termination);
+            },
+            _ => {
+                panic!("expected a non-local choice");
+            }
+        }
+    }
+    #[test]
+    fn race_test() {
+        let gen_ctx = get_test_context();
+        // alt(seq(l1 -- m1 -> l2, l1 -- m1 -> l3), l1 -- m1 -> l3)
+        let int = Interaction::Alt(Box::new(Interaction::CoReg(vec![],message(0,0,1),message(0,0,2))),
+                                   message(0,0,2));
+        let (verdict,_) = check_realizability(&gen_ctx,&CoLocalizations::get_discrete_partition(3),&int,100);
+        match verdict {
+            RealizabilityVerdict::NotRealizable(violation) => {
+                // l3 receives the m1 emitted towards l2 so that the interaction can no longer emit a second m1
+                // even though the local views are those of a prefix of the first alternative
+                assert_eq!(violation.kind,RealizabilityViolationKind::Race);
+                assert_eq!(violation.witness,vec![action(0,TraceActionKind::Emission,0),
+                                                  action(2,TraceActionKind::Reception,0),
+                                                  action(0,TraceActionKind::Emission,0)]);
+                assert!(!violation.on_termination);
+            },
+            _ => {
+                panic!("expected a race");
+            }
+        }
+    }
+
+    #[test]
+    fn deadlock_test() {
+        let gen_ctx = get_test_context();
+        // seq(l1 -- m1 -> l2, l1 -- m1 -> l3) with l1 and l3 co-localized
+        let int = Interaction::CoReg(vec![],message(0,0,1),message(0,0,2));
+        let co_localizations = CoLocalizations::new(vec![hashset!{0,2},hashset!{1}]);
+        let (verdict,_) = check_realizability(&gen_ctx,&co_localizations,&int,100);
+        match verdict {
+            RealizabilityVerdict::NotRealizable(violation) => {
+                // both copies of m1 are sent towards the same lifeline so that the other one waits forever for its own
+                // (which of l2 or l3 receives them depends on the order in which the outcomes of the emissions are explored)
+                assert_eq!(violation.kind,RealizabilityViolationKind::Deadlock);
+                let receptions = vec![vec![action(0,TraceActionKind::Emission,0),
+                                           action(0,TraceActionKind::Emission,0),
+                                           action(1,TraceActionKind::Reception,0)],
+                                      vec![action(0,TraceActionKind::Emission,0),
+                                           action(0,TraceActionKind::Emission,0),
+                                           action(2,TraceActionKind::Reception,0)]];
+                assert!(receptions.contains(&violation.witness));
+                assert!(!violation.on_termination);
+            },
+            _ => {
+                panic!("expected a deadlock");
+            }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fmt;
+
+use crate::core::execution::trace::multitrace::Trace;
+
+
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum RealizabilityViolationKind {
+    /// the local views of the witness cannot all be observed on a single global execution
+    NonLocalChoice,
+    /// the local views of the witness can be observed on a global execution but not in this order
+    Race,
+    /// the components can no longer progress although some of them have not terminated
+    /// or some messages are never received
+    Deadlock
+}
+
+impl fmt::Display for RealizabilityViolationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RealizabilityViolationKind::NonLocalChoice => {
+                write!(f,"non-local choice")
+            },
+            RealizabilityViolationKind::Race => {
+                write!(f,"race")
+            },
+            RealizabilityViolationKind::Deadlock => {
+                write!(f,"deadlock")
+            }
+        }
+    }
+}
+
+
+/**
+A behaviour of the composition of the local projections which is not a behaviour of the global interaction.
+The witness is the shortest such global trace.
+If 'on_termination' is true, the witness is a complete behaviour of the composition
+(all components terminated and all messages received)
+which is only a strict prefix of the global behaviour.
+Otherwise, its last action cannot occur at this point of the global behaviour
+(or, for a deadlock, the composition cannot progress after the witness).
+**/
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct RealizabilityViolation {
+    pub kind : RealizabilityViolationKind,
+    pub witness : Trace,
+    pub on_termination : bool
+}
+
+impl RealizabilityViolation {
+    pub fn new(kind : RealizabilityViolationKind,
+               witness : Trace,
+               on_termination : bool) -> RealizabilityViolation {
+        return RealizabilityViolation{kind,witness,on_termination};
+    }
+}
+
+
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum RealizabilityVerdict {
+    Realizable,
+    NotRealizable(RealizabilityViolation),
+    /// the bound on the number of explored states was reached before any violation was found
+    Inconclusive
+}
+
+impl fmt::Display for RealizabilityVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RealizabilityVerdict::Realizable => {
+                write!(f,"realizable")
+            },
+            RealizabilityVerdict::NotRealizable(_) => {
+                write!(f,"not realizable")
+            },
+            RealizabilityVerdict::Inconclusive => {
+                write!(f,"inconclusive")
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::semantics::execute::execute_interaction;
+use crate::core::execution::semantics::frontier::{concretize_frontier, global_frontier};
+use crate::core::execution::trace::multitrace::{MultiTrace, Trace};
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+/**
+Splits a global trace into its local views, one per co-localization.
+**/
+pub fn trace_local_views(co_localizations : &CoLocalizations,
+                         trace : &Trace) -> MultiTrace {
+    let mut multi_trace : MultiTrace = vec![vec![];co_localizations.num_colocs()];
+    for action in trace {
+        let coloc_id = co_localizations.get_lf_coloc_id(action.lf_id).unwrap();
+        multi_trace.get_mut(coloc_id).unwrap().push(action.clone());
+    }
+    return multi_trace;
+}
+
+
+/**
+Checks whether there exists an execution of the interaction on which the local views are exactly those of the multi-trace,
+either as a prefix of the execution or, if 'as_prefix' is false, as a complete execution.
+The multi-trace must be concrete.
+Every step consumes an action of the multi-trace so that the search is finite.
+**/
+pub fn are_local_views_jointly_observable(gen_ctx : &GeneralContext,
+                                          co_localizations : &CoLocalizations,
+                                          interaction : &Interaction,
+                                          multi_trace : &MultiTrace,
+                                          as_prefix : bool) -> bool {
+    let mut visited : HashSet<(Interaction,Vec<usize>)> = hashset!{};
+    let mut queue : VecDeque<(Interaction,Vec<usize>)> = VecDeque::new();
+    queue.push_back( (interaction.clone(),vec![0;multi_trace.len()]) );
+    while let Some((state_int,consumed)) = queue.pop_front() {
+        if !visited.insert( (state_int.clone(),consumed.clone()) ) {
+            continue;
+        }
+        let all_consumed = multi_trace.iter().zip(consumed.iter())
+            .all(|(canal_trace,canal_consumed)| canal_trace.len() == *canal_consumed);
+        if all_consumed && (as_prefix || state_int.express_empty()) {
+            return true;
+        }
+        for frt_elt in concretize_frontier(global_frontier(&state_int,gen_ctx,&None),gen_ctx) {
+            let coloc_id = co_localizations.get_lf_coloc_id(frt_elt.target_action.lf_id).unwrap();
+            let canal_consumed = *consumed.get(coloc_id).unwrap();
+            if multi_trace.get(coloc_id).unwrap().get(canal_consumed) == Some(&frt_elt.target_action) {
+                let new_int = execute_interaction(&state_int,
+                                                  &frt_elt.position,
+                                                  &frt_elt.target_action,
+                                                  gen_ctx);
+                let mut new_consumed = consumed.clone();
+                *new_consumed.get_mut(coloc_id).unwrap() += 1;
+                queue.push_back( (new_int,new_consumed) );
+            }
+        }
+    }
+    return false;
+}
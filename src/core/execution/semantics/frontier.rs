@@ -41,6 +41,33 @@ impl FrontierElement {
 }
 
 
+/**
+Splits the frontier elements whose target actions are symbolic
+into one frontier element per message of their message type.
+**/
+pub fn concretize_frontier(frontier : Vec<FrontierElement>,
+                           gen_ctx : &GeneralContext) -> Vec<FrontierElement> {
+    let mut got = vec![];
+    for frt_elt in frontier {
+        let resolved = frt_elt.target_action.message.resolve(gen_ctx);
+        assert!(!resolved.is_empty());
+        if resolved.len() == 1 {
+            got.push(frt_elt)
+        } else {
+            for ms_id in resolved {
+                let new_frt_elt = FrontierElement::new(frt_elt.position.clone(),
+                                                       TraceAction::new(frt_elt.target_action.lf_id,
+                                                                        frt_elt.target_action.act_kind,
+                                                                        MessageTypeExpression::Singleton(ms_id)),
+                                                       frt_elt.max_loop_depth);
+                got.push(new_frt_elt)
+            }
+        }
+    }
+    got
+}
+
+
 pub fn global_frontier(interaction : &Interaction,
                        gen_ctx : &GeneralContext,
                    to_match : &Option<HashSet<&TraceAction>>) -> Vec<FrontierElement> {
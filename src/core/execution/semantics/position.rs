@@ -19,7 +19,9 @@ limitations under the License.
 use std::fmt;
 use std::fmt::{Debug, Formatter};
 
-#[derive(Clone, PartialEq, Eq, Hash)]
+use crate::io::textual_convention::SYNTAX_POSITION_EPSILON;
+
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Position {
     Epsilon(Option<usize>),
     Left(Box<Position>),
@@ -79,6 +81,20 @@ impl fmt::Display for Position {
     }
 }
 
+/**
+Writes a position as in the .hwf syntax, where the root position, which is displayed as the empty string, is written 'ε'.
+**/
+pub fn position_as_text(position : &Position) -> String {
+    match position {
+        Position::Epsilon(None) => {
+            SYNTAX_POSITION_EPSILON.to_string()
+        },
+        _ => {
+            position.to_string()
+        }
+    }
+}
+
 
 
 
@@ -26,7 +26,7 @@ pub enum TraceActionKind {
     Emission
 }
 
-#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
 pub struct TraceAction {
     pub lf_id : usize,
     pub act_kind : TraceActionKind,
@@ -0,0 +1,181 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::error::HibouCoreError;
+use crate::core::execution::semantics::execute::execute_interaction;
+use crate::core::execution::semantics::frontier::global_frontier;
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::execution::witness::witness::ExecutionWitness;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+/**
+Re-executes a witness step by step, checking that each step consumes the next action
+of the corresponding canal of the multi-trace and occurs at a position of the frontier,
+and that, at the end, the multi-trace is entirely consumed and the interaction can terminate.
+**/
+pub fn replay_witness(gen_ctx : &GeneralContext,
+                      co_localizations : &CoLocalizations,
+                      multi_trace : &MultiTrace,
+                      interaction : &Interaction,
+                      witness : &ExecutionWitness) -> Result<(),HibouCoreError> {
+    let mut current_int = interaction.clone();
+    let mut consumed : Vec<usize> = vec![0;multi_trace.len()];
+    for (step_id,step) in witness.iter().enumerate() {
+        let canal_id = match co_localizations.get_lf_coloc_id(step.action.lf_id) {
+            None => {
+                return Err( HibouCoreError::InvalidWitnessStep(step_id,
+                                                               format!("lifeline {:} is not in any co-localization", step.action.lf_id)) );
+            },
+            Some( got_canal_id ) => {
+                got_canal_id
+            }
+        };
+        // ***
+        let canal_trace = multi_trace.get(canal_id).unwrap();
+        let canal_consumed = consumed.get_mut(canal_id).unwrap();
+        match canal_trace.get(*canal_consumed) {
+            None => {
+                return Err( HibouCoreError::InvalidWitnessStep(step_id,
+                                                               format!("canal {:} has already been entirely consumed", canal_id)) );
+            },
+            Some( trace_head ) => {
+                if *trace_head != step.action {
+                    return Err( HibouCoreError::InvalidWitnessStep(step_id,
+                                                                   format!("action {:?} is not the next action {:?} on canal {:}", step.action, trace_head, canal_id)) );
+                }
+            }
+        }
+        *canal_consumed += 1;
+        // ***
+        let mut is_in_frontier = false;
+        for frt_elt in global_frontier(&current_int,gen_ctx,&None) {
+            if frt_elt.position == step.position && step.action.is_type_included(&frt_elt.target_action,gen_ctx) {
+                is_in_frontier = true;
+                break;
+            }
+        }
+        if !is_in_frontier {
+            return Err( HibouCoreError::InvalidWitnessStep(step_id,
+                                                           format!("no action matching {:?} at position {:} of the frontier", step.action, step.position)) );
+        }
+        current_int = execute_interaction(&current_int,&step.position,&step.action,gen_ctx);
+    }
+    // ***
+    for (canal_id,canal_trace) in multi_trace.iter().enumerate() {
+        if *consumed.get(canal_id).unwrap() < canal_trace.len() {
+            return Err( HibouCoreError::IncompleteWitness(format!("canal {:} is not entirely consumed", canal_id)) );
+        }
+    }
+    if !current_int.express_empty() {
+        return Err( HibouCoreError::IncompleteWitness("the remaining interaction cannot express the empty trace".to_string()) );
+    }
+    return Ok(());
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::core::error::HibouCoreError;
+    use crate::core::execution::semantics::position::Position;
+    use crate::core::execution::trace::multitrace::MultiTrace;
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::execution::witness::witness::ExecutionWitness;
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::BroadcastPrimitive;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+    use crate::process::ana::interface::make_analysis_manager;
+    use crate::process::ana::loggers::wlog::logger::AnalysisWitnessLogger;
+    use super::replay_witness;
+
+    fn message(orig_lf_id : usize, ms_id : usize, tar_lf_id : usize) -> Box<Interaction> {
+        Box::new(Interaction::Action(BroadcastPrimitive::new(Some(orig_lf_id),
+                                                             MessageTypeExpression::Singleton(ms_id),
+                                                             vec![tar_lf_id])))
+    }
+
+    fn action(lf_id : usize, act_kind : TraceActionKind, ms_id : usize) -> TraceAction {
+        TraceAction::new(lf_id,act_kind,MessageTypeExpression::Singleton(ms_id))
+    }
+
+    fn get_witness(gen_ctx : &GeneralContext,
+                   co_localizations : &CoLocalizations,
+                   multi_trace : &MultiTrace,
+                   int : &Interaction) -> ExecutionWitness {
+        let mut ana_opts = HibouAnalyzeOptions::default();
+        ana_opts.loggers.push( Box::new(AnalysisWitnessLogger::new()) );
+        let (mut analysis_manager,init_node) = make_analysis_manager(gen_ctx,
+                                                                     co_localizations,
+                                                                     multi_trace,
+                                                                     int,
+                                                                     ana_opts);
+        analysis_manager.start_process(init_node);
+        analysis_manager.get_logger(0).unwrap()
+            .as_any().downcast_ref::<AnalysisWitnessLogger>().unwrap()
+            .witness.clone().unwrap()
+    }
+
+    fn get_example() -> (GeneralContext,CoLocalizations,MultiTrace,Interaction) {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        // seq(l1 -- m1 -> l2, l1 -- m2 -> l2)
+        let int = Interaction::CoReg(vec![],message(0,0,1),message(0,1,1));
+        let multi_trace : MultiTrace = vec![vec![action(0,TraceActionKind::Emission,0),action(0,TraceActionKind::Emission,1)],
+                                            vec![action(1,TraceActionKind::Reception,0),action(1,TraceActionKind::Reception,1)]];
+        (gen_ctx,CoLocalizations::get_discrete_partition(2),multi_trace,int)
+    }
+
+    #[test]
+    fn replay_accepting_witness_test() {
+        let (gen_ctx,co_localizations,multi_trace,int) = get_example();
+        let witness = get_witness(&gen_ctx,&co_localizations,&multi_trace,&int);
+        assert_eq!(witness.len(),4);
+        assert!(replay_witness(&gen_ctx,&co_localizations,&multi_trace,&int,&witness).is_ok());
+        // a prefix of the witness does not consume the whole multi-trace
+        let prefix : ExecutionWitness = witness.iter().take(3).cloned().collect();
+        assert!(matches!(replay_witness(&gen_ctx,&co_localizations,&multi_trace,&int,&prefix),
+                         Err(HibouCoreError::IncompleteWitness(_))));
+    }
+
+    #[test]
+    fn replay_tampered_witness_test() {
+        let (gen_ctx,co_localizations,multi_trace,int) = get_example();
+        let witness = get_witness(&gen_ctx,&co_localizations,&multi_trace,&int);
+        // the first step executes l1!m1 at the leftmost position, the right one is not in the frontier
+        let mut wrong_position = witness.clone();
+        wrong_position.get_mut(0).unwrap().position = Position::Right(Box::new(Position::Epsilon(None)));
+        assert!(matches!(replay_witness(&gen_ctx,&co_localizations,&multi_trace,&int,&wrong_position),
+                         Err(HibouCoreError::InvalidWitnessStep(0,_))));
+        // l1!m2 is not the next action on the canal of l1
+        let mut wrong_action = witness.clone();
+        wrong_action.get_mut(0).unwrap().action = action(0,TraceActionKind::Emission,1);
+        assert!(matches!(replay_witness(&gen_ctx,&co_localizations,&multi_trace,&int,&wrong_action),
+                         Err(HibouCoreError::InvalidWitnessStep(0,_))));
+    }
+
+}
@@ -0,0 +1,38 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::execution::semantics::position::Position;
+use crate::core::execution::trace::trace::TraceAction;
+
+
+/**
+A step of an accepting execution path :
+the concrete trace action which is executed and its position in the current interaction term.
+**/
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExecutionWitnessStep {
+    pub position : Position,
+    pub action : TraceAction
+}
+
+impl ExecutionWitnessStep {
+    pub fn new(position : Position, action : TraceAction) -> ExecutionWitnessStep {
+        return ExecutionWitnessStep{position,action};
+    }
+}
+
+pub type ExecutionWitness = Vec<ExecutionWitnessStep>;
@@ -15,7 +15,8 @@ limitations under the License.
 */
 
 
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
+use std::fmt;
 use std::iter::FromIterator;
 
 
@@ -169,3 +170,111 @@ impl GeneralContext {
     }
 
 }
+
+
+
+/**
+Conflicts which may occur when merging signatures.
+The signatures in which they occur are identified by their index in the merged list.
+**/
+#[derive(Clone, PartialEq, Debug, Eq)]
+pub enum SignatureMergeConflict {
+    // a type is defined with different messages in two signatures
+    TypeDefinition(String,usize,BTreeSet<String>,usize,BTreeSet<String>),
+    // a name is declared as a message in a signature and as a type in another
+    MessageTypeName(String)
+}
+
+impl fmt::Display for SignatureMergeConflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SignatureMergeConflict::TypeDefinition(mt_name,kept_sig_id,kept_msgs,other_sig_id,other_msgs) => {
+                let as_text = |msgs : &BTreeSet<String>| msgs.iter().cloned().collect::<Vec<String>>().join(",");
+                write!(f,"type '{}' is defined as {{{}}} in signature {} and as {{{}}} in signature {} ; the first definition is kept",
+                       mt_name, as_text(kept_msgs), kept_sig_id, as_text(other_msgs), other_sig_id)
+            },
+            SignatureMergeConflict::MessageTypeName(name) => {
+                write!(f,"'{}' is declared both as a message and as a type ; it is kept as a message only", name)
+            }
+        }
+    }
+}
+
+fn get_mt_message_names(gen_ctx : &GeneralContext, mt_id : usize) -> BTreeSet<String> {
+    gen_ctx.get_mt_messages(mt_id).unwrap().iter().map(|ms_id| gen_ctx.get_ms_name(*ms_id).unwrap()).collect()
+}
+
+/**
+Returns the union of several signatures, in which lifelines, messages and types are identified by their names
+and appear in the order in which they are first declared.
+**/
+pub fn merge_signatures(signatures : &Vec<GeneralContext>) -> (GeneralContext,Vec<SignatureMergeConflict>) {
+    let mut merged = GeneralContext::new();
+    let mut conflicts = vec![];
+    for signature in signatures {
+        for lf_name in &signature.lf_names {
+            merged.add_lf(lf_name.clone());
+        }
+        for ms_name in &signature.ms_names {
+            merged.add_msg(ms_name.clone());
+        }
+    }
+    // the signature from which each type of the merged signature originates
+    let mut types_origins : Vec<(usize,BTreeSet<String>)> = vec![];
+    for (sig_id,signature) in signatures.iter().enumerate() {
+        for mt_id in 0..signature.get_mt_num() {
+            let mt_name = signature.get_mt_name(mt_id).unwrap();
+            let msgs = get_mt_message_names(signature,mt_id);
+            if merged.get_ms_id(&mt_name).is_some() {
+                let conflict = SignatureMergeConflict::MessageTypeName(mt_name);
+                if !conflicts.contains(&conflict) {
+                    conflicts.push(conflict);
+                }
+                continue;
+            }
+            match merged.get_mt_id(&mt_name) {
+                None => {
+                    let ms_ids : HashSet<usize> = msgs.iter().map(|ms_name| merged.get_ms_id(ms_name).unwrap()).collect();
+                    merged.add_mt(mt_name,ms_ids);
+                    types_origins.push( (sig_id,msgs) );
+                },
+                Some( merged_mt_id ) => {
+                    let (kept_sig_id,kept_msgs) = &types_origins[merged_mt_id];
+                    if *kept_msgs != msgs {
+                        conflicts.push( SignatureMergeConflict::TypeDefinition(mt_name,*kept_sig_id,kept_msgs.clone(),sig_id,msgs) );
+                    }
+                }
+            }
+        }
+    }
+    (merged,conflicts)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::{GeneralContext, merge_signatures, SignatureMergeConflict};
+
+    #[test]
+    fn merge_signatures_test() {
+        let mut sig1 = GeneralContext::new();
+        sig1.add_lf("l1".to_string());
+        sig1.add_msg("m1".to_string());
+        sig1.add_msg("m2".to_string());
+        sig1.add_mt("A".to_string(),hashset!{0,1});
+        let mut sig2 = GeneralContext::new();
+        sig2.add_lf("l2".to_string());
+        sig2.add_lf("l1".to_string());
+        sig2.add_msg("m2".to_string());
+        sig2.add_mt("A".to_string(),hashset!{0});
+        // ***
+        let (merged,conflicts) = merge_signatures(&vec![sig1,sig2]);
+        assert_eq!(merged.get_lf_num(),2);
+        assert_eq!(merged.get_ms_num(),2);
+        assert_eq!(merged.get_mt_messages(0).unwrap(),hashset!{0,1});
+        assert_eq!(conflicts.len(),1);
+        assert!(matches!(conflicts[0],SignatureMergeConflict::TypeDefinition(_,0,_,1,_)));
+    }
+
+}
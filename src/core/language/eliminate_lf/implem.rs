@@ -91,3 +91,44 @@ impl LifelineEliminable for Interaction {
 
 }
 
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::eliminate_lf::eliminable::LifelineEliminable;
+    use crate::core::language::syntax::action::BroadcastPrimitive;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+
+    fn message(orig_lf_id : Option<usize>, ms_id : usize, tar_lf_ids : Vec<usize>) -> Box<Interaction> {
+        Box::new(Interaction::Action(BroadcastPrimitive::new(orig_lf_id,
+                                                             MessageTypeExpression::Singleton(ms_id),
+                                                             tar_lf_ids)))
+    }
+
+    #[test]
+    fn eliminate_lifelines_in_loop_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        // loopW(l1 -- m1 -> l2)
+        let int = Interaction::Loop(vec![],message(Some(0),0,vec![1]));
+        // the loop is kept around what remains of its body
+        let to_eliminate : HashSet<usize> = hashset!{1};
+        assert_eq!(int.clone().eliminate_lifelines(&to_eliminate,&gen_ctx),
+                   Interaction::Loop(vec![],message(Some(0),0,vec![])));
+        let to_eliminate : HashSet<usize> = hashset!{0};
+        assert_eq!(int.clone().eliminate_lifelines(&to_eliminate,&gen_ctx),
+                   Interaction::Loop(vec![],message(None,0,vec![1])));
+        // and disappears along with it
+        let to_eliminate : HashSet<usize> = hashset!{0,1};
+        assert_eq!(int.eliminate_lifelines(&to_eliminate,&gen_ctx),
+                   Interaction::Empty);
+    }
+
+}
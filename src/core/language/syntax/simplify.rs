@@ -52,7 +52,7 @@ impl InteractionSyntacticSimplifier {
                 }
             },
             x => {
-                x
+                Interaction::Loop(cr1, Box::new(x))
             }
         }
     }
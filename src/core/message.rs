@@ -19,7 +19,7 @@ use std::collections::HashSet;
 use crate::core::general_context::GeneralContext;
 
 
-#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+#[derive(Clone, PartialEq, Debug, Eq, Hash, PartialOrd, Ord)]
 pub enum MessageTypeExpression {
     Singleton(usize), // ms_id
     NamedType(usize), // mt_id
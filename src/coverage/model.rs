@@ -0,0 +1,259 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashSet;
+
+use crate::core::execution::semantics::position::Position;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::action::BroadcastPrimitive;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::core::message::MessageTypeExpression;
+use crate::io::output::draw_messages::text_repr_message;
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum CoverageElementKind {
+    Action,
+    AltBranch,
+    LoopBody
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct CoverageElement {
+    pub kind : CoverageElementKind,
+    pub position : Position,
+    pub description : String,
+    pub leaves : HashSet<usize>
+}
+
+impl CoverageElement {
+    pub fn new(kind: CoverageElementKind, position: Position, description: String, leaves: HashSet<usize>) -> Self {
+        Self { kind, position, description, leaves }
+    }
+}
+
+/**
+Coverage is measured on the action leaves of the original interaction term,
+which are numbered in a left-to-right depth-first traversal.
+To keep track of which leaves the residual actions stem from after executions, each leaf
+of the tagged interaction has its message replaced by a dedicated message type
+that is declared in the tagged general context and contains exactly the same messages.
+**/
+#[derive(Clone)]
+pub struct InteractionCoverageModel {
+    pub elements : Vec<CoverageElement>,
+    pub leaves_num : usize,
+    pub tagged_gen_ctx : GeneralContext,
+    pub tagged_interaction : Interaction,
+    tags_offset : usize
+}
+
+impl InteractionCoverageModel {
+
+    pub fn new(gen_ctx : &GeneralContext,
+               interaction : &Interaction) -> InteractionCoverageModel {
+        let mut tagged_gen_ctx = gen_ctx.clone();
+        let tags_offset = gen_ctx.get_mt_num();
+        let mut elements : Vec<CoverageElement> = vec![];
+        let mut path : Vec<bool> = vec![];
+        let mut leaves_num : usize = 0;
+        let (tagged_interaction,_) = tag_interaction_rec(gen_ctx,
+                                                         &mut tagged_gen_ctx,
+                                                         interaction,
+                                                         &mut path,
+                                                         false,
+                                                         &mut leaves_num,
+                                                         &mut elements);
+        InteractionCoverageModel{elements,leaves_num,tagged_gen_ctx,tagged_interaction,tags_offset}
+    }
+
+    pub fn get_message_tags(&self, message : &MessageTypeExpression) -> HashSet<usize> {
+        match message {
+            MessageTypeExpression::Singleton(_) => {
+                hashset!{}
+            },
+            MessageTypeExpression::NamedType(mt_id) => {
+                if *mt_id >= self.tags_offset {
+                    hashset!{*mt_id - self.tags_offset}
+                } else {
+                    hashset!{}
+                }
+            },
+            MessageTypeExpression::Union(mte1,mte2) => {
+                let mut tags = self.get_message_tags(mte1);
+                tags.extend(self.get_message_tags(mte2));
+                tags
+            },
+            MessageTypeExpression::Intersection(mte1,mte2) => {
+                let mut tags = self.get_message_tags(mte1);
+                tags.extend(self.get_message_tags(mte2));
+                tags
+            },
+            MessageTypeExpression::SetMinus(mte1,mte2) => {
+                let mut tags = self.get_message_tags(mte1);
+                tags.extend(self.get_message_tags(mte2));
+                tags
+            }
+        }
+    }
+
+    pub fn tag_message(&self,
+                       message : &MessageTypeExpression,
+                       tags : &HashSet<usize>) -> MessageTypeExpression {
+        let mut tagged = message.clone();
+        let mut sorted_tags : Vec<&usize> = tags.iter().collect();
+        sorted_tags.sort();
+        for tag in sorted_tags {
+            tagged = MessageTypeExpression::Intersection(Box::new(tagged),
+                                                         Box::new(MessageTypeExpression::NamedType(self.tags_offset + *tag)));
+        }
+        tagged
+    }
+
+}
+
+
+fn path_as_position(path : &Vec<bool>) -> Position {
+    let mut position = Position::Epsilon(None);
+    for go_right in path.iter().rev() {
+        if *go_right {
+            position = Position::Right(Box::new(position));
+        } else {
+            position = Position::Left(Box::new(position));
+        }
+    }
+    position
+}
+
+fn action_description(gen_ctx : &GeneralContext, bp : &BroadcastPrimitive) -> String {
+    let ms_repr = text_repr_message(&bp.message_type,gen_ctx);
+    let targets_repr = match bp.targets.len() {
+        0 => {
+            "|".to_string()
+        },
+        1 => {
+            gen_ctx.get_lf_name(*bp.targets.get(0).unwrap()).unwrap()
+        },
+        _ => {
+            let names : Vec<String> = bp.targets.iter().map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap()).collect();
+            format!("({:})", names.join(","))
+        }
+    };
+    match bp.origin_on_emission {
+        None => {
+            format!("{:} -> {:}", ms_repr, targets_repr)
+        },
+        Some( origin ) => {
+            format!("{:} -- {:} -> {:}", gen_ctx.get_lf_name(origin).unwrap(), ms_repr, targets_repr)
+        }
+    }
+}
+
+fn tag_interaction_rec(gen_ctx : &GeneralContext,
+                       tagged_gen_ctx : &mut GeneralContext,
+                       interaction : &Interaction,
+                       path : &mut Vec<bool>,
+                       is_alt_branch : bool,
+                       leaves_num : &mut usize,
+                       elements : &mut Vec<CoverageElement>) -> (Interaction,HashSet<usize>) {
+    // the element is pushed before its sub-elements so that elements are listed from top to bottom
+    let alt_branch_index = if is_alt_branch {
+        elements.push( CoverageElement::new(CoverageElementKind::AltBranch,
+                                            path_as_position(path),
+                                            "alt branch".to_string(),
+                                            hashset!{}) );
+        Some( elements.len() - 1 )
+    } else {
+        None
+    };
+    // ***
+    let (tagged,leaves) = match interaction {
+        Interaction::Empty => {
+            (Interaction::Empty,hashset!{})
+        },
+        Interaction::Action(bp) => {
+            let leaf_id = *leaves_num;
+            *leaves_num += 1;
+            let mt_id = tagged_gen_ctx.add_mt(format!("#leaf{:}",leaf_id),
+                                              bp.message_type.resolve(gen_ctx));
+            elements.push( CoverageElement::new(CoverageElementKind::Action,
+                                                path_as_position(path),
+                                                action_description(gen_ctx,bp),
+                                                hashset!{leaf_id}) );
+            let tagged_bp = BroadcastPrimitive::new(bp.origin_on_emission,
+                                                    MessageTypeExpression::NamedType(mt_id),
+                                                    bp.targets.clone());
+            (Interaction::Action(tagged_bp),hashset!{leaf_id})
+        },
+        Interaction::Loop(cr, i1) => {
+            elements.push( CoverageElement::new(CoverageElementKind::LoopBody,
+                                                path_as_position(path),
+                                                "loop body".to_string(),
+                                                hashset!{}) );
+            let loop_index = elements.len() - 1;
+            path.push(false);
+            let (tagged_i1,leaves1) = tag_interaction_rec(gen_ctx,tagged_gen_ctx,i1,path,false,leaves_num,elements);
+            path.pop();
+            elements.get_mut(loop_index).unwrap().leaves = leaves1.clone();
+            (Interaction::Loop(cr.clone(),Box::new(tagged_i1)),leaves1)
+        },
+        Interaction::Alt(i1, i2) => {
+            let i1_is_branch = match **i1 {
+                Interaction::Alt(_,_) => {false},
+                _ => {true}
+            };
+            let i2_is_branch = match **i2 {
+                Interaction::Alt(_,_) => {false},
+                _ => {true}
+            };
+            path.push(false);
+            let (tagged_i1,mut leaves) = tag_interaction_rec(gen_ctx,tagged_gen_ctx,i1,path,i1_is_branch,leaves_num,elements);
+            path.pop();
+            path.push(true);
+            let (tagged_i2,leaves2) = tag_interaction_rec(gen_ctx,tagged_gen_ctx,i2,path,i2_is_branch,leaves_num,elements);
+            path.pop();
+            leaves.extend(leaves2);
+            (Interaction::Alt(Box::new(tagged_i1),Box::new(tagged_i2)),leaves)
+        },
+        Interaction::CoReg(cr, i1, i2) => {
+            path.push(false);
+            let (tagged_i1,mut leaves) = tag_interaction_rec(gen_ctx,tagged_gen_ctx,i1,path,false,leaves_num,elements);
+            path.pop();
+            path.push(true);
+            let (tagged_i2,leaves2) = tag_interaction_rec(gen_ctx,tagged_gen_ctx,i2,path,false,leaves_num,elements);
+            path.pop();
+            leaves.extend(leaves2);
+            (Interaction::CoReg(cr.clone(),Box::new(tagged_i1),Box::new(tagged_i2)),leaves)
+        },
+        Interaction::Sync(sync, i1, i2) => {
+            path.push(false);
+            let (tagged_i1,mut leaves) = tag_interaction_rec(gen_ctx,tagged_gen_ctx,i1,path,false,leaves_num,elements);
+            path.pop();
+            path.push(true);
+            let (tagged_i2,leaves2) = tag_interaction_rec(gen_ctx,tagged_gen_ctx,i2,path,false,leaves_num,elements);
+            path.pop();
+            leaves.extend(leaves2);
+            (Interaction::Sync(sync.clone(),Box::new(tagged_i1),Box::new(tagged_i2)),leaves)
+        }
+    };
+    // ***
+    if let Some(index) = alt_branch_index {
+        elements.get_mut(index).unwrap().leaves = leaves.clone();
+    }
+    (tagged,leaves)
+}
+
@@ -0,0 +1,202 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashSet;
+
+use crate::core::execution::semantics::position::position_as_text;
+use crate::coverage::model::{CoverageElementKind, InteractionCoverageModel};
+
+
+/**
+Aggregates, for every coverage element of the model, the number of multi-traces
+accepted via at least one path executing an action leaf of this element.
+**/
+pub struct InteractionCoverageReport {
+    pub traces_num : u32,
+    pub accepted_num : u32,
+    pub elements_hits : Vec<u32>,
+    pub leaves_hits : Vec<u32>
+}
+
+impl InteractionCoverageReport {
+
+    pub fn new(model : &InteractionCoverageModel) -> InteractionCoverageReport {
+        InteractionCoverageReport{traces_num:0,
+            accepted_num:0,
+            elements_hits:vec![0;model.elements.len()],
+            leaves_hits:vec![0;model.leaves_num]}
+    }
+
+    pub fn add_trace_coverage(&mut self,
+                              model : &InteractionCoverageModel,
+                              covered_leaves : &Option<HashSet<usize>>) {
+        self.traces_num += 1;
+        if let Some(covered) = covered_leaves {
+            self.accepted_num += 1;
+            for leaf_id in covered {
+                *self.leaves_hits.get_mut(*leaf_id).unwrap() += 1;
+            }
+            for (elt_id,element) in model.elements.iter().enumerate() {
+                if !element.leaves.is_disjoint(covered) {
+                    *self.elements_hits.get_mut(elt_id).unwrap() += 1;
+                }
+            }
+        }
+    }
+
+    pub fn get_covered_leaves(&self) -> Vec<bool> {
+        self.leaves_hits.iter().map(|hits| *hits > 0).collect()
+    }
+
+    pub fn as_text_lines(&self, model : &InteractionCoverageModel) -> Vec<String> {
+        let mut lines = vec![];
+        lines.push( format!("accepted multi-traces : {:}/{:}", self.accepted_num, self.traces_num) );
+        for (kind,title) in [(CoverageElementKind::Action,"ACTIONS"),
+                             (CoverageElementKind::AltBranch,"ALT BRANCHES"),
+                             (CoverageElementKind::LoopBody,"LOOP BODIES")] {
+            let mut kind_lines = vec![];
+            let mut observable_num : u32 = 0;
+            let mut covered_num : u32 = 0;
+            for (elt_id,element) in model.elements.iter().enumerate() {
+                if element.kind != kind {
+                    continue;
+                }
+                let position_repr = position_as_text(&element.position);
+                if element.leaves.is_empty() {
+                    kind_lines.push( format!("  [n/a] {:} at position {:} (no action to observe)",
+                                             element.description, position_repr) );
+                } else {
+                    observable_num += 1;
+                    let hits = *self.elements_hits.get(elt_id).unwrap();
+                    if hits > 0 {
+                        covered_num += 1;
+                    }
+                    let mark = if hits > 0 {"[x]"} else {"[ ]"};
+                    kind_lines.push( format!("  {:} {:} at position {:} covered by {:} multi-trace(s)",
+                                             mark, element.description, position_repr, hits) );
+                }
+            }
+            if observable_num > 0 || !kind_lines.is_empty() {
+                lines.push( "".to_string() );
+                lines.push( format!("{:} COVERAGE : {:}/{:}", title, covered_num, observable_num) );
+                lines.append(&mut kind_lines);
+            }
+        }
+        lines
+    }
+
+}
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::core::execution::trace::multitrace::MultiTrace;
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::BroadcastPrimitive;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use crate::coverage::model::{CoverageElementKind, InteractionCoverageModel};
+    use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+    use crate::process::ana::interface::make_analysis_manager;
+    use crate::process::ana::loggers::plog::logger::AnalysisAcceptingPathsLogger;
+    use super::InteractionCoverageReport;
+
+    fn message(orig_lf_id : usize, ms_id : usize, tar_lf_id : usize) -> Box<Interaction> {
+        Box::new(Interaction::Action(BroadcastPrimitive::new(Some(orig_lf_id),
+                                                             MessageTypeExpression::Singleton(ms_id),
+                                                             vec![tar_lf_id])))
+    }
+
+    fn action(lf_id : usize, act_kind : TraceActionKind, ms_id : usize) -> TraceAction {
+        TraceAction::new(lf_id,act_kind,MessageTypeExpression::Singleton(ms_id))
+    }
+
+    fn get_covered_leaves(gen_ctx : &GeneralContext,
+                          int : &Interaction,
+                          model : &InteractionCoverageModel,
+                          multi_trace : &MultiTrace) -> Option<HashSet<usize>> {
+        let mut ana_opts = HibouAnalyzeOptions::default();
+        ana_opts.goal = None;
+        ana_opts.loggers.push( Box::new(AnalysisAcceptingPathsLogger::new(model.clone(),1)) );
+        let (mut analysis_manager,init_node) = make_analysis_manager(gen_ctx,
+                                                                     &CoLocalizations::get_discrete_partition(2),
+                                                                     multi_trace,
+                                                                     int,
+                                                                     ana_opts);
+        analysis_manager.start_process(init_node);
+        analysis_manager.get_logger(0).unwrap()
+            .as_any().downcast_ref::<AnalysisAcceptingPathsLogger>().unwrap()
+            .covered_leaves.clone()
+    }
+
+    fn get_element_hits(model : &InteractionCoverageModel,
+                        report : &InteractionCoverageReport,
+                        kind : CoverageElementKind,
+                        leaf_id : usize) -> u32 {
+        let elt_id = model.elements.iter()
+            .position(|element| element.kind == kind && element.leaves.contains(&leaf_id))
+            .unwrap();
+        *report.elements_hits.get(elt_id).unwrap()
+    }
+
+    #[test]
+    fn alt_loop_coverage_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        // seq(alt(l1 -- m1 -> l2, l1 -- m2 -> l2), loopW(l2 -- m1 -> l1))
+        let int = Interaction::CoReg(vec![],
+                                     Box::new(Interaction::Alt(message(0,0,1),message(0,1,1))),
+                                     Box::new(Interaction::Loop(vec![],message(1,0,0))));
+        let model = InteractionCoverageModel::new(&gen_ctx,&int);
+        assert_eq!(model.leaves_num,3);
+        // ***
+        let multi_traces : Vec<MultiTrace> = vec![
+            // left alt branch without loop iteration
+            vec![vec![action(0,TraceActionKind::Emission,0)],
+                 vec![action(1,TraceActionKind::Reception,0)]],
+            // left alt branch with one loop iteration
+            vec![vec![action(0,TraceActionKind::Emission,0),action(0,TraceActionKind::Reception,0)],
+                 vec![action(1,TraceActionKind::Reception,0),action(1,TraceActionKind::Emission,0)]],
+            // not accepted
+            vec![vec![action(0,TraceActionKind::Emission,1)],
+                 vec![action(1,TraceActionKind::Reception,0)]]
+        ];
+        let mut report = InteractionCoverageReport::new(&model);
+        for multi_trace in &multi_traces {
+            let covered = get_covered_leaves(&gen_ctx,&int,&model,multi_trace);
+            report.add_trace_coverage(&model,&covered);
+        }
+        // ***
+        assert_eq!(report.traces_num,3);
+        assert_eq!(report.accepted_num,2);
+        assert_eq!(report.leaves_hits,vec![2,0,1]);
+        assert_eq!(report.get_covered_leaves(),vec![true,false,true]);
+        assert_eq!(get_element_hits(&model,&report,CoverageElementKind::Action,0),2);
+        assert_eq!(get_element_hits(&model,&report,CoverageElementKind::Action,1),0);
+        assert_eq!(get_element_hits(&model,&report,CoverageElementKind::AltBranch,0),2);
+        assert_eq!(get_element_hits(&model,&report,CoverageElementKind::AltBranch,1),0);
+        assert_eq!(get_element_hits(&model,&report,CoverageElementKind::LoopBody,2),1);
+    }
+}
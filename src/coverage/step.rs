@@ -0,0 +1,105 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashSet;
+
+use crate::core::execution::semantics::execute::execute_interaction;
+use crate::core::execution::semantics::position::Position;
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::language::syntax::action::BroadcastPrimitive;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::coverage::model::InteractionCoverageModel;
+
+
+/**
+Executes, on a tagged interaction, the step which consumes the given concrete action at the given position,
+this position being computed on the untagged interaction which has the same structure.
+Returns the action leaves (of the original interaction) executed by the step and the resulting tagged interaction.
+**/
+pub fn execute_tagged_step(model : &InteractionCoverageModel,
+                           tagged_interaction : &Interaction,
+                           position : &Position,
+                           action : &TraceAction) -> (HashSet<usize>,Interaction) {
+    let step_leaves = get_leaves_at_position(model,tagged_interaction,position);
+    // the executed action carries the tags so that the residual receptions keep them
+    let tagged_action = TraceAction::new(action.lf_id,
+                                         action.act_kind,
+                                         model.tag_message(&action.message,&step_leaves));
+    let new_int = execute_interaction(tagged_interaction,
+                                      position,
+                                      &tagged_action,
+                                      &model.tagged_gen_ctx);
+    (step_leaves,new_int)
+}
+
+/**
+Returns the action leaves (of the original interaction) from which stem
+the actions designated by the given position of a tagged interaction.
+**/
+pub fn get_leaves_at_position(model : &InteractionCoverageModel,
+                              tagged_interaction : &Interaction,
+                              position : &Position) -> HashSet<usize> {
+    let mut leaves : HashSet<usize> = hashset!{};
+    for bp in get_actions_at_position(tagged_interaction,position) {
+        leaves.extend( model.get_message_tags(&bp.message_type) );
+    }
+    leaves
+}
+
+pub fn get_actions_at_position<'a>(interaction : &'a Interaction,
+                               position : &Position) -> Vec<&'a BroadcastPrimitive> {
+    match (position,interaction) {
+        (Position::Epsilon(_),Interaction::Action(bp)) => {
+            vec![bp]
+        },
+        (Position::Left(sub_pos),Interaction::Alt(i1,_)) => {
+            get_actions_at_position(i1,sub_pos)
+        },
+        (Position::Left(sub_pos),Interaction::CoReg(_,i1,_)) => {
+            get_actions_at_position(i1,sub_pos)
+        },
+        (Position::Left(sub_pos),Interaction::Sync(_,i1,_)) => {
+            get_actions_at_position(i1,sub_pos)
+        },
+        (Position::Left(sub_pos),Interaction::Loop(_,i1)) => {
+            get_actions_at_position(i1,sub_pos)
+        },
+        (Position::Right(sub_pos),Interaction::Alt(_,i2)) => {
+            get_actions_at_position(i2,sub_pos)
+        },
+        (Position::Right(sub_pos),Interaction::CoReg(_,_,i2)) => {
+            get_actions_at_position(i2,sub_pos)
+        },
+        (Position::Right(sub_pos),Interaction::Sync(_,_,i2)) => {
+            get_actions_at_position(i2,sub_pos)
+        },
+        (Position::Both(sub_pos1,sub_pos2),Interaction::Alt(i1,i2)) => {
+            let mut got = get_actions_at_position(i1,sub_pos1);
+            got.extend(get_actions_at_position(i2,sub_pos2));
+            got
+        },
+        (Position::Both(sub_pos1,sub_pos2),Interaction::Sync(_,i1,i2)) => {
+            let mut got = get_actions_at_position(i1,sub_pos1);
+            got.extend(get_actions_at_position(i2,sub_pos2));
+            got
+        },
+        _ => {
+            panic!("position {:?} does not exist in {:?}", position, interaction);
+        }
+    }
+}
+
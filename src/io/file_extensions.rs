@@ -15,6 +15,9 @@ limitations under the License.
 */
 
 
+use std::path::Path;
+
+
 pub static HIBOU_MODEL_FILE_EXTENSION : &'static str = "hsf";
 
 pub static HIBOU_TRACE_FILE_EXTENSION : &'static str = "htf";
@@ -26,3 +29,72 @@ pub static HIBOU_CONFIGURATION_FILE_EXTENSION : &'static str = "hcf";
 pub static HIBOU_INTERACTION_FILE_EXTENSION : &'static str = "hif";
 
 
+
+pub static HIBOU_WITNESS_FILE_EXTENSION : &'static str = "hwf";
+
+pub static HIBOU_MAPPING_FILE_EXTENSION : &'static str = "hmf";
+
+
+
+pub static HIBOU_JSON_FILE_EXTENSION : &'static str = "json";
+
+pub static HIBOU_CSV_FILE_EXTENSION : &'static str = "csv";
+
+pub static HIBOU_JSON_LINES_FILE_EXTENSION : &'static str = "jsonl";
+
+
+/**
+Format of the files written by the commands :
+either the native syntax (.hsf, .hif, .htf or .hwf) or JSON.
+Files are read back according to their extension.
+**/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HibouFileFormat {
+    Native,
+    Json
+}
+
+impl HibouFileFormat {
+
+    /**
+    The values of the 'format' options are restricted to 'hibou' and 'json' by the command line interface,
+    the native format being the default one when the option is not provided.
+    **/
+    pub fn from_option_value(value : Option<&str>) -> HibouFileFormat {
+        match value {
+            None | Some("hibou") => {
+                HibouFileFormat::Native
+            },
+            Some("json") => {
+                HibouFileFormat::Json
+            },
+            Some( other ) => {
+                panic!("unknown file format '{}' : expected either hibou or json", other);
+            }
+        }
+    }
+
+    pub fn get_extension(&self, native_extension : &'static str) -> &'static str {
+        match self {
+            HibouFileFormat::Native => {
+                native_extension
+            },
+            HibouFileFormat::Json => {
+                HIBOU_JSON_FILE_EXTENSION
+            }
+        }
+    }
+
+}
+
+
+pub fn is_json_file_path(file_path : &Path) -> bool {
+    match file_path.extension() {
+        None => {
+            false
+        },
+        Some( extension ) => {
+            extension == HIBOU_JSON_FILE_EXTENSION
+        }
+    }
+}
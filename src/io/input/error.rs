@@ -22,6 +22,7 @@ pub enum HibouParsingError {
     FileFormatError(String,String),
     FileError(String),
     MatchError(String),
+    JsonStructureError(String),
     // ***
     HsfSetupError(String),
     HcfSetupError(String),
@@ -50,6 +51,9 @@ impl fmt::Display for HibouParsingError {
             HibouParsingError::MatchError(sub_e) => {
                 return write!(f, "{}", format!("error while parsing SD string : {:}", sub_e));
             },
+            HibouParsingError::JsonStructureError(sub_e) => {
+                return write!(f, "{}", format!("error while parsing .json file : {:}", sub_e));
+            },
             // ***
             HibouParsingError::HsfSetupError(sub_e) => {
                 return write!(f, "{}", format!("error while parsing setup section of .hsf file : {:}", sub_e));
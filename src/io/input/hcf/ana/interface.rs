@@ -32,6 +32,7 @@ use crate::io::input::hcf::parser::{HcfParser,Rule};
 use crate::core::general_context::GeneralContext;
 use crate::io::file_extensions::HIBOU_CONFIGURATION_FILE_EXTENSION;
 use crate::io::input::error::HibouParsingError;
+use crate::io::input::hcf::draw::options::find_drawing_options;
 use crate::io::input::hcf::ana::options::{HibouAnalyzeOptions, parse_analyze_options};
 
 pub fn parse_hcf_file_for_ana(gen_ctx : &GeneralContext,
@@ -82,6 +83,15 @@ fn parse_conf_pair_for_ana(gen_ctx : &GeneralContext,
     let mut got_section_ana_options   : bool = false;
     let mut ana_options = HibouAnalyzeOptions::default();
 
+    let draw_conf = match find_drawing_options(&conf_pair) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( got_conf ) => {
+            got_conf
+        }
+    };
+
     let mut contents = conf_pair.into_inner();
 
     while let Some(current_pair) = contents.next() {
@@ -92,7 +102,7 @@ fn parse_conf_pair_for_ana(gen_ctx : &GeneralContext,
                 }
                 got_section_ana_options = true;
                 // ***
-                match parse_analyze_options(gen_ctx, current_pair, file_name) {
+                match parse_analyze_options(gen_ctx, current_pair, file_name,&draw_conf) {
                     Err(e) => {
                         return Err(e);
                     },
@@ -104,6 +114,9 @@ fn parse_conf_pair_for_ana(gen_ctx : &GeneralContext,
             Rule::EXPLORE_OPTION_SECTION => {
                 // nothing
             },
+            Rule::DRAWING_OPTION_SECTION => {
+                // already handled
+            },
             _ => {
                 panic!("what rule then ? : {:?}", current_pair.as_rule() );
             }
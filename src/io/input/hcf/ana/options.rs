@@ -16,14 +16,16 @@ limitations under the License.
 
 
 use graph_process_manager_core::delegate::priorities::GenericProcessPriorities;
-use graph_process_manager_core::handler::filter::AbstractFilter;
 use graph_process_manager_core::manager::logger::AbstractProcessLogger;
 use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
 use pest::iterators::Pair;
 use crate::core::general_context::GeneralContext;
 
 use crate::io::input::error::HibouParsingError;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
 use crate::io::input::hcf::loggers::graphviz::parse_graphic_logger;
+use crate::io::input::hcf::loggers::html::parse_html_logger;
+use crate::io::input::hcf::loggers::lts::parse_lts_logger;
 use crate::io::input::hcf::loggers::tracegen::parse_tracegen_logger;
 
 
@@ -34,8 +36,7 @@ use crate::io::input::hcf::parser::{HcfParser,Rule};
 
 
 use crate::process::ana::conf::AnalysisConfig;
-use crate::process::ana::filter::elim::AnalysisFilterEliminationKind;
-use crate::process::ana::filter::filter::{AnalysisFilter, AnalysisFilterCriterion};
+use crate::process::ana::filter::filter::AnalysisFilter;
 use crate::process::ana::param::anakind::{SimulationActionCriterion, SimulationConfiguration, SimulationLoopCriterion};
 use crate::process::ana::param::param::AnalysisParameterization;
 use crate::process::ana::priorities::AnalysisPriorities;
@@ -45,22 +46,24 @@ use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
 pub struct HibouAnalyzeOptions {
     pub loggers : Vec<Box< dyn AbstractProcessLogger<AnalysisConfig>>>,
     pub strategy : QueueSearchStrategy,
-    pub filters : Vec<Box<dyn AbstractFilter<AnalysisFilterCriterion,AnalysisFilterEliminationKind>>>,
+    pub filters : Vec<AnalysisFilter>,
     pub priorities : GenericProcessPriorities<AnalysisPriorities>,
     pub use_memoization : bool,
     pub goal : Option<AnalysisGlobalVerdict>,
+    pub ambiguity : Option<u32>,
     pub ana_param : AnalysisParameterization
 }
 
 impl HibouAnalyzeOptions {
     pub fn new(loggers : Vec<Box< dyn AbstractProcessLogger<AnalysisConfig>>>,
                strategy : QueueSearchStrategy,
-               filters : Vec<Box<dyn AbstractFilter<AnalysisFilterCriterion,AnalysisFilterEliminationKind>>>,
+               filters : Vec<AnalysisFilter>,
                priorities : GenericProcessPriorities<AnalysisPriorities>,
                ana_param : AnalysisParameterization,
                use_memoization : bool,
-               goal : Option<AnalysisGlobalVerdict>) -> HibouAnalyzeOptions {
-        HibouAnalyzeOptions{loggers,strategy,filters,priorities,use_memoization,goal,ana_param}
+               goal : Option<AnalysisGlobalVerdict>,
+               ambiguity : Option<u32>) -> HibouAnalyzeOptions {
+        HibouAnalyzeOptions{loggers,strategy,filters,priorities,use_memoization,goal,ambiguity,ana_param}
     }
 
     pub fn default() -> HibouAnalyzeOptions {
@@ -72,23 +75,61 @@ impl HibouAnalyzeOptions {
             GenericProcessPriorities::new(AnalysisPriorities::default(),false),
             default_param,
             true,
-            Some(AnalysisGlobalVerdict::WeakPass)
+            Some(AnalysisGlobalVerdict::WeakPass),
+            None
         )
     }
+
+    /**
+    Returns a copy of the options without their loggers,
+    so that utilities which analyze many multi-traces in a row can parse the options once
+    and provide a fresh copy to each analysis without logging every one of them.
+    **/
+    pub fn clone_without_loggers(&self) -> HibouAnalyzeOptions {
+        let strategy = match self.strategy {
+            QueueSearchStrategy::BFS => {
+                QueueSearchStrategy::BFS
+            },
+            QueueSearchStrategy::DFS => {
+                QueueSearchStrategy::DFS
+            },
+            QueueSearchStrategy::HCS => {
+                QueueSearchStrategy::HCS
+            }
+        };
+        let specific = &self.priorities.specific;
+        let priorities = GenericProcessPriorities::new(AnalysisPriorities::new(specific.emission,
+                                                                               specific.reception,
+                                                                               specific.in_loop,
+                                                                               specific.simu),
+                                                       self.priorities.randomize);
+        let ana_param = AnalysisParameterization::new(self.ana_param.use_simulation.clone(),
+                                                      self.ana_param.use_locana);
+        HibouAnalyzeOptions::new(vec![],
+                                 strategy,
+                                 self.filters.clone(),
+                                 priorities,
+                                 ana_param,
+                                 self.use_memoization,
+                                 self.goal.clone(),
+                                 self.ambiguity)
+    }
 }
 
 
 pub fn parse_analyze_options(gen_ctx : &GeneralContext,
                              option_pair : Pair<Rule>,
-                             file_name : &str) -> Result<HibouAnalyzeOptions,HibouParsingError> {
+                             file_name : &str,
+                             draw_conf : &DrawingConfiguration) -> Result<HibouAnalyzeOptions,HibouParsingError> {
     let mut loggers : Vec<Box< dyn AbstractProcessLogger<AnalysisConfig>>> = Vec::new();
     let mut strategy : QueueSearchStrategy = QueueSearchStrategy::BFS;
-    let mut filters : Vec<Box<dyn AbstractFilter<AnalysisFilterCriterion,AnalysisFilterEliminationKind>>> = Vec::new();
+    let mut filters : Vec<AnalysisFilter> = Vec::new();
     let mut priorities : GenericProcessPriorities<AnalysisPriorities> = GenericProcessPriorities::new(AnalysisPriorities::default(),false);
     let mut sim_conf : Option<SimulationConfiguration> = None;
     let mut use_local_analysis = true;
     let mut use_memoization = true;
     let mut goal = Some(AnalysisGlobalVerdict::WeakPass);
+    let mut ambiguity : Option<u32> = None;
     // ***
     for option_decl_pair in option_pair.into_inner() {
         match option_decl_pair.as_rule() {
@@ -100,7 +141,7 @@ pub fn parse_analyze_options(gen_ctx : &GeneralContext,
                     logger_id += 1;
                     match logger_kind_pair.as_rule() {
                         Rule::OPTION_GRAPHIC_LOGGER => {
-                            let glogger = parse_graphic_logger(logger_id,file_name,logger_kind_pair);
+                            let glogger = parse_graphic_logger(logger_id,file_name,draw_conf,logger_kind_pair);
                             loggers.push( Box::new(glogger));
                         },
                         Rule::OPTION_TRACEGEN_LOGGER => {
@@ -113,6 +154,14 @@ pub fn parse_analyze_options(gen_ctx : &GeneralContext,
                                 }
                             }
                         },
+                        Rule::OPTION_LTS_LOGGER => {
+                            let llogger = parse_lts_logger(logger_id,file_name,logger_kind_pair);
+                            loggers.push( Box::new(llogger));
+                        },
+                        Rule::OPTION_HTML_LOGGER => {
+                            let hlogger = parse_html_logger(logger_id,file_name,draw_conf,logger_kind_pair);
+                            loggers.push( Box::new(hlogger));
+                        },
                         _ => {
                             panic!("what rule then ? : {:?}", logger_kind_pair.as_rule() );
                         }
@@ -220,12 +269,32 @@ pub fn parse_analyze_options(gen_ctx : &GeneralContext,
                 match goal_pair.as_rule() {
                     Rule::OPTION_GOAL_pass => {
                         goal = Some( AnalysisGlobalVerdict::Pass );
+                        ambiguity = None;
                     },
                     Rule::OPTION_GOAL_weakpass => {
                         goal = Some( AnalysisGlobalVerdict::WeakPass );
+                        ambiguity = None;
                     },
                     Rule::OPTION_GOAL_none => {
                         goal = None;
+                        ambiguity = None;
+                    },
+                    Rule::OPTION_GOAL_ambiguity => {
+                        // the analysis is pursued after the first accepting path is found
+                        goal = None;
+                        match goal_pair.into_inner().next() {
+                            None => {
+                                ambiguity = Some(10);
+                            },
+                            Some( max_pair ) => {
+                                let content_str : String = max_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+                                let max_num = content_str.parse::<u32>().unwrap();
+                                if max_num == 0 {
+                                    return Err( HibouParsingError::HcfSetupError("the maximum number of accepting paths of the Ambiguity goal must be positive".to_string()) );
+                                }
+                                ambiguity = Some( max_num );
+                            }
+                        }
                     },
                     _ => {
                         panic!("what rule then ? : {:?}", goal_pair.as_rule() );
@@ -239,32 +308,32 @@ pub fn parse_analyze_options(gen_ctx : &GeneralContext,
     }
     // ***
     let param = AnalysisParameterization::new(sim_conf,use_local_analysis);
-    let hoptions = HibouAnalyzeOptions::new(loggers,strategy,filters,priorities,param,use_memoization,goal);
+    let hoptions = HibouAnalyzeOptions::new(loggers,strategy,filters,priorities,param,use_memoization,goal,ambiguity);
     return Ok(hoptions);
 }
 
 
-fn parse_filters(filters_decl_pair : Pair<Rule>) -> Result<Vec<Box<dyn AbstractFilter<AnalysisFilterCriterion,AnalysisFilterEliminationKind>>>,HibouParsingError> {
-    let mut filters : Vec<Box<dyn AbstractFilter<AnalysisFilterCriterion,AnalysisFilterEliminationKind>>> = Vec::new();
+fn parse_filters(filters_decl_pair : Pair<Rule>) -> Result<Vec<AnalysisFilter>,HibouParsingError> {
+    let mut filters : Vec<AnalysisFilter> = Vec::new();
     for filter_pair in filters_decl_pair.into_inner() {
         match filter_pair.as_rule() {
             Rule::OPTION_FILTER_MAX_DEPTH => {
                 let content = filter_pair.into_inner().next().unwrap();
                 let content_str : String = content.as_str().chars().filter(|c| !c.is_whitespace()).collect();
                 let my_val : u32 = content_str.parse::<u32>().unwrap();
-                filters.push(Box::new(AnalysisFilter::MaxProcessDepth(my_val)));
+                filters.push(AnalysisFilter::MaxProcessDepth(my_val));
             },
             Rule::OPTION_FILTER_MAX_LOOP_DEPTH  => {
                 let content = filter_pair.into_inner().next().unwrap();
                 let content_str : String = content.as_str().chars().filter(|c| !c.is_whitespace()).collect();
                 let my_val : u32 = content_str.parse::<u32>().unwrap();
-                filters.push(Box::new(AnalysisFilter::MaxLoopInstanciation(my_val)));
+                filters.push(AnalysisFilter::MaxLoopInstanciation(my_val));
             },
             Rule::OPTION_FILTER_MAX_NODE_NUMBER  => {
                 let content = filter_pair.into_inner().next().unwrap();
                 let content_str : String = content.as_str().chars().filter(|c| !c.is_whitespace()).collect();
                 let my_val : u32 = content_str.parse::<u32>().unwrap();
-                filters.push(Box::new(AnalysisFilter::MaxNodeNumber(my_val)));
+                filters.push(AnalysisFilter::MaxNodeNumber(my_val));
             },
             _ => {
                 panic!("what rule then ? : {:?}", filter_pair.as_rule() );
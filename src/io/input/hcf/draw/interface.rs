@@ -0,0 +1,91 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fs;
+use std::path::Path;
+
+
+#[allow(unused_imports)]
+use pest::Parser;
+#[allow(unused_imports)]
+use crate::io::input::hcf::parser::{HcfParser,Rule};
+
+
+use crate::io::file_extensions::HIBOU_CONFIGURATION_FILE_EXTENSION;
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::hcf::draw::options::find_drawing_options;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
+
+
+pub fn parse_hcf_file_for_drawing(file_path : &str) -> Result<DrawingConfiguration,HibouParsingError> {
+    let path_object = Path::new(file_path);
+    let file_extension : &str = path_object.extension().unwrap().to_str().unwrap();
+    if file_extension != HIBOU_CONFIGURATION_FILE_EXTENSION {
+        return Err( HibouParsingError::FileFormatError(file_extension.to_string(),HIBOU_CONFIGURATION_FILE_EXTENSION.to_string()));
+    }
+    match fs::read_to_string(file_path) {
+        Ok( unparsed_hcf_str ) => {
+            return parse_hcf_string_for_drawing(unparsed_hcf_str);
+        },
+        Err(e) => {
+            return Err( HibouParsingError::FileError(e.to_string()) );
+        }
+    }
+}
+
+
+pub fn parse_hcf_string_for_drawing(hcf_string : String) -> Result<DrawingConfiguration,HibouParsingError> {
+    match HcfParser::parse(Rule::HCF_PEST_FILE, &hcf_string) {
+        Ok( ref mut got_pair ) => {
+            let conf_pair = got_pair.next().unwrap();
+            match conf_pair.as_rule() {
+                Rule::HIBOU_CONFIGURATION => {
+                    return find_drawing_options(&conf_pair);
+                },
+                _ => {
+                    panic!("what rule then ? : {:?}", conf_pair.as_rule() );
+                }
+            }
+        },
+        Err(e) => {
+            return Err( HibouParsingError::MatchError(e.to_string()) );
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::io::output::draw_commons::drawing_configuration::{DrawingConfiguration, DrawingPalette};
+    use super::parse_hcf_string_for_drawing;
+
+    #[test]
+    fn drawing_option_section_test() {
+        let hcf = "@drawing_option{theme = dark; palette = [message = #ff8000, background = #000000]; scale = 0.5; show_types = false}\n@explore_option{strategy = DFS}".to_string();
+        let draw_conf = parse_hcf_string_for_drawing(hcf).unwrap();
+        assert_eq!(draw_conf.palette.message, [255,128,0]);
+        assert_eq!(draw_conf.palette.background, [0,0,0]);
+        assert_eq!(draw_conf.palette.lifeline, DrawingPalette::dark().lifeline);
+        assert_eq!(draw_conf.scale, 0.5);
+        assert!(!draw_conf.show_types);
+        // ***
+        let without_section = parse_hcf_string_for_drawing("@explore_option{strategy = DFS}".to_string()).unwrap();
+        assert_eq!(without_section, DrawingConfiguration::default());
+        // ***
+        assert!(parse_hcf_string_for_drawing("@drawing_option{font_size = 0}".to_string()).is_err());
+    }
+}
@@ -0,0 +1,190 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use pest::iterators::Pair;
+
+#[allow(unused_imports)]
+use pest::Parser;
+#[allow(unused_imports)]
+use crate::io::input::hcf::parser::{HcfParser,Rule};
+
+use crate::io::input::error::HibouParsingError;
+use crate::io::output::draw_commons::drawing_configuration::{DrawingConfiguration, DrawingPalette};
+
+
+/**
+Looks for the (optional) '@drawing_option' section of a parsed configuration file.
+If it is absent, the default drawing configuration is returned.
+**/
+pub fn find_drawing_options(conf_pair : &Pair<Rule>) -> Result<DrawingConfiguration,HibouParsingError> {
+    let mut got_section_draw_options : bool = false;
+    let mut draw_conf = DrawingConfiguration::default();
+    for current_pair in conf_pair.clone().into_inner() {
+        match current_pair.as_rule() {
+            Rule::DRAWING_OPTION_SECTION => {
+                if got_section_draw_options {
+                    return Err( HibouParsingError::HcfSetupError("several '@drawing_option' sections declared".to_string()));
+                }
+                got_section_draw_options = true;
+                // ***
+                match parse_drawing_options(current_pair) {
+                    Err(e) => {
+                        return Err(e);
+                    },
+                    Ok( got_conf ) => {
+                        draw_conf = got_conf;
+                    }
+                }
+            },
+            _ => {
+                // nothing
+            }
+        }
+    }
+    return Ok(draw_conf);
+}
+
+
+pub fn parse_drawing_options(option_pair : Pair<Rule>) -> Result<DrawingConfiguration,HibouParsingError> {
+    let mut draw_conf = DrawingConfiguration::default();
+    // the theme is applied first so that the palette declarations can override some of its colors
+    let mut palette_overrides : Vec<Pair<Rule>> = Vec::new();
+    for option_decl_pair in option_pair.into_inner() {
+        match option_decl_pair.as_rule() {
+            Rule::DRAWING_OPTION_THEME => {
+                let inner = option_decl_pair.into_inner().next().unwrap();
+                match inner.as_rule() {
+                    Rule::DRAWING_OPTION_THEME_hibou => {
+                        draw_conf.palette = DrawingPalette::hibou();
+                    },
+                    Rule::DRAWING_OPTION_THEME_monochrome => {
+                        draw_conf.palette = DrawingPalette::monochrome();
+                    },
+                    Rule::DRAWING_OPTION_THEME_dark => {
+                        draw_conf.palette = DrawingPalette::dark();
+                    },
+                    _ => {
+                        panic!("what rule then ? : {:?}", inner.as_rule());
+                    }
+                }
+            },
+            Rule::DRAWING_OPTION_PALETTE => {
+                palette_overrides.extend(option_decl_pair.into_inner());
+            },
+            Rule::DRAWING_OPTION_SCALE => {
+                match parse_positive_float(option_decl_pair,"scale") {
+                    Err(e) => {
+                        return Err(e);
+                    },
+                    Ok( scale ) => {
+                        draw_conf.scale = scale;
+                    }
+                }
+            },
+            Rule::DRAWING_OPTION_FONT_SIZE => {
+                match parse_positive_float(option_decl_pair,"font_size") {
+                    Err(e) => {
+                        return Err(e);
+                    },
+                    Ok( font_size ) => {
+                        draw_conf.font_size = font_size;
+                    }
+                }
+            },
+            Rule::DRAWING_OPTION_MARGIN => {
+                let inner = option_decl_pair.into_inner().next().unwrap();
+                draw_conf.margin = inner.as_str().parse::<f32>().unwrap();
+            },
+            Rule::DRAWING_OPTION_SHOW_TYPES => {
+                let inner = option_decl_pair.into_inner().next().unwrap();
+                match inner.as_rule() {
+                    Rule::HIBOU_true => {
+                        draw_conf.show_types = true;
+                    },
+                    Rule::HIBOU_false => {
+                        draw_conf.show_types = false;
+                    },
+                    _ => {
+                        panic!("what rule then ? : {:?}", inner.as_rule());
+                    }
+                }
+            },
+            _ => {
+                panic!("what rule then ? : {:?}", option_decl_pair.as_rule());
+            }
+        }
+    }
+    // ***
+    for palette_elt_pair in palette_overrides {
+        let mut content = palette_elt_pair.into_inner();
+        let role_pair = content.next().unwrap();
+        let color = parse_color(content.next().unwrap());
+        match role_pair.as_rule() {
+            Rule::DRAWING_OPTION_ROLE_background => {
+                draw_conf.palette.background = color;
+            },
+            Rule::DRAWING_OPTION_ROLE_lifeline => {
+                draw_conf.palette.lifeline = color;
+            },
+            Rule::DRAWING_OPTION_ROLE_message_type => {
+                draw_conf.palette.message_type = color;
+            },
+            Rule::DRAWING_OPTION_ROLE_message => {
+                draw_conf.palette.message = color;
+            },
+            Rule::DRAWING_OPTION_ROLE_symbol => {
+                draw_conf.palette.symbol = color;
+            },
+            Rule::DRAWING_OPTION_ROLE_faded => {
+                draw_conf.palette.faded = color;
+            },
+            Rule::DRAWING_OPTION_ROLE_highlight => {
+                draw_conf.palette.highlight = color;
+            },
+            Rule::DRAWING_OPTION_ROLE_covered => {
+                draw_conf.palette.covered = color;
+            },
+            Rule::DRAWING_OPTION_ROLE_uncovered => {
+                draw_conf.palette.uncovered = color;
+            },
+            _ => {
+                panic!("what rule then ? : {:?}", role_pair.as_rule());
+            }
+        }
+    }
+    // ***
+    return Ok(draw_conf);
+}
+
+fn parse_positive_float(option_decl_pair : Pair<Rule>,
+                        option_name : &str) -> Result<f32,HibouParsingError> {
+    let inner = option_decl_pair.into_inner().next().unwrap();
+    let value = inner.as_str().parse::<f32>().unwrap();
+    if value <= 0.0 {
+        return Err( HibouParsingError::HcfSetupError(format!("drawing option '{:}' must be strictly positive", option_name)));
+    }
+    return Ok(value);
+}
+
+fn parse_color(color_pair : Pair<Rule>) -> [u8;3] {
+    // the grammar ensures the color is of the form #rrggbb
+    let hex = &color_pair.as_str()[1..];
+    let r = u8::from_str_radix(&hex[0..2],16).unwrap();
+    let g = u8::from_str_radix(&hex[2..4],16).unwrap();
+    let b = u8::from_str_radix(&hex[4..6],16).unwrap();
+    [r,g,b]
+}
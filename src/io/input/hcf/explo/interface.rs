@@ -29,6 +29,7 @@ use crate::io::input::hcf::parser::{HcfParser,Rule};
 use crate::core::general_context::GeneralContext;
 use crate::io::file_extensions::HIBOU_CONFIGURATION_FILE_EXTENSION;
 use crate::io::input::error::HibouParsingError;
+use crate::io::input::hcf::draw::options::find_drawing_options;
 use crate::io::input::hcf::explo::options::{HibouExploreOptions, parse_explore_options};
 
 
@@ -80,6 +81,15 @@ fn parse_conf_pair_for_explore(gen_ctx : &GeneralContext,
     let mut got_section_explore_options   : bool = false;
     let mut explore_options = HibouExploreOptions::default();
 
+    let draw_conf = match find_drawing_options(&conf_pair) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( got_conf ) => {
+            got_conf
+        }
+    };
+
     let mut contents = conf_pair.into_inner();
 
     while let Some(current_pair) = contents.next() {
@@ -90,7 +100,7 @@ fn parse_conf_pair_for_explore(gen_ctx : &GeneralContext,
                 }
                 got_section_explore_options = true;
                 // ***
-                match parse_explore_options(gen_ctx,current_pair,file_name) {
+                match parse_explore_options(gen_ctx,current_pair,file_name,&draw_conf) {
                     Err(e) => {
                         return Err(e);
                     },
@@ -102,6 +112,9 @@ fn parse_conf_pair_for_explore(gen_ctx : &GeneralContext,
             Rule::ANALYZE_OPTION_SECTION => {
                 // nothing
             },
+            Rule::DRAWING_OPTION_SECTION => {
+                // already handled
+            },
             _ => {
                 panic!("what rule then ? : {:?}", current_pair.as_rule() );
             }
@@ -24,7 +24,10 @@ use pest::iterators::Pair;
 
 use crate::core::general_context::GeneralContext;
 use crate::io::input::error::HibouParsingError;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
 use crate::io::input::hcf::loggers::graphviz::parse_graphic_logger;
+use crate::io::input::hcf::loggers::html::parse_html_logger;
+use crate::io::input::hcf::loggers::lts::parse_lts_logger;
 use crate::io::input::hcf::loggers::tracegen::parse_tracegen_logger;
 
 
@@ -79,7 +82,8 @@ impl HibouExploreOptions {
 
 pub fn parse_explore_options(gen_ctx: &GeneralContext,
                              option_pair : Pair<Rule>,
-                             file_name : &str) -> Result<HibouExploreOptions,HibouParsingError> {
+                             file_name : &str,
+                             draw_conf : &DrawingConfiguration) -> Result<HibouExploreOptions,HibouParsingError> {
     let mut loggers : Vec<Box<dyn AbstractProcessLogger<ExplorationConfig>>> = Vec::new();
     let mut strategy : QueueSearchStrategy = QueueSearchStrategy::BFS;
     let mut filters : Vec<Box<dyn AbstractFilter<ExplorationFilterCriterion,ExplorationFilterEliminationKind>>> = Vec::new();
@@ -97,7 +101,7 @@ pub fn parse_explore_options(gen_ctx: &GeneralContext,
                     logger_id += 1;
                     match logger_kind_pair.as_rule() {
                         Rule::OPTION_GRAPHIC_LOGGER => {
-                            let glogger = parse_graphic_logger(logger_id,file_name,logger_kind_pair);
+                            let glogger = parse_graphic_logger(logger_id,file_name,draw_conf,logger_kind_pair);
                             loggers.push( Box::new(glogger));
                         },
                         Rule::OPTION_TRACEGEN_LOGGER => {
@@ -110,6 +114,14 @@ pub fn parse_explore_options(gen_ctx: &GeneralContext,
                                 }
                             }
                         },
+                        Rule::OPTION_LTS_LOGGER => {
+                            let llogger = parse_lts_logger(logger_id,file_name,logger_kind_pair);
+                            loggers.push( Box::new(llogger));
+                        },
+                        Rule::OPTION_HTML_LOGGER => {
+                            let hlogger = parse_html_logger(logger_id,file_name,draw_conf,logger_kind_pair);
+                            loggers.push( Box::new(hlogger));
+                        },
                         _ => {
                             panic!("what rule then ? : {:?}", logger_kind_pair.as_rule() );
                         }
@@ -28,11 +28,13 @@ use pest::Parser;
 #[allow(unused_imports)]
 use crate::io::input::hcf::parser::{HcfParser,Rule};
 
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
 use crate::loggers::graphviz::drawer::InteractionProcessDrawer;
 
 
 pub fn parse_graphic_logger<Conf : AbstractProcessConfiguration>(logger_id : u32,
                                                                  file_name : &str,
+                                                                 draw_conf : &DrawingConfiguration,
                                                                  logger_kind_pair : Pair<Rule>)
         -> GenericGraphVizLogger<Conf>
         where
@@ -139,9 +141,19 @@ pub fn parse_graphic_logger<Conf : AbstractProcessConfiguration>(logger_id : u32
         }
     }
     // ***
+    let sd_as_svg = match output_format {
+        GraphVizOutputFormat::svg => {
+            true
+        },
+        _ => {
+            false
+        }
+    };
     let drawer = InteractionProcessDrawer::new(format!("graphviz_temp_l{:}", logger_id),
                                                int_repr_sd,
-                                               int_repr_tt);
+                                               int_repr_tt,
+                                               sd_as_svg,
+                                               draw_conf.clone());
     GenericGraphVizLogger::new(Box::new(drawer),
                                output_format,
                                layout,
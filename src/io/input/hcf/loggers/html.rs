@@ -0,0 +1,68 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use graph_process_manager_core::manager::config::AbstractProcessConfiguration;
+
+use pest::iterators::Pair;
+
+#[allow(unused_imports)]
+use pest::Parser;
+#[allow(unused_imports)]
+use crate::io::input::hcf::parser::{HcfParser,Rule};
+
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
+use crate::loggers::html::logger::GenericHtmlLogger;
+use crate::loggers::html::printer::{HtmlProcessPrinter, InteractionHtmlPrinter};
+
+
+pub fn parse_html_logger<Conf : AbstractProcessConfiguration>(logger_id : u32,
+                                                              file_name : &str,
+                                                              draw_conf : &DrawingConfiguration,
+                                                              logger_kind_pair : Pair<Rule>)
+        -> GenericHtmlLogger<Conf>
+        where
+            InteractionHtmlPrinter : HtmlProcessPrinter<Conf> + 'static {
+    // default configuration
+    let mut parent_folder = "".to_string();
+    let mut output_file_name = format!("{:}_l{:}",file_name,logger_id);
+    // ***
+    match logger_kind_pair.into_inner().next() {
+        None => {
+            // nothing
+        },
+        Some(html_logger_opts) => {
+            for opt_pair in html_logger_opts.into_inner() {
+                match opt_pair.as_rule() {
+                    Rule::HTML_LOGGER_parent_folder => {
+                        let inner_pair = opt_pair.into_inner().next().unwrap();
+                        parent_folder = inner_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+                    },
+                    Rule::HTML_LOGGER_output_file => {
+                        let inner_pair = opt_pair.into_inner().next().unwrap();
+                        output_file_name = inner_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+                    },
+                    _ => {
+                        panic!("what rule then ? : {:?}", opt_pair.as_rule());
+                    }
+                }
+            }
+        }
+    }
+    return GenericHtmlLogger::new(Box::new(InteractionHtmlPrinter::new(draw_conf.clone())),
+                                  parent_folder,
+                                  output_file_name);
+}
@@ -0,0 +1,66 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use graph_process_manager_core::manager::config::AbstractProcessConfiguration;
+
+use pest::iterators::Pair;
+
+#[allow(unused_imports)]
+use pest::Parser;
+#[allow(unused_imports)]
+use crate::io::input::hcf::parser::{HcfParser,Rule};
+
+use crate::loggers::lts::logger::GenericLtsLogger;
+use crate::loggers::lts::printer::{InteractionLtsPrinter, LtsProcessPrinter};
+
+
+pub fn parse_lts_logger<Conf : AbstractProcessConfiguration>(logger_id : u32,
+                                                             file_name : &str,
+                                                             logger_kind_pair : Pair<Rule>)
+        -> GenericLtsLogger<Conf>
+        where
+            InteractionLtsPrinter : LtsProcessPrinter<Conf> + 'static {
+    // default configuration
+    let mut parent_folder = "".to_string();
+    let mut output_file_name = format!("{:}_l{:}",file_name,logger_id);
+    // ***
+    match logger_kind_pair.into_inner().next() {
+        None => {
+            // nothing
+        },
+        Some(lts_logger_opts) => {
+            for opt_pair in lts_logger_opts.into_inner() {
+                match opt_pair.as_rule() {
+                    Rule::LTS_LOGGER_parent_folder => {
+                        let inner_pair = opt_pair.into_inner().next().unwrap();
+                        parent_folder = inner_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+                    },
+                    Rule::LTS_LOGGER_output_file => {
+                        let inner_pair = opt_pair.into_inner().next().unwrap();
+                        output_file_name = inner_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+                    },
+                    _ => {
+                        panic!("what rule then ? : {:?}", opt_pair.as_rule());
+                    }
+                }
+            }
+        }
+    }
+    return GenericLtsLogger::new(Box::new(InteractionLtsPrinter::new()),
+                                 parent_folder,
+                                 output_file_name);
+}
@@ -15,4 +15,6 @@ limitations under the License.
 */
 
 pub mod graphviz;
-pub mod tracegen;
\ No newline at end of file
+pub mod tracegen;
+pub mod lts;
+pub mod html;
\ No newline at end of file
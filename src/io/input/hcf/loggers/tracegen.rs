@@ -24,7 +24,7 @@ use pest::iterators::Pair;
 use crate::core::colocalizations::CoLocalizations;
 
 use crate::core::general_context::GeneralContext;
-use crate::io::file_extensions::HIBOU_TRACE_FILE_EXTENSION;
+use crate::io::file_extensions::{HibouFileFormat, HIBOU_TRACE_FILE_EXTENSION};
 use crate::io::input::error::HibouParsingError;
 
 
@@ -50,6 +50,7 @@ pub fn parse_tracegen_logger<Conf : AbstractProcessConfiguration>(logger_id : u3
     let mut co_localizations = CoLocalizations::get_trivial_partition(gen_ctx.get_lf_num());
     let mut parent_folder = format!("tracegen_l{:}", logger_id);
     let mut files_prefix = "trace".to_string();
+    let mut file_format = HibouFileFormat::Native;
     // ***
     match logger_kind_pair.into_inner().next() {
         None => {
@@ -66,6 +67,12 @@ pub fn parse_tracegen_logger<Conf : AbstractProcessConfiguration>(logger_id : u3
                         let inner_pair = opt_pair.into_inner().next().unwrap();
                         files_prefix = inner_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
                     },
+                    Rule::TRACEGEN_LOGGER_format_hibou => {
+                        file_format = HibouFileFormat::Native;
+                    },
+                    Rule::TRACEGEN_LOGGER_format_json => {
+                        file_format = HibouFileFormat::Json;
+                    },
                     Rule::TRACEGEN_LOGGER_terminal => {
                         generation = TracegenProcessLoggerGeneration::terminal;
                     },
@@ -127,7 +134,7 @@ pub fn parse_tracegen_logger<Conf : AbstractProcessConfiguration>(logger_id : u3
     let printer = MultiTraceProcessPrinter::new(co_localizations,generation);
     return Ok(GenericStepsTraceLogger::new(Box::new(printer),
                                            files_prefix,
-                                           HIBOU_TRACE_FILE_EXTENSION.to_string(),
+                                           file_format.get_extension(HIBOU_TRACE_FILE_EXTENSION).to_string(),
                                          parent_folder,
                                          ));
 }
@@ -21,3 +21,5 @@ pub mod loggers;
 
 pub mod explo;
 pub mod ana;
+
+pub mod draw;
\ No newline at end of file
@@ -42,7 +42,9 @@ pub fn parse_comm_act_targets_as_lifelines(gen_ctx : &GeneralContext, target_pai
                 }
             }
         },
-        Rule::HIBOU_LABEL_LIST_paren => {
+        Rule::HIBOU_LABEL_LIST_no_sep => {
+            // the 'HIBOU_LABEL_LIST_paren' rule of the targets is silent
+            // so that the list of labels it wraps is the pair which is reached here
             let mut target_lf_ids : Vec<usize> = vec![];
             for label_pair in inner_pair.into_inner() {
                 let lf_name : String = label_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
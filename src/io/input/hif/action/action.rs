@@ -37,8 +37,7 @@ use crate::io::input::hif::action::act_targets::*;
 use crate::io::input::error::HibouParsingError;
 
 
-fn parse_message_pair(gen_ctx : &GeneralContext, pair : &Pair<Rule>) -> Result<MessageTypeExpression,HibouParsingError> {
-    let ms_name : String = pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+fn parse_message_name(gen_ctx : &GeneralContext, ms_name : String) -> Result<MessageTypeExpression,HibouParsingError> {
     match gen_ctx.get_ms_id(&ms_name) {
         None => {
             match gen_ctx.get_mt_id(&ms_name) {
@@ -56,6 +55,38 @@ fn parse_message_pair(gen_ctx : &GeneralContext, pair : &Pair<Rule>) -> Result<M
     }
 }
 
+/**
+A message is either the name of a message or of a message type
+or a set of such names, which stands for the union of the corresponding messages.
+The empty set '{}' (which remains e.g. from the execution of 'sync' operators)
+stands for a declared message minus itself.
+**/
+fn parse_message_pair(gen_ctx : &GeneralContext, pair : &Pair<Rule>) -> Result<MessageTypeExpression,HibouParsingError> {
+    match pair.as_rule() {
+        Rule::SD_MESSAGE_SET => {
+            let mut frags = vec![];
+            if let Some(names_pair) = pair.clone().into_inner().next() {
+                for name_pair in names_pair.into_inner() {
+                    let ms_name : String = name_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+                    frags.push( parse_message_name(gen_ctx,ms_name)? );
+                }
+            }
+            if frags.is_empty() {
+                if gen_ctx.get_ms_num() == 0 {
+                    return Err( HibouParsingError::OtherDefinitionError("empty set of messages '{}' without any declared message".to_string()) );
+                }
+                return Ok( MessageTypeExpression::SetMinus(Box::new(MessageTypeExpression::Singleton(0)),
+                                                           Box::new(MessageTypeExpression::Singleton(0))) );
+            }
+            Ok( MessageTypeExpression::fold_recursive_msg_unions(&mut frags) )
+        },
+        _ => {
+            let ms_name : String = pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+            parse_message_name(gen_ctx,ms_name)
+        }
+    }
+}
+
 pub fn parse_communication_action(gen_ctx : &GeneralContext, contents : &mut Pairs<Rule>) -> Result<Interaction,HibouParsingError> {
 
     let message : MessageTypeExpression;
@@ -78,7 +109,7 @@ pub fn parse_communication_action(gen_ctx : &GeneralContext, contents : &mut Pai
                 }
             }
         },
-        Rule::HIBOU_LABEL => {
+        Rule::HIBOU_LABEL | Rule::SD_MESSAGE_SET => {
             match parse_message_pair(gen_ctx,&first_pair) {
                 Err(e) => {return Err(e);},
                 Ok(m) => {message = m;}
@@ -244,3 +244,196 @@ fn fold_interactions_in_binary_operator(op_kind : &BinaryOperatorKind, sub_ints
 
 
 
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use rand::{Rng, SeedableRng};
+    use rand::rngs::StdRng;
+    use crate::core::execution::trace::trace::TraceActionKind;
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::BroadcastPrimitive;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use crate::io::output::to_hfiles::interaction::to_hif::interaction_as_hif_encoding;
+    use super::parse_hif_string;
+
+    fn get_test_context() -> GeneralContext {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_lf("l3".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        gen_ctx.add_msg("m3".to_string());
+        gen_ctx.add_mt("A".to_string(),hashset!{0,1});
+        return gen_ctx;
+    }
+
+    fn random_lifelines(rng : &mut StdRng, allow_empty : bool) -> Vec<usize> {
+        loop {
+            let lf_ids : Vec<usize> = (0..3).filter(|_| rng.gen_bool(0.5)).collect();
+            if allow_empty || !lf_ids.is_empty() {
+                return lf_ids;
+            }
+        }
+    }
+
+    fn random_message(rng : &mut StdRng, with_composite : bool) -> MessageTypeExpression {
+        let named = if rng.gen_bool(0.75) {
+            MessageTypeExpression::Singleton(rng.gen_range(0..3))
+        } else {
+            MessageTypeExpression::NamedType(0)
+        };
+        if !with_composite || rng.gen_bool(0.5) {
+            return named;
+        }
+        let other = Box::new(MessageTypeExpression::Singleton(rng.gen_range(0..3)));
+        match rng.gen_range(0..3) {
+            0 => MessageTypeExpression::Union(Box::new(named),other),
+            1 => MessageTypeExpression::Intersection(Box::new(named),other),
+            _ => MessageTypeExpression::SetMinus(Box::new(named),other)
+        }
+    }
+
+    /**
+    Interactions are semantically equal if they have the same structure
+    and if their messages denote the same sets of messages.
+    **/
+    fn semantically_equal(gen_ctx : &GeneralContext, i1 : &Interaction, i2 : &Interaction) -> bool {
+        match (i1,i2) {
+            (Interaction::Empty,Interaction::Empty) => {
+                true
+            },
+            (Interaction::Action(bp1),Interaction::Action(bp2)) => {
+                bp1.origin_on_emission == bp2.origin_on_emission
+                    && bp1.targets == bp2.targets
+                    && bp1.message_type.resolve(gen_ctx) == bp2.message_type.resolve(gen_ctx)
+            },
+            (Interaction::CoReg(cr1,i11,i12),Interaction::CoReg(cr2,i21,i22)) => {
+                cr1 == cr2 && semantically_equal(gen_ctx,i11,i21) && semantically_equal(gen_ctx,i12,i22)
+            },
+            (Interaction::Alt(i11,i12),Interaction::Alt(i21,i22)) => {
+                semantically_equal(gen_ctx,i11,i21) && semantically_equal(gen_ctx,i12,i22)
+            },
+            (Interaction::Sync(acts1,i11,i12),Interaction::Sync(acts2,i21,i22)) => {
+                acts1.keys().eq(acts2.keys())
+                    && acts1.values().zip(acts2.values()).all(|(m1,m2)| m1.resolve(gen_ctx) == m2.resolve(gen_ctx))
+                    && semantically_equal(gen_ctx,i11,i21) && semantically_equal(gen_ctx,i12,i22)
+            },
+            (Interaction::Loop(cr1,i11),Interaction::Loop(cr2,i21)) => {
+                cr1 == cr2 && semantically_equal(gen_ctx,i11,i21)
+            },
+            _ => {
+                false
+            }
+        }
+    }
+
+    fn random_interaction(rng : &mut StdRng, depth : u32, with_composite : bool) -> Interaction {
+        let choice = if depth == 0 { rng.gen_range(0..2) } else { rng.gen_range(0..6) };
+        match choice {
+            0 => {
+                Interaction::Empty
+            },
+            1 => {
+                let message = random_message(rng,with_composite);
+                if rng.gen_bool(0.5) {
+                    Interaction::Action(BroadcastPrimitive::new(Some(rng.gen_range(0..3)),message,random_lifelines(rng,true)))
+                } else {
+                    Interaction::Action(BroadcastPrimitive::new(None,message,random_lifelines(rng,false)))
+                }
+            },
+            2 => {
+                let cr = if rng.gen_bool(0.5) { vec![] } else { random_lifelines(rng,false) };
+                Interaction::CoReg(cr,
+                                   Box::new(random_interaction(rng,depth-1,with_composite)),
+                                   Box::new(random_interaction(rng,depth-1,with_composite)))
+            },
+            3 => {
+                Interaction::Alt(Box::new(random_interaction(rng,depth-1,with_composite)),
+                                 Box::new(random_interaction(rng,depth-1,with_composite)))
+            },
+            4 => {
+                let mut sync = BTreeMap::new();
+                let act_kind = if rng.gen_bool(0.5) { TraceActionKind::Emission } else { TraceActionKind::Reception };
+                sync.insert((rng.gen_range(0..3),act_kind), random_message(rng,false));
+                sync.insert((rng.gen_range(0..3),act_kind), random_message(rng,false));
+                Interaction::Sync(sync,
+                                  Box::new(random_interaction(rng,depth-1,with_composite)),
+                                  Box::new(random_interaction(rng,depth-1,with_composite)))
+            },
+            _ => {
+                let cr = if rng.gen_bool(0.5) { vec![] } else { random_lifelines(rng,false) };
+                Interaction::Loop(cr,Box::new(random_interaction(rng,depth-1,with_composite)))
+            }
+        }
+    }
+
+    #[test]
+    fn hif_round_trip_test() {
+        let gen_ctx = get_test_context();
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..500 {
+            let int = random_interaction(&mut rng,4,false);
+            let encoded = interaction_as_hif_encoding(&gen_ctx,&int);
+            assert_eq!(parse_hif_string(&gen_ctx,encoded.clone()).unwrap(),int,"{}",encoded);
+        }
+    }
+
+    #[test]
+    fn hif_round_trip_composite_messages_test() {
+        // composite messages, including those which contain no message,
+        // are re-parsed as the messages or sets of messages they denote
+        let gen_ctx = get_test_context();
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut got_empty = false;
+        for _ in 0..500 {
+            let int = random_interaction(&mut rng,4,true);
+            let encoded = interaction_as_hif_encoding(&gen_ctx,&int);
+            got_empty = got_empty || encoded.contains("{}");
+            let reparsed = parse_hif_string(&gen_ctx,encoded.clone()).unwrap();
+            assert!(semantically_equal(&gen_ctx,&reparsed,&int),"{}",encoded);
+        }
+        assert!(got_empty);
+    }
+
+    #[test]
+    fn multi_target_communications_test() {
+        let gen_ctx = get_test_context();
+        let expected = Interaction::CoReg(vec![],
+                                          Box::new(Interaction::Action(BroadcastPrimitive::new(None,
+                                                                                               MessageTypeExpression::Singleton(0),
+                                                                                               vec![1,2]))),
+                                          Box::new(Interaction::Action(BroadcastPrimitive::new(Some(0),
+                                                                                               MessageTypeExpression::Singleton(1),
+                                                                                               vec![2,1]))));
+        let parsed = parse_hif_string(&gen_ctx,"seq(m1 -> (l2,l3), l1 -- m2 -> (l3,l2))".to_string()).unwrap();
+        assert_eq!(parsed,expected);
+        // a lifeline cannot be targeted twice
+        assert!(parse_hif_string(&gen_ctx,"m1 -> (l2,l2)".to_string()).is_err());
+    }
+
+    #[test]
+    fn message_sets_test() {
+        let gen_ctx = get_test_context();
+        let expected = Interaction::Action(BroadcastPrimitive::new(Some(0),
+                                                                   MessageTypeExpression::Union(Box::new(MessageTypeExpression::Singleton(0)),
+                                                                                                Box::new(MessageTypeExpression::Singleton(2))),
+                                                                   vec![1]));
+        assert_eq!(parse_hif_string(&gen_ctx,"l1 -- {m1,m3} -> l2".to_string()).unwrap(),expected);
+        // the empty set of messages is parsed as an action carrying no message
+        match parse_hif_string(&gen_ctx,"l1 -- {} -> l2".to_string()).unwrap() {
+            Interaction::Action(bp) => {
+                assert!(bp.message_type.resolve(&gen_ctx).is_empty());
+            },
+            _ => {
+                panic!();
+            }
+        }
+        assert!(parse_hif_string(&GeneralContext::new(),"{} -> |".to_string()).is_err());
+    }
+
+}
@@ -24,18 +24,25 @@ use crate::core::general_context::GeneralContext;
 use crate::core::language::syntax::interaction::Interaction;
 use crate::io::input::error::HibouParsingError;
 use crate::io::input::hif::interaction::parse_hif_string;
+use crate::io::input::json::interaction::interaction_from_json_text;
 
-use crate::io::file_extensions::{HIBOU_INTERACTION_FILE_EXTENSION};
+use crate::io::file_extensions::{HIBOU_INTERACTION_FILE_EXTENSION, HIBOU_JSON_FILE_EXTENSION};
 
 
+/**
+Reads an interaction from a .hif file or from a .json file.
+**/
 pub fn parse_hif_file(gen_ctx : &GeneralContext, file_path : &str) -> Result<Interaction,HibouParsingError> {
     let path_object = Path::new(file_path);
     let file_extension : &str = path_object.extension().unwrap().to_str().unwrap();
-    if file_extension != HIBOU_INTERACTION_FILE_EXTENSION {
+    if file_extension != HIBOU_INTERACTION_FILE_EXTENSION && file_extension != HIBOU_JSON_FILE_EXTENSION {
         return Err( HibouParsingError::FileFormatError(file_extension.to_string(),HIBOU_INTERACTION_FILE_EXTENSION.to_string()));
     }
     match fs::read_to_string(file_path) {
         Ok( unparsed_hif_str ) => {
+            if file_extension == HIBOU_JSON_FILE_EXTENSION {
+                return interaction_from_json_text(gen_ctx,&unparsed_hif_str);
+            }
             return parse_hif_string(gen_ctx,unparsed_hif_str);
         },
         Err(e) => {
@@ -17,5 +17,5 @@ limitations under the License.
 mod parser;
 mod action;
 mod trace;
-mod interaction;
+pub(crate) mod interaction;
 pub mod interface;
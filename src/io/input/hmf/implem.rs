@@ -0,0 +1,180 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::HashSet;
+
+use pest::iterators::Pair;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::general_context::GeneralContext;
+use crate::io::input::error::HibouParsingError;
+use crate::trace_manip::import::mapping::{LogFormat, TraceImportMapping};
+
+
+#[allow(unused_imports)]
+use pest::Parser;
+#[allow(unused_imports)]
+use crate::io::input::hmf::parser::{HmfParser,Rule};
+
+
+
+pub fn trace_import_mapping_from_text(gen_ctx : &GeneralContext,
+                                      mapping_str : &str) -> Result<TraceImportMapping,HibouParsingError> {
+    match HmfParser::parse(Rule::HMF_PEST_FILE, mapping_str) {
+        Err(e) => {
+            return Err( HibouParsingError::MatchError(e.to_string()) );
+        },
+        Ok( ref mut hmf_pair ) => {
+            let mapping_pair : Pair<Rule> = hmf_pair.next().unwrap();
+            let mut mapping = TraceImportMapping::new_default(CoLocalizations::get_discrete_partition(gen_ctx.get_lf_num()));
+            let mut separator : Option<char> = None;
+            for decl_pair in mapping_pair.into_inner() {
+                match decl_pair.as_rule() {
+                    Rule::HMF_FORMAT => {
+                        match decl_pair.into_inner().next().unwrap().as_rule() {
+                            Rule::HMF_FORMAT_csv => {
+                                mapping.format = Some( LogFormat::Csv(',') );
+                            },
+                            Rule::HMF_FORMAT_jsonl => {
+                                mapping.format = Some( LogFormat::JsonLines );
+                            },
+                            _ => {
+                                unreachable!();
+                            }
+                        }
+                    },
+                    Rule::HMF_SEPARATOR => {
+                        let sep_str = value_from_pair(decl_pair.into_inner().next().unwrap());
+                        let mut sep_chars = sep_str.chars();
+                        match (sep_chars.next(),sep_chars.next()) {
+                            (Some(sep_char),None) => {
+                                separator = Some(sep_char);
+                            },
+                            _ => {
+                                return Err( HibouParsingError::OtherDefinitionError(format!("the separator '{}' is not a single character", sep_str)) );
+                            }
+                        }
+                    },
+                    Rule::HMF_LIFELINE => {
+                        mapping.lifeline_field = value_from_pair(decl_pair.into_inner().next().unwrap());
+                    },
+                    Rule::HMF_MESSAGE => {
+                        mapping.message_field = value_from_pair(decl_pair.into_inner().next().unwrap());
+                    },
+                    Rule::HMF_DIRECTION => {
+                        mapping.direction_field = value_from_pair(decl_pair.into_inner().next().unwrap());
+                    },
+                    Rule::HMF_TIMESTAMP => {
+                        mapping.timestamp_field = Some( value_from_pair(decl_pair.into_inner().next().unwrap()) );
+                    },
+                    Rule::HMF_EMISSION => {
+                        mapping.emission_values = values_from_pair(decl_pair.into_inner().next().unwrap());
+                    },
+                    Rule::HMF_RECEPTION => {
+                        mapping.reception_values = values_from_pair(decl_pair.into_inner().next().unwrap());
+                    },
+                    Rule::HMF_COLOCS => {
+                        mapping.co_localizations = co_localizations_from_pair(gen_ctx,decl_pair.into_inner().next().unwrap())?;
+                    },
+                    _ => {
+                        panic!("what rule then ? : {:?}", decl_pair.as_rule() );
+                    }
+                }
+            }
+            // ***
+            if let Some(sep_char) = separator {
+                match mapping.format {
+                    Some( LogFormat::JsonLines ) => {
+                        return Err( HibouParsingError::OtherDefinitionError("a separator is given for a JSON lines log".to_string()) );
+                    },
+                    _ => {
+                        mapping.format = Some( LogFormat::Csv(sep_char) );
+                    }
+                }
+            }
+            for value in &mapping.emission_values {
+                if mapping.reception_values.contains(value) {
+                    return Err( HibouParsingError::EmissionDefinitionError(format!("direction '{}' denotes both emissions and receptions", value)) );
+                }
+            }
+            return Ok( mapping );
+        }
+    }
+}
+
+
+fn value_from_pair(value_pair : Pair<Rule>) -> String {
+    match value_pair.as_rule() {
+        Rule::HMF_STRING => {
+            value_pair.into_inner().next().unwrap().as_str().to_string()
+        },
+        Rule::HIBOU_LABEL => {
+            value_pair.as_str().to_string()
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", value_pair.as_rule() );
+        }
+    }
+}
+
+fn values_from_pair(values_pair : Pair<Rule>) -> Vec<String> {
+    values_pair.into_inner().map(value_from_pair).collect()
+}
+
+
+fn co_localizations_from_pair(gen_ctx : &GeneralContext,
+                              colocs_pair : Pair<Rule>) -> Result<CoLocalizations,HibouParsingError> {
+    match colocs_pair.as_rule() {
+        Rule::HMF_COLOCS_trivial => {
+            return Ok( CoLocalizations::get_trivial_partition(gen_ctx.get_lf_num()) );
+        },
+        Rule::HMF_COLOCS_discrete => {
+            return Ok( CoLocalizations::get_discrete_partition(gen_ctx.get_lf_num()) );
+        },
+        Rule::HMF_COLOCS_list => {
+            let mut colocs : Vec<HashSet<usize>> = vec![];
+            let mut got_lfs : HashSet<usize> = hashset!{};
+            for coloc_pair in colocs_pair.into_inner() {
+                let mut coloc : HashSet<usize> = hashset!{};
+                for lf_pair in coloc_pair.into_inner() {
+                    let lf_name = lf_pair.as_str();
+                    match gen_ctx.get_lf_id(lf_name) {
+                        None => {
+                            return Err( HibouParsingError::MissingLifelineDeclarationError(lf_name.to_string()) );
+                        },
+                        Some( lf_id ) => {
+                            if !got_lfs.insert(lf_id) {
+                                return Err( HibouParsingError::NonDisjointTraceComponents );
+                            }
+                            coloc.insert(lf_id);
+                        }
+                    }
+                }
+                colocs.push(coloc);
+            }
+            // lifelines which are not mentioned are each put in their own co-localization
+            for lf_id in 0..gen_ctx.get_lf_num() {
+                if !got_lfs.contains(&lf_id) {
+                    colocs.push( hashset!{lf_id} );
+                }
+            }
+            return Ok( CoLocalizations::new(colocs) );
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", colocs_pair.as_rule() );
+        }
+    }
+}
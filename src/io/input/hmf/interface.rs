@@ -0,0 +1,81 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::fs;
+use std::path::Path;
+
+use crate::core::general_context::GeneralContext;
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::hmf::implem::trace_import_mapping_from_text;
+use crate::io::file_extensions::{HIBOU_CSV_FILE_EXTENSION, HIBOU_JSON_LINES_FILE_EXTENSION, HIBOU_MAPPING_FILE_EXTENSION};
+use crate::trace_manip::import::mapping::{LogFormat, TraceImportMapping};
+use crate::trace_manip::import::records::{log_records_from_text, LogRecord};
+
+
+
+pub fn parse_hmf_file(gen_ctx : &GeneralContext,
+                      file_path : &str) -> Result<TraceImportMapping,HibouParsingError> {
+    let path_object = Path::new(file_path);
+    let file_extension : &str = path_object.extension().unwrap().to_str().unwrap();
+    if file_extension != HIBOU_MAPPING_FILE_EXTENSION {
+        return Err( HibouParsingError::FileFormatError(file_extension.to_string(),HIBOU_MAPPING_FILE_EXTENSION.to_string()));
+    }
+    // ***
+    match fs::read_to_string(file_path) {
+        Ok( unparsed_hmf_str ) => {
+            return trace_import_mapping_from_text(gen_ctx,&unparsed_hmf_str);
+        },
+        Err(e) => {
+            return Err( HibouParsingError::FileError(e.to_string()) );
+        }
+    }
+}
+
+
+/**
+Reads the events of a log file.
+If the mapping does not specify the syntax of the log, it is deduced from
+the extension of the file : .csv for comma separated values and .jsonl for JSON lines.
+**/
+pub fn parse_log_file(mapping : &TraceImportMapping,
+                      file_path : &str) -> Result<Vec<LogRecord>,HibouParsingError> {
+    let format = match &mapping.format {
+        Some( format ) => {
+            format.clone()
+        },
+        None => {
+            let path_object = Path::new(file_path);
+            let file_extension : &str = path_object.extension().map(|ext| ext.to_str().unwrap()).unwrap_or("");
+            if file_extension == HIBOU_CSV_FILE_EXTENSION {
+                LogFormat::Csv(',')
+            } else if file_extension == HIBOU_JSON_LINES_FILE_EXTENSION {
+                LogFormat::JsonLines
+            } else {
+                return Err( HibouParsingError::FileFormatError(file_extension.to_string(),
+                                                               format!("{}' or '.{}", HIBOU_CSV_FILE_EXTENSION, HIBOU_JSON_LINES_FILE_EXTENSION)) );
+            }
+        }
+    };
+    // ***
+    match fs::read_to_string(file_path) {
+        Ok( log_str ) => {
+            return log_records_from_text(&format,&log_str);
+        },
+        Err(e) => {
+            return Err( HibouParsingError::FileError(e.to_string()) );
+        }
+    }
+}
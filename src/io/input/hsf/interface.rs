@@ -19,19 +19,26 @@ limitations under the License.
 use std::fs;
 use std::path::Path;
 use crate::core::general_context::GeneralContext;
-use crate::io::file_extensions::HIBOU_SIGNATURE_FILE_EXTENSION;
+use crate::io::file_extensions::{HIBOU_JSON_FILE_EXTENSION, HIBOU_SIGNATURE_FILE_EXTENSION};
 use crate::io::input::error::HibouParsingError;
 
 use crate::io::input::hsf::implem::parse_hsf_string;
+use crate::io::input::json::signature::general_context_from_json_text;
 
+/**
+Reads a signature from a .hsf file or from a .json file.
+**/
 pub fn parse_hsf_file(file_path : &str) -> Result<GeneralContext,HibouParsingError> {
     let path_object = Path::new(file_path);
     let file_extension : &str = path_object.extension().unwrap().to_str().unwrap();
-    if file_extension != HIBOU_SIGNATURE_FILE_EXTENSION {
+    if file_extension != HIBOU_SIGNATURE_FILE_EXTENSION && file_extension != HIBOU_JSON_FILE_EXTENSION {
         return Err( HibouParsingError::FileFormatError(file_extension.to_string(),HIBOU_SIGNATURE_FILE_EXTENSION.to_string()));
     }
     match fs::read_to_string(file_path) {
         Ok( unparsed_hsf_str ) => {
+            if file_extension == HIBOU_JSON_FILE_EXTENSION {
+                return general_context_from_json_text(&unparsed_hsf_str);
+            }
             return parse_hsf_string(unparsed_hsf_str);
         },
         Err(e) => {
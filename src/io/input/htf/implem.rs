@@ -88,7 +88,7 @@ pub fn multitrace_from_text(gen_ctx : &GeneralContext,
 }
 
 
-fn complete_canals_up_to_defined_lifelines(gen_ctx : &GeneralContext,
+pub(crate) fn complete_canals_up_to_defined_lifelines(gen_ctx : &GeneralContext,
                                            colocs : &mut Vec<HashSet<usize>>,
                                            multi_trace : &mut MultiTrace) {
     let mut rem_lifelines : HashSet<usize> = gen_ctx.get_all_lfs_ids();
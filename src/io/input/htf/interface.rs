@@ -27,7 +27,8 @@ use crate::core::execution::trace::multitrace::MultiTrace;
 
 use crate::io::input::error::HibouParsingError;
 use crate::io::input::htf::implem::multitrace_from_text;
-use crate::io::file_extensions::HIBOU_TRACE_FILE_EXTENSION;
+use crate::io::input::json::multi_trace::multitrace_from_json_text;
+use crate::io::file_extensions::{HIBOU_JSON_FILE_EXTENSION, HIBOU_TRACE_FILE_EXTENSION};
 
 
 #[allow(unused_imports)]
@@ -37,16 +38,22 @@ use crate::io::input::htf::parser::{HtfParser,Rule};
 
 
 
+/**
+Reads a multi-trace and its co-localizations from a .htf file or from a .json file.
+**/
 pub fn parse_htf_file(gen_ctx : &GeneralContext,
                       file_path : &str) -> Result<(CoLocalizations,MultiTrace),HibouParsingError> {
     let path_object = Path::new(file_path);
     let file_extension : &str = path_object.extension().unwrap().to_str().unwrap();
-    if file_extension != HIBOU_TRACE_FILE_EXTENSION {
+    if file_extension != HIBOU_TRACE_FILE_EXTENSION && file_extension != HIBOU_JSON_FILE_EXTENSION {
         return Err( HibouParsingError::FileFormatError(file_extension.to_string(),HIBOU_TRACE_FILE_EXTENSION.to_string()));
     }
     // ***
     match fs::read_to_string(file_path) {
         Ok( unparsed_htf_str ) => {
+            if file_extension == HIBOU_JSON_FILE_EXTENSION {
+                return multitrace_from_json_text(gen_ctx,&unparsed_htf_str);
+            }
             return multitrace_from_text(gen_ctx,&unparsed_htf_str);
         },
         Err(e) => {
@@ -17,5 +17,5 @@ limitations under the License.
 mod parser;
 mod trace;
 mod multi_trace;
-mod implem;
+pub(crate) mod implem;
 pub mod interface;
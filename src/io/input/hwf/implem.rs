@@ -0,0 +1,159 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use pest::iterators::Pair;
+
+use crate::core::execution::semantics::position::Position;
+use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use crate::core::execution::witness::witness::{ExecutionWitness, ExecutionWitnessStep};
+use crate::core::general_context::GeneralContext;
+use crate::core::message::MessageTypeExpression;
+use crate::io::input::error::HibouParsingError;
+
+
+#[allow(unused_imports)]
+use pest::Parser;
+#[allow(unused_imports)]
+use crate::io::input::hwf::parser::{HwfParser,Rule};
+
+
+
+pub fn witness_from_text(gen_ctx : &GeneralContext,
+                         witness_str : &String) -> Result<ExecutionWitness,HibouParsingError> {
+    match HwfParser::parse(Rule::HWF_PEST_FILE, witness_str) {
+        Err(e) => {
+            return Err( HibouParsingError::MatchError(e.to_string()) );
+        },
+        Ok( ref mut hwf_pair ) => {
+            let mut content = hwf_pair.next().unwrap().into_inner();
+            let witness_pair : Pair<Rule> = content.next().unwrap();
+            let mut witness : ExecutionWitness = vec![];
+            for step_pair in witness_pair.into_inner() {
+                let mut step_content = step_pair.into_inner();
+                let position_pair = step_content.next().unwrap().into_inner().next().unwrap();
+                let position = position_from_pair(position_pair);
+                match witness_action_from_pair(gen_ctx,step_content.next().unwrap()) {
+                    Err(e) => {
+                        return Err(e);
+                    },
+                    Ok( action ) => {
+                        witness.push( ExecutionWitnessStep::new(position,action) );
+                    }
+                }
+            }
+            return Ok( witness );
+        }
+    }
+}
+
+
+/**
+Reads a position written as in the .hwf syntax, e.g. 'ε', '1', '2s0' or '(11,2)'.
+**/
+pub fn position_from_text(position_str : &str) -> Result<Position,HibouParsingError> {
+    match HwfParser::parse(Rule::HWF_POSITION, position_str) {
+        Err(e) => {
+            return Err( HibouParsingError::MatchError(e.to_string()) );
+        },
+        Ok( ref mut position_pairs ) => {
+            let witness_position_pair = position_pairs.next().unwrap().into_inner().next().unwrap();
+            return Ok( position_from_pair(witness_position_pair.into_inner().next().unwrap()) );
+        }
+    }
+}
+
+
+fn position_from_pair(position_pair : Pair<Rule>) -> Position {
+    match position_pair.as_rule() {
+        Rule::POS_EPSILON_ROOT => {
+            return Position::Epsilon(None);
+        },
+        Rule::POS_SUB => {
+            let sub_idx : usize = position_pair.as_str()[1..].parse().unwrap();
+            return Position::Epsilon(Some(sub_idx));
+        },
+        Rule::POS_LEFT => {
+            return Position::Left( Box::new(sub_position_from_pair(position_pair)) );
+        },
+        Rule::POS_RIGHT => {
+            return Position::Right( Box::new(sub_position_from_pair(position_pair)) );
+        },
+        Rule::POS_BOTH => {
+            let mut content = position_pair.into_inner();
+            let left_pair = content.next().unwrap();
+            let right_pair = content.next().unwrap();
+            return Position::Both( Box::new(sub_position_from_pair(left_pair)),
+                                   Box::new(sub_position_from_pair(right_pair)) );
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", position_pair.as_rule() );
+        }
+    }
+}
+
+fn sub_position_from_pair(position_pair : Pair<Rule>) -> Position {
+    match position_pair.into_inner().next() {
+        None => {
+            return Position::Epsilon(None);
+        },
+        Some( sub_pair ) => {
+            return position_from_pair(sub_pair);
+        }
+    }
+}
+
+
+fn witness_action_from_pair(gen_ctx : &GeneralContext,
+                            action_pair : Pair<Rule>) -> Result<TraceAction,HibouParsingError> {
+    let mut contents = action_pair.into_inner();
+    // ***
+    let lf_name : String  = contents.next().unwrap().as_str().chars().filter(|c| !c.is_whitespace()).collect();
+    let lf_id = match gen_ctx.get_lf_id(&lf_name) {
+        None => {
+            return Err( HibouParsingError::MissingLifelineDeclarationError(lf_name) );
+        },
+        Some( lf_id ) => {
+            lf_id
+        }
+    };
+    // ***
+    let act_kind_pair : Pair<Rule> = contents.next().unwrap();
+    let act_kind = match act_kind_pair.as_rule() {
+        Rule::TRACE_EMISSION_SYMBOL => {
+            TraceActionKind::Emission
+        },
+        Rule::TRACE_RECEPTION_SYMBOL => {
+            TraceActionKind::Reception
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", act_kind_pair.as_rule() );
+        }
+    };
+    // ***
+    let ms_name : String  = contents.next().unwrap().as_str().chars().filter(|c| !c.is_whitespace()).collect();
+    let ms_id = match gen_ctx.get_ms_id(&ms_name) {
+        None => {
+            return Err( HibouParsingError::MissingMessageDeclarationError(ms_name) );
+        },
+        Some( ms_id ) => {
+            ms_id
+        }
+    };
+    // ***
+    return Ok( TraceAction::new(lf_id,act_kind,MessageTypeExpression::Singleton(ms_id)) );
+}
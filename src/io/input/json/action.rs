@@ -0,0 +1,144 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use crate::core::general_context::GeneralContext;
+use crate::core::message::MessageTypeExpression;
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::json::value::JsonValue;
+
+
+pub fn lifeline_from_json_name(gen_ctx : &GeneralContext, lf_name : &str) -> Result<usize,HibouParsingError> {
+    match gen_ctx.get_lf_id(lf_name) {
+        None => {
+            Err( HibouParsingError::MissingLifelineDeclarationError(lf_name.to_string()) )
+        },
+        Some( lf_id ) => {
+            Ok( lf_id )
+        }
+    }
+}
+
+/**
+Reads lifelines names, duplicates being rejected.
+**/
+pub fn lifelines_from_json_names(gen_ctx : &GeneralContext, lf_names : &Vec<&str>) -> Result<Vec<usize>,HibouParsingError> {
+    let mut lf_ids = vec![];
+    for lf_name in lf_names {
+        let lf_id = lifeline_from_json_name(gen_ctx,lf_name)?;
+        if lf_ids.contains(&lf_id) {
+            return Err( HibouParsingError::OtherDefinitionError( format!("duplicate lifeline {:}", lf_name) ) );
+        }
+        lf_ids.push(lf_id);
+    }
+    Ok( lf_ids )
+}
+
+/**
+A message is either the name of a message or of a message type (resolved in that order)
+or an object with a single "union", "intersection" or "setminus" field holding two operands.
+**/
+pub fn message_type_from_json(gen_ctx : &GeneralContext, json : &JsonValue) -> Result<MessageTypeExpression,HibouParsingError> {
+    match json {
+        JsonValue::String(ms_name) => {
+            match gen_ctx.get_ms_id(ms_name) {
+                Some( ms_id ) => {
+                    Ok( MessageTypeExpression::Singleton(ms_id) )
+                },
+                None => {
+                    match gen_ctx.get_mt_id(ms_name) {
+                        None => {
+                            Err( HibouParsingError::MissingMessageDeclarationError(ms_name.to_string()) )
+                        },
+                        Some( mt_id ) => {
+                            Ok( MessageTypeExpression::NamedType(mt_id) )
+                        }
+                    }
+                }
+            }
+        },
+        JsonValue::Object(members) if members.len() == 1 => {
+            let (operator,operands) = members.first().unwrap();
+            let (mte1,mte2) = match operands.as_array() {
+                Some( elements ) if elements.len() == 2 => {
+                    (message_type_from_json(gen_ctx,&elements[0])?,
+                     message_type_from_json(gen_ctx,&elements[1])?)
+                },
+                _ => {
+                    return Err( HibouParsingError::JsonStructureError(format!("'{:}' must have exactly two operands", operator)) );
+                }
+            };
+            match operator.as_str() {
+                "union" => {
+                    Ok( MessageTypeExpression::Union(Box::new(mte1),Box::new(mte2)) )
+                },
+                "intersection" => {
+                    Ok( MessageTypeExpression::Intersection(Box::new(mte1),Box::new(mte2)) )
+                },
+                "setminus" => {
+                    Ok( MessageTypeExpression::SetMinus(Box::new(mte1),Box::new(mte2)) )
+                },
+                _ => {
+                    Err( HibouParsingError::JsonStructureError(format!("unknown message operator '{:}'", operator)) )
+                }
+            }
+        },
+        _ => {
+            Err( HibouParsingError::JsonStructureError("a message must be a name or a union, intersection or setminus object".to_string()) )
+        }
+    }
+}
+
+pub fn trace_action_kind_from_json(json : &JsonValue) -> Result<TraceActionKind,HibouParsingError> {
+    match json.get_string_field("kind")? {
+        "emission" => {
+            Ok( TraceActionKind::Emission )
+        },
+        "reception" => {
+            Ok( TraceActionKind::Reception )
+        },
+        other => {
+            Err( HibouParsingError::JsonStructureError(format!("unknown action kind '{:}'", other)) )
+        }
+    }
+}
+
+/**
+Reads an action with its "lifeline", "kind" (emission or reception) and "message".
+If 'concrete' is set, as for multi-traces and witnesses, the message must be the name of a declared message.
+**/
+pub fn trace_action_from_json(gen_ctx : &GeneralContext,
+                              json : &JsonValue,
+                              concrete : bool) -> Result<TraceAction,HibouParsingError> {
+    let lf_id = lifeline_from_json_name(gen_ctx,json.get_string_field("lifeline")?)?;
+    let act_kind = trace_action_kind_from_json(json)?;
+    let message = if concrete {
+        let ms_name = json.get_string_field("message")?;
+        match gen_ctx.get_ms_id(ms_name) {
+            None => {
+                return Err( HibouParsingError::MissingMessageDeclarationError(ms_name.to_string()) );
+            },
+            Some( ms_id ) => {
+                MessageTypeExpression::Singleton(ms_id)
+            }
+        }
+    } else {
+        message_type_from_json(gen_ctx,json.get_required_field("message")?)?
+    };
+    Ok( TraceAction::new(lf_id,act_kind,message) )
+}
@@ -0,0 +1,178 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeMap;
+
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::action::BroadcastPrimitive;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::json::action::{lifeline_from_json_name, lifelines_from_json_names, message_type_from_json, trace_action_from_json};
+use crate::io::input::json::value::{json_value_from_text, JsonValue};
+use crate::io::textual_convention::{SYNTAX_ALT, SYNTAX_LOOP_C, SYNTAX_LOOP_P, SYNTAX_LOOP_W, SYNTAX_PAR, SYNTAX_SEQ, SYNTAX_SYNC};
+
+
+/**
+Reads an interaction encoded as a JSON term whose nodes are objects with a "kind" field,
+i.e. 'empty', 'action' (with its "origin", "message" and "targets"), the n-ary 'seq', 'par', 'coreg', 'alt' and 'sync'
+and the 'loopW', 'loopP' and 'loopC' loops which have a single operand.
+As in the .hif syntax, n-ary operators are folded to the right.
+**/
+pub fn interaction_from_json_text(gen_ctx : &GeneralContext, json_str : &str) -> Result<Interaction,HibouParsingError> {
+    let json = json_value_from_text(json_str)?;
+    interaction_from_json(gen_ctx,&json)
+}
+
+
+fn interaction_from_json(gen_ctx : &GeneralContext, json : &JsonValue) -> Result<Interaction,HibouParsingError> {
+    let kind = json.get_string_field("kind")?;
+    match kind {
+        "empty" => {
+            return Ok( Interaction::Empty );
+        },
+        "action" => {
+            return action_from_json(gen_ctx,json);
+        },
+        _ => {}
+    }
+    // ***
+    let mut sub_ints = vec![];
+    for sub_json in json.get_array_field("operands")? {
+        sub_ints.push( interaction_from_json(gen_ctx,sub_json)? );
+    }
+    if sub_ints.is_empty() {
+        return Err( HibouParsingError::JsonStructureError(format!("'{:}' must have at least one operand", kind)) );
+    }
+    // ***
+    if kind == SYNTAX_LOOP_W || kind == SYNTAX_LOOP_P || kind == SYNTAX_LOOP_C {
+        if sub_ints.len() != 1 {
+            return Err( HibouParsingError::JsonStructureError(format!("'{:}' must have exactly one operand", kind)) );
+        }
+        let coreg = if kind == SYNTAX_LOOP_W {
+            vec![]
+        } else if kind == SYNTAX_LOOP_P {
+            (0..gen_ctx.get_lf_num()).collect()
+        } else {
+            lifelines_from_json_names(gen_ctx,&json.get_string_array_field("lifelines")?)?
+        };
+        return Ok( Interaction::Loop(coreg,Box::new(sub_ints.remove(0))) );
+    }
+    // ***
+    let mut folded = sub_ints.pop().unwrap();
+    if kind == SYNTAX_SEQ || kind == SYNTAX_PAR || kind == "coreg" {
+        let coreg : Vec<usize> = if kind == SYNTAX_SEQ {
+            vec![]
+        } else if kind == SYNTAX_PAR {
+            (0..gen_ctx.get_lf_num()).collect()
+        } else {
+            lifelines_from_json_names(gen_ctx,&json.get_string_array_field("lifelines")?)?
+        };
+        while let Some(sub_int) = sub_ints.pop() {
+            folded = Interaction::CoReg(coreg.clone(),Box::new(sub_int),Box::new(folded));
+        }
+    } else if kind == SYNTAX_ALT {
+        while let Some(sub_int) = sub_ints.pop() {
+            folded = Interaction::Alt(Box::new(sub_int),Box::new(folded));
+        }
+    } else if kind == SYNTAX_SYNC {
+        let mut sync_acts = BTreeMap::new();
+        for act_json in json.get_array_field("actions")? {
+            let act = trace_action_from_json(gen_ctx,act_json,false)?;
+            sync_acts.insert((act.lf_id,act.act_kind),act.message);
+        }
+        while let Some(sub_int) = sub_ints.pop() {
+            folded = Interaction::Sync(sync_acts.clone(),Box::new(sub_int),Box::new(folded));
+        }
+    } else {
+        return Err( HibouParsingError::JsonStructureError(format!("unknown interaction kind '{:}'", kind)) );
+    }
+    Ok( folded )
+}
+
+fn action_from_json(gen_ctx : &GeneralContext, json : &JsonValue) -> Result<Interaction,HibouParsingError> {
+    let origin = match json.get_field("origin") {
+        None | Some(JsonValue::Null) => {
+            None
+        },
+        Some( JsonValue::String(lf_name) ) => {
+            Some( lifeline_from_json_name(gen_ctx,lf_name)? )
+        },
+        Some( _ ) => {
+            return Err( HibouParsingError::JsonStructureError("field 'origin' must be a string or null".to_string()) );
+        }
+    };
+    let message = message_type_from_json(gen_ctx,json.get_required_field("message")?)?;
+    let targets = lifelines_from_json_names(gen_ctx,&json.get_string_array_field("targets")?)?;
+    Ok( Interaction::Action(BroadcastPrimitive::new(origin,message,targets)) )
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::BroadcastPrimitive;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use crate::io::input::hif::interaction::parse_hif_string;
+    use crate::io::output::to_json::interaction::interaction_as_json_encoding;
+    use super::interaction_from_json_text;
+
+    fn get_test_context() -> GeneralContext {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_lf("l3".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        gen_ctx.add_msg("m3".to_string());
+        gen_ctx.add_mt("A".to_string(),hashset!{0,1});
+        return gen_ctx;
+    }
+
+    #[test]
+    fn json_round_trip_test() {
+        let gen_ctx = get_test_context();
+        let hif = "loopW(seq(
+            l1 -- m1 -> (l2,l3),
+            alt(coreg(l1,l2)(l2 -- A -> l1, l3 -- {m1,m3} -> |, o), par(m2 -> l3, l1 -- m1 -> l2)),
+            sync(l2!m2)(l2 -- m2 -> l1, loopP(l2 -- m2 -> l3)),
+            loopC(l1,l3)(l3 -- m3 -> l1)
+        ))";
+        let interaction = parse_hif_string(&gen_ctx,hif.to_string()).unwrap();
+        let encoded = interaction_as_json_encoding(&gen_ctx,&interaction);
+        let decoded = interaction_from_json_text(&gen_ctx,&encoded).unwrap();
+        assert_eq!(decoded,interaction);
+    }
+
+    #[test]
+    fn json_round_trip_composite_messages_test() {
+        let gen_ctx = get_test_context();
+        // unlike the .hif syntax, composite messages are kept as such rather than resolved
+        let message = MessageTypeExpression::Intersection(
+            Box::new(MessageTypeExpression::NamedType(0)),
+            Box::new(MessageTypeExpression::SetMinus(
+                Box::new(MessageTypeExpression::Singleton(1)),
+                Box::new(MessageTypeExpression::Singleton(2)))));
+        let interaction = Interaction::Action(BroadcastPrimitive::new(Some(0),message,vec![2,1]));
+        let encoded = interaction_as_json_encoding(&gen_ctx,&interaction);
+        let decoded = interaction_from_json_text(&gen_ctx,&encoded).unwrap();
+        assert_eq!(decoded,interaction);
+    }
+
+}
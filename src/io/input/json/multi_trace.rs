@@ -0,0 +1,90 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::HashSet;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::{MultiTrace, Trace};
+use crate::core::general_context::GeneralContext;
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::htf::implem::complete_canals_up_to_defined_lifelines;
+use crate::io::input::json::action::{lifelines_from_json_names, trace_action_from_json};
+use crate::io::input::json::value::json_value_from_text;
+
+
+/**
+Reads a multi-trace encoded as a JSON object with a "components" field
+which lists, for each co-localization, its "lifelines" and the "trace" of actions observed on them.
+As for .htf files, lifelines which are not in any component are given their own empty component.
+**/
+pub fn multitrace_from_json_text(gen_ctx : &GeneralContext,
+                                 json_str : &str) -> Result<(CoLocalizations,MultiTrace),HibouParsingError> {
+    let json = json_value_from_text(json_str)?;
+    let mut unavailable_lifelines : HashSet<usize> = HashSet::new();
+    let mut colocs : Vec<HashSet<usize>> = vec![];
+    let mut multi_trace : MultiTrace = vec![];
+    for component_json in json.get_array_field("components")? {
+        let lifelines : HashSet<usize> = lifelines_from_json_names(gen_ctx,&component_json.get_string_array_field("lifelines")?)?
+            .into_iter().collect();
+        if !unavailable_lifelines.is_disjoint(&lifelines) {
+            return Err( HibouParsingError::NonDisjointTraceComponents );
+        }
+        let mut trace : Trace = vec![];
+        for action_json in component_json.get_array_field("trace")? {
+            let action = trace_action_from_json(gen_ctx,action_json,true)?;
+            if !lifelines.contains(&action.lf_id) {
+                return Err( HibouParsingError::IllDefinedTraceComponents(format!("lifeline of action {:?} not in predefined co-localisation {:?}",
+                                                                                 action, lifelines)) );
+            }
+            trace.push(action);
+        }
+        unavailable_lifelines.extend(lifelines.iter().cloned());
+        colocs.push(lifelines);
+        multi_trace.push(trace);
+    }
+    complete_canals_up_to_defined_lifelines(gen_ctx, &mut colocs, &mut multi_trace);
+    Ok( (CoLocalizations::new(colocs),multi_trace) )
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::io::input::hsf::implem::parse_hsf_string;
+    use crate::io::input::htf::implem::multitrace_from_text;
+    use crate::io::input::json::signature::general_context_from_json_text;
+    use crate::io::output::to_json::multi_trace::multi_trace_as_json_encoding;
+    use crate::io::output::to_json::signature::signature_as_json_encoding;
+    use super::multitrace_from_json_text;
+
+    #[test]
+    fn json_signature_and_multi_trace_round_trip_test() {
+        let hsf = "@message{m1;m2;m3}\n@lifeline{l1;l2;l3;l4}\n@type{A = {m1,m3}}";
+        let gen_ctx = parse_hsf_string(hsf.to_string()).unwrap();
+        let decoded_ctx = general_context_from_json_text(&signature_as_json_encoding(&gen_ctx)).unwrap();
+        assert_eq!(signature_as_json_encoding(&decoded_ctx),signature_as_json_encoding(&gen_ctx));
+        // ***
+        let htf = "{[l1,l3] l1!m1.l3?m2.l1!m3; [l2] l2?m1; [l4] }".to_string();
+        let (co_localizations,multi_trace) = multitrace_from_text(&gen_ctx,&htf).unwrap();
+        let encoded = multi_trace_as_json_encoding(&gen_ctx,&co_localizations,&multi_trace,Some("a \"quoted\" comment"));
+        let (decoded_colocs,decoded_mt) = multitrace_from_json_text(&decoded_ctx,&encoded).unwrap();
+        assert_eq!(decoded_colocs.locs_lf_ids,co_localizations.locs_lf_ids);
+        assert_eq!(decoded_mt,multi_trace);
+    }
+
+}
@@ -0,0 +1,58 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::HashSet;
+
+use crate::core::general_context::GeneralContext;
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::json::value::json_value_from_text;
+
+
+/**
+Reads a signature encoded as a JSON object with the "lifelines", "messages" and "types" fields
+(each type being an object with a "name" and the "messages" it contains).
+The "types" field may be omitted.
+**/
+pub fn general_context_from_json_text(json_str : &str) -> Result<GeneralContext,HibouParsingError> {
+    let json = json_value_from_text(json_str)?;
+    let mut gen_ctx = GeneralContext::new();
+    for lf_name in json.get_string_array_field("lifelines")? {
+        gen_ctx.add_lf(lf_name.to_string());
+    }
+    for ms_name in json.get_string_array_field("messages")? {
+        gen_ctx.add_msg(ms_name.to_string());
+    }
+    if json.get_field("types").is_some() {
+        for type_json in json.get_array_field("types")? {
+            let mt_name = type_json.get_string_field("name")?;
+            let mut messages : HashSet<usize> = HashSet::new();
+            for ms_name in type_json.get_string_array_field("messages")? {
+                match gen_ctx.get_ms_id(ms_name) {
+                    None => {
+                        return Err( HibouParsingError::MissingMessageDeclarationError(ms_name.to_string()) );
+                    },
+                    Some( ms_id ) => {
+                        messages.insert(ms_id);
+                    }
+                }
+            }
+            gen_ctx.add_mt(mt_name.to_string(),messages);
+        }
+    }
+    Ok( gen_ctx )
+}
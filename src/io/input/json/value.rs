@@ -0,0 +1,252 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use pest::iterators::Pair;
+
+use crate::io::input::error::HibouParsingError;
+
+
+#[allow(unused_imports)]
+use pest::Parser;
+#[allow(unused_imports)]
+use crate::io::input::json::parser::{JsonParser,Rule};
+
+
+/**
+A parsed JSON value.
+The members of objects are kept in their order of declaration.
+**/
+#[derive(Clone, PartialEq, Debug)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String,JsonValue)>)
+}
+
+impl JsonValue {
+
+    pub fn get_field(&self, key : &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(members) => {
+                members.iter().find(|(got_key,_)| got_key == key).map(|(_,value)| value)
+            },
+            _ => {
+                None
+            }
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(content) => {
+                Some(content)
+            },
+            _ => {
+                None
+            }
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&Vec<JsonValue>> {
+        match self {
+            JsonValue::Array(elements) => {
+                Some(elements)
+            },
+            _ => {
+                None
+            }
+        }
+    }
+
+    pub fn get_required_field(&self, key : &str) -> Result<&JsonValue,HibouParsingError> {
+        match self.get_field(key) {
+            None => {
+                Err( HibouParsingError::JsonStructureError(format!("missing field '{:}' in {:}", key, self.get_kind_name())) )
+            },
+            Some( value ) => {
+                Ok( value )
+            }
+        }
+    }
+
+    pub fn get_string_field(&self, key : &str) -> Result<&str,HibouParsingError> {
+        match self.get_required_field(key)?.as_str() {
+            None => {
+                Err( HibouParsingError::JsonStructureError(format!("field '{:}' must be a string", key)) )
+            },
+            Some( content ) => {
+                Ok( content )
+            }
+        }
+    }
+
+    pub fn get_array_field(&self, key : &str) -> Result<&Vec<JsonValue>,HibouParsingError> {
+        match self.get_required_field(key)?.as_array() {
+            None => {
+                Err( HibouParsingError::JsonStructureError(format!("field '{:}' must be an array", key)) )
+            },
+            Some( elements ) => {
+                Ok( elements )
+            }
+        }
+    }
+
+    /**
+    Returns the strings contained in the array of the given field.
+    **/
+    pub fn get_string_array_field(&self, key : &str) -> Result<Vec<&str>,HibouParsingError> {
+        let mut contents = vec![];
+        for element in self.get_array_field(key)? {
+            match element.as_str() {
+                None => {
+                    return Err( HibouParsingError::JsonStructureError(format!("field '{:}' must be an array of strings", key)) );
+                },
+                Some( content ) => {
+                    contents.push(content);
+                }
+            }
+        }
+        Ok( contents )
+    }
+
+    fn get_kind_name(&self) -> &'static str {
+        match self {
+            JsonValue::Null => "null",
+            JsonValue::Bool(_) => "boolean",
+            JsonValue::Number(_) => "number",
+            JsonValue::String(_) => "string",
+            JsonValue::Array(_) => "array",
+            JsonValue::Object(_) => "object"
+        }
+    }
+
+}
+
+
+
+pub fn json_value_from_text(json_str : &str) -> Result<JsonValue,HibouParsingError> {
+    match JsonParser::parse(Rule::JSON_PEST_FILE, json_str) {
+        Err(e) => {
+            return Err( HibouParsingError::MatchError(e.to_string()) );
+        },
+        Ok( ref mut json_pairs ) => {
+            let value_pair = json_pairs.next().unwrap();
+            return Ok( json_value_from_pair(value_pair) );
+        }
+    }
+}
+
+fn json_value_from_pair(value_pair : Pair<Rule>) -> JsonValue {
+    match value_pair.as_rule() {
+        Rule::JSON_NULL => {
+            JsonValue::Null
+        },
+        Rule::JSON_TRUE => {
+            JsonValue::Bool(true)
+        },
+        Rule::JSON_FALSE => {
+            JsonValue::Bool(false)
+        },
+        Rule::JSON_NUMBER => {
+            JsonValue::Number(value_pair.as_str().parse::<f64>().unwrap())
+        },
+        Rule::JSON_STRING => {
+            JsonValue::String(json_string_from_pair(value_pair))
+        },
+        Rule::JSON_ARRAY => {
+            JsonValue::Array(value_pair.into_inner().map(json_value_from_pair).collect())
+        },
+        Rule::JSON_OBJECT => {
+            let mut members = vec![];
+            for member_pair in value_pair.into_inner() {
+                let mut member_content = member_pair.into_inner();
+                let key = json_string_from_pair(member_content.next().unwrap());
+                let value = json_value_from_pair(member_content.next().unwrap());
+                members.push( (key,value) );
+            }
+            JsonValue::Object(members)
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", value_pair.as_rule() );
+        }
+    }
+}
+
+fn json_string_from_pair(string_pair : Pair<Rule>) -> String {
+    let content_pair = string_pair.into_inner().next().unwrap();
+    unescape_json_string(content_pair.as_str())
+}
+
+/**
+Escape sequences are validated by the grammar.
+UTF-16 surrogate pairs are recombined and lone surrogates replaced by U+FFFD.
+**/
+fn unescape_json_string(escaped : &str) -> String {
+    let mut unescaped = String::with_capacity(escaped.len());
+    let mut chars = escaped.chars();
+    let mut pending_high_surrogate : Option<u32> = None;
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            if pending_high_surrogate.take().is_some() {
+                unescaped.push(char::REPLACEMENT_CHARACTER);
+            }
+            unescaped.push(c);
+            continue;
+        }
+        let escape = chars.next().unwrap();
+        if escape == 'u' {
+            let hex : String = chars.by_ref().take(4).collect();
+            let code = u32::from_str_radix(&hex, 16).unwrap();
+            match pending_high_surrogate.take() {
+                Some( high ) if (0xDC00..0xE000).contains(&code) => {
+                    let combined = 0x10000 + ((high - 0xD800) << 10) + (code - 0xDC00);
+                    unescaped.push(char::from_u32(combined).unwrap_or(char::REPLACEMENT_CHARACTER));
+                },
+                previous => {
+                    if previous.is_some() {
+                        unescaped.push(char::REPLACEMENT_CHARACTER);
+                    }
+                    if (0xD800..0xDC00).contains(&code) {
+                        pending_high_surrogate = Some(code);
+                    } else {
+                        unescaped.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+                    }
+                }
+            }
+            continue;
+        }
+        if pending_high_surrogate.take().is_some() {
+            unescaped.push(char::REPLACEMENT_CHARACTER);
+        }
+        match escape {
+            'b' => unescaped.push('\u{8}'),
+            'f' => unescaped.push('\u{c}'),
+            'n' => unescaped.push('\n'),
+            'r' => unescaped.push('\r'),
+            't' => unescaped.push('\t'),
+            other => unescaped.push(other)
+        }
+    }
+    if pending_high_surrogate.is_some() {
+        unescaped.push(char::REPLACEMENT_CHARACTER);
+    }
+    unescaped
+}
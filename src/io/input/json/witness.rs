@@ -0,0 +1,48 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::execution::witness::witness::{ExecutionWitness, ExecutionWitnessStep};
+use crate::core::general_context::GeneralContext;
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::hwf::implem::position_from_text;
+use crate::io::input::json::action::trace_action_from_json;
+use crate::io::input::json::value::{json_value_from_text, JsonValue};
+
+
+/**
+Reads a witness encoded as a JSON array of steps, each with its "position" (written as in the .hwf syntax) and "action".
+**/
+pub fn witness_from_json_text(gen_ctx : &GeneralContext,
+                              json_str : &str) -> Result<ExecutionWitness,HibouParsingError> {
+    let json = json_value_from_text(json_str)?;
+    let steps = match &json {
+        JsonValue::Array(steps) => {
+            steps
+        },
+        _ => {
+            return Err( HibouParsingError::JsonStructureError("a witness must be an array of steps".to_string()) );
+        }
+    };
+    let mut witness : ExecutionWitness = vec![];
+    for step_json in steps {
+        let position = position_from_text(step_json.get_string_field("position")?)?;
+        let action = trace_action_from_json(gen_ctx,step_json.get_required_field("action")?,true)?;
+        witness.push( ExecutionWitnessStep::new(position,action) );
+    }
+    Ok( witness )
+}
@@ -0,0 +1,212 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use pest::iterators::Pair;
+
+use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use crate::core::general_context::GeneralContext;
+use crate::core::message::MessageTypeExpression;
+use crate::io::input::error::HibouParsingError;
+use crate::ltl::formula::LtlFormula;
+
+
+#[allow(unused_imports)]
+use pest::Parser;
+#[allow(unused_imports)]
+use crate::io::input::ltl::parser::{LtlParser,Rule};
+
+
+
+pub fn ltl_formula_from_text(gen_ctx : &GeneralContext,
+                             formula_str : &str) -> Result<LtlFormula,HibouParsingError> {
+    match LtlParser::parse(Rule::LTL_PEST_FILE, formula_str) {
+        Err(e) => {
+            return Err( HibouParsingError::MatchError(e.to_string()) );
+        },
+        Ok( ref mut ltl_pair ) => {
+            let mut content = ltl_pair.next().unwrap().into_inner();
+            let formula_pair : Pair<Rule> = content.next().unwrap();
+            return ltl_formula_from_pair(gen_ctx,formula_pair);
+        }
+    }
+}
+
+
+fn ltl_formula_from_pair(gen_ctx : &GeneralContext,
+                         formula_pair : Pair<Rule>) -> Result<LtlFormula,HibouParsingError> {
+    match formula_pair.as_rule() {
+        Rule::LTL_FORMULA => {
+            // implication is right associative
+            let mut operands = vec![];
+            for sub_pair in formula_pair.into_inner() {
+                operands.push( ltl_formula_from_pair(gen_ctx,sub_pair)? );
+            }
+            let mut formula = operands.pop().unwrap();
+            while let Some(left) = operands.pop() {
+                formula = LtlFormula::Implies(Box::new(left),Box::new(formula));
+            }
+            return Ok( formula );
+        },
+        Rule::LTL_DISJUNCTION => {
+            let mut formula : Option<LtlFormula> = None;
+            for sub_pair in formula_pair.into_inner() {
+                let operand = ltl_formula_from_pair(gen_ctx,sub_pair)?;
+                formula = match formula {
+                    None => {
+                        Some(operand)
+                    },
+                    Some( left ) => {
+                        Some( LtlFormula::Or(Box::new(left),Box::new(operand)) )
+                    }
+                };
+            }
+            return Ok( formula.unwrap() );
+        },
+        Rule::LTL_CONJUNCTION => {
+            let mut formula : Option<LtlFormula> = None;
+            for sub_pair in formula_pair.into_inner() {
+                let operand = ltl_formula_from_pair(gen_ctx,sub_pair)?;
+                formula = match formula {
+                    None => {
+                        Some(operand)
+                    },
+                    Some( left ) => {
+                        Some( LtlFormula::And(Box::new(left),Box::new(operand)) )
+                    }
+                };
+            }
+            return Ok( formula.unwrap() );
+        },
+        Rule::LTL_TEMPORAL => {
+            // binary temporal operators are right associative
+            let mut operands = vec![];
+            let mut operators = vec![];
+            for sub_pair in formula_pair.into_inner() {
+                match sub_pair.as_rule() {
+                    Rule::LTL_UNTIL | Rule::LTL_WEAK_UNTIL | Rule::LTL_RELEASE => {
+                        operators.push(sub_pair.as_rule());
+                    },
+                    _ => {
+                        operands.push( ltl_formula_from_pair(gen_ctx,sub_pair)? );
+                    }
+                }
+            }
+            let mut formula = operands.pop().unwrap();
+            while let Some(left) = operands.pop() {
+                let (left,right) = (Box::new(left),Box::new(formula));
+                formula = match operators.pop().unwrap() {
+                    Rule::LTL_UNTIL => {
+                        LtlFormula::Until(left,right)
+                    },
+                    Rule::LTL_WEAK_UNTIL => {
+                        LtlFormula::WeakUntil(left,right)
+                    },
+                    _ => {
+                        LtlFormula::Release(left,right)
+                    }
+                };
+            }
+            return Ok( formula );
+        },
+        Rule::LTL_UNARY => {
+            let mut sub_pairs : Vec<Pair<Rule>> = formula_pair.into_inner().collect();
+            let mut formula = ltl_formula_from_pair(gen_ctx,sub_pairs.pop().unwrap())?;
+            while let Some(operator_pair) = sub_pairs.pop() {
+                let sub = Box::new(formula);
+                formula = match operator_pair.as_rule() {
+                    Rule::LTL_NOT => {
+                        LtlFormula::Not(sub)
+                    },
+                    Rule::LTL_NEXT => {
+                        LtlFormula::Next(sub)
+                    },
+                    Rule::LTL_EVENTUALLY => {
+                        LtlFormula::Eventually(sub)
+                    },
+                    Rule::LTL_GLOBALLY => {
+                        LtlFormula::Globally(sub)
+                    },
+                    _ => {
+                        panic!("what rule then ? : {:?}", operator_pair.as_rule() );
+                    }
+                };
+            }
+            return Ok( formula );
+        },
+        Rule::HIBOU_true => {
+            return Ok( LtlFormula::True );
+        },
+        Rule::HIBOU_false => {
+            return Ok( LtlFormula::False );
+        },
+        Rule::TRACE_ACTION => {
+            return Ok( LtlFormula::Atom( ltl_atom_from_pair(gen_ctx,formula_pair)? ) );
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", formula_pair.as_rule() );
+        }
+    }
+}
+
+
+/**
+An atomic proposition is given as a trace action in which the message
+may either be the name of a message or that of a message type.
+**/
+fn ltl_atom_from_pair(gen_ctx : &GeneralContext,
+                      action_pair : Pair<Rule>) -> Result<TraceAction,HibouParsingError> {
+    let mut contents = action_pair.into_inner();
+    // ***
+    let lf_name : String  = contents.next().unwrap().as_str().chars().filter(|c| !c.is_whitespace()).collect();
+    let lf_id = match gen_ctx.get_lf_id(&lf_name) {
+        None => {
+            return Err( HibouParsingError::MissingLifelineDeclarationError(lf_name) );
+        },
+        Some( lf_id ) => {
+            lf_id
+        }
+    };
+    // ***
+    let act_kind_pair : Pair<Rule> = contents.next().unwrap();
+    let act_kind = match act_kind_pair.as_rule() {
+        Rule::TRACE_EMISSION_SYMBOL => {
+            TraceActionKind::Emission
+        },
+        Rule::TRACE_RECEPTION_SYMBOL => {
+            TraceActionKind::Reception
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", act_kind_pair.as_rule() );
+        }
+    };
+    // ***
+    let msg_name : String  = contents.next().unwrap().as_str().chars().filter(|c| !c.is_whitespace()).collect();
+    let message = match (gen_ctx.get_ms_id(&msg_name),gen_ctx.get_mt_id(&msg_name)) {
+        (Some( ms_id ),_) => {
+            MessageTypeExpression::Singleton(ms_id)
+        },
+        (None,Some( mt_id )) => {
+            MessageTypeExpression::NamedType(mt_id)
+        },
+        (None,None) => {
+            return Err( HibouParsingError::MissingMessageDeclarationError(msg_name) );
+        }
+    };
+    // ***
+    return Ok( TraceAction::new(lf_id,act_kind,message) );
+}
@@ -0,0 +1,29 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::general_context::GeneralContext;
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::ltl::implem::ltl_formula_from_text;
+use crate::ltl::formula::LtlFormula;
+
+
+
+pub fn parse_ltl_formula(gen_ctx : &GeneralContext,
+                         formula_str : &str) -> Result<LtlFormula,HibouParsingError> {
+    ltl_formula_from_text(gen_ctx,formula_str)
+}
@@ -19,6 +19,12 @@ pub mod hsf;
 pub mod hcf;
 pub mod htf;
 pub mod hif;
+pub mod hwf;
+pub mod json;
+pub mod ltl;
+pub mod query;
+pub mod hmf;
+pub mod puml;
 
 
 
@@ -0,0 +1,278 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::BTreeMap;
+
+use pest::iterators::Pair;
+
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::action::BroadcastPrimitive;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::core::message::MessageTypeExpression;
+use crate::io::input::error::HibouParsingError;
+use crate::io::textual_convention::{SYNTAX_ALT, SYNTAX_LOOP_C, SYNTAX_LOOP_P, SYNTAX_LOOP_W, SYNTAX_PAR, SYNTAX_SEQ, SYNTAX_SYNC};
+
+
+#[allow(unused_imports)]
+use pest::Parser;
+#[allow(unused_imports)]
+use crate::io::input::puml::parser::{PumlParser,Rule};
+
+
+/**
+A PlantUML sequence diagram imported as an interaction,
+together with its signature and the names which had to be changed into hibou labels.
+**/
+pub struct PumlImport {
+    pub gen_ctx : GeneralContext,
+    pub interaction : Interaction,
+    pub renamings : BTreeMap<String,String>
+}
+
+
+/**
+Imports the subset of PlantUML sequence diagrams made of participants, messages
+(possibly from or to the environment via gates)
+and of the 'alt'/'else', 'opt', 'loop', 'par'/'else' and 'group' fragments.
+Notes, references, separators, activations and other layout directives are ignored.
+Lifelines are declared in the order of the participants, followed by the ones only used in messages.
+**/
+pub fn interaction_from_puml_text(puml_str : &str) -> Result<PumlImport,HibouParsingError> {
+    // each statement must be followed by a new line
+    let puml_str = format!("{:}\n", puml_str);
+    match PumlParser::parse(Rule::PUML_PEST_FILE, &puml_str) {
+        Err(e) => {
+            return Err( HibouParsingError::MatchError(e.to_string()) );
+        },
+        Ok( ref mut puml_pair ) => {
+            let block_pair = puml_pair.next().unwrap().into_inner().next().unwrap();
+            let mut import = PumlImport{gen_ctx:GeneralContext::new(),
+                interaction:Interaction::Empty,
+                renamings:BTreeMap::new()};
+            declare_names_in_block(&mut import,block_pair.clone())?;
+            import.interaction = interaction_from_block(&import,block_pair)?;
+            return Ok( import );
+        }
+    }
+}
+
+
+fn hibou_label_from_text(import : &mut PumlImport, text : &str) -> String {
+    let mut label = String::new();
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            label.push(c);
+        } else if !label.ends_with('_') {
+            label.push('_');
+        }
+    }
+    let mut label = label.trim_matches('_').to_string();
+    if !label.chars().next().map_or(false, |c| c.is_alphabetic()) {
+        label = format!("x_{:}", label);
+    }
+    if label == "o" || [SYNTAX_SEQ,SYNTAX_PAR,SYNTAX_ALT,SYNTAX_SYNC,"coreg",SYNTAX_LOOP_W,SYNTAX_LOOP_P,SYNTAX_LOOP_C,"loopS"].contains(&label.as_str()) {
+        label = format!("{:}_", label);
+    }
+    if label != text {
+        import.renamings.insert(text.to_string(),label.clone());
+    }
+    return label;
+}
+
+fn participant_id_from_pair(id_pair : Pair<Rule>) -> String {
+    match id_pair.as_rule() {
+        Rule::PUML_QUOTED_NAME => {
+            id_pair.into_inner().next().unwrap().as_str().to_string()
+        },
+        _ => {
+            id_pair.as_str().to_string()
+        }
+    }
+}
+
+fn message_label_from_pair(import : &mut PumlImport, msg_pair : &Pair<Rule>) -> Result<String,HibouParsingError> {
+    let line = msg_pair.as_span().start_pos().line_col().0;
+    match msg_pair.clone().into_inner().find(|pair| pair.as_rule() == Rule::PUML_LABEL) {
+        Some( label_pair ) if !label_pair.as_str().trim().is_empty() => {
+            Ok( hibou_label_from_text(import,label_pair.as_str().trim()) )
+        },
+        _ => {
+            Err( HibouParsingError::OtherDefinitionError(format!("line {:?} : message without label", line)) )
+        }
+    }
+}
+
+
+fn declare_names_in_block(import : &mut PumlImport, block_pair : Pair<Rule>) -> Result<(),HibouParsingError> {
+    for statement_pair in block_pair.into_inner() {
+        match statement_pair.as_rule() {
+            Rule::PUML_PARTICIPANT => {
+                let mut contents = statement_pair.into_inner();
+                // the kind of participant
+                contents.next();
+                let name_pair = contents.next().unwrap();
+                let name_is_quoted = name_pair.as_rule() == Rule::PUML_QUOTED_NAME;
+                let mut key = participant_id_from_pair(name_pair);
+                if let Some(alias_pair) = contents.next().filter(|pair| pair.as_rule() == Rule::PUML_PARTICIPANT_ALIAS) {
+                    let alias_id_pair = alias_pair.into_inner().next().unwrap();
+                    // the identifier used in messages is the unquoted one
+                    if name_is_quoted || alias_id_pair.as_rule() == Rule::PUML_NAME {
+                        key = participant_id_from_pair(alias_id_pair);
+                    }
+                }
+                let lf_name = hibou_label_from_text(import,&key);
+                import.gen_ctx.add_lf(lf_name);
+            },
+            Rule::PUML_MESSAGE => {
+                let ms_name = message_label_from_pair(import,&statement_pair)?;
+                import.gen_ctx.add_msg(ms_name);
+                for endpoint_pair in statement_pair.into_inner() {
+                    match endpoint_pair.as_rule() {
+                        Rule::PUML_NAME | Rule::PUML_QUOTED_NAME => {
+                            let lf_name = hibou_label_from_text(import,&participant_id_from_pair(endpoint_pair));
+                            import.gen_ctx.add_lf(lf_name);
+                        },
+                        _ => {}
+                    }
+                }
+            },
+            _ => {
+                for sub_pair in statement_pair.into_inner().filter(|pair| pair.as_rule() == Rule::PUML_BLOCK) {
+                    declare_names_in_block(import,sub_pair)?;
+                }
+            }
+        }
+    }
+    return Ok(());
+}
+
+
+fn fold_interactions(mut sub_ints : Vec<Interaction>,
+                     fold : &dyn Fn(Interaction,Interaction) -> Interaction) -> Interaction {
+    match sub_ints.pop() {
+        None => {
+            Interaction::Empty
+        },
+        Some( last ) => {
+            sub_ints.into_iter().rev().fold(last, |acc,sub_int| fold(sub_int,acc))
+        }
+    }
+}
+
+fn sub_blocks_interactions(import : &PumlImport, fragment_pair : Pair<Rule>) -> Result<Vec<Interaction>,HibouParsingError> {
+    let mut sub_ints = vec![];
+    for sub_pair in fragment_pair.into_inner().filter(|pair| pair.as_rule() == Rule::PUML_BLOCK) {
+        sub_ints.push( interaction_from_block(import,sub_pair)? );
+    }
+    return Ok( sub_ints );
+}
+
+fn interaction_from_block(import : &PumlImport, block_pair : Pair<Rule>) -> Result<Interaction,HibouParsingError> {
+    let mut sub_ints = vec![];
+    for statement_pair in block_pair.into_inner() {
+        match statement_pair.as_rule() {
+            Rule::PUML_PARTICIPANT => {
+                // already declared
+            },
+            Rule::PUML_IGNORED => {
+                // notes, separators and layout directives
+            },
+            Rule::PUML_MESSAGE => {
+                sub_ints.push( interaction_from_message(import,statement_pair)? );
+            },
+            Rule::PUML_ALT => {
+                let alternatives = sub_blocks_interactions(import,statement_pair)?;
+                sub_ints.push( fold_interactions(alternatives,&|i1,i2| Interaction::Alt(Box::new(i1),Box::new(i2))) );
+            },
+            Rule::PUML_OPT => {
+                let mut optional = sub_blocks_interactions(import,statement_pair)?;
+                sub_ints.push( Interaction::Alt(Box::new(optional.remove(0)),Box::new(Interaction::Empty)) );
+            },
+            Rule::PUML_LOOP => {
+                let mut repeated = sub_blocks_interactions(import,statement_pair)?;
+                sub_ints.push( Interaction::Loop(vec![],Box::new(repeated.remove(0))) );
+            },
+            Rule::PUML_PAR => {
+                let all_lfs : Vec<usize> = (0..import.gen_ctx.get_lf_num()).collect();
+                let parallel = sub_blocks_interactions(import,statement_pair)?;
+                sub_ints.push( fold_interactions(parallel,&|i1,i2| Interaction::CoReg(all_lfs.clone(),Box::new(i1),Box::new(i2))) );
+            },
+            Rule::PUML_GROUP => {
+                let mut grouped = sub_blocks_interactions(import,statement_pair)?;
+                sub_ints.push( grouped.remove(0) );
+            },
+            _ => {
+                panic!("what rule then ? : {:?}", statement_pair.as_rule() );
+            }
+        }
+    }
+    return Ok( fold_interactions(sub_ints,&|i1,i2| Interaction::CoReg(vec![],Box::new(i1),Box::new(i2))) );
+}
+
+
+fn interaction_from_message(import : &PumlImport, msg_pair : Pair<Rule>) -> Result<Interaction,HibouParsingError> {
+    let line = msg_pair.as_span().start_pos().line_col().0;
+    let label_pair = msg_pair.clone().into_inner().find(|pair| pair.as_rule() == Rule::PUML_LABEL).unwrap();
+    // names were already made into hibou labels when declared
+    let renamed = |text : &str| import.renamings.get(text).cloned().unwrap_or(text.to_string());
+    let ms_id = import.gen_ctx.get_ms_id(&renamed(label_pair.as_str().trim())).unwrap();
+    // ***
+    let mut endpoints : Vec<Option<usize>> = vec![];
+    let mut is_left_arrow = false;
+    for sub_pair in msg_pair.into_inner() {
+        match sub_pair.as_rule() {
+            Rule::PUML_GATE => {
+                endpoints.push(None);
+            },
+            Rule::PUML_NAME | Rule::PUML_QUOTED_NAME => {
+                let lf_name = renamed(&participant_id_from_pair(sub_pair));
+                endpoints.push( Some(import.gen_ctx.get_lf_id(&lf_name).unwrap()) );
+            },
+            Rule::PUML_ARROW_LEFT => {
+                is_left_arrow = true;
+            },
+            _ => {}
+        }
+    }
+    let (origin,target) = if is_left_arrow {
+        (endpoints[1],endpoints[0])
+    } else {
+        (endpoints[0],endpoints[1])
+    };
+    if origin.is_none() && target.is_none() {
+        return Err( HibouParsingError::OtherDefinitionError(format!("line {:?} : message between two gates", line)) );
+    }
+    let targets = target.map_or(vec![], |lf_id| vec![lf_id]);
+    return Ok( Interaction::Action(BroadcastPrimitive::new(origin,MessageTypeExpression::Singleton(ms_id),targets)) );
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::io::output::to_hfiles::interaction::to_hif::interaction_as_hif_encoding;
+    use super::interaction_from_puml_text;
+
+    #[test]
+    fn puml_import_test() {
+        let puml = "@startuml\nparticipant \"The Client\" as C\nC -> S : get(x)\nnote over S\n  end of\nend note\nalt ok\n  S --> C : data\nelse\n  S ->] : error\nend\nloop\n  C <- [ : tick\nend\n@enduml";
+        let import = interaction_from_puml_text(puml).unwrap();
+        assert_eq!(import.renamings.get("get(x)"), Some(&"get_x".to_string()));
+        let hif = interaction_as_hif_encoding(&import.gen_ctx,&import.interaction);
+        assert_eq!(hif.split_whitespace().collect::<String>(),
+                   "seq(C--get_x->S,alt(S--data->C,S--error->|),loopW(tick->C))");
+    }
+}
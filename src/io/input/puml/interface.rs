@@ -0,0 +1,41 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::BTreeMap;
+use std::fs;
+
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::puml::implem::interaction_from_puml_text;
+
+
+/**
+Reads an interaction and its signature from a PlantUML sequence diagram.
+Also returns the names of participants and messages which were changed into hibou labels.
+**/
+pub fn parse_puml_file(file_path : &str) -> Result<(GeneralContext,Interaction,BTreeMap<String,String>),HibouParsingError> {
+    match fs::read_to_string(file_path) {
+        Ok( unparsed_puml_str ) => {
+            let import = interaction_from_puml_text(&unparsed_puml_str)?;
+            return Ok( (import.gen_ctx,import.interaction,import.renamings) );
+        },
+        Err(e) => {
+            return Err( HibouParsingError::FileError(e.to_string()) );
+        }
+    }
+}
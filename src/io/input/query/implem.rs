@@ -0,0 +1,210 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use pest::iterators::Pair;
+
+use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use crate::core::general_context::GeneralContext;
+use crate::core::message::MessageTypeExpression;
+use crate::io::input::error::HibouParsingError;
+use crate::trace_manip::query::query::{TraceQuery, TraceQueryActions, TraceQueryPattern, TraceQueryScope};
+
+
+#[allow(unused_imports)]
+use pest::Parser;
+#[allow(unused_imports)]
+use crate::io::input::query::parser::{QueryParser,Rule};
+
+
+
+pub fn trace_queries_from_text(gen_ctx : &GeneralContext,
+                               queries_str : &str) -> Result<Vec<TraceQuery>,HibouParsingError> {
+    match QueryParser::parse(Rule::QUERY_PEST_FILE, queries_str) {
+        Err(e) => {
+            return Err( HibouParsingError::MatchError(e.to_string()) );
+        },
+        Ok( ref mut query_pair ) => {
+            let mut content = query_pair.next().unwrap().into_inner();
+            let queries_pair : Pair<Rule> = content.next().unwrap();
+            let mut queries = vec![];
+            for sub_pair in queries_pair.into_inner() {
+                queries.push( trace_query_from_pair(gen_ctx,sub_pair)? );
+            }
+            return Ok( queries );
+        }
+    }
+}
+
+
+fn trace_query_from_pair(gen_ctx : &GeneralContext,
+                         query_pair : Pair<Rule>) -> Result<TraceQuery,HibouParsingError> {
+    let mut contents = query_pair.into_inner();
+    let pattern_pair = contents.next().unwrap();
+    let pattern_rule = pattern_pair.as_rule();
+    let mut pattern_contents = pattern_pair.into_inner();
+    let pattern = match pattern_rule {
+        Rule::QUERY_RESPONSE => {
+            let triggers = query_actions_from_pair(gen_ctx,pattern_contents.next().unwrap())?;
+            let responses = query_actions_from_pair(gen_ctx,pattern_contents.next().unwrap())?;
+            TraceQueryPattern::Response(triggers,responses)
+        },
+        Rule::QUERY_PRECEDENCE => {
+            let causes = query_actions_from_pair(gen_ctx,pattern_contents.next().unwrap())?;
+            let effects = query_actions_from_pair(gen_ctx,pattern_contents.next().unwrap())?;
+            TraceQueryPattern::Precedence(causes,effects)
+        },
+        Rule::QUERY_ABSENCE => {
+            let forbidden = query_actions_from_pair(gen_ctx,pattern_contents.next().unwrap())?;
+            TraceQueryPattern::Absence(forbidden)
+        },
+        Rule::QUERY_BOUNDED_EXISTENCE => {
+            let bounded = query_actions_from_pair(gen_ctx,pattern_contents.next().unwrap())?;
+            let bound_str = pattern_contents.next().unwrap().as_str();
+            match bound_str.parse::<u32>() {
+                Err(e) => {
+                    return Err( HibouParsingError::MatchError(format!("invalid bound '{}' : {}", bound_str, e)) );
+                },
+                Ok( bound ) => {
+                    TraceQueryPattern::BoundedExistence(bounded,bound)
+                }
+            }
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", pattern_rule );
+        }
+    };
+    let scope = match contents.next() {
+        None => {
+            TraceQueryScope::Component
+        },
+        Some( scope_pair ) => {
+            match scope_pair.as_rule() {
+                Rule::QUERY_SCOPE_COMPONENT => {
+                    TraceQueryScope::Component
+                },
+                Rule::QUERY_SCOPE_LIFELINE => {
+                    TraceQueryScope::Lifeline
+                },
+                _ => {
+                    panic!("what rule then ? : {:?}", scope_pair.as_rule() );
+                }
+            }
+        }
+    };
+    return Ok( TraceQuery::new(pattern,scope) );
+}
+
+
+fn query_actions_from_pair(gen_ctx : &GeneralContext,
+                           actions_pair : Pair<Rule>) -> Result<TraceQueryActions,HibouParsingError> {
+    let mut query_actions = vec![];
+    for action_pair in actions_pair.into_inner() {
+        query_actions.push( query_action_from_pair(gen_ctx,action_pair)? );
+    }
+    return Ok( query_actions );
+}
+
+
+/**
+The message of an action pattern may either be the name of a message or that of a message type.
+**/
+fn query_action_from_pair(gen_ctx : &GeneralContext,
+                          action_pair : Pair<Rule>) -> Result<TraceAction,HibouParsingError> {
+    let mut contents = action_pair.into_inner();
+    // ***
+    let lf_name : String  = contents.next().unwrap().as_str().chars().filter(|c| !c.is_whitespace()).collect();
+    let lf_id = match gen_ctx.get_lf_id(&lf_name) {
+        None => {
+            return Err( HibouParsingError::MissingLifelineDeclarationError(lf_name) );
+        },
+        Some( lf_id ) => {
+            lf_id
+        }
+    };
+    // ***
+    let act_kind_pair : Pair<Rule> = contents.next().unwrap();
+    let act_kind = match act_kind_pair.as_rule() {
+        Rule::TRACE_EMISSION_SYMBOL => {
+            TraceActionKind::Emission
+        },
+        Rule::TRACE_RECEPTION_SYMBOL => {
+            TraceActionKind::Reception
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", act_kind_pair.as_rule() );
+        }
+    };
+    // ***
+    let msg_name : String  = contents.next().unwrap().as_str().chars().filter(|c| !c.is_whitespace()).collect();
+    let message = match (gen_ctx.get_ms_id(&msg_name),gen_ctx.get_mt_id(&msg_name)) {
+        (Some( ms_id ),_) => {
+            MessageTypeExpression::Singleton(ms_id)
+        },
+        (None,Some( mt_id )) => {
+            MessageTypeExpression::NamedType(mt_id)
+        },
+        (None,None) => {
+            return Err( HibouParsingError::MissingMessageDeclarationError(msg_name) );
+        }
+    };
+    // ***
+    return Ok( TraceAction::new(lf_id,act_kind,message) );
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::core::message::MessageTypeExpression;
+    use crate::trace_manip::query::query::{TraceQuery, TraceQueryPattern, TraceQueryScope};
+    use super::trace_queries_from_text;
+
+    #[test]
+    fn query_list_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        gen_ctx.add_mt("t".to_string(),hashset!{0,1});
+        let emission_m1 = TraceAction::new(0,TraceActionKind::Emission,MessageTypeExpression::Singleton(0));
+        let reception_m1 = TraceAction::new(1,TraceActionKind::Reception,MessageTypeExpression::Singleton(0));
+        let reception_m2 = TraceAction::new(1,TraceActionKind::Reception,MessageTypeExpression::Singleton(1));
+        let emission_t = TraceAction::new(0,TraceActionKind::Emission,MessageTypeExpression::NamedType(0));
+        // ***
+        let queries_str = "response(l1!m1, l2?m1|l2?m2) per lifeline;
+                           precedence(l1!m1, l2?m1) per component;
+                           absence(l2?m2);
+                           bounded_existence(l1!t, 2);";
+        let queries = trace_queries_from_text(&gen_ctx,queries_str).unwrap();
+        assert_eq!(queries,
+                   vec![TraceQuery::new(TraceQueryPattern::Response(vec![emission_m1.clone()],vec![reception_m1.clone(),reception_m2.clone()]),
+                                        TraceQueryScope::Lifeline),
+                        TraceQuery::new(TraceQueryPattern::Precedence(vec![emission_m1],vec![reception_m1]),
+                                        TraceQueryScope::Component),
+                        TraceQuery::new(TraceQueryPattern::Absence(vec![reception_m2]),
+                                        TraceQueryScope::Component),
+                        TraceQuery::new(TraceQueryPattern::BoundedExistence(vec![emission_t],2),
+                                        TraceQueryScope::Component)]);
+        // undeclared messages and missing separators are rejected
+        assert!(trace_queries_from_text(&gen_ctx,"absence(l1!m3)").is_err());
+        assert!(trace_queries_from_text(&gen_ctx,"absence(l1!m1) absence(l1!m2)").is_err());
+    }
+}
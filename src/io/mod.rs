@@ -15,7 +15,7 @@ limitations under the License.
 */
 
 pub mod file_extensions;
-mod textual_convention;
+pub(crate) mod textual_convention;
 
 pub mod input;
 pub mod output;
\ No newline at end of file
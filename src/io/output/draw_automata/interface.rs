@@ -0,0 +1,38 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use autour_core::traits::repr::AutGraphvizDrawable;
+use graphviz_dot_builder::traits::DotTranslatable;
+
+use crate::core::execution::automaton::build::InteractionAutomaton;
+use crate::core::general_context::GeneralContext;
+use crate::io::output::draw_automata::letter_printer::TraceActionLetterPrinter;
+
+
+
+pub fn write_interaction_automaton_as_dot(file_path : &Path,
+                                          gen_ctx : &GeneralContext,
+                                          automaton : &InteractionAutomaton) {
+    let printer = TraceActionLetterPrinter::new(gen_ctx,&automaton.letters);
+    let graph = automaton.nfa.to_dot(false,&HashSet::new(),&printer);
+    let mut file = File::create(file_path).unwrap();
+    file.write( graph.to_dot_string().as_bytes() );
+}
@@ -0,0 +1,78 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+use crate::io::output::draw_traces::interface::text_repr_trace_action;
+
+
+/**
+Prints the letters of an interaction automaton as the trace actions they index.
+**/
+pub struct TraceActionLetterPrinter<'a> {
+    pub gen_ctx : &'a GeneralContext,
+    pub letters : &'a Vec<TraceAction>
+}
+
+impl<'a> TraceActionLetterPrinter<'a> {
+    pub fn new(gen_ctx : &'a GeneralContext, letters : &'a Vec<TraceAction>) -> Self {
+        TraceActionLetterPrinter{gen_ctx,letters}
+    }
+}
+
+impl<'a> AbstractLanguagePrinter<usize> for TraceActionLetterPrinter<'a> {
+
+    fn is_letter_string_repr_atomic(&self, _letter : &usize) -> bool {
+        true
+    }
+
+    fn get_letter_string_repr(&self, letter : &usize) -> String {
+        text_repr_trace_action(self.letters.get(*letter).unwrap(),self.gen_ctx)
+    }
+
+    fn get_concatenation_separator(&self, _use_html : bool) -> &'static str {
+        "."
+    }
+
+    fn get_alternation_separator(&self, _use_html : bool) -> &'static str {
+        "|"
+    }
+
+    fn get_intersection_separator(&self, _use_html : bool) -> &'static str {
+        "&"
+    }
+
+    fn get_wildcard_symbol(&self, _use_html : bool) -> &'static str {
+        "#"
+    }
+
+    fn get_negate_symbol(&self, _use_html : bool) -> &'static str {
+        "!"
+    }
+
+    fn get_empty_symbol(&self, _use_html : bool) -> &'static str {
+        "∅"
+    }
+
+    fn get_epsilon_symbol(&self, _use_html : bool) -> &'static str {
+        "ε"
+    }
+
+}
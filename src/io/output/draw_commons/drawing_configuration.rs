@@ -0,0 +1,169 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use rusttype::Scale;
+
+use crate::io::output::draw_commons::hibou_color_palette::*;
+use crate::io::output::draw_commons::sd_drawing_conf::*;
+
+// **********
+
+/**
+The colors used by the drawers, each associated to a role.
+The "symbol" color is used for grammar symbols, fragment frames and actions.
+The "faded" color is used for elements which are no longer relevant (e.g. unobserved canals).
+**/
+#[derive(Clone, PartialEq, Debug)]
+pub struct DrawingPalette {
+    pub background : [u8;3],
+    pub lifeline : [u8;3],
+    pub message : [u8;3],
+    pub message_type : [u8;3],
+    pub symbol : [u8;3],
+    pub faded : [u8;3],
+    pub highlight : [u8;3],
+    pub covered : [u8;3],
+    pub uncovered : [u8;3]
+}
+
+impl DrawingPalette {
+
+    pub fn hibou() -> DrawingPalette {
+        DrawingPalette{background:HCP_White,
+            lifeline:HC_Lifeline,
+            message:HC_Message,
+            message_type:HC_MessageKind,
+            symbol:HC_Grammar_Symbol,
+            faded:HCP_LightGray,
+            highlight:HCP_StandardPurple,
+            covered:HCP_StandardGreen,
+            uncovered:HCP_LightRed}
+    }
+
+    pub fn monochrome() -> DrawingPalette {
+        DrawingPalette{background:HCP_White,
+            lifeline:HCP_Black,
+            message:HCP_Black,
+            message_type:HCP_Black,
+            symbol:HCP_Black,
+            faded:HCP_BrightGray,
+            highlight:HCP_Black,
+            covered:HCP_Black,
+            uncovered:HCP_BrightGray}
+    }
+
+    pub fn dark() -> DrawingPalette {
+        DrawingPalette{background:HCP_DarkGray,
+            lifeline:HCP_BrightBlue,
+            message:HCP_BrightGreen,
+            message_type:HCP_LightGreen,
+            symbol:HCP_White,
+            faded:HCP_LightGray,
+            highlight:HCP_BrightPurple,
+            covered:HCP_BrightGreen,
+            uncovered:HCP_BrightRed}
+    }
+
+}
+
+/**
+Configuration of the drawing of interactions, multi-traces and process transitions.
+Sizes are given before being multiplied by the scale,
+and the default configuration reproduces the historical rendering of HIBOU.
+**/
+#[derive(Clone, PartialEq, Debug)]
+pub struct DrawingConfiguration {
+    pub palette : DrawingPalette,
+    pub scale : f32,
+    pub font_size : f32,
+    pub margin : f32,
+    // if false, message type expressions are replaced by the set of messages they denote
+    pub show_types : bool
+}
+
+impl DrawingConfiguration {
+
+    pub fn new(palette : DrawingPalette,
+               scale : f32,
+               font_size : f32,
+               margin : f32,
+               show_types : bool) -> DrawingConfiguration {
+        DrawingConfiguration{palette,scale,font_size,margin,show_types}
+    }
+
+    pub fn default() -> DrawingConfiguration {
+        DrawingConfiguration::new(DrawingPalette::hibou(),
+                                  1.0,
+                                  BASE_FONT_HEIGHT,
+                                  BASE_MARGIN,
+                                  true)
+    }
+
+    fn scale_factor(&self) -> f32 {
+        DEFAULT_SCALE_FACTOR*self.scale
+    }
+
+    pub fn margin(&self) -> f32 {
+        self.margin*self.scale_factor()
+    }
+
+    pub fn horizontal_size(&self) -> f32 {
+        BASE_HORIZONTAL_SIZE*self.scale_factor()
+    }
+
+    /**
+    The vertical unit follows the font size so that lines of text do not overlap.
+    **/
+    pub fn vertical_size(&self) -> f32 {
+        BASE_VERTICAL_SIZE*(self.font_size/BASE_FONT_HEIGHT)*self.scale_factor()
+    }
+
+    pub fn fragment_padding(&self) -> f32 {
+        BASE_FRAGMENT_PADDING*self.scale_factor()
+    }
+
+    pub fn fragment_title_margin(&self) -> f32 {
+        BASE_FRAGMENT_TITLE_MARGIN*self.scale_factor()
+    }
+
+    pub fn thickness(&self) -> f32 {
+        BASE_THICKNESS*self.scale_factor()
+    }
+
+    pub fn arrow_head_length(&self) -> f32 {
+        BASE_ARROW_HEAD_LENGTH*self.scale_factor()
+    }
+
+    pub fn font_height(&self) -> f32 {
+        self.font_size*self.scale_factor()
+    }
+
+    pub fn font_width(&self) -> f32 {
+        self.font_height()*FONT_X_PROPORTION
+    }
+
+    pub fn font_scale(&self) -> Scale {
+        Scale { x: self.font_width(), y: self.font_height() }
+    }
+
+    /**
+    Font size (in points) of the labels of term trees drawn with graphviz.
+    **/
+    pub fn term_tree_font_size(&self) -> u32 {
+        (BASE_TERM_TREE_FONT_SIZE*(self.font_size/BASE_FONT_HEIGHT)*self.scale).round() as u32
+    }
+
+}
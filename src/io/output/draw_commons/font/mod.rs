@@ -14,8 +14,7 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
-use rusttype::{Font, Scale};
-use crate::io::output::draw_commons::sd_drawing_conf::{FONT_HEIGHT, FONT_WIDTH};
+use rusttype::Font;
 
 const DRAWING_GRAPHIC_FONT: &'static [u8] = include_bytes!("DejaVuSansMono.ttf");
 
@@ -25,5 +24,3 @@ const DRAWING_GRAPHIC_FONT: &'static [u8] = include_bytes!("DejaVuSansMono.ttf")
 pub fn get_hibou_font() -> Font<'static> {
     Font::try_from_bytes(DRAWING_GRAPHIC_FONT).unwrap()
 }
-
-pub const HIBOU_FONT_SCALE : Scale = Scale { x: FONT_WIDTH, y: FONT_HEIGHT };
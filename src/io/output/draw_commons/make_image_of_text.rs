@@ -23,38 +23,47 @@ use image_colored_text::ttp::TextToPrint;
 use imageproc::drawing::draw_filled_rect_mut;
 use imageproc::rect::Rect;
 
-use crate::io::output::draw_commons::font::{get_hibou_font, HIBOU_FONT_SCALE};
-use crate::io::output::draw_commons::hibou_color_palette::HCP_White;
-use crate::io::output::draw_commons::sd_drawing_conf::{FONT_HEIGHT, MARGIN};
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
+use crate::io::output::draw_commons::font::get_hibou_font;
 
 
 pub fn new_image_with_colored_text(path : &Path,
+                                   draw_conf : &DrawingConfiguration,
                                    alignment : &MultiLineTextAlignment,
                                    text_lines : &Vec<Vec<TextToPrint>>) {
+    let image = make_image_of_colored_text(draw_conf,alignment,text_lines);
+    image.save(path).unwrap();
+}
+
+pub fn make_image_of_colored_text(draw_conf : &DrawingConfiguration,
+                                  alignment : &MultiLineTextAlignment,
+                                  text_lines : &Vec<Vec<TextToPrint>>) -> RgbImage {
+    let font_scale = draw_conf.font_scale();
+    let margin = draw_conf.margin();
     //
     let lines_widths : Vec<f32> = text_lines.iter()
-        .map(|x| TextToPrint::get_text_width(x, &get_hibou_font(), &HIBOU_FONT_SCALE) ).collect();
+        .map(|x| TextToPrint::get_text_width(x, &get_hibou_font(), &font_scale) ).collect();
     let max_line_width = lines_widths.into_iter()
         .max_by(|x, y| x.abs().partial_cmp(&y.abs()).unwrap() )
         .unwrap();
     // ***
-    let img_width : f32 = 2.0*MARGIN + max_line_width;
-    let img_height : f32 = 2.0*MARGIN + (text_lines.len() as f32)*(FONT_HEIGHT);
+    let img_width : f32 = 2.0*margin + max_line_width;
+    let img_height : f32 = 2.0*margin + (text_lines.len() as f32)*(draw_conf.font_height());
     // Draw Frame
     let mut image = RgbImage::new( img_width as u32, img_height as u32);
     draw_filled_rect_mut(&mut image,
                          Rect::at(0,0).of_size(img_width as u32,img_height as u32),
-                         Rgb(HCP_White));
+                         Rgb(draw_conf.palette.background));
     // Draw content text
     draw_multiline_colored_text(&mut image,
-                                &DrawCoord::StartingAt(MARGIN),
-                                &DrawCoord::StartingAt(MARGIN),
+                                &DrawCoord::StartingAt(margin),
+                                &DrawCoord::StartingAt(margin),
                                 alignment,
                                 text_lines,
                                 &get_hibou_font(),
-                                &HIBOU_FONT_SCALE);
+                                &font_scale);
     // ***
-    image.save(path).unwrap();
+    image
 }
 
 
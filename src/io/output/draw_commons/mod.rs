@@ -17,4 +17,5 @@ limitations under the License.
 pub mod font;
 pub mod hibou_color_palette;
 pub mod sd_drawing_conf;
-pub mod make_image_of_text;
\ No newline at end of file
+pub mod make_image_of_text;
+pub mod drawing_configuration;
\ No newline at end of file
@@ -29,21 +29,9 @@ pub const BASE_ARROW_HEAD_LENGTH : f32 = 5.0;
 pub const BASE_FRONTIER_CIRCLE_RADIUS : f32 = 5.0;
 pub const BASE_GATE_SIZE : f32 = 4.0;
 // **********
-const SCALE_FACTOR : f32 = 2.0;
+pub const BASE_TERM_TREE_FONT_SIZE : f32 = 14.0;
+pub const FONT_X_PROPORTION : f32 = 1.0;
 // **********
-pub const MARGIN : f32 = BASE_MARGIN*SCALE_FACTOR;
-pub const HORIZONTAL_SIZE : f32 = BASE_HORIZONTAL_SIZE*SCALE_FACTOR;
-pub const VERTICAL_SIZE : f32 = BASE_VERTICAL_SIZE*SCALE_FACTOR;
-pub const FRAGMENT_PADDING : f32 = BASE_FRAGMENT_PADDING*SCALE_FACTOR;
-pub const FRAGMENT_TITLE_MARGIN : f32 = BASE_FRAGMENT_TITLE_MARGIN*SCALE_FACTOR;
+// the scaled sizes are given by the DrawingConfiguration
+pub const DEFAULT_SCALE_FACTOR : f32 = 2.0;
 // **********
-pub const THICKNESS : f32 = BASE_THICKNESS*SCALE_FACTOR;
-pub const FONT_HEIGHT : f32 = BASE_FONT_HEIGHT*SCALE_FACTOR;
-pub const FONT_X_PROPORTION : f32 = 1.0;
-pub const FONT_WIDTH : f32 = FONT_HEIGHT*FONT_X_PROPORTION;
-pub const EVAL_X_PADDING : f32 = BASE_EVAL_X_PADDING*SCALE_FACTOR;
-pub const EVAL_HEIGHT : f32 = BASE_EVAL_HEIGHT*SCALE_FACTOR;
-pub const ARROW_HEAD_LENGTH : f32 = BASE_ARROW_HEAD_LENGTH*SCALE_FACTOR;
-pub const FRONTIER_CIRCLE_RADIUS : f32 = BASE_FRONTIER_CIRCLE_RADIUS*SCALE_FACTOR;
-pub const GATE_SIZE : f32 = BASE_GATE_SIZE*SCALE_FACTOR;
-// **********
\ No newline at end of file
@@ -14,18 +14,16 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
-use image::{Rgb, RgbImage};
-use imageproc::drawing::draw_line_segment_mut;
+use image::Rgb;
 
-use crate::io::output::draw_commons::hibou_color_palette::HCP_Black;
+use crate::io::output::draw_interactions::as_sd::util::canvas::SequenceDiagramCanvas;
 
 
 // **********
 
 
-pub fn draw_line_for_message_exchange(image : &mut RgbImage, x_left : f32, x_right : f32, y_pos : f32) {
-    draw_line_segment_mut(image,
-                          (x_left, y_pos),
-                          (x_right, y_pos),
-                          Rgb(HCP_Black));
+pub fn draw_line_for_message_exchange(canvas : &mut dyn SequenceDiagramCanvas, x_left : f32, x_right : f32, y_pos : f32, color : Rgb<u8>) {
+    canvas.draw_line((x_left, y_pos),
+                     (x_right, y_pos),
+                     color);
 }
\ No newline at end of file
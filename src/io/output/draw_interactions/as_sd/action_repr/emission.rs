@@ -19,38 +19,39 @@ limitations under the License.
 
 use std::collections::HashMap;
 
-use image::{Rgb, RgbImage};
-use image_colored_text::draw::single_line::{draw_line_of_colored_text, DrawCoord};
+use image::Rgb;
+use image_colored_text::draw::single_line::DrawCoord;
 
 
 use crate::core::general_context::GeneralContext;
 use crate::core::message::MessageTypeExpression;
-use crate::io::output::draw_commons::font::{get_hibou_font, HIBOU_FONT_SCALE};
-use crate::io::output::draw_commons::hibou_color_palette::HCP_Black;
-use crate::io::output::draw_commons::sd_drawing_conf::*;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
 use crate::io::output::draw_interactions::as_sd::action_repr::common::draw_line_for_message_exchange;
 use crate::io::output::draw_interactions::as_sd::util::arrow_heads::{draw_arrowhead_leftward, draw_arrowhead_rightward};
+use crate::io::output::draw_interactions::as_sd::util::canvas::SequenceDiagramCanvas;
 use crate::io::output::draw_interactions::as_sd::util::dimensions_tools::get_y_pos_from_yshift;
 use crate::io::output::draw_interactions::as_sd::util::lf_coords::DrawingLifelineCoords;
 use crate::io::output::draw_messages::diagram_repr_message;
 
 // **********
 
-pub fn draw_emission( image : &mut RgbImage,
+pub fn draw_emission( canvas : &mut dyn SequenceDiagramCanvas,
                     gen_ctx: &GeneralContext,
+                    draw_conf : &DrawingConfiguration,
                       message : &MessageTypeExpression,
                       source : usize,
                       targets : &Vec<usize>,
                     lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
                     yshift : u32) -> [usize;2] {
+    let color = Rgb(draw_conf.palette.symbol);
     // ***
     let mut min_lf_id : usize = source;
     let mut max_lf_id : usize = source;
     // ***
-    let msg_to_print = diagram_repr_message(message,gen_ctx);
+    let msg_to_print = diagram_repr_message(message,gen_ctx,draw_conf);
     // ***
-    let text_y_pos = get_y_pos_from_yshift(yshift) + VERTICAL_SIZE/2.0;
-    let arrow_y_pos = get_y_pos_from_yshift(yshift+2);
+    let text_y_pos = get_y_pos_from_yshift(draw_conf,yshift) + draw_conf.vertical_size()/2.0;
+    let arrow_y_pos = get_y_pos_from_yshift(draw_conf,yshift+2);
     // ***
     match targets.len() {
         0 => {
@@ -58,15 +59,13 @@ pub fn draw_emission( image : &mut RgbImage,
             // ***
             let msg_x_left = main_lf_coords.x_middle;
             let msg_x_right= msg_x_left + (main_lf_coords.x_span_inner/2.0);
-            draw_arrowhead_rightward(image,msg_x_right,arrow_y_pos,Rgb(HCP_Black));
-            draw_line_for_message_exchange(image,msg_x_left,msg_x_right,arrow_y_pos);
+            draw_arrowhead_rightward(canvas,draw_conf,msg_x_right,arrow_y_pos,color);
+            draw_line_for_message_exchange(canvas,msg_x_left,msg_x_right,arrow_y_pos,color);
             let msg_x_middle = (msg_x_left + msg_x_right)/2.0;
-            draw_line_of_colored_text(image,
-                                      &DrawCoord::CenteredAround(msg_x_middle),
-                                      &DrawCoord::CenteredAround(text_y_pos),
-                                      &msg_to_print,
-                                      &get_hibou_font(),
-                                      &HIBOU_FONT_SCALE);
+            canvas.draw_text(&DrawCoord::CenteredAround(msg_x_middle),
+                             &DrawCoord::CenteredAround(text_y_pos),
+                             &msg_to_print,
+                             &draw_conf.font_scale());
         },
         1 => {
             let origin_lf_coords = lf_x_widths.get(&source).unwrap();
@@ -78,11 +77,11 @@ pub fn draw_emission( image : &mut RgbImage,
             let target_lf_coords = lf_x_widths.get(&target_lf_id).unwrap();
             // ***
             if source < *target_lf_id {
-                draw_arrowhead_rightward(image,target_lf_coords.x_middle, arrow_y_pos,Rgb(HCP_Black));
+                draw_arrowhead_rightward(canvas,draw_conf,target_lf_coords.x_middle, arrow_y_pos,color);
             } else {
-                draw_arrowhead_leftward(image,target_lf_coords.x_middle, arrow_y_pos,Rgb(HCP_Black));
+                draw_arrowhead_leftward(canvas,draw_conf,target_lf_coords.x_middle, arrow_y_pos,color);
             }
-            draw_line_for_message_exchange(image,target_lf_coords.x_middle,origin_lf_coords.x_middle,arrow_y_pos);
+            draw_line_for_message_exchange(canvas,target_lf_coords.x_middle,origin_lf_coords.x_middle,arrow_y_pos,color);
             // ***
             let mut anchor_lf_id : usize = *target_lf_id;
             if target_lf_id == &source {
@@ -102,12 +101,10 @@ pub fn draw_emission( image : &mut RgbImage,
             }
             let anchor_lf_coords = lf_x_widths.get(&anchor_lf_id).unwrap();
             let msg_x_middle = (origin_lf_coords.x_middle + anchor_lf_coords.x_middle)/2.0;
-            draw_line_of_colored_text(image,
-                                      &DrawCoord::CenteredAround(msg_x_middle),
-                                      &DrawCoord::CenteredAround(text_y_pos),
-                                      &msg_to_print,
-                                      &get_hibou_font(),
-                                      &HIBOU_FONT_SCALE);
+            canvas.draw_text(&DrawCoord::CenteredAround(msg_x_middle),
+                             &DrawCoord::CenteredAround(text_y_pos),
+                             &msg_to_print,
+                             &draw_conf.font_scale());
         },
         _ => {
             {
@@ -115,16 +112,14 @@ pub fn draw_emission( image : &mut RgbImage,
                 // ***
                 let msg_x_left = main_lf_coords.x_middle;
                 let msg_x_right= msg_x_left +(main_lf_coords.x_span_inner/2.0);
-                draw_arrowhead_rightward(image,msg_x_right, arrow_y_pos,Rgb(HCP_Black));
+                draw_arrowhead_rightward(canvas,draw_conf,msg_x_right, arrow_y_pos,color);
                 //
-                draw_line_for_message_exchange(image,msg_x_left,msg_x_right,arrow_y_pos);
+                draw_line_for_message_exchange(canvas,msg_x_left,msg_x_right,arrow_y_pos,color);
                 let msg_x_middle = (msg_x_left + msg_x_right)/2.0;
-                draw_line_of_colored_text(image,
-                                          &DrawCoord::CenteredAround(msg_x_middle),
-                                          &DrawCoord::CenteredAround(text_y_pos),
-                                          &msg_to_print,
-                                          &get_hibou_font(),
-                                          &HIBOU_FONT_SCALE);
+                canvas.draw_text(&DrawCoord::CenteredAround(msg_x_middle),
+                                 &DrawCoord::CenteredAround(text_y_pos),
+                                 &msg_to_print,
+                                 &draw_conf.font_scale());
                 // ***
             }
             for tar_lf_id in targets {
@@ -137,8 +132,8 @@ pub fn draw_emission( image : &mut RgbImage,
                 let tar_x_right = tar_lf_coords.x_middle;
                 let tar_x_left= tar_x_right - (tar_lf_coords.x_span_inner/2.0);
                 //
-                draw_arrowhead_rightward(image, tar_x_right, arrow_y_pos,Rgb(HCP_Black));
-                draw_line_for_message_exchange(image,tar_x_left,tar_x_right,arrow_y_pos);
+                draw_arrowhead_rightward(canvas,draw_conf, tar_x_right, arrow_y_pos,color);
+                draw_line_for_message_exchange(canvas,tar_x_left,tar_x_right,arrow_y_pos,color);
             }
         }
     }
@@ -16,22 +16,21 @@ limitations under the License.
 
 use std::cmp;
 use std::collections::{BTreeSet, HashMap};
+use std::slice::Iter;
 
-use image::{Rgb, RgbImage};
-use image_colored_text::draw::single_line::{draw_line_of_colored_text, DrawCoord};
+use image::Rgb;
+use image_colored_text::draw::single_line::DrawCoord;
 use image_colored_text::ttp::TextToPrint;
-use imageproc::drawing::draw_line_segment_mut;
 use crate::core::execution::trace::trace::TraceAction;
 
 
 use crate::core::general_context::GeneralContext;
 use crate::core::language::syntax::interaction::Interaction;
 use crate::core::language::util::get_recursive_frag::{get_recursive_alt_frags, get_recursive_coreg_frags, get_recursive_sync_frags};
-use crate::io::output::draw_commons::font::{get_hibou_font, HIBOU_FONT_SCALE};
-use crate::io::output::draw_commons::hibou_color_palette::{HC_Lifeline, HCP_Black};
-use crate::io::output::draw_commons::sd_drawing_conf::*;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
 use crate::io::output::draw_interactions::as_sd::action_repr::emission::draw_emission;
 use crate::io::output::draw_interactions::as_sd::action_repr::reception::draw_reception;
+use crate::io::output::draw_interactions::as_sd::util::canvas::SequenceDiagramCanvas;
 use crate::io::output::draw_interactions::as_sd::util::dimensions_tools::get_y_pos_from_yshift;
 use crate::io::output::draw_interactions::as_sd::util::lf_coords::DrawingLifelineCoords;
 use crate::io::output::draw_traces::implem::trace_action::diagram_repr_trace_actions;
@@ -40,25 +39,38 @@ use crate::io::textual_convention::*;
 
 // **********
 
-pub fn draw_interaction_rec(    image : &mut RgbImage,
+pub fn draw_interaction_rec(    canvas : &mut dyn SequenceDiagramCanvas,
                                 gen_ctx : &GeneralContext,
+                                draw_conf : &DrawingConfiguration,
                                 interaction : &Interaction,
                                 lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
                                 lf_num : usize,
                                 nest_shift : &mut u32,
-                                yshift : &mut u32 )
+                                yshift : &mut u32,
+                                actions_colors : &mut Iter<Rgb<u8>>)
                         -> [usize;2] { // returns left and right borders of the interaction
     match interaction {
         &Interaction::Empty => {
             return [lf_num,0]; // because when going up we keep the minimum on the left and maximum on the right
         },
         &Interaction::Action(ref bp) => {
+            // the arrows of the action are drawn in the next given color, if any
+            let action_conf = match actions_colors.next() {
+                None => {
+                    draw_conf.clone()
+                },
+                Some( got_color ) => {
+                    let mut colored_conf = draw_conf.clone();
+                    colored_conf.palette.symbol = got_color.0;
+                    colored_conf
+                }
+            };
             let lr_bounds = match bp.origin_on_emission {
                 None => {
-                    draw_reception(image,gen_ctx,&bp.message_type,&bp.targets,lf_x_widths,*yshift)
+                    draw_reception(canvas,gen_ctx,&action_conf,&bp.message_type,&bp.targets,lf_x_widths,*yshift)
                 },
                 Some(origin) => {
-                    draw_emission(image,gen_ctx,&bp.message_type,origin,&bp.targets,lf_x_widths,*yshift)
+                    draw_emission(canvas,gen_ctx,&action_conf,&bp.message_type,origin,&bp.targets,lf_x_widths,*yshift)
                 }
             };
             *yshift = *yshift + 3;
@@ -67,13 +79,13 @@ pub fn draw_interaction_rec(    image : &mut RgbImage,
         &Interaction::CoReg(ref cr, ref i1,ref i2) => {
             let mut frags = get_recursive_coreg_frags(cr, i1);
             frags.extend( get_recursive_coreg_frags(cr,i2) );
-            return draw_n_ary_coregion(image, gen_ctx,frags,cr,lf_x_widths, lf_num, nest_shift, yshift);
+            return draw_n_ary_coregion(canvas, gen_ctx, draw_conf,frags,cr,lf_x_widths, lf_num, nest_shift, yshift, actions_colors);
         },
         &Interaction::Alt(ref i1,ref i2) => {
             let mut frags = get_recursive_alt_frags(i1);
             frags.extend( get_recursive_alt_frags(i2) );
-            let label = vec![TextToPrint::new(SYNTAX_ALT.to_string(),Rgb(HCP_Black))];
-            return draw_n_ary_combined_fragment(image, gen_ctx,frags,lf_x_widths, lf_num,label, nest_shift, yshift);
+            let label = vec![TextToPrint::new(SYNTAX_ALT.to_string(),Rgb(draw_conf.palette.symbol))];
+            return draw_n_ary_combined_fragment(canvas, gen_ctx, draw_conf,frags,lf_x_widths, lf_num,label, nest_shift, yshift, actions_colors);
         },
         &Interaction::Sync(ref sync, ref i1,ref i2) => {
             let mut frags = get_recursive_sync_frags(sync,i1);
@@ -84,73 +96,77 @@ pub fn draw_interaction_rec(    image : &mut RgbImage,
                 sync_acts.push(TraceAction::new(*lf_id,*act_kind,ms_ty.clone()));
             }
             // ***
-            let mut label = vec![TextToPrint::new(SYNTAX_SYNC.to_string(),Rgb(HCP_Black))];
-            label.append(&mut diagram_repr_trace_actions(&sync_acts,gen_ctx,true));
-            return draw_n_ary_combined_fragment(image, gen_ctx,frags,lf_x_widths, lf_num,label, nest_shift, yshift);
+            let mut label = vec![TextToPrint::new(SYNTAX_SYNC.to_string(),Rgb(draw_conf.palette.symbol))];
+            label.append(&mut diagram_repr_trace_actions(&sync_acts,gen_ctx,draw_conf,true));
+            return draw_n_ary_combined_fragment(canvas, gen_ctx, draw_conf,frags,lf_x_widths, lf_num,label, nest_shift, yshift, actions_colors);
         },
         &Interaction::Loop(ref cr, ref i1) => {
             let label = match cr.len() {
                 0 => {
-                    vec![TextToPrint::new(SYNTAX_LOOP_W.to_string(),Rgb(HCP_Black))]
+                    vec![TextToPrint::new(SYNTAX_LOOP_W.to_string(),Rgb(draw_conf.palette.symbol))]
                 },
                 x if x == gen_ctx.get_lf_num() => {
-                    vec![TextToPrint::new(SYNTAX_LOOP_P.to_string(),Rgb(HCP_Black))]
+                    vec![TextToPrint::new(SYNTAX_LOOP_P.to_string(),Rgb(draw_conf.palette.symbol))]
                 },
                 x => {
                     let mut got = vec![
-                        TextToPrint::new(SYNTAX_LOOP_C.to_string(),Rgb(HCP_Black)),
-                        TextToPrint::new("{".to_string(),Rgb(HCP_Black))
+                        TextToPrint::new(SYNTAX_LOOP_C.to_string(),Rgb(draw_conf.palette.symbol)),
+                        TextToPrint::new("{".to_string(),Rgb(draw_conf.palette.symbol))
                     ];
                     // ***
                     let mut rem = x;
                     for lf_id in cr {
                         let lf_name = gen_ctx.get_lf_name(*lf_id).unwrap();
-                        got.push(TextToPrint::new(lf_name,Rgb(HC_Lifeline)));
+                        got.push(TextToPrint::new(lf_name,Rgb(draw_conf.palette.lifeline)));
                         rem -= 1;
                         if rem > 0 {
-                            got.push( TextToPrint::new(",".to_string(),Rgb(HCP_Black)) );
+                            got.push( TextToPrint::new(",".to_string(),Rgb(draw_conf.palette.symbol)) );
                         }
                     }
                     // ***
-                    got.push( TextToPrint::new("}".to_string(),Rgb(HCP_Black)) );
+                    got.push( TextToPrint::new("}".to_string(),Rgb(draw_conf.palette.symbol)) );
                     got
                 }
             };
-            draw_unary_combined_fragment(image,  gen_ctx, i1, lf_x_widths, lf_num, label, nest_shift, yshift)
+            draw_unary_combined_fragment(canvas,  gen_ctx, draw_conf, i1, lf_x_widths, lf_num, label, nest_shift, yshift, actions_colors)
         }
     }
 }
 
-fn draw_unary_combined_fragment(    image : &mut RgbImage,
+fn draw_unary_combined_fragment(    canvas : &mut dyn SequenceDiagramCanvas,
                                     gen_ctx : &GeneralContext,
+                                    draw_conf : &DrawingConfiguration,
                                     i1 : &Interaction,
                                     lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
                                     lf_num : usize,
                                     label : Vec<TextToPrint>,
                                     nest_shift : &mut u32,
-                                    yshift : &mut u32) -> [usize;2] {
+                                    yshift : &mut u32,
+                                    actions_colors : &mut Iter<Rgb<u8>>) -> [usize;2] {
     // draw content and gather data
     *nest_shift += 1;
     let start_y : u32 = *yshift;
     *yshift += 3;
-    let lr_bounds : [usize;2] = draw_interaction_rec(image,  gen_ctx,i1, lf_x_widths,  lf_num,nest_shift, yshift);
+    let lr_bounds : [usize;2] = draw_interaction_rec(canvas,  gen_ctx, draw_conf,i1, lf_x_widths,  lf_num,nest_shift, yshift, actions_colors);
     *yshift += 1;
     let end_y : u32 = *yshift;
     *nest_shift -= 1;
     // draw frame
     let mut y_drafts : Vec<u32> = [start_y,end_y].to_vec();
-    draw_combined_fragment_frame(image, label, *nest_shift,lf_x_widths,lr_bounds[0],lr_bounds[1],y_drafts);
+    draw_combined_fragment_frame(canvas, draw_conf, label, *nest_shift,lf_x_widths,lr_bounds[0],lr_bounds[1],y_drafts);
     return lr_bounds;
 }
 
-fn draw_n_ary_combined_fragment(  image : &mut RgbImage,
+fn draw_n_ary_combined_fragment(  canvas : &mut dyn SequenceDiagramCanvas,
                                   gen_ctx : &GeneralContext,
+                                  draw_conf : &DrawingConfiguration,
                                   sub_ints : Vec<&Interaction>,
                                   lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
                                   lf_num : usize,
                                   label : Vec<TextToPrint>,
                                   nest_shift : &mut u32,
-                                  yshift : &mut u32) -> [usize;2] {
+                                  yshift : &mut u32,
+                                  actions_colors : &mut Iter<Rgb<u8>>) -> [usize;2] {
     let mut y_drafts : Vec<u32> = Vec::new();
     // draw content and gather data
     *nest_shift += 1;
@@ -161,7 +177,7 @@ fn draw_n_ary_combined_fragment(  image : &mut RgbImage,
     let mut max_lf_id : usize = 0;
     for my_int in sub_ints {
         *yshift += 1;
-        let lr_bounds = draw_interaction_rec(image,  gen_ctx,my_int, lf_x_widths,  lf_num,nest_shift, yshift);
+        let lr_bounds = draw_interaction_rec(canvas,  gen_ctx, draw_conf,my_int, lf_x_widths,  lf_num,nest_shift, yshift, actions_colors);
         min_lf_id = cmp::min( min_lf_id, lr_bounds[0]);
         max_lf_id = cmp::max( max_lf_id, lr_bounds[1]);
         *yshift += 1;
@@ -171,18 +187,20 @@ fn draw_n_ary_combined_fragment(  image : &mut RgbImage,
     //
     let lr_bounds: [usize;2] = [ min_lf_id, max_lf_id ];
     // draw frame
-    draw_combined_fragment_frame(image,label,*nest_shift,lf_x_widths,lr_bounds[0],lr_bounds[1],y_drafts);
+    draw_combined_fragment_frame(canvas, draw_conf,label,*nest_shift,lf_x_widths,lr_bounds[0],lr_bounds[1],y_drafts);
     return lr_bounds;
 }
 
-fn draw_n_ary_coregion(  image : &mut RgbImage,
+fn draw_n_ary_coregion(  canvas : &mut dyn SequenceDiagramCanvas,
                                   gen_ctx : &GeneralContext,
+                                  draw_conf : &DrawingConfiguration,
                                   sub_ints : Vec<&Interaction>,
                                   coreg_ids : &Vec<usize>,
                                   lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
                                   lf_num : usize,
                                   nest_shift : &mut u32,
-                                  yshift : &mut u32) -> [usize;2] {
+                                  yshift : &mut u32,
+                                  actions_colors : &mut Iter<Rgb<u8>>) -> [usize;2] {
     let mut y_drafts : Vec<u32> = Vec::new();
     // draw content and gather data
     *nest_shift += 1;
@@ -193,7 +211,7 @@ fn draw_n_ary_coregion(  image : &mut RgbImage,
     let mut max_lf_id : usize = 0;
     for my_int in sub_ints {
         *yshift += 1;
-        let lr_bounds = draw_interaction_rec(image,  gen_ctx,my_int, lf_x_widths,  lf_num,nest_shift, yshift);
+        let lr_bounds = draw_interaction_rec(canvas,  gen_ctx, draw_conf,my_int, lf_x_widths,  lf_num,nest_shift, yshift, actions_colors);
         min_lf_id = cmp::min( min_lf_id, lr_bounds[0]);
         max_lf_id = cmp::max( max_lf_id, lr_bounds[1]);
         *yshift += 1;
@@ -203,11 +221,12 @@ fn draw_n_ary_coregion(  image : &mut RgbImage,
     //
     let lr_bounds: [usize;2] = [ min_lf_id, max_lf_id ];
     // draw frame
-    draw_coregion_frame(image,*nest_shift,lf_x_widths,coreg_ids,y_drafts);
+    draw_coregion_frame(canvas, draw_conf,*nest_shift,lf_x_widths,coreg_ids,y_drafts);
     return lr_bounds;
 }
 
-fn draw_combined_fragment_frame(    image : &mut RgbImage,
+fn draw_combined_fragment_frame(    canvas : &mut dyn SequenceDiagramCanvas,
+                                    draw_conf : &DrawingConfiguration,
                                     label : Vec<TextToPrint>,
                                     nest_shift : u32,
                                     lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
@@ -216,47 +235,41 @@ fn draw_combined_fragment_frame(    image : &mut RgbImage,
                                     y_drafts : Vec<u32>) {
     match (lf_x_widths.get(&left_bound), lf_x_widths.get(&right_bound)) {
         (Some(left_lf_coords),Some(right_lf_coords)) => {
-            let x_left : f32 = left_lf_coords.x_start + (nest_shift as f32)*FRAGMENT_PADDING;
-            let x_right : f32 = (right_lf_coords.x_start + right_lf_coords.x_span_outer) - (nest_shift as f32)*FRAGMENT_PADDING;
+            let x_left : f32 = left_lf_coords.x_start + (nest_shift as f32)*draw_conf.fragment_padding();
+            let x_right : f32 = (right_lf_coords.x_start + right_lf_coords.x_span_outer) - (nest_shift as f32)*draw_conf.fragment_padding();
 
-            let mut y_coords : Vec<f32> = y_drafts.into_iter().map(|y| get_y_pos_from_yshift(y) ).collect::< Vec<f32> >();
+            let mut y_coords : Vec<f32> = y_drafts.into_iter().map(|y| get_y_pos_from_yshift(draw_conf,y) ).collect::< Vec<f32> >();
             let y_start : f32 = y_coords.remove(0);
             let y_end : f32 = y_coords.pop().unwrap();// - (nest_shift as f32)*FRAGMENT_PADDING;
-            draw_line_segment_mut(image,
-                                  (x_left, y_start),
-                                  (x_left, y_end),
-                                  Rgb(HCP_Black));
-            draw_line_segment_mut(image,
-                                  (x_right, y_start),
-                                  (x_right, y_end),
-                                  Rgb(HCP_Black));
-            draw_line_segment_mut(image,
-                                  (x_left, y_start),
-                                  (x_right, y_start),
-                                  Rgb(HCP_Black));
-            draw_line_segment_mut(image,
-                                  (x_left, y_end),
-                                  (x_right, y_end),
-                                  Rgb(HCP_Black));
+            canvas.draw_line((x_left, y_start),
+                             (x_left, y_end),
+                             Rgb(draw_conf.palette.symbol));
+            canvas.draw_line((x_right, y_start),
+                             (x_right, y_end),
+                             Rgb(draw_conf.palette.symbol));
+            canvas.draw_line((x_left, y_start),
+                             (x_right, y_start),
+                             Rgb(draw_conf.palette.symbol));
+            canvas.draw_line((x_left, y_end),
+                             (x_right, y_end),
+                             Rgb(draw_conf.palette.symbol));
             for y_coord in y_coords {
-                draw_line_segment_mut(image,
-                                      (x_left, y_coord),
-                                      (x_right, y_coord),
-                                      Rgb(HCP_Black));
+                canvas.draw_line((x_left, y_coord),
+                                 (x_right, y_coord),
+                                 Rgb(draw_conf.palette.symbol));
             }
-            draw_line_of_colored_text(image,
-                                      &DrawCoord::StartingAt(x_left + FRAGMENT_TITLE_MARGIN),
-                                      &DrawCoord::CenteredAround(y_start + VERTICAL_SIZE+ FRAGMENT_TITLE_MARGIN),
-                                      &label,
-                                      &get_hibou_font(),
-                                      &HIBOU_FONT_SCALE);
+            canvas.draw_text(&DrawCoord::StartingAt(x_left + draw_conf.fragment_title_margin()),
+                             &DrawCoord::CenteredAround(y_start + draw_conf.vertical_size()+ draw_conf.fragment_title_margin()),
+                             &label,
+                             &draw_conf.font_scale());
         },
         _ => {}
     }
 }
 
 
-fn draw_coregion_frame(    image : &mut RgbImage,
+fn draw_coregion_frame(    canvas : &mut dyn SequenceDiagramCanvas,
+                                    draw_conf : &DrawingConfiguration,
                                     nest_shift : u32,
                                     lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
                                     coreg_ids : &Vec<usize>,
@@ -274,7 +287,7 @@ fn draw_coregion_frame(    image : &mut RgbImage,
         }
     }
 
-    let mut y_coords : Vec<f32> = y_drafts.into_iter().map(|y| get_y_pos_from_yshift(y) ).collect::< Vec<f32> >();
+    let mut y_coords : Vec<f32> = y_drafts.into_iter().map(|y| get_y_pos_from_yshift(draw_conf,y) ).collect::< Vec<f32> >();
     let y_start : f32 = y_coords.remove(0);
     // ***
     let y_end : f32 = y_coords.pop().unwrap();
@@ -282,52 +295,43 @@ fn draw_coregion_frame(    image : &mut RgbImage,
         let x_left = lf_coord.x_middle - lf_coord.x_span_inner/2.0;
         let x_right = lf_coord.x_middle + lf_coord.x_span_inner/2.0;
         // ***
-        draw_line_segment_mut(image,
-                              (x_left, y_start),
-                              (x_right, y_start),
-                              Rgb(HCP_Black));
-        draw_line_segment_mut(image,
-                              (x_left, y_start),
-                              (x_left, y_start + VERTICAL_SIZE/2.0),
-                              Rgb(HCP_Black));
-        draw_line_segment_mut(image,
-                              (x_right, y_start),
-                              (x_right, y_start + VERTICAL_SIZE/2.0),
-                              Rgb(HCP_Black));
+        canvas.draw_line((x_left, y_start),
+                         (x_right, y_start),
+                         Rgb(draw_conf.palette.symbol));
+        canvas.draw_line((x_left, y_start),
+                         (x_left, y_start + draw_conf.vertical_size()/2.0),
+                         Rgb(draw_conf.palette.symbol));
+        canvas.draw_line((x_right, y_start),
+                         (x_right, y_start + draw_conf.vertical_size()/2.0),
+                         Rgb(draw_conf.palette.symbol));
         // ***
-        draw_line_segment_mut(image,
-                              (x_left, y_end),
-                              (x_right, y_end),
-                              Rgb(HCP_Black));
-        draw_line_segment_mut(image,
-                              (x_left, y_end),
-                              (x_left, y_end - VERTICAL_SIZE/2.0),
-                              Rgb(HCP_Black));
-        draw_line_segment_mut(image,
-                              (x_right, y_end),
-                              (x_right, y_end - VERTICAL_SIZE/2.0),
-                              Rgb(HCP_Black));
+        canvas.draw_line((x_left, y_end),
+                         (x_right, y_end),
+                         Rgb(draw_conf.palette.symbol));
+        canvas.draw_line((x_left, y_end),
+                         (x_left, y_end - draw_conf.vertical_size()/2.0),
+                         Rgb(draw_conf.palette.symbol));
+        canvas.draw_line((x_right, y_end),
+                         (x_right, y_end - draw_conf.vertical_size()/2.0),
+                         Rgb(draw_conf.palette.symbol));
         // ***
         for y_coord in &y_coords {
-            draw_line_segment_mut(image,
-                                  (x_left, *y_coord),
-                                  (x_right, *y_coord),
-                                  Rgb(HCP_Black));
-            draw_line_segment_mut(image,
-                                  (x_left, *y_coord + VERTICAL_SIZE/4.0),
-                                  (x_left, *y_coord - VERTICAL_SIZE/4.0),
-                                  Rgb(HCP_Black));
-            draw_line_segment_mut(image,
-                                  (x_right, *y_coord + VERTICAL_SIZE/4.0),
-                                  (x_right, *y_coord - VERTICAL_SIZE/4.0),
-                                  Rgb(HCP_Black));
+            canvas.draw_line((x_left, *y_coord),
+                             (x_right, *y_coord),
+                             Rgb(draw_conf.palette.symbol));
+            canvas.draw_line((x_left, *y_coord + draw_conf.vertical_size()/4.0),
+                             (x_left, *y_coord - draw_conf.vertical_size()/4.0),
+                             Rgb(draw_conf.palette.symbol));
+            canvas.draw_line((x_right, *y_coord + draw_conf.vertical_size()/4.0),
+                             (x_right, *y_coord - draw_conf.vertical_size()/4.0),
+                             Rgb(draw_conf.palette.symbol));
         }
     }
     // ***
     /*
     let font = FontCollection::from_bytes(HIBOU_GRAPHIC_FONT).unwrap().into_font().unwrap();
     let scale = Scale { x: FONT_WIDTH, y: FONT_HEIGHT };
-    draw_colored_text(image,&label,x_left+FRAGMENT_TITLE_MARGIN,y_start + VERTICAL_SIZE);
+    draw_colored_text(canvas,&label,x_left+FRAGMENT_TITLE_MARGIN,y_start + VERTICAL_SIZE);
     */
 }
 
@@ -17,61 +17,54 @@ limitations under the License.
 
 use std::collections::HashMap;
 
-use image::{Rgb, RgbImage};
-use image_colored_text::draw::single_line::{draw_line_of_colored_text, DrawCoord};
+use image::Rgb;
+use image_colored_text::draw::single_line::DrawCoord;
 use image_colored_text::ttp::TextToPrint;
-use imageproc::drawing::{
-    draw_filled_rect_mut,
-    draw_hollow_rect_mut,
-    draw_line_segment_mut
-};
-use imageproc::rect::Rect;
 
 
 use crate::core::general_context::GeneralContext;
-use crate::io::output::draw_commons::font::{get_hibou_font, HIBOU_FONT_SCALE};
-use crate::io::output::draw_commons::hibou_color_palette::*;
-use crate::io::output::draw_commons::sd_drawing_conf::*;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
+use crate::io::output::draw_interactions::as_sd::util::canvas::SequenceDiagramCanvas;
 use crate::io::output::draw_interactions::as_sd::util::lf_coords::DrawingLifelineCoords;
 
 // **********
 
-pub fn draw_frame(image : &mut RgbImage, img_width : &f32, img_height : &f32, max_y_shift : usize) {
-    draw_filled_rect_mut(image, Rect::at(0,0).of_size(*img_width as u32,*img_height as u32), Rgb(HCP_White));
+pub fn draw_frame(canvas : &mut dyn SequenceDiagramCanvas, draw_conf : &DrawingConfiguration, img_width : &f32, img_height : &f32, max_y_shift : usize) {
+    canvas.draw_filled_rect(0.0, 0.0, *img_width, *img_height, Rgb(draw_conf.palette.background));
 }
 
-pub fn draw_lifelines(image : &mut RgbImage,
+pub fn draw_lifelines(canvas : &mut dyn SequenceDiagramCanvas,
+                      draw_conf : &DrawingConfiguration,
                       lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
                       inner_height : f32,
                       gen_ctx:&GeneralContext) {
     // Draw Lifelines
-    let lifeline_y_start :f32 = MARGIN;
-    let lifeline_y_end :f32 = MARGIN+inner_height;
+    let lifeline_y_start :f32 = draw_conf.margin();
+    let lifeline_y_end :f32 = draw_conf.margin()+inner_height;
     for (lf_id,lf_coords) in lf_x_widths.iter() {
         // ***
         let lf_name = gen_ctx.get_lf_name(*lf_id).unwrap();
-        let lf_name_span = FONT_WIDTH*(lf_name.chars().count() as f32)/2.0;
+        let lf_name_span = draw_conf.font_width()*(lf_name.chars().count() as f32)/2.0;
         // ***
-        let label = vec![TextToPrint::new(lf_name,Rgb(HC_Lifeline))];
-        draw_line_of_colored_text(image,
-                                  &DrawCoord::CenteredAround(lf_coords.x_middle),
-                                  &DrawCoord::CenteredAround(lifeline_y_start + VERTICAL_SIZE),
-                                  &label,
-                                  &get_hibou_font(),
-                                  &HIBOU_FONT_SCALE);
+        let label = vec![TextToPrint::new(lf_name,Rgb(draw_conf.palette.lifeline))];
+        canvas.draw_text(&DrawCoord::CenteredAround(lf_coords.x_middle),
+                         &DrawCoord::CenteredAround(lifeline_y_start + draw_conf.vertical_size()),
+                         &label,
+                         &draw_conf.font_scale());
         // ***
         let yshift : usize = 2;
         // ***
-        let square_span_with_margin = lf_name_span + 2.0*MARGIN;
+        let square_span_with_margin = lf_name_span + 2.0*draw_conf.margin();
         let actor_x_start : f32 = lf_coords.x_middle - (square_span_with_margin/2.0);
-        draw_hollow_rect_mut(image,
-                             Rect::at(actor_x_start as i32, lifeline_y_start as i32).of_size(square_span_with_margin as u32, ((yshift as f32)*VERTICAL_SIZE) as u32),
-                             Rgb(HC_Grammar_Symbol));
+        canvas.draw_hollow_rect(actor_x_start,
+                                lifeline_y_start,
+                                square_span_with_margin,
+                                (yshift as f32)*draw_conf.vertical_size(),
+                                Rgb(draw_conf.palette.symbol));
         // ***
-        draw_line_segment_mut(image,
-                              (lf_coords.x_middle, lifeline_y_start + (yshift as f32)*VERTICAL_SIZE),
-                              (lf_coords.x_middle, lifeline_y_end),
-                              Rgb(HC_Grammar_Symbol));
+        canvas.draw_line((lf_coords.x_middle, lifeline_y_start + (yshift as f32)*draw_conf.vertical_size()),
+                         (lf_coords.x_middle, lifeline_y_end),
+                         Rgb(draw_conf.palette.symbol));
     }
 }
 
@@ -17,55 +17,145 @@ limitations under the License.
 
 use std::collections::HashMap;
 
-use image::RgbImage;
+use image::{Rgb, RgbImage};
 
 use crate::core::general_context::GeneralContext;
 use crate::core::language::involve::involves::InvolvesLifelines;
 use crate::core::language::syntax::interaction::Interaction;
-use crate::io::output::draw_commons::sd_drawing_conf::*;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
 use crate::io::output::draw_interactions::as_sd::interaction_repr::img_content::draw_interaction_rec;
 use crate::io::output::draw_interactions::as_sd::interaction_repr::img_frame::{draw_frame, draw_lifelines};
+use crate::io::output::draw_interactions::as_sd::util::canvas::{SequenceDiagramCanvas, SvgCanvas};
 use crate::io::output::draw_interactions::as_sd::util::dimensions_tools::get_interaction_max_yshift;
 use crate::io::output::draw_interactions::as_sd::util::lf_coords::DrawingLifelineCoords;
 
 
 pub fn make_interaction_image(gen_ctx : &GeneralContext,
+                              draw_conf : &DrawingConfiguration,
                               interaction : &Interaction) -> RgbImage {
-    // ***
-    let mut lf_x_widths : HashMap<usize,DrawingLifelineCoords> = HashMap::new();
-    let mut current_x : f32 = MARGIN;
-    for lf_id in 0..gen_ctx.get_lf_num() {
-        if interaction.involves_any_of(&hashset!{lf_id}) {
-            let lf_char_width = gen_ctx.get_lf_name(lf_id).unwrap().len();
-            // ***
-            let span_inner = (HORIZONTAL_SIZE - 2.0*MARGIN).max( 2.0*MARGIN + (lf_char_width as f32)*FONT_WIDTH/2.0 );
-            let span_outer = span_inner + 2.0*MARGIN;
-            let middle = current_x + (span_outer/2.0) + THICKNESS;
-            lf_x_widths.insert(lf_id,DrawingLifelineCoords{x_start:current_x,
-                x_span_inner:span_inner,
-                x_span_outer:span_outer,
-                x_middle:middle});
-            current_x = current_x + span_outer + MARGIN;
+    make_interaction_image_with_actions_colors(gen_ctx,draw_conf,interaction,&vec![])
+}
+
+/**
+The colors of the actions are given in the order in which the action leaves of the interaction term
+are encountered in a left-to-right depth-first traversal.
+Actions without an associated color are drawn in black.
+**/
+pub fn make_interaction_image_with_actions_colors(gen_ctx : &GeneralContext,
+                                                  draw_conf : &DrawingConfiguration,
+                                                  interaction : &Interaction,
+                                                  actions_colors : &Vec<Rgb<u8>>) -> RgbImage {
+    let layout = InteractionDiagramLayout::new(gen_ctx,draw_conf,interaction);
+    let mut image = RgbImage::new( layout.img_width as u32, layout.img_height as u32);
+    layout.draw_on_canvas(&mut image,gen_ctx,draw_conf,interaction,actions_colors);
+    return image;
+}
+
+pub fn make_interaction_svg(gen_ctx : &GeneralContext,
+                            draw_conf : &DrawingConfiguration,
+                            interaction : &Interaction) -> String {
+    make_interaction_svg_with_actions_colors(gen_ctx,draw_conf,interaction,&vec![])
+}
+
+/**
+Same as make_interaction_image_with_actions_colors but emits an SVG document
+in which lifelines, arrows, fragments and message labels are vector elements.
+**/
+pub fn make_interaction_svg_with_actions_colors(gen_ctx : &GeneralContext,
+                                                draw_conf : &DrawingConfiguration,
+                                                interaction : &Interaction,
+                                                actions_colors : &Vec<Rgb<u8>>) -> String {
+    let layout = InteractionDiagramLayout::new(gen_ctx,draw_conf,interaction);
+    let mut canvas = SvgCanvas::new(layout.img_width, layout.img_height);
+    layout.draw_on_canvas(&mut canvas,gen_ctx,draw_conf,interaction,actions_colors);
+    return canvas.to_svg_string();
+}
+
+struct InteractionDiagramLayout {
+    lf_x_widths : HashMap<usize,DrawingLifelineCoords>,
+    max_y_shift : usize,
+    inner_height : f32,
+    img_width : f32,
+    img_height : f32
+}
+
+impl InteractionDiagramLayout {
+
+    fn new(gen_ctx : &GeneralContext,
+           draw_conf : &DrawingConfiguration,
+           interaction : &Interaction) -> InteractionDiagramLayout {
+        let margin = draw_conf.margin();
+        let mut lf_x_widths : HashMap<usize,DrawingLifelineCoords> = HashMap::new();
+        let mut current_x : f32 = margin;
+        for lf_id in 0..gen_ctx.get_lf_num() {
+            if interaction.involves_any_of(&hashset!{lf_id}) {
+                let lf_char_width = gen_ctx.get_lf_name(lf_id).unwrap().len();
+                // ***
+                let span_inner = (draw_conf.horizontal_size() - 2.0*margin).max( 2.0*margin + (lf_char_width as f32)*draw_conf.font_width()/2.0 );
+                let span_outer = span_inner + 2.0*margin;
+                let middle = current_x + (span_outer/2.0) + draw_conf.thickness();
+                lf_x_widths.insert(lf_id,DrawingLifelineCoords{x_start:current_x,
+                    x_span_inner:span_inner,
+                    x_span_outer:span_outer,
+                    x_middle:middle});
+                current_x = current_x + span_outer + margin;
+            }
         }
+        // ***
+        let max_y_shift = get_interaction_max_yshift(interaction);
+        let inner_height : f32 = (max_y_shift as f32)*draw_conf.vertical_size();
+        // ***
+        let img_width : f32 = current_x;
+        let img_height : f32 = inner_height + 2.0*margin;
+        // ***
+        InteractionDiagramLayout{lf_x_widths,max_y_shift,inner_height,img_width,img_height}
     }
-    // ***
-    let max_y_shift = get_interaction_max_yshift(interaction);
-    let mut inner_height : f32 = (max_y_shift as f32)*VERTICAL_SIZE;
-    // ***
-    let img_width : f32 = current_x;
-    let img_height : f32 = inner_height + 2.0*MARGIN;
-
-    // Draw Frame
-    let mut image = RgbImage::new( img_width as u32, img_height as u32);
-    draw_frame(&mut image, &img_width, &img_height, max_y_shift);
-
-    // Draw Lifelines
-    draw_lifelines(&mut image, &lf_x_widths, inner_height, gen_ctx);
-
-    // Draw Fragments
-    let mut nest_shift : u32 = 1; // shift to display nested fragments
-    let mut yshift : u32 = 3;
-    draw_interaction_rec(&mut image,  gen_ctx, interaction, &lf_x_widths, gen_ctx.get_lf_num(), &mut nest_shift, &mut yshift);
-    // ***
-    return image;
-}
\ No newline at end of file
+
+    fn draw_on_canvas(&self,
+                      canvas : &mut dyn SequenceDiagramCanvas,
+                      gen_ctx : &GeneralContext,
+                      draw_conf : &DrawingConfiguration,
+                      interaction : &Interaction,
+                      actions_colors : &Vec<Rgb<u8>>) {
+        // Draw Frame
+        draw_frame(canvas, draw_conf, &self.img_width, &self.img_height, self.max_y_shift);
+
+        // Draw Lifelines
+        draw_lifelines(canvas, draw_conf, &self.lf_x_widths, self.inner_height, gen_ctx);
+
+        // Draw Fragments
+        let mut nest_shift : u32 = 1; // shift to display nested fragments
+        let mut yshift : u32 = 3;
+        draw_interaction_rec(canvas,  gen_ctx, draw_conf, interaction, &self.lf_x_widths, gen_ctx.get_lf_num(), &mut nest_shift, &mut yshift, &mut actions_colors.iter());
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::BroadcastPrimitive;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
+    use super::{make_interaction_image, make_interaction_svg};
+
+    #[test]
+    fn svg_matches_raster_layout_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m".to_string());
+        // l1 -- m -> l2
+        let interaction = Interaction::Action(BroadcastPrimitive::new(Some(0),MessageTypeExpression::Singleton(0),vec![1]));
+        let draw_conf = DrawingConfiguration::default();
+        let image = make_interaction_image(&gen_ctx,&draw_conf,&interaction);
+        let svg = make_interaction_svg(&gen_ctx,&draw_conf,&interaction);
+        assert!(svg.starts_with(&format!("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\"", image.width(), image.height())));
+        assert!(svg.ends_with("</svg>\n"));
+        for name in ["l1","l2","m"] {
+            assert!(svg.contains(&format!(">{}</tspan>",name)));
+        }
+    }
+}
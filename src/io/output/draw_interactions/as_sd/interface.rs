@@ -15,22 +15,73 @@ limitations under the License.
 */
 
 
+use std::fs::File;
+use std::io::Write;
 use std::path::PathBuf;
+use image::{Rgb, RgbImage};
 use crate::core::general_context::GeneralContext;
 use crate::core::language::syntax::interaction::Interaction;
-use crate::io::output::draw_interactions::as_sd::interaction_repr::interaction::make_interaction_image;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
+use crate::io::output::draw_interactions::as_sd::interaction_repr::interaction::{make_interaction_image, make_interaction_image_with_actions_colors, make_interaction_svg};
 
 
 pub fn draw_int_as_sd(gen_ctx : &GeneralContext,
+                      draw_conf : &DrawingConfiguration,
                       interaction : &Interaction,
                       parent_folder : &String,
                       output_file_name : &String) {
     // ***
     let output_file_name = format!("{:}.png", output_file_name);
     let output_path : PathBuf = [parent_folder, &output_file_name].iter().collect();
-    let image = make_interaction_image(gen_ctx,interaction);
+    let image = make_interaction_image(gen_ctx,draw_conf,interaction);
     image.save(output_path.as_path());
 }
 
+pub fn draw_int_as_sd_svg(gen_ctx : &GeneralContext,
+                          draw_conf : &DrawingConfiguration,
+                          interaction : &Interaction,
+                          parent_folder : &String,
+                          output_file_name : &String) {
+    // ***
+    let output_file_name = format!("{:}.svg", output_file_name);
+    let output_path : PathBuf = [parent_folder, &output_file_name].iter().collect();
+    let mut file = File::create(output_path.as_path()).unwrap();
+    file.write(int_as_sd_svg_string(gen_ctx,draw_conf,interaction).as_bytes());
+}
 
+pub fn int_as_sd_image_with_actions_colors(gen_ctx : &GeneralContext,
+                                           draw_conf : &DrawingConfiguration,
+                                           interaction : &Interaction,
+                                           actions_colors : &Vec<Rgb<u8>>) -> RgbImage {
+    make_interaction_image_with_actions_colors(gen_ctx,draw_conf,interaction,actions_colors)
+}
+
+pub fn int_as_sd_svg_string(gen_ctx : &GeneralContext,
+                            draw_conf : &DrawingConfiguration,
+                            interaction : &Interaction) -> String {
+    make_interaction_svg(gen_ctx,draw_conf,interaction)
+}
 
+/**
+Draws the interaction with covered and uncovered actions in the corresponding colors of the palette
+(green and red by default).
+The covered flags are given in the order of the action leaves in a left-to-right depth-first traversal.
+**/
+pub fn draw_int_as_sd_with_coverage(gen_ctx : &GeneralContext,
+                                    draw_conf : &DrawingConfiguration,
+                                    interaction : &Interaction,
+                                    covered_actions : &Vec<bool>,
+                                    parent_folder : &String,
+                                    output_file_name : &String) {
+    // ***
+    let output_file_name = format!("{:}.png", output_file_name);
+    let output_path : PathBuf = [parent_folder, &output_file_name].iter().collect();
+    let actions_colors : Vec<Rgb<u8>> = covered_actions.iter().map(|is_covered|
+        if *is_covered {
+            Rgb(draw_conf.palette.covered)
+        } else {
+            Rgb(draw_conf.palette.uncovered)
+        }).collect();
+    let image = make_interaction_image_with_actions_colors(gen_ctx,draw_conf,interaction,&actions_colors);
+    image.save(output_path.as_path());
+}
@@ -16,63 +16,63 @@ limitations under the License.
 
 
 use image::{Rgb, RgbImage};
-use imageproc::drawing::draw_line_segment_mut;
 use imageproc::drawing::draw_cubic_bezier_curve_mut;
 
-use crate::io::output::draw_commons::sd_drawing_conf::ARROW_HEAD_LENGTH;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
+use crate::io::output::draw_interactions::as_sd::util::canvas::SequenceDiagramCanvas;
 
 // **********
 
-pub fn draw_double_half_ellipsis_leftward(image : &mut RgbImage, x_pos : f32, y_pos : f32, my_color : Rgb<u8>) {
+pub fn draw_double_half_ellipsis_leftward(image : &mut RgbImage, draw_conf : &DrawingConfiguration, x_pos : f32, y_pos : f32, my_color : Rgb<u8>) {
+    let arrow_head_length = draw_conf.arrow_head_length();
     draw_cubic_bezier_curve_mut(image,
-                                (x_pos - 0.5*(ARROW_HEAD_LENGTH as f32), y_pos - 0.5*(ARROW_HEAD_LENGTH as f32)),
-                                (x_pos - 0.5*(ARROW_HEAD_LENGTH as f32), y_pos + 0.5*(ARROW_HEAD_LENGTH as f32)),
-                                (x_pos + 1.5*(ARROW_HEAD_LENGTH as f32), y_pos - 0.5*(ARROW_HEAD_LENGTH as f32)),
-                                (x_pos + 1.5*(ARROW_HEAD_LENGTH as f32), y_pos + 0.5*(ARROW_HEAD_LENGTH as f32)),
+                                (x_pos - 0.5*arrow_head_length, y_pos - 0.5*arrow_head_length),
+                                (x_pos - 0.5*arrow_head_length, y_pos + 0.5*arrow_head_length),
+                                (x_pos + 1.5*arrow_head_length, y_pos - 0.5*arrow_head_length),
+                                (x_pos + 1.5*arrow_head_length, y_pos + 0.5*arrow_head_length),
                                 my_color);
     draw_cubic_bezier_curve_mut(image,
-                                (x_pos - 0.5*(ARROW_HEAD_LENGTH as f32), y_pos - 0.25*(ARROW_HEAD_LENGTH as f32)),
-                                (x_pos - 0.5*(ARROW_HEAD_LENGTH as f32), y_pos + 0.25*(ARROW_HEAD_LENGTH as f32)),
-                                (x_pos + 1.0*(ARROW_HEAD_LENGTH as f32), y_pos - 0.25*(ARROW_HEAD_LENGTH as f32)),
-                                (x_pos + 1.0*(ARROW_HEAD_LENGTH as f32), y_pos + 0.25*(ARROW_HEAD_LENGTH as f32)),
+                                (x_pos - 0.5*arrow_head_length, y_pos - 0.25*arrow_head_length),
+                                (x_pos - 0.5*arrow_head_length, y_pos + 0.25*arrow_head_length),
+                                (x_pos + 1.0*arrow_head_length, y_pos - 0.25*arrow_head_length),
+                                (x_pos + 1.0*arrow_head_length, y_pos + 0.25*arrow_head_length),
                                 my_color);
 }
 
-pub fn draw_double_half_ellipsis_rightward(image : &mut RgbImage, x_pos : f32, y_pos : f32, my_color : Rgb<u8>) {
+pub fn draw_double_half_ellipsis_rightward(image : &mut RgbImage, draw_conf : &DrawingConfiguration, x_pos : f32, y_pos : f32, my_color : Rgb<u8>) {
+    let arrow_head_length = draw_conf.arrow_head_length();
     draw_cubic_bezier_curve_mut(image,
-                                (x_pos + 0.5*(ARROW_HEAD_LENGTH as f32), y_pos - 0.5*(ARROW_HEAD_LENGTH as f32)),
-                                (x_pos + 0.5*(ARROW_HEAD_LENGTH as f32), y_pos + 0.5*(ARROW_HEAD_LENGTH as f32)),
-                                (x_pos - 1.5*(ARROW_HEAD_LENGTH as f32), y_pos - 0.5*(ARROW_HEAD_LENGTH as f32)),
-                                (x_pos - 1.5*(ARROW_HEAD_LENGTH as f32), y_pos + 0.5*(ARROW_HEAD_LENGTH as f32)),
+                                (x_pos + 0.5*arrow_head_length, y_pos - 0.5*arrow_head_length),
+                                (x_pos + 0.5*arrow_head_length, y_pos + 0.5*arrow_head_length),
+                                (x_pos - 1.5*arrow_head_length, y_pos - 0.5*arrow_head_length),
+                                (x_pos - 1.5*arrow_head_length, y_pos + 0.5*arrow_head_length),
                                 my_color);
     draw_cubic_bezier_curve_mut(image,
-                                (x_pos + 0.5*(ARROW_HEAD_LENGTH as f32), y_pos - 0.25*(ARROW_HEAD_LENGTH as f32)),
-                                (x_pos + 0.5*(ARROW_HEAD_LENGTH as f32), y_pos + 0.25*(ARROW_HEAD_LENGTH as f32)),
-                                (x_pos - 1.0*(ARROW_HEAD_LENGTH as f32), y_pos - 0.25*(ARROW_HEAD_LENGTH as f32)),
-                                (x_pos - 1.0*(ARROW_HEAD_LENGTH as f32), y_pos + 0.25*(ARROW_HEAD_LENGTH as f32)),
+                                (x_pos + 0.5*arrow_head_length, y_pos - 0.25*arrow_head_length),
+                                (x_pos + 0.5*arrow_head_length, y_pos + 0.25*arrow_head_length),
+                                (x_pos - 1.0*arrow_head_length, y_pos - 0.25*arrow_head_length),
+                                (x_pos - 1.0*arrow_head_length, y_pos + 0.25*arrow_head_length),
                                 my_color);
 }
 
-pub fn draw_arrowhead_rightward(image : &mut RgbImage, x_pos : f32, y_pos : f32, my_color : Rgb<u8>) {
-    draw_line_segment_mut(image,
-                          (x_pos, y_pos),
-                          (x_pos - (ARROW_HEAD_LENGTH as f32), y_pos - (ARROW_HEAD_LENGTH as f32)),
-                          my_color);
-    draw_line_segment_mut(image,
-                          (x_pos, y_pos),
-                          (x_pos - (ARROW_HEAD_LENGTH as f32), y_pos + (ARROW_HEAD_LENGTH as f32)),
-                          my_color);
+pub fn draw_arrowhead_rightward(canvas : &mut dyn SequenceDiagramCanvas, draw_conf : &DrawingConfiguration, x_pos : f32, y_pos : f32, my_color : Rgb<u8>) {
+    let arrow_head_length = draw_conf.arrow_head_length();
+    canvas.draw_line((x_pos, y_pos),
+                     (x_pos - arrow_head_length, y_pos - arrow_head_length),
+                     my_color);
+    canvas.draw_line((x_pos, y_pos),
+                     (x_pos - arrow_head_length, y_pos + arrow_head_length),
+                     my_color);
 }
 
-pub fn draw_arrowhead_leftward(image : &mut RgbImage, x_pos : f32, y_pos : f32, my_color : Rgb<u8>) {
-    draw_line_segment_mut(image,
-                          (x_pos, y_pos),
-                          (x_pos + (ARROW_HEAD_LENGTH as f32), y_pos - (ARROW_HEAD_LENGTH as f32)),
-                          my_color);
-    draw_line_segment_mut(image,
-                          (x_pos, y_pos),
-                          (x_pos + (ARROW_HEAD_LENGTH as f32), y_pos + (ARROW_HEAD_LENGTH as f32)),
-                          my_color);
+pub fn draw_arrowhead_leftward(canvas : &mut dyn SequenceDiagramCanvas, draw_conf : &DrawingConfiguration, x_pos : f32, y_pos : f32, my_color : Rgb<u8>) {
+    let arrow_head_length = draw_conf.arrow_head_length();
+    canvas.draw_line((x_pos, y_pos),
+                     (x_pos + arrow_head_length, y_pos - arrow_head_length),
+                     my_color);
+    canvas.draw_line((x_pos, y_pos),
+                     (x_pos + arrow_head_length, y_pos + arrow_head_length),
+                     my_color);
 }
 
 // **********
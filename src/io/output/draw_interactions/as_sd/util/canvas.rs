@@ -0,0 +1,158 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use image::{Rgb, RgbImage};
+use image_colored_text::draw::single_line::{draw_line_of_colored_text, DrawCoord};
+use image_colored_text::ttp::TextToPrint;
+use imageproc::drawing::{draw_filled_rect_mut, draw_hollow_rect_mut, draw_line_segment_mut};
+use imageproc::rect::Rect;
+use rusttype::Scale;
+
+use crate::io::output::draw_commons::font::get_hibou_font;
+
+// **********
+
+/**
+The drawing primitives used to draw sequence diagrams,
+so that the same layout may be either rasterized or written as a vector image.
+**/
+pub trait SequenceDiagramCanvas {
+
+    fn draw_line(&mut self, start : (f32,f32), end : (f32,f32), color : Rgb<u8>);
+
+    fn draw_hollow_rect(&mut self, x : f32, y : f32, width : f32, height : f32, color : Rgb<u8>);
+
+    fn draw_filled_rect(&mut self, x : f32, y : f32, width : f32, height : f32, color : Rgb<u8>);
+
+    fn draw_text(&mut self, x_pos : &DrawCoord, y_pos : &DrawCoord, text : &Vec<TextToPrint>, font_scale : &Scale);
+
+}
+
+impl SequenceDiagramCanvas for RgbImage {
+
+    fn draw_line(&mut self, start : (f32,f32), end : (f32,f32), color : Rgb<u8>) {
+        draw_line_segment_mut(self, start, end, color);
+    }
+
+    fn draw_hollow_rect(&mut self, x : f32, y : f32, width : f32, height : f32, color : Rgb<u8>) {
+        draw_hollow_rect_mut(self, Rect::at(x as i32, y as i32).of_size(width as u32, height as u32), color);
+    }
+
+    fn draw_filled_rect(&mut self, x : f32, y : f32, width : f32, height : f32, color : Rgb<u8>) {
+        draw_filled_rect_mut(self, Rect::at(x as i32, y as i32).of_size(width as u32, height as u32), color);
+    }
+
+    fn draw_text(&mut self, x_pos : &DrawCoord, y_pos : &DrawCoord, text : &Vec<TextToPrint>, font_scale : &Scale) {
+        draw_line_of_colored_text(self, x_pos, y_pos, text, &get_hibou_font(), font_scale);
+    }
+
+}
+
+// **********
+
+/**
+Ratio between the height of the raster font (including ascent and descent)
+and the em size of the SVG font which approximately matches it.
+**/
+const SVG_FONT_SIZE_RATIO : f32 = 1.2;
+
+/**
+Builds an SVG document element by element.
+Texts are written in a monospace font and anchored
+so that their placement does not depend on the font metrics of the viewer.
+**/
+pub struct SvgCanvas {
+    width : f32,
+    height : f32,
+    elements : Vec<String>
+}
+
+impl SvgCanvas {
+
+    pub fn new(width : f32, height : f32) -> SvgCanvas {
+        SvgCanvas{width,height,elements:vec![]}
+    }
+
+    pub fn to_svg_string(&self) -> String {
+        let mut svg = format!("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{:}\" height=\"{:}\" viewBox=\"0 0 {:} {:}\">\n",
+                              self.width as u32, self.height as u32, self.width as u32, self.height as u32);
+        for element in &self.elements {
+            svg.push_str("  ");
+            svg.push_str(element);
+            svg.push('\n');
+        }
+        svg.push_str("</svg>\n");
+        return svg;
+    }
+
+}
+
+fn svg_color(color : &Rgb<u8>) -> String {
+    format!("rgb({:},{:},{:})", color.0[0], color.0[1], color.0[2])
+}
+
+fn svg_escaped_text(text : &str) -> String {
+    text.replace('&',"&amp;").replace('<',"&lt;").replace('>',"&gt;")
+}
+
+impl SequenceDiagramCanvas for SvgCanvas {
+
+    fn draw_line(&mut self, start : (f32,f32), end : (f32,f32), color : Rgb<u8>) {
+        self.elements.push( format!("<line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" stroke=\"{:}\"/>",
+                                    start.0, start.1, end.0, end.1, svg_color(&color)) );
+    }
+
+    fn draw_hollow_rect(&mut self, x : f32, y : f32, width : f32, height : f32, color : Rgb<u8>) {
+        self.elements.push( format!("<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"none\" stroke=\"{:}\"/>",
+                                    x, y, width, height, svg_color(&color)) );
+    }
+
+    fn draw_filled_rect(&mut self, x : f32, y : f32, width : f32, height : f32, color : Rgb<u8>) {
+        self.elements.push( format!("<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"{:}\"/>",
+                                    x, y, width, height, svg_color(&color)) );
+    }
+
+    fn draw_text(&mut self, x_pos : &DrawCoord, y_pos : &DrawCoord, text : &Vec<TextToPrint>, font_scale : &Scale) {
+        let (x,anchor) = match x_pos {
+            DrawCoord::StartingAt(x) => {
+                (x,"start")
+            },
+            DrawCoord::CenteredAround(x) => {
+                (x,"middle")
+            },
+            DrawCoord::EndingAt(x) => {
+                (x,"end")
+            }
+        };
+        let (y,baseline) = match y_pos {
+            DrawCoord::StartingAt(y) => {
+                (y,"text-before-edge")
+            },
+            DrawCoord::CenteredAround(y) => {
+                (y,"central")
+            },
+            DrawCoord::EndingAt(y) => {
+                (y,"text-after-edge")
+            }
+        };
+        let spans : Vec<String> = text.iter()
+            .map(|ttp| format!("<tspan fill=\"{:}\">{}</tspan>", svg_color(&ttp.color), svg_escaped_text(&ttp.text)))
+            .collect();
+        self.elements.push( format!("<text x=\"{:.1}\" y=\"{:.1}\" font-family=\"DejaVu Sans Mono, monospace\" font-size=\"{:.1}\" text-anchor=\"{:}\" dominant-baseline=\"{:}\" xml:space=\"preserve\">{}</text>",
+                                    x, y, font_scale.y/SVG_FONT_SIZE_RATIO, anchor, baseline, spans.join("")) );
+    }
+
+}
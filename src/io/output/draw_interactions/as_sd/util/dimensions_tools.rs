@@ -17,7 +17,7 @@ limitations under the License.
 
 use crate::core::language::syntax::interaction::Interaction;
 use crate::core::language::util::get_recursive_frag::{get_recursive_alt_frags, get_recursive_coreg_frags, get_recursive_sync_frags};
-use crate::io::output::draw_commons::sd_drawing_conf::*;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
 
 pub fn get_interaction_max_yshift(interaction : &Interaction) -> usize {
     let mut cpt = 4;
@@ -67,7 +67,7 @@ fn get_interaction_depth(interaction : &Interaction ) -> usize {
     }
 }
 
-pub fn get_y_pos_from_yshift(yshift : u32) -> f32 {
-    return MARGIN + VERTICAL_SIZE*(yshift as f32);
+pub fn get_y_pos_from_yshift(draw_conf : &DrawingConfiguration, yshift : u32) -> f32 {
+    return draw_conf.margin() + draw_conf.vertical_size()*(yshift as f32);
 }
 
@@ -16,4 +16,5 @@ limitations under the License.
 
 pub mod arrow_heads;
 pub mod lf_coords;
-pub mod dimensions_tools;
\ No newline at end of file
+pub mod dimensions_tools;
+pub mod canvas;
\ No newline at end of file
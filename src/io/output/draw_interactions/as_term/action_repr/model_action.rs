@@ -15,17 +15,20 @@ limitations under the License.
 */
 
 
+use image_colored_text::ttp::TextToPrint;
 use itertools::Itertools;
 
 use crate::core::general_context::GeneralContext;
 use crate::core::language::syntax::action::BroadcastPrimitive;
-use crate::io::output::draw_messages::text_repr_message;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
+use crate::io::output::draw_messages::diagram_repr_message;
 use crate::io::textual_convention::{SYNTAX_EMISSION, SYNTAX_RECEPTION};
 
 
 pub fn broadcast_prim_as_gv_label(gen_ctx : &GeneralContext,
+                                  draw_conf : &DrawingConfiguration,
                                   bp : &BroadcastPrimitive) -> String {
-    let msg_label = text_repr_message(&bp.message_type,gen_ctx);
+    let msg_label = TextToPrint::flatten(&diagram_repr_message(&bp.message_type,gen_ctx,draw_conf));
     let targs_label = match bp.targets.len() {
         0 => {
             "".to_string()
@@ -16,11 +16,15 @@ limitations under the License.
 
 
 use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use image_colored_text::ttp::TextToPrint;
+
 use crate::core::general_context::GeneralContext;
-use crate::io::output::draw_messages::text_repr_message;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
+use crate::io::output::draw_messages::diagram_repr_message;
 use crate::io::textual_convention::{SYNTAX_EMISSION,SYNTAX_RECEPTION};
 
 pub fn trace_action_as_gv_label (gen_ctx : &GeneralContext,
+                                 draw_conf : &DrawingConfiguration,
                                  tr_act : &TraceAction) -> String {
     let lf_name = gen_ctx.get_lf_name(tr_act.lf_id).unwrap();
     // ***
@@ -34,14 +38,15 @@ pub fn trace_action_as_gv_label (gen_ctx : &GeneralContext,
         }
     }
     // ***
-    let ms_string = text_repr_message(&tr_act.message,gen_ctx);
+    let ms_string = TextToPrint::flatten(&diagram_repr_message(&tr_act.message,gen_ctx,draw_conf));
     // ***
     format!("{}{}{}",lf_name,act_kind_label,ms_string)
 }
 
 pub fn trace_actions_as_gv_label<'a,I>(gen_ctx : &GeneralContext,
+                                       draw_conf : &DrawingConfiguration,
                                        tracts : I) -> String
     where I: Iterator<Item = &'a TraceAction> {
-    let sub_strs : Vec<String> = tracts.map(|act| trace_action_as_gv_label(gen_ctx,act)).collect();
+    let sub_strs : Vec<String> = tracts.map(|act| trace_action_as_gv_label(gen_ctx,draw_conf,act)).collect();
     return format!("{{{}}}", sub_strs.join(","));
 }
\ No newline at end of file
@@ -25,20 +25,23 @@ use crate::core::execution::semantics::position::Position;
 use crate::core::execution::trace::trace::TraceAction;
 use crate::core::general_context::GeneralContext;
 use crate::core::language::syntax::interaction::Interaction;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
 use crate::io::output::draw_interactions::as_term::action_repr::model_action::broadcast_prim_as_gv_label;
 use crate::io::output::draw_interactions::as_term::action_repr::trace_action::trace_actions_as_gv_label;
 use crate::io::textual_convention::{SYNTAX_ALT, SYNTAX_COREG, SYNTAX_LOOP_P, SYNTAX_LOOP_W, SYNTAX_SYNC, SYNTAX_LOOP_C};
 
 
 pub fn interaction_gv_repr(gen_ctx : &GeneralContext,
+                        draw_conf : &DrawingConfiguration,
                         interaction : &Interaction) -> GraphVizDiGraph {
     let mut digraph = GraphVizDiGraph::new(vec![]);
-    interaction_gv_repr_rec(gen_ctx, interaction,Position::Epsilon(None), &mut digraph);
+    interaction_gv_repr_rec(gen_ctx, draw_conf, interaction,Position::Epsilon(None), &mut digraph);
     return digraph;
 }
 
 
 fn interaction_gv_repr_rec(gen_ctx : &GeneralContext,
+                        draw_conf : &DrawingConfiguration,
                         interaction : &Interaction,
                         current_pos : Position,
                            gv_graph : &mut GraphVizDiGraph) -> String {
@@ -47,37 +50,40 @@ fn interaction_gv_repr_rec(gen_ctx : &GeneralContext,
         &Interaction::Empty => {
             let mut node_gv_options : GraphvizNodeStyle = Vec::new();
             node_gv_options.push( GraphvizNodeStyleItem::Shape(GvNodeShape::PlainText) );
+            node_gv_options.push( GraphvizNodeStyleItem::FontSize(draw_conf.term_tree_font_size()) );
             node_gv_options.push( GraphvizNodeStyleItem::Label( "o".to_string() ) );
             gv_graph.add_node( GraphVizNode::new(node_name.clone(), node_gv_options) );
         },
         &Interaction::Action(ref bp) => {
             let mut node_gv_options : GraphvizNodeStyle = Vec::new();
             node_gv_options.push( GraphvizNodeStyleItem::Shape(GvNodeShape::PlainText) );
-            node_gv_options.push( GraphvizNodeStyleItem::Label( broadcast_prim_as_gv_label(gen_ctx,bp) ) );
+            node_gv_options.push( GraphvizNodeStyleItem::FontSize(draw_conf.term_tree_font_size()) );
+            node_gv_options.push( GraphvizNodeStyleItem::Label( broadcast_prim_as_gv_label(gen_ctx,draw_conf,bp) ) );
             gv_graph.add_node( GraphVizNode::new(node_name.clone(), node_gv_options) );
         },
         &Interaction::CoReg(ref cr, ref i1, ref i2) => {
             let co_localised_lf_names : Vec<String> = cr.iter().map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap()).collect();
             let op_label = format!("{}({})", SYNTAX_COREG, co_localised_lf_names.join(","));
-            repr_binary_operator(gen_ctx, i1, i2, &op_label, current_pos, gv_graph);
+            repr_binary_operator(gen_ctx, draw_conf, i1, i2, &op_label, current_pos, gv_graph);
         },
         &Interaction::Sync(ref sync, ref i1, ref i2) => {
             let mut sync_acts = vec![];
             for ((lf_id,act_kind),ms_ty) in sync {
                 sync_acts.push(TraceAction::new(*lf_id,*act_kind,ms_ty.clone()));
             }
-            let acts_as_str = trace_actions_as_gv_label(gen_ctx,sync_acts.iter());
+            let acts_as_str = trace_actions_as_gv_label(gen_ctx,draw_conf,sync_acts.iter());
             let op_label = format!("{}{}", SYNTAX_SYNC,acts_as_str);
-            repr_binary_operator(gen_ctx, i1, i2, &op_label, current_pos, gv_graph);
+            repr_binary_operator(gen_ctx, draw_conf, i1, i2, &op_label, current_pos, gv_graph);
         },
         &Interaction::Alt(ref i1, ref i2) => {
-            repr_binary_operator(gen_ctx, i1, i2, SYNTAX_ALT, current_pos, gv_graph);
+            repr_binary_operator(gen_ctx, draw_conf, i1, i2, SYNTAX_ALT, current_pos, gv_graph);
         },
         &Interaction::Loop(ref cr, ref i1) => {
             // the parent loop node
             {
                 let mut node_gv_options : GraphvizNodeStyle = Vec::new();
                 node_gv_options.push( GraphvizNodeStyleItem::Shape(GvNodeShape::PlainText) );
+                node_gv_options.push( GraphvizNodeStyleItem::FontSize(draw_conf.term_tree_font_size()) );
                 let label = match cr.len() {
                     0 => {
                         SYNTAX_LOOP_W.to_string()
@@ -96,7 +102,7 @@ fn interaction_gv_repr_rec(gen_ctx : &GeneralContext,
             // then the left sub-interaction
             {
                 let left_position = Position::Left(Box::new(current_pos.clone()));
-                let child_node_name = interaction_gv_repr_rec(gen_ctx,i1,left_position, gv_graph);
+                let child_node_name = interaction_gv_repr_rec(gen_ctx,draw_conf,i1,left_position, gv_graph);
                 let gv_edge = GraphVizEdge::new(node_name.clone(),
                                                 None,
                                                 child_node_name,
@@ -110,6 +116,7 @@ fn interaction_gv_repr_rec(gen_ctx : &GeneralContext,
 }
 
 fn repr_binary_operator(gen_ctx : &GeneralContext,
+                        draw_conf : &DrawingConfiguration,
                         i1 : &Interaction,
                         i2 : &Interaction,
                         operator_label : &str,
@@ -120,13 +127,14 @@ fn repr_binary_operator(gen_ctx : &GeneralContext,
     {
         let mut parent_node_gv_options : GraphvizNodeStyle = Vec::new();
         parent_node_gv_options.push( GraphvizNodeStyleItem::Shape(GvNodeShape::PlainText) );
+        parent_node_gv_options.push( GraphvizNodeStyleItem::FontSize(draw_conf.term_tree_font_size()) );
         parent_node_gv_options.push( GraphvizNodeStyleItem::Label( operator_label.to_string() ) );
         gv_graph.add_node( GraphVizNode::new(node_name.clone(), parent_node_gv_options) );
     }
     // then the left sub-interaction
     {
         let left_position = Position::Left(Box::new(current_pos.clone()));
-        let child_node_name = interaction_gv_repr_rec(gen_ctx,i1,left_position.clone(), gv_graph);
+        let child_node_name = interaction_gv_repr_rec(gen_ctx,draw_conf,i1,left_position.clone(), gv_graph);
         let gv_edge = GraphVizEdge::new(node_name.clone(),
                                         None,
                                         child_node_name,
@@ -137,7 +145,7 @@ fn repr_binary_operator(gen_ctx : &GeneralContext,
     // then the right sub-interaction
     {
         let right_position = Position::Right(Box::new(current_pos.clone()));
-        let child_node_name = interaction_gv_repr_rec(gen_ctx,i2,right_position.clone(), gv_graph);
+        let child_node_name = interaction_gv_repr_rec(gen_ctx,draw_conf,i2,right_position.clone(), gv_graph);
         let gv_edge = GraphVizEdge::new(node_name,
                                         None,
                                         child_node_name,
@@ -0,0 +1,232 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::BTreeSet;
+
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::action::BroadcastPrimitive;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::core::message::MessageTypeExpression;
+use crate::io::output::draw_interactions::as_text::interface::TextualDiagramLanguage;
+use crate::io::output::draw_interactions::as_text::syntax::{emission_to_environment_line, fragment_close_line, fragment_open_line, fragment_separator_line, message_line, note_line, reception_from_environment_line, TextualFragmentKind};
+use crate::io::output::draw_messages::text_repr_message;
+use crate::io::output::draw_traces::interface::text_repr_trace_action;
+use crate::io::output::to_hfiles::interaction::to_hif::is_full_coreg;
+use crate::io::textual_convention::{SYNTAX_EMPTY, SYNTAX_LOOP_C, SYNTAX_LOOP_P, SYNTAX_SYNC};
+
+
+/**
+Appends the lines representing an interaction, indented according to the depth :
+- weak sequencing (seq) is the default composition of sequence diagrams
+- alternatives (alt), interleaving (par) and repetitions (loopW) are the corresponding combined fragments
+- co-regions (coreg), synchronizations (sync) and the other loops (loopP, loopC)
+have no equivalent and are drawn as the closest fragment together with a note giving their semantics
+**/
+pub fn interaction_as_text_diagram_lines(gen_ctx : &GeneralContext,
+                                         interaction : &Interaction,
+                                         language : &TextualDiagramLanguage,
+                                         depth : usize,
+                                         lines : &mut Vec<String>) {
+    let indent = "  ".repeat(depth);
+    match interaction {
+        Interaction::Empty => {
+            // nothing to draw
+        },
+        Interaction::Action(bp) => {
+            for line in action_lines(gen_ctx,bp,language) {
+                lines.push( format!("{:}{:}", indent, line) );
+            }
+        },
+        Interaction::CoReg(cr,_,_) => {
+            let mut sub_ints = vec![];
+            let mut rem = interaction;
+            while let Interaction::CoReg(got_cr,i1,i2) = rem {
+                if got_cr != cr {
+                    break;
+                }
+                sub_ints.push(&**i1);
+                rem = i2;
+            }
+            sub_ints.push(rem);
+            if cr.is_empty() {
+                for sub_int in sub_ints {
+                    interaction_as_text_diagram_lines(gen_ctx,sub_int,language,depth,lines);
+                }
+            } else if is_full_coreg(gen_ctx,cr) {
+                fragment_lines(gen_ctx,&TextualFragmentKind::Par,"",None,&sub_ints,language,depth,lines);
+            } else {
+                let note = (lifelines_names(gen_ctx,cr),
+                            format!("co-region : interleaving on {:} and weak sequencing on the other lifelines", lifelines_names(gen_ctx,cr).join(",")));
+                fragment_lines(gen_ctx,
+                               &TextualFragmentKind::Par,
+                               &format!("coreg({:})", lifelines_names(gen_ctx,cr).join(",")),
+                               Some(note),
+                               &sub_ints,
+                               language,
+                               depth,
+                               lines);
+            }
+        },
+        Interaction::Alt(_,_) => {
+            let mut sub_ints = vec![];
+            let mut rem = interaction;
+            while let Interaction::Alt(i1,i2) = rem {
+                sub_ints.push(&**i1);
+                rem = i2;
+            }
+            sub_ints.push(rem);
+            fragment_lines(gen_ctx,&TextualFragmentKind::Alt,"",None,&sub_ints,language,depth,lines);
+        },
+        Interaction::Sync(sync_acts,_,_) => {
+            let mut sub_ints = vec![];
+            let mut rem = interaction;
+            while let Interaction::Sync(got_sync_acts,i1,i2) = rem {
+                if got_sync_acts != sync_acts {
+                    break;
+                }
+                sub_ints.push(&**i1);
+                rem = i2;
+            }
+            sub_ints.push(rem);
+            let acts_labels : Vec<String> = sync_acts.iter()
+                .map(|((lf_id,act_kind),message)| text_repr_trace_action(&TraceAction::new(*lf_id,act_kind.clone(),message.clone()),gen_ctx))
+                .collect();
+            // without synchronized actions, there are no lifelines over which to put the note
+            let note = if sync_acts.is_empty() {
+                None
+            } else {
+                let lf_ids : BTreeSet<usize> = sync_acts.keys().map(|(lf_id,_)| *lf_id).collect();
+                Some( (lifelines_names(gen_ctx,&lf_ids.into_iter().collect()),
+                       format!("synchronization on {:} : performed jointly by all operands", acts_labels.join(","))) )
+            };
+            fragment_lines(gen_ctx,
+                           &TextualFragmentKind::Par,
+                           &format!("{:}({:})", SYNTAX_SYNC, acts_labels.join(",")),
+                           note,
+                           &sub_ints,
+                           language,
+                           depth,
+                           lines);
+        },
+        Interaction::Loop(cr,i1) => {
+            if cr.is_empty() {
+                fragment_lines(gen_ctx,&TextualFragmentKind::Loop,"",None,&vec![&**i1],language,depth,lines);
+            } else if is_full_coreg(gen_ctx,cr) {
+                let note = (lifelines_names(gen_ctx,cr),
+                            "successive iterations may be interleaved".to_string());
+                fragment_lines(gen_ctx,&TextualFragmentKind::Loop,SYNTAX_LOOP_P,Some(note),&vec![&**i1],language,depth,lines);
+            } else {
+                let note = (lifelines_names(gen_ctx,cr),
+                            format!("successive iterations may be interleaved on {:} and are weakly sequenced on the other lifelines", lifelines_names(gen_ctx,cr).join(",")));
+                fragment_lines(gen_ctx,
+                               &TextualFragmentKind::Loop,
+                               &format!("{:}({:})", SYNTAX_LOOP_C, lifelines_names(gen_ctx,cr).join(",")),
+                               Some(note),
+                               &vec![&**i1],
+                               language,
+                               depth,
+                               lines);
+            }
+        }
+    }
+}
+
+
+/**
+Appends a combined fragment whose operands are separated by the separators of the fragment.
+Empty operands are labelled with the empty interaction symbol
+and the note, if any, is put at the beginning of the fragment.
+**/
+fn fragment_lines(gen_ctx : &GeneralContext,
+                  kind : &TextualFragmentKind,
+                  label : &str,
+                  note : Option<(Vec<String>,String)>,
+                  sub_ints : &Vec<&Interaction>,
+                  language : &TextualDiagramLanguage,
+                  depth : usize,
+                  lines : &mut Vec<String>) {
+    let indent = "  ".repeat(depth);
+    for (sub_id,sub_int) in sub_ints.iter().enumerate() {
+        let operand_label = if **sub_int == Interaction::Empty {
+            SYNTAX_EMPTY
+        } else {
+            ""
+        };
+        if sub_id == 0 {
+            let open_label = vec![label,operand_label].into_iter().filter(|x| !x.is_empty()).collect::<Vec<&str>>().join(" ");
+            lines.push( format!("{:}{:}", indent, fragment_open_line(kind,&open_label)) );
+            if let Some((lf_names,text)) = &note {
+                lines.push( format!("{:}  {:}", indent, note_line(language,lf_names,text)) );
+            }
+        } else {
+            lines.push( format!("{:}{:}", indent, fragment_separator_line(language,kind,operand_label)) );
+        }
+        interaction_as_text_diagram_lines(gen_ctx,sub_int,language,depth + 1,lines);
+    }
+    lines.push( format!("{:}{:}", indent, fragment_close_line()) );
+}
+
+
+fn action_lines(gen_ctx : &GeneralContext,
+                bp : &BroadcastPrimitive,
+                language : &TextualDiagramLanguage) -> Vec<String> {
+    let label = message_label(gen_ctx,&bp.message_type);
+    let targets = lifelines_names(gen_ctx,&bp.targets);
+    let mut lines = vec![];
+    match bp.origin_on_emission {
+        Some( origin_lf_id ) => {
+            let origin = gen_ctx.get_lf_name(origin_lf_id).unwrap();
+            if targets.is_empty() {
+                lines.push( emission_to_environment_line(language,&origin,&label) );
+            } else {
+                for target in &targets {
+                    lines.push( message_line(language,&origin,target,&label) );
+                }
+                if targets.len() > 1 {
+                    lines.push( note_line(language,&targets,&format!("broadcast : a single emission of {:} by {:}", label, origin)) );
+                }
+            }
+        },
+        None => {
+            for target in &targets {
+                lines.push( reception_from_environment_line(language,target,&label) );
+            }
+            if targets.len() > 1 {
+                lines.push( note_line(language,&targets,&format!("broadcast : a single message {:} from the environment", label)) );
+            }
+        }
+    }
+    return lines;
+}
+
+/**
+Messages are given by their name and message types by their type expression.
+**/
+fn message_label(gen_ctx : &GeneralContext, message : &MessageTypeExpression) -> String {
+    match message {
+        MessageTypeExpression::Singleton(ms_id) => {
+            gen_ctx.get_ms_name(*ms_id).unwrap()
+        },
+        _ => {
+            text_repr_message(message,gen_ctx)
+        }
+    }
+}
+
+fn lifelines_names(gen_ctx : &GeneralContext, lf_ids : &Vec<usize>) -> Vec<String> {
+    lf_ids.iter().map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap()).collect()
+}
@@ -0,0 +1,169 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::output::draw_interactions::as_text::implem::interaction_as_text_diagram_lines;
+use crate::io::output::draw_interactions::as_text::syntax::{diagram_footer, diagram_header};
+
+
+/**
+Text-based sequence diagram languages into which interactions can be exported.
+**/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TextualDiagramLanguage {
+    PlantUml,
+    Mermaid
+}
+
+impl TextualDiagramLanguage {
+
+    pub fn get_extension(&self) -> &'static str {
+        match self {
+            TextualDiagramLanguage::PlantUml => {
+                "puml"
+            },
+            TextualDiagramLanguage::Mermaid => {
+                "mmd"
+            }
+        }
+    }
+
+}
+
+
+pub fn interaction_as_text_diagram(gen_ctx : &GeneralContext,
+                                   interaction : &Interaction,
+                                   language : &TextualDiagramLanguage) -> String {
+    let mut lines = diagram_header(gen_ctx,language);
+    interaction_as_text_diagram_lines(gen_ctx,interaction,language,1,&mut lines);
+    lines.append(&mut diagram_footer(language));
+    let mut diagram = lines.join("\n");
+    diagram.push('\n');
+    return diagram;
+}
+
+
+pub fn draw_int_as_text(gen_ctx : &GeneralContext,
+                        interaction : &Interaction,
+                        language : &TextualDiagramLanguage,
+                        parent_folder : &String,
+                        output_file_name : &String) {
+    let output_file_name = format!("{:}.{:}", output_file_name, language.get_extension());
+    let output_path : PathBuf = [parent_folder, &output_file_name].iter().collect();
+    let mut file = File::create(output_path.as_path()).unwrap();
+    file.write(interaction_as_text_diagram(gen_ctx,interaction,language).as_bytes());
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::execution::trace::trace::TraceActionKind;
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::BroadcastPrimitive;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use super::{interaction_as_text_diagram, TextualDiagramLanguage};
+
+    #[test]
+    fn alt_with_gate_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m".to_string());
+        let message = MessageTypeExpression::Singleton(0);
+        // alt(l1 -- m -> l2, m -> l2)
+        let interaction = Interaction::Alt(Box::new(Interaction::Action(BroadcastPrimitive::new(Some(0),message.clone(),vec![1]))),
+                                           Box::new(Interaction::Action(BroadcastPrimitive::new(None,message,vec![1]))));
+        assert_eq!(interaction_as_text_diagram(&gen_ctx,&interaction,&TextualDiagramLanguage::PlantUml),
+                   "@startuml\n  participant l1\n  participant l2\n  alt\n    l1 -> l2 : m\n  else\n    [-> l2 : m\n  end\n@enduml\n");
+        assert_eq!(interaction_as_text_diagram(&gen_ctx,&interaction,&TextualDiagramLanguage::Mermaid),
+                   "sequenceDiagram\n  participant l1\n  participant l2\n  alt\n    l1->>l2: m\n  else\n    Note left of l2: l2 receives m from the environment\n  end\n");
+    }
+
+    fn get_test_context() -> GeneralContext {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m".to_string());
+        gen_ctx
+    }
+
+    // l1 -- m -> l2
+    fn message() -> Box<Interaction> {
+        Box::new(Interaction::Action(BroadcastPrimitive::new(Some(0),MessageTypeExpression::Singleton(0),vec![1])))
+    }
+
+    // m -> l2
+    fn reception() -> Box<Interaction> {
+        Box::new(Interaction::Action(BroadcastPrimitive::new(None,MessageTypeExpression::Singleton(0),vec![1])))
+    }
+
+    fn assert_diagrams(interaction : &Interaction, plantuml_body : &str, mermaid_body : &str) {
+        let gen_ctx = get_test_context();
+        assert_eq!(interaction_as_text_diagram(&gen_ctx,interaction,&TextualDiagramLanguage::PlantUml),
+                   format!("@startuml\n  participant l1\n  participant l2\n{:}@enduml\n", plantuml_body));
+        assert_eq!(interaction_as_text_diagram(&gen_ctx,interaction,&TextualDiagramLanguage::Mermaid),
+                   format!("sequenceDiagram\n  participant l1\n  participant l2\n{:}", mermaid_body));
+    }
+
+    #[test]
+    fn par_test() {
+        let interaction = Interaction::CoReg(vec![0,1],message(),reception());
+        assert_diagrams(&interaction,
+                        "  par\n    l1 -> l2 : m\n  else\n    [-> l2 : m\n  end\n",
+                        "  par\n    l1->>l2: m\n  and\n    Note left of l2: l2 receives m from the environment\n  end\n");
+    }
+
+    #[test]
+    fn coreg_test() {
+        let interaction = Interaction::CoReg(vec![1],message(),reception());
+        assert_diagrams(&interaction,
+                        "  par coreg(l2)\n    note over l2 : co-region : interleaving on l2 and weak sequencing on the other lifelines\n    l1 -> l2 : m\n  else\n    [-> l2 : m\n  end\n",
+                        "  par coreg(l2)\n    Note over l2: co-region : interleaving on l2 and weak sequencing on the other lifelines\n    l1->>l2: m\n  and\n    Note left of l2: l2 receives m from the environment\n  end\n");
+    }
+
+    #[test]
+    fn loops_test() {
+        let loop_p = Interaction::Loop(vec![0,1],message());
+        assert_diagrams(&loop_p,
+                        "  loop loopP\n    note over l1, l2 : successive iterations may be interleaved\n    l1 -> l2 : m\n  end\n",
+                        "  loop loopP\n    Note over l1,l2: successive iterations may be interleaved\n    l1->>l2: m\n  end\n");
+        let loop_c = Interaction::Loop(vec![1],message());
+        assert_diagrams(&loop_c,
+                        "  loop loopC(l2)\n    note over l2 : successive iterations may be interleaved on l2 and are weakly sequenced on the other lifelines\n    l1 -> l2 : m\n  end\n",
+                        "  loop loopC(l2)\n    Note over l2: successive iterations may be interleaved on l2 and are weakly sequenced on the other lifelines\n    l1->>l2: m\n  end\n");
+    }
+
+    #[test]
+    fn sync_test() {
+        let sync_acts = btreemap!{(1,TraceActionKind::Reception) => MessageTypeExpression::Singleton(0)};
+        let interaction = Interaction::Sync(sync_acts,message(),reception());
+        assert_diagrams(&interaction,
+                        "  par sync(l2?{m})\n    note over l2 : synchronization on l2?{m} : performed jointly by all operands\n    l1 -> l2 : m\n  else\n    [-> l2 : m\n  end\n",
+                        "  par sync(l2?{m})\n    Note over l2: synchronization on l2?{m} : performed jointly by all operands\n    l1->>l2: m\n  and\n    Note left of l2: l2 receives m from the environment\n  end\n");
+        // without synchronized actions, no note is put over an empty list of lifelines
+        let interaction = Interaction::Sync(btreemap!{},message(),reception());
+        assert_diagrams(&interaction,
+                        "  par sync()\n    l1 -> l2 : m\n  else\n    [-> l2 : m\n  end\n",
+                        "  par sync()\n    l1->>l2: m\n  and\n    Note left of l2: l2 receives m from the environment\n  end\n");
+    }
+}
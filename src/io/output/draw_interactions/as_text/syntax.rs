@@ -0,0 +1,165 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use crate::core::general_context::GeneralContext;
+use crate::io::output::draw_interactions::as_text::interface::TextualDiagramLanguage;
+
+
+/**
+Combined fragments used to represent the operators of interactions.
+**/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TextualFragmentKind {
+    Alt,
+    Par,
+    Loop
+}
+
+
+pub fn diagram_header(gen_ctx : &GeneralContext,
+                      language : &TextualDiagramLanguage) -> Vec<String> {
+    let mut lines = vec![];
+    match language {
+        TextualDiagramLanguage::PlantUml => {
+            lines.push( "@startuml".to_string() );
+        },
+        TextualDiagramLanguage::Mermaid => {
+            lines.push( "sequenceDiagram".to_string() );
+        }
+    }
+    for lf_id in 0..gen_ctx.get_lf_num() {
+        lines.push( format!("  participant {:}", gen_ctx.get_lf_name(lf_id).unwrap()) );
+    }
+    return lines;
+}
+
+pub fn diagram_footer(language : &TextualDiagramLanguage) -> Vec<String> {
+    match language {
+        TextualDiagramLanguage::PlantUml => {
+            vec!["@enduml".to_string()]
+        },
+        TextualDiagramLanguage::Mermaid => {
+            vec![]
+        }
+    }
+}
+
+
+pub fn message_line(language : &TextualDiagramLanguage,
+                    origin : &str,
+                    target : &str,
+                    label : &str) -> String {
+    match language {
+        TextualDiagramLanguage::PlantUml => {
+            format!("{:} -> {:} : {:}", origin, target, label)
+        },
+        TextualDiagramLanguage::Mermaid => {
+            format!("{:}->>{:}: {:}", origin, target, label)
+        }
+    }
+}
+
+/**
+PlantUML represents exchanges with the environment using gates on the border of the diagram.
+Mermaid has no such construct hence a note is used instead.
+**/
+pub fn emission_to_environment_line(language : &TextualDiagramLanguage,
+                                    origin : &str,
+                                    label : &str) -> String {
+    match language {
+        TextualDiagramLanguage::PlantUml => {
+            format!("{:} ->] : {:}", origin, label)
+        },
+        TextualDiagramLanguage::Mermaid => {
+            format!("Note right of {:}: {:} emits {:} to the environment", origin, origin, label)
+        }
+    }
+}
+
+pub fn reception_from_environment_line(language : &TextualDiagramLanguage,
+                                       target : &str,
+                                       label : &str) -> String {
+    match language {
+        TextualDiagramLanguage::PlantUml => {
+            format!("[-> {:} : {:}", target, label)
+        },
+        TextualDiagramLanguage::Mermaid => {
+            format!("Note left of {:}: {:} receives {:} from the environment", target, target, label)
+        }
+    }
+}
+
+
+/**
+A note spanning the given lifelines (which must not be empty).
+**/
+pub fn note_line(language : &TextualDiagramLanguage,
+                 lf_names : &Vec<String>,
+                 text : &str) -> String {
+    match language {
+        TextualDiagramLanguage::PlantUml => {
+            format!("note over {:} : {:}", lf_names.join(", "), text)
+        },
+        TextualDiagramLanguage::Mermaid => {
+            if lf_names.len() == 1 {
+                format!("Note over {:}: {:}", lf_names[0], text)
+            } else {
+                format!("Note over {:},{:}: {:}", lf_names.first().unwrap(), lf_names.last().unwrap(), text)
+            }
+        }
+    }
+}
+
+
+fn with_label(keyword : &str, label : &str) -> String {
+    if label.is_empty() {
+        keyword.to_string()
+    } else {
+        format!("{:} {:}", keyword, label)
+    }
+}
+
+pub fn fragment_open_line(kind : &TextualFragmentKind,
+                          label : &str) -> String {
+    match kind {
+        TextualFragmentKind::Alt => {
+            with_label("alt",label)
+        },
+        TextualFragmentKind::Par => {
+            with_label("par",label)
+        },
+        TextualFragmentKind::Loop => {
+            with_label("loop",label)
+        }
+    }
+}
+
+pub fn fragment_separator_line(language : &TextualDiagramLanguage,
+                               kind : &TextualFragmentKind,
+                               label : &str) -> String {
+    match (language,kind) {
+        (TextualDiagramLanguage::Mermaid,TextualFragmentKind::Par) => {
+            with_label("and",label)
+        },
+        _ => {
+            with_label("else",label)
+        }
+    }
+}
+
+pub fn fragment_close_line() -> String {
+    "end".to_string()
+}
@@ -16,20 +16,28 @@ limitations under the License.
 
 
 
+use image::{Rgb, RgbImage};
+
 use crate::core::general_context::GeneralContext;
 use crate::core::language::syntax::interaction::Interaction;
-use crate::io::output::draw_interactions::as_sd::interface::draw_int_as_sd;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
+use crate::io::output::draw_interactions::as_sd::interface::{draw_int_as_sd, draw_int_as_sd_svg, draw_int_as_sd_with_coverage, int_as_sd_image_with_actions_colors, int_as_sd_svg_string};
 use crate::io::output::draw_interactions::as_term::interface::draw_int_as_term;
+use crate::io::output::draw_interactions::as_text::interface::{draw_int_as_text, TextualDiagramLanguage};
 
 
 
 
 pub enum InteractionGraphicalRepresentation {
     AsSequenceDiagram,
-    AsTerm
+    AsSvgSequenceDiagram,
+    AsTerm,
+    AsPlantUml,
+    AsMermaid
 }
 
 pub fn draw_interaction(gen_ctx : &GeneralContext,
+                        draw_conf : &DrawingConfiguration,
                         int : &Interaction,
                         repr : &InteractionGraphicalRepresentation,
                         temp_folder : &String,
@@ -37,10 +45,49 @@ pub fn draw_interaction(gen_ctx : &GeneralContext,
                         output_file_name : &String) {
     match repr {
         InteractionGraphicalRepresentation::AsSequenceDiagram => {
-            draw_int_as_sd(gen_ctx,int,parent_folder,output_file_name);
+            draw_int_as_sd(gen_ctx,draw_conf,int,parent_folder,output_file_name);
+        },
+        InteractionGraphicalRepresentation::AsSvgSequenceDiagram => {
+            draw_int_as_sd_svg(gen_ctx,draw_conf,int,parent_folder,output_file_name);
         },
         InteractionGraphicalRepresentation::AsTerm => {
-            draw_int_as_term(gen_ctx,int,temp_folder,parent_folder,output_file_name);
+            draw_int_as_term(gen_ctx,draw_conf,int,temp_folder,parent_folder,output_file_name);
+        },
+        InteractionGraphicalRepresentation::AsPlantUml => {
+            draw_int_as_text(gen_ctx,int,&TextualDiagramLanguage::PlantUml,parent_folder,output_file_name);
+        },
+        InteractionGraphicalRepresentation::AsMermaid => {
+            draw_int_as_text(gen_ctx,int,&TextualDiagramLanguage::Mermaid,parent_folder,output_file_name);
         }
     }
-}
\ No newline at end of file
+}
+
+/**
+Returns the interaction drawn as an SVG sequence diagram, so that it can be embedded into another document.
+**/
+pub fn interaction_as_svg_string(gen_ctx : &GeneralContext,
+                                 draw_conf : &DrawingConfiguration,
+                                 int : &Interaction) -> String {
+    int_as_sd_svg_string(gen_ctx,draw_conf,int)
+}
+
+/**
+Returns the interaction drawn as a sequence diagram in which the actions are colored
+in the order of the action leaves in a left-to-right depth-first traversal
+(actions without a given color are drawn in the default color).
+**/
+pub fn interaction_as_image_with_actions_colors(gen_ctx : &GeneralContext,
+                                                draw_conf : &DrawingConfiguration,
+                                                int : &Interaction,
+                                                actions_colors : &Vec<Rgb<u8>>) -> RgbImage {
+    int_as_sd_image_with_actions_colors(gen_ctx,draw_conf,int,actions_colors)
+}
+
+pub fn draw_interaction_coverage(gen_ctx : &GeneralContext,
+                                 draw_conf : &DrawingConfiguration,
+                                 int : &Interaction,
+                                 covered_actions : &Vec<bool>,
+                                 parent_folder : &String,
+                                 output_file_name : &String) {
+    draw_int_as_sd_with_coverage(gen_ctx,draw_conf,int,covered_actions,parent_folder,output_file_name);
+}
@@ -16,6 +16,7 @@ limitations under the License.
 
 mod as_term;
 mod as_sd;
+mod as_text;
 
 pub mod interface;
 
@@ -18,9 +18,10 @@ limitations under the License.
 
 use image::Rgb;
 use image_colored_text::ttp::TextToPrint;
+use itertools::Itertools;
 use crate::core::general_context::GeneralContext;
 use crate::core::message::MessageTypeExpression;
-use crate::io::output::draw_commons::hibou_color_palette::{HC_Grammar_Symbol, HC_Message, HC_MessageKind};
+use crate::io::output::draw_commons::drawing_configuration::{DrawingConfiguration, DrawingPalette};
 
 
 
@@ -28,14 +29,39 @@ use crate::io::output::draw_commons::hibou_color_palette::{HC_Grammar_Symbol, HC
 
 
 pub fn text_repr_message(message : &MessageTypeExpression, gen_ctx : &GeneralContext) -> String {
-    let ttp = diagram_repr_message(message,gen_ctx);
+    let ttp = diagram_repr_message(message,gen_ctx,&DrawingConfiguration::default());
     TextToPrint::flatten(&ttp)
 }
 
 pub fn diagram_repr_message(message : &MessageTypeExpression,
-                            gen_ctx : &GeneralContext) -> Vec<TextToPrint> {
-    let (repr,_) = diagram_repr_message_inner(message,gen_ctx);
-    repr
+                            gen_ctx : &GeneralContext,
+                            draw_conf : &DrawingConfiguration) -> Vec<TextToPrint> {
+    if draw_conf.show_types {
+        let (repr,_) = diagram_repr_message_inner(message,gen_ctx,&draw_conf.palette);
+        repr
+    } else {
+        diagram_repr_resolved_message(message,gen_ctx,&draw_conf.palette)
+    }
+}
+
+/**
+Represents the set of messages denoted by the message type expression, e.g. {m1,m2}.
+**/
+fn diagram_repr_resolved_message(message : &MessageTypeExpression,
+                                 gen_ctx : &GeneralContext,
+                                 palette : &DrawingPalette) -> Vec<TextToPrint> {
+    let mut ret = vec![TextToPrint::new("{".to_string(),Rgb(palette.symbol))];
+    let resolved = message.resolve(gen_ctx);
+    let mut rem = resolved.len();
+    for ms_id in resolved.into_iter().sorted() {
+        ret.push( TextToPrint::new(gen_ctx.get_ms_name(ms_id).unwrap(),Rgb(palette.message)) );
+        rem -= 1;
+        if rem > 0 {
+            ret.push( TextToPrint::new(",".to_string(),Rgb(palette.symbol)) );
+        }
+    }
+    ret.push( TextToPrint::new("}".to_string(),Rgb(palette.symbol)) );
+    ret
 }
 
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
@@ -47,96 +73,97 @@ enum MessageTypeExpressionRepresentationFlag {
 }
 
 fn diagram_repr_message_inner(message : &MessageTypeExpression,
-                            gen_ctx : &GeneralContext) -> (Vec<TextToPrint>,MessageTypeExpressionRepresentationFlag) {
+                            gen_ctx : &GeneralContext,
+                            palette : &DrawingPalette) -> (Vec<TextToPrint>,MessageTypeExpressionRepresentationFlag) {
     match message {
         MessageTypeExpression::Singleton(ms_id) => {
             let label = gen_ctx.get_ms_name(*ms_id).unwrap();
             (vec![
-                TextToPrint::new("{".to_string(),Rgb(HC_Grammar_Symbol)),
-                TextToPrint::new(label,Rgb(HC_Message)),
-                TextToPrint::new("}".to_string(),Rgb(HC_Grammar_Symbol))
+                TextToPrint::new("{".to_string(),Rgb(palette.symbol)),
+                TextToPrint::new(label,Rgb(palette.message)),
+                TextToPrint::new("}".to_string(),Rgb(palette.symbol))
             ],
              MessageTypeExpressionRepresentationFlag::Atomic)
         },
         MessageTypeExpression::NamedType(mt_id) => {
             let label = gen_ctx.get_mt_name(*mt_id).unwrap();
-            (vec![TextToPrint::new(label,Rgb(HC_MessageKind))],MessageTypeExpressionRepresentationFlag::Atomic)
+            (vec![TextToPrint::new(label,Rgb(palette.message_type))],MessageTypeExpressionRepresentationFlag::Atomic)
         },
         MessageTypeExpression::Union(mte1,mte2) => {
-            let (mut v1,t1) = diagram_repr_message_inner(mte1,gen_ctx);
-            let (mut v2,t2) = diagram_repr_message_inner(mte2,gen_ctx);
+            let (mut v1,t1) = diagram_repr_message_inner(mte1,gen_ctx,palette);
+            let (mut v2,t2) = diagram_repr_message_inner(mte2,gen_ctx,palette);
             let mut ret = vec![];
             // ***
             if t1 == MessageTypeExpressionRepresentationFlag::Atomic
                 || t1 == MessageTypeExpressionRepresentationFlag::Union {
                 ret.append(&mut v1);
             } else {
-                ret.push( TextToPrint::new("(".to_string(),Rgb(HC_Grammar_Symbol)) );
+                ret.push( TextToPrint::new("(".to_string(),Rgb(palette.symbol)) );
                 ret.append(&mut v1);
-                ret.push( TextToPrint::new(")".to_string(),Rgb(HC_Grammar_Symbol)) );
+                ret.push( TextToPrint::new(")".to_string(),Rgb(palette.symbol)) );
             }
             // ***
-            ret.push( TextToPrint::new("∪".to_string(),Rgb(HC_Grammar_Symbol)) );
+            ret.push( TextToPrint::new("∪".to_string(),Rgb(palette.symbol)) );
             // ***
             if t2 == MessageTypeExpressionRepresentationFlag::Atomic
                 || t2 == MessageTypeExpressionRepresentationFlag::Union {
                 ret.append(&mut v2);
             } else {
-                ret.push( TextToPrint::new("(".to_string(),Rgb(HC_Grammar_Symbol)) );
+                ret.push( TextToPrint::new("(".to_string(),Rgb(palette.symbol)) );
                 ret.append(&mut v2);
-                ret.push( TextToPrint::new(")".to_string(),Rgb(HC_Grammar_Symbol)) );
+                ret.push( TextToPrint::new(")".to_string(),Rgb(palette.symbol)) );
             }
             // ***
             (ret,MessageTypeExpressionRepresentationFlag::Union)
         },
         MessageTypeExpression::Intersection(mte1,mte2) => {
-            let (mut v1,t1) = diagram_repr_message_inner(mte1,gen_ctx);
-            let (mut v2,t2) = diagram_repr_message_inner(mte2,gen_ctx);
+            let (mut v1,t1) = diagram_repr_message_inner(mte1,gen_ctx,palette);
+            let (mut v2,t2) = diagram_repr_message_inner(mte2,gen_ctx,palette);
             let mut ret = vec![];
             // ***
             if t1 == MessageTypeExpressionRepresentationFlag::Atomic
                 || t1 == MessageTypeExpressionRepresentationFlag::Intersection {
                 ret.append(&mut v1);
             } else {
-                ret.push( TextToPrint::new("(".to_string(),Rgb(HC_Grammar_Symbol)) );
+                ret.push( TextToPrint::new("(".to_string(),Rgb(palette.symbol)) );
                 ret.append(&mut v1);
-                ret.push( TextToPrint::new(")".to_string(),Rgb(HC_Grammar_Symbol)) );
+                ret.push( TextToPrint::new(")".to_string(),Rgb(palette.symbol)) );
             }
             // ***
-            ret.push( TextToPrint::new("∩".to_string(),Rgb(HC_Grammar_Symbol)) );
+            ret.push( TextToPrint::new("∩".to_string(),Rgb(palette.symbol)) );
             // ***
             if t2 == MessageTypeExpressionRepresentationFlag::Atomic
                 || t2 == MessageTypeExpressionRepresentationFlag::Intersection {
                 ret.append(&mut v2);
             } else {
-                ret.push( TextToPrint::new("(".to_string(),Rgb(HC_Grammar_Symbol)) );
+                ret.push( TextToPrint::new("(".to_string(),Rgb(palette.symbol)) );
                 ret.append(&mut v2);
-                ret.push( TextToPrint::new(")".to_string(),Rgb(HC_Grammar_Symbol)) );
+                ret.push( TextToPrint::new(")".to_string(),Rgb(palette.symbol)) );
             }
             // ***
             (ret,MessageTypeExpressionRepresentationFlag::Intersection)
         },
         MessageTypeExpression::SetMinus(mte1,mte2) => {
-            let (mut v1,t1) = diagram_repr_message_inner(mte1,gen_ctx);
-            let (mut v2,t2) = diagram_repr_message_inner(mte2,gen_ctx);
+            let (mut v1,t1) = diagram_repr_message_inner(mte1,gen_ctx,palette);
+            let (mut v2,t2) = diagram_repr_message_inner(mte2,gen_ctx,palette);
             let mut ret = vec![];
             // ***
             if t1 == MessageTypeExpressionRepresentationFlag::Atomic {
                 ret.append(&mut v1);
             } else {
-                ret.push( TextToPrint::new("(".to_string(),Rgb(HC_Grammar_Symbol)) );
+                ret.push( TextToPrint::new("(".to_string(),Rgb(palette.symbol)) );
                 ret.append(&mut v1);
-                ret.push( TextToPrint::new(")".to_string(),Rgb(HC_Grammar_Symbol)) );
+                ret.push( TextToPrint::new(")".to_string(),Rgb(palette.symbol)) );
             }
             // ***
-            ret.push( TextToPrint::new("\\".to_string(),Rgb(HC_Grammar_Symbol)) );
+            ret.push( TextToPrint::new("\\".to_string(),Rgb(palette.symbol)) );
             // ***
             if t2 == MessageTypeExpressionRepresentationFlag::Atomic {
                 ret.append(&mut v2);
             } else {
-                ret.push( TextToPrint::new("(".to_string(),Rgb(HC_Grammar_Symbol)) );
+                ret.push( TextToPrint::new("(".to_string(),Rgb(palette.symbol)) );
                 ret.append(&mut v2);
-                ret.push( TextToPrint::new(")".to_string(),Rgb(HC_Grammar_Symbol)) );
+                ret.push( TextToPrint::new(")".to_string(),Rgb(palette.symbol)) );
             }
             // ***
             (ret,MessageTypeExpressionRepresentationFlag::Difference)
@@ -25,90 +25,93 @@ use crate::core::execution::trace::multitrace::{MultiTrace, Trace};
 
 use crate::core::general_context::GeneralContext;
 use crate::core::execution::trace::trace::TraceAction;
-use crate::io::output::draw_commons::hibou_color_palette::{HC_Grammar_Symbol, HC_Lifeline, HCP_Black, HCP_LightGray};
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
 use crate::io::output::draw_traces::implem::trace_action::{diagram_repr_trace_action, diagram_repr_trace_actions};
 use crate::core::execution::trace::flags::{MultiTraceAnalysisFlags, TraceAnalysisFlags};
 
 fn extract_texts_on_canal_hidden(gen_ctx : &GeneralContext,
+                                 draw_conf : &DrawingConfiguration,
                                  lifelines : &HashSet<usize>,
                                  canal_flag : &TraceAnalysisFlags) -> Vec<Vec<TextToPrint>> {
     let mut canal_l1_text : Vec<TextToPrint> = Vec::new();
-    add_lifelines_text_to_canal(gen_ctx,lifelines,&mut canal_l1_text,&mut 0, HCP_LightGray,HCP_LightGray);
-    canal_l1_text.push( TextToPrint::new(" ".to_string(), Rgb(HCP_Black)) );
+    add_lifelines_text_to_canal(gen_ctx,lifelines,&mut canal_l1_text,&mut 0, draw_conf.palette.faded,draw_conf.palette.faded);
+    canal_l1_text.push( TextToPrint::new(" ".to_string(), Rgb(draw_conf.palette.symbol)) );
     // ***
     if canal_flag.simulated_before > 0 {
         canal_l1_text.push( TextToPrint::new(
             format!("♧{:}", canal_flag.simulated_before),
-            Rgb(HCP_LightGray)) );
+            Rgb(draw_conf.palette.faded)) );
     }
     canal_l1_text.push( TextToPrint::new(
         format!("⚐{:}", canal_flag.consumed),
-        Rgb(HCP_LightGray)) );
+        Rgb(draw_conf.palette.faded)) );
     canal_l1_text.push( TextToPrint::new(
         "⚑".to_string(),
-        Rgb(HCP_LightGray)) );
+        Rgb(draw_conf.palette.faded)) );
     canal_l1_text.push( TextToPrint::new(
         " non-obs".to_string(),
-        Rgb(HCP_LightGray)) );
+        Rgb(draw_conf.palette.faded)) );
     canal_l1_text.push( TextToPrint::new(
         " ".to_string(),
-        Rgb(HCP_Black)) );
+        Rgb(draw_conf.palette.symbol)) );
     return vec![ canal_l1_text, vec![] ];
 }
 
 fn extract_texts_on_canal_visible(gen_ctx : &GeneralContext,
+                                  draw_conf : &DrawingConfiguration,
                                  lifelines : &HashSet<usize>,
                                  canal_trace : &Trace,
                                   canal_flags : &TraceAnalysisFlags) -> Vec<Vec<TextToPrint>> {
     let mut canal_l1_text : Vec<TextToPrint> = Vec::new();
     let mut char_width_canal : usize = 1;
     {
-        add_lifelines_text_to_canal(gen_ctx,lifelines,&mut canal_l1_text,&mut char_width_canal, HC_Lifeline,HC_Grammar_Symbol);
+        add_lifelines_text_to_canal(gen_ctx,lifelines,&mut canal_l1_text,&mut char_width_canal, draw_conf.palette.lifeline,draw_conf.palette.symbol);
         // ***
         canal_l1_text.push( TextToPrint::new(
             " ←".to_string(),
-            Rgb(HC_Grammar_Symbol)) );
+            Rgb(draw_conf.palette.symbol)) );
         let rem_len = canal_trace.len() - canal_flags.consumed;
         if rem_len > 0 {
             let mut rem = (&canal_trace[canal_flags.consumed..canal_trace.len()]).iter();
-            add_trace_text_to_canal(gen_ctx,&mut canal_l1_text,rem_len, &mut rem);
+            add_trace_text_to_canal(gen_ctx,draw_conf,&mut canal_l1_text,rem_len, &mut rem);
         } else {
-            canal_l1_text.push( TextToPrint::new("ε".to_string(), Rgb(HCP_LightGray)) );
+            canal_l1_text.push( TextToPrint::new("ε".to_string(), Rgb(draw_conf.palette.faded)) );
         }
         // ***
-        canal_l1_text.push( TextToPrint::new(" ".to_string(), Rgb(HCP_Black)) );
+        canal_l1_text.push( TextToPrint::new(" ".to_string(), Rgb(draw_conf.palette.symbol)) );
     }
     // ***
     let mut canal_l2_text : Vec<TextToPrint> = Vec::new();
     {
         let blank_space : String = (0..char_width_canal).map(|_| " ").collect::<String>();
-        canal_l2_text.push( TextToPrint::new(blank_space, Rgb(HCP_Black)) );
+        canal_l2_text.push( TextToPrint::new(blank_space, Rgb(draw_conf.palette.symbol)) );
         // ***
         if canal_flags.simulated_before > 0 {
             canal_l2_text.push( TextToPrint::new(
                 format!("♧{:}", canal_flags.simulated_before),
-                Rgb(HC_Grammar_Symbol)) );
+                Rgb(draw_conf.palette.symbol)) );
         }
         if (canal_flags.consumed > 0) || (canal_flags.simulated_after > 0) {
             canal_l2_text.push( TextToPrint::new(
                 format!("⚐{:}", canal_flags.consumed),
-                Rgb(HC_Grammar_Symbol)) );
+                Rgb(draw_conf.palette.symbol)) );
             if canal_trace.len() == canal_flags.consumed {
-                canal_l2_text.push( TextToPrint::new("⚑".to_string(), Rgb(HC_Grammar_Symbol)) );
+                canal_l2_text.push( TextToPrint::new("⚑".to_string(), Rgb(draw_conf.palette.symbol)) );
                 if canal_flags.simulated_after > 0 {
                     canal_l2_text.push( TextToPrint::new(
                         format!("{:}♣", canal_flags.simulated_after),
-                        Rgb(HC_Grammar_Symbol)) );
+                        Rgb(draw_conf.palette.symbol)) );
                 }
             }
         }
-        canal_l2_text.push( TextToPrint::new(" ".to_string(), Rgb(HCP_Black)) );
+        canal_l2_text.push( TextToPrint::new(" ".to_string(), Rgb(draw_conf.palette.symbol)) );
     }
     // ***
     return vec![ canal_l1_text, canal_l2_text ];
 }
 
 pub fn extract_texts_on_multi_trace(gen_ctx : &GeneralContext,
+                                    draw_conf : &DrawingConfiguration,
                                     co_localizations : &CoLocalizations,
                                     multi_trace : &MultiTrace,
                                     flags : &MultiTraceAnalysisFlags,
@@ -122,25 +125,25 @@ pub fn extract_texts_on_multi_trace(gen_ctx : &GeneralContext,
         let lifelines = co_localizations.get_lf_ids_from_coloc_ids(&hashset!{canal_id});
         // ***
         if canal_flags.no_longer_observed {
-            all_texts.extend( extract_texts_on_canal_hidden(gen_ctx,&lifelines,canal_flags) );
+            all_texts.extend( extract_texts_on_canal_hidden(gen_ctx,draw_conf,&lifelines,canal_flags) );
         } else {
-            all_texts.extend( extract_texts_on_canal_visible(gen_ctx,&lifelines,canal_trace, canal_flags) )
+            all_texts.extend( extract_texts_on_canal_visible(gen_ctx,draw_conf,&lifelines,canal_trace, canal_flags) )
         }
     }
     if is_simulation {
         let mut simu_vec: Vec<TextToPrint> = vec![];
         // ***
-        simu_vec.push( TextToPrint::new(" ⌕ ".to_string(), Rgb(HC_Grammar_Symbol)) );
+        simu_vec.push( TextToPrint::new(" ⌕ ".to_string(), Rgb(draw_conf.palette.symbol)) );
         if (!sim_crit_loop) && (!sim_crit_act) {
-            simu_vec.push( TextToPrint::new("*".to_string(), Rgb(HC_Grammar_Symbol)) );
+            simu_vec.push( TextToPrint::new("*".to_string(), Rgb(draw_conf.palette.symbol)) );
         } else {
             if sim_crit_loop {
                 simu_vec.push( TextToPrint::new(format!("L{:} ",flags.rem_loop_in_sim),
-                    Rgb(HC_Grammar_Symbol)) );
+                    Rgb(draw_conf.palette.symbol)) );
             }
             if sim_crit_act {
                 simu_vec.push( TextToPrint::new(format!("A{:}",flags.rem_act_in_sim),
-                    Rgb(HC_Grammar_Symbol)) );
+                    Rgb(draw_conf.palette.symbol)) );
             }
         }
         // ***
@@ -174,15 +177,16 @@ fn add_lifelines_text_to_canal(gen_ctx : &GeneralContext,
 
 
 fn add_trace_text_to_canal<'a>(gen_ctx: &GeneralContext,
+                               draw_conf : &DrawingConfiguration,
                                canal_text : &mut Vec<TextToPrint>,
                                init_len : usize,
                                rem_actions : &mut impl Iterator<Item = &'a TraceAction> ) {
     let mut rem_len = init_len;
     while let Some(action) = rem_actions.next() {
-        canal_text.append(&mut diagram_repr_trace_action(action,gen_ctx));
+        canal_text.append(&mut diagram_repr_trace_action(action,gen_ctx,draw_conf));
         rem_len -= 1;
         if rem_len > 0 {
-            canal_text.push( TextToPrint::new(".".to_string(), Rgb(HC_Grammar_Symbol)) );
+            canal_text.push( TextToPrint::new(".".to_string(), Rgb(draw_conf.palette.symbol)) );
         }
     }
 }
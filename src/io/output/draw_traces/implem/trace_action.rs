@@ -23,30 +23,32 @@ use itertools::Itertools;
 
 use crate::core::general_context::GeneralContext;
 use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
-use crate::io::output::draw_commons::hibou_color_palette::{HC_Grammar_Symbol, HC_Lifeline, HC_Message};
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
 use crate::io::output::draw_messages::diagram_repr_message;
 use crate::io::textual_convention::*;
 
 
-pub fn diagram_repr_trace_action(action : &TraceAction, gen_ctx : &GeneralContext) -> Vec<TextToPrint> {
+pub fn diagram_repr_trace_action(action : &TraceAction,
+                                 gen_ctx : &GeneralContext,
+                                 draw_conf : &DrawingConfiguration) -> Vec<TextToPrint> {
     let mut to_print : Vec<TextToPrint> = Vec::new();
     // ***
     {
         let lf_name = gen_ctx.get_lf_name(action.lf_id).unwrap();
-        to_print.push( TextToPrint::new(lf_name,Rgb(HC_Lifeline)) );
+        to_print.push( TextToPrint::new(lf_name,Rgb(draw_conf.palette.lifeline)) );
     }
     // ***
     match &action.act_kind {
         &TraceActionKind::Reception => {
-            to_print.push( TextToPrint::new(SYNTAX_RECEPTION.to_string(),Rgb(HC_Grammar_Symbol)) );
+            to_print.push( TextToPrint::new(SYNTAX_RECEPTION.to_string(),Rgb(draw_conf.palette.symbol)) );
         },
         &TraceActionKind::Emission => {
-            to_print.push( TextToPrint::new(SYNTAX_EMISSION.to_string(),Rgb(HC_Grammar_Symbol)) );
+            to_print.push( TextToPrint::new(SYNTAX_EMISSION.to_string(),Rgb(draw_conf.palette.symbol)) );
         }
     }
     // ***
     {
-        let mut ms_ttp = diagram_repr_message(&action.message,gen_ctx);
+        let mut ms_ttp = diagram_repr_message(&action.message,gen_ctx,draw_conf);
         to_print.append(&mut ms_ttp);
     }
     // ***
@@ -56,9 +58,10 @@ pub fn diagram_repr_trace_action(action : &TraceAction, gen_ctx : &GeneralContex
 
 pub fn diagram_repr_trace_actions(actions : &Vec<TraceAction>,
                                   gen_ctx : &GeneralContext,
+                                  draw_conf : &DrawingConfiguration,
                                   draw_brackets : bool) -> Vec<TextToPrint> {
     let mut inner_reprs : Vec<Vec<TextToPrint>> =
-        actions.iter().map(|act| diagram_repr_trace_action(act, gen_ctx)).collect();
+        actions.iter().map(|act| diagram_repr_trace_action(act, gen_ctx, draw_conf)).collect();
     if draw_brackets || inner_reprs.len() > 1 {
         let mut joined : Vec<TextToPrint> = vec![];
         {
@@ -67,14 +70,14 @@ pub fn diagram_repr_trace_actions(actions : &Vec<TraceAction>,
                 rem = rem - 1;
                 joined.append(&mut sub_repr);
                 if rem > 0 {
-                    joined.push(TextToPrint::new(",".to_string(), Rgb(HC_Grammar_Symbol)));
+                    joined.push(TextToPrint::new(",".to_string(), Rgb(draw_conf.palette.symbol)));
                 }
             }
         }
         let mut to_print : Vec<TextToPrint> = Vec::new();
-        to_print.push( TextToPrint::new("{".to_string(), Rgb(HC_Grammar_Symbol)) );
+        to_print.push( TextToPrint::new("{".to_string(), Rgb(draw_conf.palette.symbol)) );
         to_print.append(&mut joined);
-        to_print.push( TextToPrint::new("}".to_string(), Rgb(HC_Grammar_Symbol)) );
+        to_print.push( TextToPrint::new("}".to_string(), Rgb(draw_conf.palette.symbol)) );
         return to_print;
     } else if inner_reprs.len() == 1 {
         return inner_reprs.pop().unwrap();
@@ -18,15 +18,60 @@ limitations under the License.
 
 use std::path::PathBuf;
 use image_colored_text::draw::multi_line::MultiLineTextAlignment;
+use image_colored_text::ttp::TextToPrint;
 use crate::core::colocalizations::CoLocalizations;
 use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::execution::trace::trace::TraceAction;
 use crate::core::general_context::GeneralContext;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
 use crate::io::output::draw_commons::make_image_of_text::new_image_with_colored_text;
 use crate::io::output::draw_traces::implem::ext_mu::extract_texts_on_multi_trace;
+use crate::io::output::draw_traces::implem::trace_action::diagram_repr_trace_action;
 use crate::core::execution::trace::flags::MultiTraceAnalysisFlags;
+use crate::io::textual_convention::{SYNTAX_TRACE_EPSILON, SYNTAX_TRACE_SEPARATOR};
 
 
+pub fn text_repr_trace_action(action : &TraceAction, gen_ctx : &GeneralContext) -> String {
+    let ttp = diagram_repr_trace_action(action,gen_ctx,&DrawingConfiguration::default());
+    TextToPrint::flatten(&ttp)
+}
+
+/**
+Writes a sequence of actions on a single line, the empty sequence being written as the empty trace.
+**/
+pub fn text_repr_trace(actions : &Vec<TraceAction>, gen_ctx : &GeneralContext) -> String {
+    if actions.is_empty() {
+        return SYNTAX_TRACE_EPSILON.to_string();
+    }
+    actions.iter()
+        .map(|action| text_repr_trace_action(action,gen_ctx))
+        .collect::<Vec<String>>()
+        .join(SYNTAX_TRACE_SEPARATOR)
+}
+
+/**
+Describes the state of a multi-trace as lines of colored text, one per co-localization.
+**/
+pub fn multitrace_as_colored_texts(gen_ctx : &GeneralContext,
+                                   draw_conf : &DrawingConfiguration,
+                                   co_localizations : &CoLocalizations,
+                                   multi_trace : &MultiTrace,
+                                   flags : &MultiTraceAnalysisFlags,
+                                   is_simulation : bool,
+                                   sim_crit_loop : bool,
+                                   sim_crit_act : bool) -> Vec<Vec<TextToPrint>> {
+    extract_texts_on_multi_trace(gen_ctx,
+                                 draw_conf,
+                                 co_localizations,
+                                 multi_trace,
+                                 flags,
+                                 is_simulation,
+                                 sim_crit_loop,
+                                 sim_crit_act)
+}
+
 pub fn draw_multitrace(gen_ctx : &GeneralContext,
+                       draw_conf : &DrawingConfiguration,
                        co_localizations : &CoLocalizations,
                        multi_trace : &MultiTrace,
                        flags : &MultiTraceAnalysisFlags,
@@ -40,6 +85,7 @@ pub fn draw_multitrace(gen_ctx : &GeneralContext,
     let output_path : PathBuf = [parent_folder, &output_file_name].iter().collect();
     // ***
     let text_lines = extract_texts_on_multi_trace(gen_ctx,
+                                                  draw_conf,
                                                   co_localizations,
                                                   multi_trace,
                                                   flags,
@@ -48,6 +94,7 @@ pub fn draw_multitrace(gen_ctx : &GeneralContext,
                                                   sim_crit_act);
     // ***
     new_image_with_colored_text(output_path.as_path(),
+                                draw_conf,
                                 &MultiLineTextAlignment::Left,
                                 &text_lines);
 }
\ No newline at end of file
@@ -27,7 +27,7 @@ use crate::core::execution::semantics::position::Position;
 
 use crate::core::general_context::GeneralContext;
 use crate::core::execution::trace::trace::TraceAction;
-use crate::io::output::draw_commons::hibou_color_palette::*;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
 use crate::io::output::draw_commons::make_image_of_text::new_image_with_colored_text;
 use crate::io::output::draw_traces::implem::trace_action::diagram_repr_trace_action;
 
@@ -35,29 +35,36 @@ use crate::io::output::draw_traces::implem::trace_action::diagram_repr_trace_act
 
 
 
+/**
+Describes the firing of an action at a given position of an interaction as a line of colored text.
+**/
+pub fn firing_as_colored_text(gen_ctx : &GeneralContext,
+                              draw_conf : &DrawingConfiguration,
+                              action_position : &Position,
+                              executed_action : &TraceAction,
+                              is_simulated : bool) -> Vec<TextToPrint> {
+    let mut ttp: Vec<TextToPrint> = Vec::new();
+    if is_simulated {
+        ttp.push( TextToPrint::new("SIMU ".to_string(),Rgb(draw_conf.palette.faded)) );
+    }
+    ttp.append( &mut diagram_repr_trace_action(executed_action,gen_ctx,draw_conf) );
+    ttp.push( TextToPrint::new(" ".to_string(),Rgb(draw_conf.palette.symbol)) );
+    // ***
+    ttp.push( TextToPrint::new("@".to_string(),Rgb(draw_conf.palette.highlight)) );
+    ttp.push( TextToPrint::new(action_position.to_string(),Rgb(draw_conf.palette.symbol)) );
+    ttp
+}
+
 pub fn draw_firing(path : &Path,
                    gen_ctx : &GeneralContext,
+                   draw_conf : &DrawingConfiguration,
                    action_position : &Position,
                    executed_action : &TraceAction,
                           is_simulated : bool) {
-    let mut text_lines : Vec<Vec<TextToPrint>> = Vec::new();
-    {
-        let mut ttp: Vec<TextToPrint> = Vec::new();
-        if is_simulated {
-            ttp.push( TextToPrint::new("SIMU ".to_string(),Rgb(HCP_LightGray)) );
-        }
-        ttp.append( &mut diagram_repr_trace_action(executed_action,gen_ctx) );
-        ttp.push( TextToPrint::new(" ".to_string(),Rgb(HCP_Black)) );
-        // ***
-        ttp.push( TextToPrint::new("@".to_string(),Rgb(HCP_StandardPurple)) );
-        ttp.push( TextToPrint::new(action_position.to_string(),Rgb(HCP_Black)) );
-        text_lines.push( ttp );
-    }
+    let text_lines : Vec<Vec<TextToPrint>> = vec![firing_as_colored_text(gen_ctx,draw_conf,action_position,executed_action,is_simulated)];
     // ***
     new_image_with_colored_text(path,
+                                draw_conf,
                                 &MultiLineTextAlignment::Center,
                                 &text_lines);
-}
-
-
-
+}
\ No newline at end of file
@@ -21,7 +21,7 @@ use image::Rgb;
 use image_colored_text::draw::multi_line::MultiLineTextAlignment;
 use image_colored_text::ttp::TextToPrint;
 
-use crate::io::output::draw_commons::hibou_color_palette::*;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
 use crate::io::output::draw_commons::make_image_of_text::new_image_with_colored_text;
 
 
@@ -30,6 +30,7 @@ use crate::io::output::draw_commons::make_image_of_text::new_image_with_colored_
 
 
 pub fn draw_string_label(path : &Path,
+                         draw_conf : &DrawingConfiguration,
                          string_label : String) {
     // ***
     let mut text_lines : Vec<Vec<TextToPrint>> = Vec::new();
@@ -37,11 +38,12 @@ pub fn draw_string_label(path : &Path,
     {
         let mut ttp = Vec::new();
         // ***
-        ttp.push( TextToPrint::new(string_label,Rgb(HCP_Black)) );
+        ttp.push( TextToPrint::new(string_label,Rgb(draw_conf.palette.symbol)) );
         text_lines.push( ttp );
     }
     // ***
     new_image_with_colored_text(path,
+                                draw_conf,
                                 &MultiLineTextAlignment::Center,
                                 &text_lines);
 }
@@ -0,0 +1,129 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use image::{Rgb, RgbImage};
+use image::imageops::overlay;
+use image_colored_text::draw::multi_line::MultiLineTextAlignment;
+use image_colored_text::ttp::TextToPrint;
+use imageproc::drawing::draw_filled_rect_mut;
+use imageproc::rect::Rect;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::semantics::position::Position;
+use crate::core::execution::trace::flags::MultiTraceAnalysisFlags;
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::coverage::model::InteractionCoverageModel;
+use crate::coverage::step::get_leaves_at_position;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
+use crate::io::output::draw_commons::make_image_of_text::make_image_of_colored_text;
+use crate::io::output::draw_interactions::interface::interaction_as_image_with_actions_colors;
+use crate::io::output::draw_traces::implem::ext_mu::extract_texts_on_multi_trace;
+
+
+/**
+Draws the interaction with the actions designated by the position (if any) in the highlight color.
+**/
+pub fn make_interaction_image_with_fired_position(gen_ctx : &GeneralContext,
+                                                  draw_conf : &DrawingConfiguration,
+                                                  interaction : &Interaction,
+                                                  fired_position : Option<&Position>) -> RgbImage {
+    let mut actions_colors = vec![];
+    if let Some(position) = fired_position {
+        // the coverage model numbers the action leaves in the same order as the drawing
+        let model = InteractionCoverageModel::new(gen_ctx,interaction);
+        actions_colors = vec![Rgb(draw_conf.palette.symbol);model.leaves_num];
+        for leaf_id in get_leaves_at_position(&model,&model.tagged_interaction,position) {
+            actions_colors[leaf_id] = Rgb(draw_conf.palette.highlight);
+        }
+    }
+    interaction_as_image_with_actions_colors(gen_ctx,draw_conf,interaction,&actions_colors)
+}
+
+/**
+Composes a frame of the animation : a caption, the interaction and the state of the multi-trace, from top to bottom.
+**/
+pub fn make_frame(gen_ctx : &GeneralContext,
+                  draw_conf : &DrawingConfiguration,
+                  caption : &Vec<TextToPrint>,
+                  interaction : &Interaction,
+                  fired_position : Option<&Position>,
+                  co_localizations : &CoLocalizations,
+                  multi_trace : &MultiTrace,
+                  flags : &MultiTraceAnalysisFlags) -> RgbImage {
+    let caption_image = make_image_of_colored_text(draw_conf,
+                                                   &MultiLineTextAlignment::Center,
+                                                   &vec![caption.clone()]);
+    let mu_texts = extract_texts_on_multi_trace(gen_ctx,
+                                                draw_conf,
+                                                co_localizations,
+                                                multi_trace,
+                                                flags,
+                                                false,
+                                                false,
+                                                false);
+    let mu_image = make_image_of_colored_text(draw_conf,
+                                              &MultiLineTextAlignment::Left,
+                                              &mu_texts);
+    if interaction == &Interaction::Empty {
+        stack_images_vertically(draw_conf, &vec![caption_image, mu_image])
+    } else {
+        let int_image = make_interaction_image_with_fired_position(gen_ctx,draw_conf,interaction,fired_position);
+        stack_images_vertically(draw_conf, &vec![caption_image, int_image, mu_image])
+    }
+}
+
+fn stack_images_vertically(draw_conf : &DrawingConfiguration,
+                           images : &Vec<RgbImage>) -> RgbImage {
+    let width = images.iter().map(|x| x.width()).max().unwrap_or(1);
+    let height : u32 = images.iter().map(|x| x.height()).sum();
+    let mut stacked = new_background_image(draw_conf, width, height);
+    let mut y_shift : u32 = 0;
+    for image in images {
+        overlay(&mut stacked, image, ((width - image.width())/2) as i64, y_shift as i64);
+        y_shift += image.height();
+    }
+    stacked
+}
+
+/**
+Places each frame at the top center of a background of the size of the largest frame,
+so that all the frames of the animation have the same dimensions.
+**/
+pub fn pad_frames_to_same_size(draw_conf : &DrawingConfiguration,
+                               frames : Vec<RgbImage>) -> Vec<RgbImage> {
+    let width = frames.iter().map(|x| x.width()).max().unwrap_or(1);
+    let height = frames.iter().map(|x| x.height()).max().unwrap_or(1);
+    let mut padded = vec![];
+    for frame in frames {
+        let mut background = new_background_image(draw_conf, width, height);
+        overlay(&mut background, &frame, ((width - frame.width())/2) as i64, 0);
+        padded.push(background);
+    }
+    padded
+}
+
+fn new_background_image(draw_conf : &DrawingConfiguration,
+                        width : u32,
+                        height : u32) -> RgbImage {
+    let mut image = RgbImage::new(width, height);
+    draw_filled_rect_mut(&mut image,
+                         Rect::at(0,0).of_size(width,height),
+                         Rgb(draw_conf.palette.background));
+    image
+}
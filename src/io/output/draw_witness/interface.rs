@@ -0,0 +1,180 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fs;
+use std::fs::File;
+use std::path::PathBuf;
+
+use image::{DynamicImage, Delay, Frame, Rgb};
+use image::codecs::gif::{GifEncoder, Repeat};
+use image_colored_text::ttp::TextToPrint;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::semantics::execute::execute_interaction;
+use crate::core::execution::trace::flags::MultiTraceAnalysisFlags;
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::execution::witness::witness::ExecutionWitness;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
+use crate::io::output::draw_transitions::draw_firing::firing_as_colored_text;
+use crate::io::output::draw_witness::frames::{make_frame, pad_frames_to_same_size};
+
+
+/**
+Renders the execution path described by a witness as a sequence of numbered PNG images
+('{output_file_name}_000.png', ...) and as an animated GIF ('{output_file_name}.gif').
+The first image shows the initial interaction and multi-trace.
+Each of the following images shows the interaction before a step, with the fired action highlighted,
+and the multi-trace that remains to be consumed after it.
+The last image shows the residual interaction.
+The witness is expected to have been replayed successfully beforehand.
+Returns the number of images.
+**/
+pub fn draw_witness_animation(gen_ctx : &GeneralContext,
+                              draw_conf : &DrawingConfiguration,
+                              co_localizations : &CoLocalizations,
+                              multi_trace : &MultiTrace,
+                              interaction : &Interaction,
+                              witness : &ExecutionWitness,
+                              frame_delay_ms : u32,
+                              parent_folder : &String,
+                              output_file_name : &String) -> usize {
+    let mut frames = vec![];
+    let mut current_int = interaction.clone();
+    let mut flags = MultiTraceAnalysisFlags::new_init(multi_trace.len(),0,0);
+    // ***
+    frames.push( make_frame(gen_ctx,
+                            draw_conf,
+                            &vec![TextToPrint::new("initial state".to_string(),Rgb(draw_conf.palette.symbol))],
+                            &current_int,
+                            None,
+                            co_localizations,
+                            multi_trace,
+                            &flags) );
+    for (step_id,step) in witness.iter().enumerate() {
+        let canal_id = co_localizations.get_lf_coloc_id(step.action.lf_id).unwrap();
+        flags.canals.get_mut(canal_id).unwrap().consumed += 1;
+        // ***
+        let mut caption = vec![TextToPrint::new(format!("step {:}/{:} : ", step_id + 1, witness.len()),Rgb(draw_conf.palette.symbol))];
+        caption.append( &mut firing_as_colored_text(gen_ctx,draw_conf,&step.position,&step.action,false) );
+        frames.push( make_frame(gen_ctx,
+                                draw_conf,
+                                &caption,
+                                &current_int,
+                                Some(&step.position),
+                                co_localizations,
+                                multi_trace,
+                                &flags) );
+        current_int = execute_interaction(&current_int,&step.position,&step.action,gen_ctx);
+    }
+    frames.push( make_frame(gen_ctx,
+                            draw_conf,
+                            &vec![TextToPrint::new("final state".to_string(),Rgb(draw_conf.palette.symbol))],
+                            &current_int,
+                            None,
+                            co_localizations,
+                            multi_trace,
+                            &flags) );
+    // ***
+    if !parent_folder.is_empty() {
+        fs::create_dir_all(parent_folder).unwrap();
+    }
+    let frames = pad_frames_to_same_size(draw_conf, frames);
+    let frames_num = frames.len();
+    let gif_path : PathBuf = [parent_folder, &format!("{:}.gif", output_file_name)].iter().collect();
+    let mut encoder = GifEncoder::new_with_speed(File::create(gif_path.as_path()).unwrap(), 10);
+    encoder.set_repeat(Repeat::Infinite).unwrap();
+    for (frame_id,frame) in frames.into_iter().enumerate() {
+        let png_path : PathBuf = [parent_folder, &format!("{:}_{:03}.png", output_file_name, frame_id)].iter().collect();
+        frame.save(png_path.as_path()).unwrap();
+        let rgba = DynamicImage::ImageRgb8(frame).into_rgba8();
+        encoder.encode_frame( Frame::from_parts(rgba, 0, 0, Delay::from_numer_denom_ms(frame_delay_ms, 1)) ).unwrap();
+    }
+    frames_num
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::core::execution::trace::multitrace::MultiTrace;
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::BroadcastPrimitive;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+    use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
+    use crate::process::ana::interface::make_analysis_manager;
+    use crate::process::ana::loggers::wlog::logger::AnalysisWitnessLogger;
+    use super::draw_witness_animation;
+
+    #[test]
+    fn two_steps_animation_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        let co_localizations = CoLocalizations::get_discrete_partition(2);
+        // l1 -- m1 -> l2
+        let int = Interaction::Action(BroadcastPrimitive::new(Some(0),MessageTypeExpression::Singleton(0),vec![1]));
+        let multi_trace : MultiTrace = vec![vec![TraceAction::new(0,TraceActionKind::Emission,MessageTypeExpression::Singleton(0))],
+                                            vec![TraceAction::new(1,TraceActionKind::Reception,MessageTypeExpression::Singleton(0))]];
+        let mut ana_opts = HibouAnalyzeOptions::default();
+        ana_opts.loggers.push( Box::new(AnalysisWitnessLogger::new()) );
+        let (mut analysis_manager,init_node) = make_analysis_manager(&gen_ctx,
+                                                                     &co_localizations,
+                                                                     &multi_trace,
+                                                                     &int,
+                                                                     ana_opts);
+        analysis_manager.start_process(init_node);
+        let witness = analysis_manager.get_logger(0).unwrap()
+            .as_any().downcast_ref::<AnalysisWitnessLogger>().unwrap()
+            .witness.clone().unwrap();
+        assert_eq!(witness.len(),2);
+        // ***
+        let parent_folder = std::env::temp_dir().join(format!("hibou_animate_test_{:}", std::process::id()))
+            .to_str().unwrap().to_string();
+        let frames_num = draw_witness_animation(&gen_ctx,
+                                                &DrawingConfiguration::default(),
+                                                &co_localizations,
+                                                &multi_trace,
+                                                &int,
+                                                &witness,
+                                                100,
+                                                &parent_folder,
+                                                &"anim".to_string());
+        // the initial state, one image per step and the final state
+        assert_eq!(frames_num,4);
+        let mut dimensions = vec![];
+        for frame_id in 0..frames_num {
+            let png_path : PathBuf = [&parent_folder, &format!("anim_{:03}.png", frame_id)].iter().collect();
+            dimensions.push( image::open(png_path).unwrap().to_rgb8().dimensions() );
+        }
+        assert!(dimensions.iter().all(|dims| dims == dimensions.first().unwrap()));
+        let gif_path : PathBuf = [&parent_folder, &"anim.gif".to_string()].iter().collect();
+        assert!(gif_path.exists());
+        fs::remove_dir_all(&parent_folder).unwrap();
+    }
+
+}
@@ -15,13 +15,16 @@ limitations under the License.
 */
 
 
-mod draw_commons;
+pub mod draw_commons;
 
 pub mod draw_messages;
 pub mod draw_interactions;
 pub mod draw_traces;
 pub mod draw_transitions;
+pub mod draw_witness;
+pub mod draw_automata;
 
 pub mod to_hfiles;
+pub mod to_json;
 
 
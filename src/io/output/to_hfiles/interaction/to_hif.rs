@@ -0,0 +1,220 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use crate::core::execution::trace::trace::TraceActionKind;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::action::BroadcastPrimitive;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::core::message::MessageTypeExpression;
+use crate::io::file_extensions::is_json_file_path;
+use crate::io::output::to_json::interaction::interaction_as_json_encoding;
+use crate::io::textual_convention::{SYNTAX_ALT, SYNTAX_EMISSION, SYNTAX_LOOP_C, SYNTAX_LOOP_P, SYNTAX_LOOP_W, SYNTAX_PAR, SYNTAX_RECEPTION, SYNTAX_SEQ, SYNTAX_SYNC};
+
+
+/**
+The interaction is written in JSON if the file has a '.json' extension and in the .hif syntax otherwise.
+**/
+pub fn write_interaction_into_file(file_path : &Path,
+                                   gen_ctx : &GeneralContext,
+                                   interaction : &Interaction) {
+    let mut file = File::create(file_path).unwrap();
+    if is_json_file_path(file_path) {
+        file.write(interaction_as_json_encoding(gen_ctx,interaction).as_bytes() );
+    } else {
+        file.write(interaction_as_hif_encoding(gen_ctx,interaction).as_bytes() );
+    }
+}
+
+
+/**
+Encodes an interaction in the .hif syntax.
+Right-nested binary operators are re-folded into n-ary ones (which the parser folds back to the right)
+and co-regions are written as 'seq', 'par' or 'coreg' and loops as 'loopW', 'loopP' or 'loopC' accordingly.
+Composite messages (e.g. intersections resulting from the execution of 'sync' operators)
+are resolved into the declared message or message type they denote
+or, if there is none, into the set of the messages they contain.
+Composite messages containing no message are written as the empty set '{}'.
+**/
+pub fn interaction_as_hif_encoding(gen_ctx : &GeneralContext,
+                                   interaction : &Interaction) -> String {
+    interaction_as_hif_encoding_rec(gen_ctx,interaction,0)
+}
+
+
+fn interaction_as_hif_encoding_rec(gen_ctx : &GeneralContext,
+                                   interaction : &Interaction,
+                                   depth : usize) -> String {
+    match interaction {
+        Interaction::Empty => {
+            "o".to_string()
+        },
+        Interaction::Action(bp) => {
+            action_as_hif_encoding(gen_ctx,bp)
+        },
+        Interaction::CoReg(cr,_,_) => {
+            let mut sub_ints = vec![];
+            let mut rem = interaction;
+            while let Interaction::CoReg(got_cr,i1,i2) = rem {
+                if got_cr != cr {
+                    break;
+                }
+                sub_ints.push(&**i1);
+                rem = i2;
+            }
+            sub_ints.push(rem);
+            let operator = if cr.is_empty() {
+                SYNTAX_SEQ.to_string()
+            } else if is_full_coreg(gen_ctx,cr) {
+                SYNTAX_PAR.to_string()
+            } else {
+                format!("coreg({:})", lifelines_as_hif_encoding(gen_ctx,cr))
+            };
+            nary_as_hif_encoding(gen_ctx,&operator,&sub_ints,depth)
+        },
+        Interaction::Alt(_,_) => {
+            let mut sub_ints = vec![];
+            let mut rem = interaction;
+            while let Interaction::Alt(i1,i2) = rem {
+                sub_ints.push(&**i1);
+                rem = i2;
+            }
+            sub_ints.push(rem);
+            nary_as_hif_encoding(gen_ctx,SYNTAX_ALT,&sub_ints,depth)
+        },
+        Interaction::Sync(sync_acts,_,_) => {
+            let mut sub_ints = vec![];
+            let mut rem = interaction;
+            while let Interaction::Sync(got_sync_acts,i1,i2) = rem {
+                if got_sync_acts != sync_acts {
+                    break;
+                }
+                sub_ints.push(&**i1);
+                rem = i2;
+            }
+            sub_ints.push(rem);
+            let operator = format!("{:}{:}", SYNTAX_SYNC, sync_actions_as_hif_encoding(gen_ctx,sync_acts));
+            nary_as_hif_encoding(gen_ctx,&operator,&sub_ints,depth)
+        },
+        Interaction::Loop(cr,i1) => {
+            let operator = if cr.is_empty() {
+                SYNTAX_LOOP_W.to_string()
+            } else if is_full_coreg(gen_ctx,cr) {
+                SYNTAX_LOOP_P.to_string()
+            } else {
+                format!("{:}({:})", SYNTAX_LOOP_C, lifelines_as_hif_encoding(gen_ctx,cr))
+            };
+            nary_as_hif_encoding(gen_ctx,&operator,&vec![&**i1],depth)
+        }
+    }
+}
+
+fn nary_as_hif_encoding(gen_ctx : &GeneralContext,
+                        operator : &str,
+                        sub_ints : &Vec<&Interaction>,
+                        depth : usize) -> String {
+    let indent = "\t".repeat(depth + 1);
+    let sub_ints_encodings : Vec<String> = sub_ints.iter()
+        .map(|sub_int| format!("{:}{:}", indent, interaction_as_hif_encoding_rec(gen_ctx,sub_int,depth + 1)))
+        .collect();
+    format!("{:}(\n{:}\n{:})", operator, sub_ints_encodings.join(",\n"), "\t".repeat(depth))
+}
+
+/**
+The co-region of 'par' and 'loopP' contains all the lifelines in increasing order.
+**/
+pub(crate) fn is_full_coreg(gen_ctx : &GeneralContext, cr : &Vec<usize>) -> bool {
+    *cr == (0..gen_ctx.get_lf_num()).collect::<Vec<usize>>()
+}
+
+fn lifelines_as_hif_encoding(gen_ctx : &GeneralContext, lf_ids : &Vec<usize>) -> String {
+    let names : Vec<String> = lf_ids.iter().map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap()).collect();
+    names.join(",")
+}
+
+fn message_as_hif_encoding(gen_ctx : &GeneralContext, message : &MessageTypeExpression) -> String {
+    match message {
+        MessageTypeExpression::Singleton(ms_id) => {
+            gen_ctx.get_ms_name(*ms_id).unwrap()
+        },
+        MessageTypeExpression::NamedType(mt_id) => {
+            gen_ctx.get_mt_name(*mt_id).unwrap()
+        },
+        _ => {
+            let resolved = message.resolve(gen_ctx);
+            if resolved.len() == 1 {
+                return gen_ctx.get_ms_name(*resolved.iter().next().unwrap()).unwrap();
+            }
+            for mt_id in 0..gen_ctx.get_mt_num() {
+                if gen_ctx.get_mt_messages(mt_id).unwrap() == resolved {
+                    return gen_ctx.get_mt_name(mt_id).unwrap();
+                }
+            }
+            let mut ms_ids : Vec<usize> = resolved.into_iter().collect();
+            ms_ids.sort();
+            let names : Vec<String> = ms_ids.iter().map(|ms_id| gen_ctx.get_ms_name(*ms_id).unwrap()).collect();
+            format!("{{{:}}}", names.join(","))
+        }
+    }
+}
+
+fn action_as_hif_encoding(gen_ctx : &GeneralContext, bp : &BroadcastPrimitive) -> String {
+    let ms_repr = message_as_hif_encoding(gen_ctx,&bp.message_type);
+    let targets_repr = match bp.targets.len() {
+        0 => {
+            "|".to_string()
+        },
+        1 => {
+            gen_ctx.get_lf_name(*bp.targets.get(0).unwrap()).unwrap()
+        },
+        _ => {
+            format!("({:})", lifelines_as_hif_encoding(gen_ctx,&bp.targets))
+        }
+    };
+    match bp.origin_on_emission {
+        None => {
+            format!("{:} -> {:}", ms_repr, targets_repr)
+        },
+        Some( origin ) => {
+            format!("{:} -- {:} -> {:}", gen_ctx.get_lf_name(origin).unwrap(), ms_repr, targets_repr)
+        }
+    }
+}
+
+fn sync_actions_as_hif_encoding(gen_ctx : &GeneralContext,
+                                sync_acts : &BTreeMap<(usize,TraceActionKind),MessageTypeExpression>) -> String {
+    let mut acts_encodings = vec![];
+    for ((lf_id,act_kind),message) in sync_acts {
+        let kind_repr = match act_kind {
+            TraceActionKind::Emission => SYNTAX_EMISSION,
+            TraceActionKind::Reception => SYNTAX_RECEPTION
+        };
+        acts_encodings.push( format!("{:}{:}{:}",
+                                     gen_ctx.get_lf_name(*lf_id).unwrap(),
+                                     kind_repr,
+                                     message_as_hif_encoding(gen_ctx,message)) );
+    }
+    if acts_encodings.len() == 1 {
+        format!("({:})", acts_encodings.first().unwrap())
+    } else {
+        format!("{{{:}}}", acts_encodings.join(","))
+    }
+}
@@ -16,3 +16,9 @@ limitations under the License.
 
 
 pub mod trace;
+
+pub mod witness;
+
+pub mod interaction;
+
+pub mod signature;
@@ -0,0 +1,113 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use crate::core::general_context::GeneralContext;
+use crate::io::file_extensions::is_json_file_path;
+use crate::io::output::to_json::signature::signature_as_json_encoding;
+
+
+/**
+Encodes a signature in the .hsf syntax.
+Sections without declarations are omitted given that the syntax requires at least one declaration per section
+and the '@message' section is written before the '@type' section which refers to it.
+**/
+pub fn signature_as_hsf_encoding(gen_ctx : &GeneralContext) -> String {
+    let mut sections = vec![];
+    if gen_ctx.get_lf_num() > 0 {
+        let lf_names : Vec<String> = (0..gen_ctx.get_lf_num()).map(|lf_id| gen_ctx.get_lf_name(lf_id).unwrap()).collect();
+        sections.push( format!("@lifeline{{\n\t{:}\n}}", lf_names.join(";\n\t")) );
+    }
+    if gen_ctx.get_ms_num() > 0 {
+        let ms_names : Vec<String> = (0..gen_ctx.get_ms_num()).map(|ms_id| gen_ctx.get_ms_name(ms_id).unwrap()).collect();
+        sections.push( format!("@message{{\n\t{:}\n}}", ms_names.join(";\n\t")) );
+    }
+    let mut types_encodings = vec![];
+    for mt_id in 0..gen_ctx.get_mt_num() {
+        let mut ms_ids : Vec<usize> = gen_ctx.get_mt_messages(mt_id).unwrap().into_iter().collect();
+        if ms_ids.is_empty() {
+            // a type without messages cannot be declared
+            continue;
+        }
+        ms_ids.sort();
+        let ms_names : Vec<String> = ms_ids.iter().map(|ms_id| gen_ctx.get_ms_name(*ms_id).unwrap()).collect();
+        types_encodings.push( format!("{:} = {{{:}}}", gen_ctx.get_mt_name(mt_id).unwrap(), ms_names.join(",")) );
+    }
+    if !types_encodings.is_empty() {
+        sections.push( format!("@type{{\n\t{:}\n}}", types_encodings.join(";\n\t")) );
+    }
+    sections.join("\n")
+}
+
+/**
+The signature is written in JSON if the file has a '.json' extension and in the .hsf syntax otherwise.
+**/
+pub fn write_signature_into_file(file_path : &Path,
+                                 gen_ctx : &GeneralContext) {
+    let mut file = File::create(file_path).unwrap();
+    if is_json_file_path(file_path) {
+        file.write(signature_as_json_encoding(gen_ctx).as_bytes() );
+    } else {
+        file.write(signature_as_hsf_encoding(gen_ctx).as_bytes() );
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::general_context::{GeneralContext, merge_signatures};
+    use crate::io::input::hsf::implem::parse_hsf_string;
+    use super::signature_as_hsf_encoding;
+
+    #[test]
+    fn merged_signature_round_trip_test() {
+        let mut sig1 = GeneralContext::new();
+        sig1.add_lf("l1".to_string());
+        sig1.add_msg("m1".to_string());
+        sig1.add_msg("m2".to_string());
+        sig1.add_mt("A".to_string(),hashset!{0,1});
+        let mut sig2 = GeneralContext::new();
+        sig2.add_lf("l2".to_string());
+        sig2.add_msg("m3".to_string());
+        sig2.add_msg("m2".to_string());
+        sig2.add_mt("B".to_string(),hashset!{0,1});
+        let (merged,conflicts) = merge_signatures(&vec![sig1,sig2]);
+        assert!(conflicts.is_empty());
+        // ***
+        let parsed = parse_hsf_string(signature_as_hsf_encoding(&merged)).unwrap();
+        assert_eq!(parsed.get_lf_num(),merged.get_lf_num());
+        for lf_id in 0..merged.get_lf_num() {
+            assert_eq!(parsed.get_lf_name(lf_id).unwrap(),merged.get_lf_name(lf_id).unwrap());
+        }
+        assert_eq!(parsed.get_ms_num(),merged.get_ms_num());
+        for ms_id in 0..merged.get_ms_num() {
+            assert_eq!(parsed.get_ms_name(ms_id).unwrap(),merged.get_ms_name(ms_id).unwrap());
+        }
+        assert_eq!(parsed.get_mt_num(),2);
+        for mt_id in 0..merged.get_mt_num() {
+            assert_eq!(parsed.get_mt_name(mt_id).unwrap(),merged.get_mt_name(mt_id).unwrap());
+            assert_eq!(parsed.get_mt_messages(mt_id).unwrap(),merged.get_mt_messages(mt_id).unwrap());
+        }
+        // the messages of the second type are those of the merged signature
+        assert_eq!(parsed.get_mt_messages(1).unwrap(),hashset!{1,2});
+    }
+}
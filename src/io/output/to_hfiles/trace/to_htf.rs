@@ -25,16 +25,41 @@ use crate::core::execution::trace::multitrace::MultiTrace;
 use crate::core::general_context::GeneralContext;
 
 
+use crate::io::file_extensions::is_json_file_path;
 use crate::io::output::to_hfiles::trace::multi_trace::multi_trace_as_htf_encoding;
+use crate::io::output::to_json::multi_trace::multi_trace_as_json_encoding;
 
 
+/**
+The multi-trace is written in JSON if the file has a '.json' extension and in the .htf syntax otherwise.
+**/
 pub fn write_multi_trace_into_file(file_path : &Path,
                                    gen_ctx : &GeneralContext,
                                    co_localizations : &CoLocalizations,
                                    multi_trace : &MultiTrace) {
     let mut file = File::create(file_path).unwrap();
-    file.write(multi_trace_as_htf_encoding(gen_ctx,co_localizations,multi_trace).as_bytes() );
+    if is_json_file_path(file_path) {
+        file.write(multi_trace_as_json_encoding(gen_ctx,co_localizations,multi_trace,None).as_bytes() );
+    } else {
+        file.write(multi_trace_as_htf_encoding(gen_ctx,co_localizations,multi_trace).as_bytes() );
+    }
 }
 
 
-
+/**
+Writes the multi-trace preceded by a comment, e.g. to record how it was generated
+(in JSON, the comment is given as a field of the multi-trace object).
+**/
+pub fn write_multi_trace_into_file_with_comment(file_path : &Path,
+                                                gen_ctx : &GeneralContext,
+                                                co_localizations : &CoLocalizations,
+                                                multi_trace : &MultiTrace,
+                                                comment : &str) {
+    let mut file = File::create(file_path).unwrap();
+    if is_json_file_path(file_path) {
+        file.write(multi_trace_as_json_encoding(gen_ctx,co_localizations,multi_trace,Some(comment)).as_bytes() );
+    } else {
+        file.write(format!("/* {:} */\n",comment).as_bytes() );
+        file.write(multi_trace_as_htf_encoding(gen_ctx,co_localizations,multi_trace).as_bytes() );
+    }
+}
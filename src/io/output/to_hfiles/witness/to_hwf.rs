@@ -0,0 +1,53 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use crate::core::execution::semantics::position::position_as_text;
+use crate::core::execution::witness::witness::ExecutionWitness;
+use crate::core::general_context::GeneralContext;
+use crate::io::file_extensions::is_json_file_path;
+use crate::io::output::to_hfiles::trace::trace_action::trace_action_as_htf_encoding;
+use crate::io::output::to_json::witness::witness_as_json_encoding;
+
+
+pub fn witness_as_hwf_encoding(gen_ctx : &GeneralContext,
+                               witness : &ExecutionWitness) -> String {
+    let mut steps_strings : Vec<String> = vec![];
+    for step in witness {
+        let pos_string = position_as_text(&step.position);
+        steps_strings.push( format!("{:} : {:}", pos_string, trace_action_as_htf_encoding(gen_ctx, &step.action)) );
+    }
+    // ***
+    return format!( "[\n{:}\n]", steps_strings.join(";\n"));
+}
+
+/**
+The witness is written in JSON if the file has a '.json' extension and in the .hwf syntax otherwise.
+**/
+pub fn write_witness_into_file(file_path : &Path,
+                               gen_ctx : &GeneralContext,
+                               witness : &ExecutionWitness) {
+    let mut file = File::create(file_path).unwrap();
+    if is_json_file_path(file_path) {
+        file.write(witness_as_json_encoding(gen_ctx,witness).as_bytes() );
+    } else {
+        file.write(witness_as_hwf_encoding(gen_ctx,witness).as_bytes() );
+    }
+}
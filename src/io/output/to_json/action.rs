@@ -0,0 +1,78 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use crate::core::general_context::GeneralContext;
+use crate::core::message::MessageTypeExpression;
+use crate::io::output::to_json::string::to_json_string;
+
+
+/**
+Declared messages and message types are encoded by their names (a name is resolved first as a message and then as a type)
+while composite message types are encoded as objects with a single "union", "intersection" or "setminus" field
+holding their two operands.
+**/
+pub fn message_type_as_json_encoding(gen_ctx : &GeneralContext,
+                                     message : &MessageTypeExpression) -> String {
+    match message {
+        MessageTypeExpression::Singleton(ms_id) => {
+            to_json_string(&gen_ctx.get_ms_name(*ms_id).unwrap())
+        },
+        MessageTypeExpression::NamedType(mt_id) => {
+            to_json_string(&gen_ctx.get_mt_name(*mt_id).unwrap())
+        },
+        MessageTypeExpression::Union(mte1,mte2) => {
+            binary_message_type_as_json_encoding(gen_ctx,"union",mte1,mte2)
+        },
+        MessageTypeExpression::Intersection(mte1,mte2) => {
+            binary_message_type_as_json_encoding(gen_ctx,"intersection",mte1,mte2)
+        },
+        MessageTypeExpression::SetMinus(mte1,mte2) => {
+            binary_message_type_as_json_encoding(gen_ctx,"setminus",mte1,mte2)
+        }
+    }
+}
+
+fn binary_message_type_as_json_encoding(gen_ctx : &GeneralContext,
+                                        operator : &str,
+                                        mte1 : &MessageTypeExpression,
+                                        mte2 : &MessageTypeExpression) -> String {
+    format!("{{\"{:}\": [{:}, {:}]}}",
+            operator,
+            message_type_as_json_encoding(gen_ctx,mte1),
+            message_type_as_json_encoding(gen_ctx,mte2))
+}
+
+pub fn trace_action_kind_as_json_encoding(act_kind : &TraceActionKind) -> String {
+    match act_kind {
+        TraceActionKind::Emission => {
+            to_json_string("emission")
+        },
+        TraceActionKind::Reception => {
+            to_json_string("reception")
+        }
+    }
+}
+
+pub fn trace_action_as_json_encoding(gen_ctx : &GeneralContext,
+                                     action : &TraceAction) -> String {
+    format!("{{\"lifeline\": {:}, \"kind\": {:}, \"message\": {:}}}",
+            to_json_string(&gen_ctx.get_lf_name(action.lf_id).unwrap()),
+            trace_action_kind_as_json_encoding(&action.act_kind),
+            message_type_as_json_encoding(gen_ctx,&action.message))
+}
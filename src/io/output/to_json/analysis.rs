@@ -0,0 +1,77 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use crate::core::execution::witness::witness::ExecutionWitness;
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_json::string::to_json_string;
+use crate::io::output::to_json::witness::witness_as_json_encoding;
+
+
+/**
+Encodes the results of an offline analysis as a JSON object,
+i.e. the input files, the length of the analyzed multi-trace, the verdict,
+the number of nodes explored, the elapsed time in seconds and the witness if one was searched for.
+**/
+pub fn analysis_results_as_json_encoding(gen_ctx : &GeneralContext,
+                                         input_files : &Vec<(&str,&str)>,
+                                         multi_trace_length : usize,
+                                         verdict : &str,
+                                         node_count : u32,
+                                         elapsed : f64,
+                                         witness : Option<&ExecutionWitness>) -> String {
+    let inputs : Vec<String> = input_files.iter()
+        .map(|(file_kind,file_path)| format!("{:}: {:}", to_json_string(file_kind), to_json_string(file_path)))
+        .collect();
+    let witness_repr = match witness {
+        None => {
+            "null".to_string()
+        },
+        Some( got_witness ) => {
+            witness_as_json_encoding(gen_ctx,got_witness).replace("\n","\n  ")
+        }
+    };
+    format!("{{\n  \"inputs\": {{{:}}},\n  \"length\": {:},\n  \"verdict\": {:},\n  \"node_count\": {:},\n  \"elapsed\": {:?},\n  \"witness\": {:}\n}}",
+            inputs.join(", "),
+            multi_trace_length,
+            to_json_string(verdict),
+            node_count,
+            elapsed,
+            witness_repr)
+}
+
+pub fn write_analysis_results_into_file(file_path : &Path,
+                                        gen_ctx : &GeneralContext,
+                                        input_files : &Vec<(&str,&str)>,
+                                        multi_trace_length : usize,
+                                        verdict : &str,
+                                        node_count : u32,
+                                        elapsed : f64,
+                                        witness : Option<&ExecutionWitness>) {
+    let mut file = File::create(file_path).unwrap();
+    file.write(analysis_results_as_json_encoding(gen_ctx,
+                                                 input_files,
+                                                 multi_trace_length,
+                                                 verdict,
+                                                 node_count,
+                                                 elapsed,
+                                                 witness).as_bytes() );
+}
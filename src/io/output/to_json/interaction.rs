@@ -0,0 +1,160 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeMap;
+
+use crate::core::execution::trace::trace::TraceActionKind;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::action::BroadcastPrimitive;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::core::message::MessageTypeExpression;
+use crate::io::output::to_hfiles::interaction::to_hif::is_full_coreg;
+use crate::io::output::to_json::action::{message_type_as_json_encoding, trace_action_kind_as_json_encoding};
+use crate::io::output::to_json::string::to_json_string;
+use crate::io::textual_convention::{SYNTAX_ALT, SYNTAX_LOOP_C, SYNTAX_LOOP_P, SYNTAX_LOOP_W, SYNTAX_PAR, SYNTAX_SEQ, SYNTAX_SYNC};
+
+
+/**
+Encodes an interaction as a JSON term whose nodes are objects with a "kind" field.
+As in the .hif syntax, right-nested binary operators are re-folded into n-ary ones with an "operands" field,
+co-regions are given as 'seq', 'par' or 'coreg' (with its "lifelines")
+and loops as 'loopW', 'loopP' or 'loopC' (with its "lifelines") with a single operand.
+**/
+pub fn interaction_as_json_encoding(gen_ctx : &GeneralContext,
+                                    interaction : &Interaction) -> String {
+    interaction_as_json_encoding_rec(gen_ctx,interaction,0)
+}
+
+
+fn interaction_as_json_encoding_rec(gen_ctx : &GeneralContext,
+                                    interaction : &Interaction,
+                                    depth : usize) -> String {
+    match interaction {
+        Interaction::Empty => {
+            format!("{{\"kind\": {:}}}", to_json_string("empty"))
+        },
+        Interaction::Action(bp) => {
+            action_as_json_encoding(gen_ctx,bp)
+        },
+        Interaction::CoReg(cr,_,_) => {
+            let mut sub_ints = vec![];
+            let mut rem = interaction;
+            while let Interaction::CoReg(got_cr,i1,i2) = rem {
+                if got_cr != cr {
+                    break;
+                }
+                sub_ints.push(&**i1);
+                rem = i2;
+            }
+            sub_ints.push(rem);
+            let fields = if cr.is_empty() {
+                vec![("kind",to_json_string(SYNTAX_SEQ))]
+            } else if is_full_coreg(gen_ctx,cr) {
+                vec![("kind",to_json_string(SYNTAX_PAR))]
+            } else {
+                vec![("kind",to_json_string("coreg")),
+                     ("lifelines",lifelines_as_json_encoding(gen_ctx,cr))]
+            };
+            nary_as_json_encoding(gen_ctx,fields,&sub_ints,depth)
+        },
+        Interaction::Alt(_,_) => {
+            let mut sub_ints = vec![];
+            let mut rem = interaction;
+            while let Interaction::Alt(i1,i2) = rem {
+                sub_ints.push(&**i1);
+                rem = i2;
+            }
+            sub_ints.push(rem);
+            nary_as_json_encoding(gen_ctx,vec![("kind",to_json_string(SYNTAX_ALT))],&sub_ints,depth)
+        },
+        Interaction::Sync(sync_acts,_,_) => {
+            let mut sub_ints = vec![];
+            let mut rem = interaction;
+            while let Interaction::Sync(got_sync_acts,i1,i2) = rem {
+                if got_sync_acts != sync_acts {
+                    break;
+                }
+                sub_ints.push(&**i1);
+                rem = i2;
+            }
+            sub_ints.push(rem);
+            let fields = vec![("kind",to_json_string(SYNTAX_SYNC)),
+                              ("actions",sync_actions_as_json_encoding(gen_ctx,sync_acts))];
+            nary_as_json_encoding(gen_ctx,fields,&sub_ints,depth)
+        },
+        Interaction::Loop(cr,i1) => {
+            let fields = if cr.is_empty() {
+                vec![("kind",to_json_string(SYNTAX_LOOP_W))]
+            } else if is_full_coreg(gen_ctx,cr) {
+                vec![("kind",to_json_string(SYNTAX_LOOP_P))]
+            } else {
+                vec![("kind",to_json_string(SYNTAX_LOOP_C)),
+                     ("lifelines",lifelines_as_json_encoding(gen_ctx,cr))]
+            };
+            nary_as_json_encoding(gen_ctx,fields,&vec![&**i1],depth)
+        }
+    }
+}
+
+fn nary_as_json_encoding(gen_ctx : &GeneralContext,
+                         fields : Vec<(&str,String)>,
+                         sub_ints : &Vec<&Interaction>,
+                         depth : usize) -> String {
+    let indent = "  ".repeat(depth + 1);
+    let mut fields_encodings : Vec<String> = fields.iter()
+        .map(|(key,value)| format!("{:}\"{:}\": {:}", indent, key, value))
+        .collect();
+    let sub_ints_encodings : Vec<String> = sub_ints.iter()
+        .map(|sub_int| format!("{:}  {:}", indent, interaction_as_json_encoding_rec(gen_ctx,sub_int,depth + 2)))
+        .collect();
+    fields_encodings.push( format!("{:}\"operands\": [\n{:}\n{:}]", indent, sub_ints_encodings.join(",\n"), indent) );
+    format!("{{\n{:}\n{:}}}", fields_encodings.join(",\n"), "  ".repeat(depth))
+}
+
+fn lifelines_as_json_encoding(gen_ctx : &GeneralContext, lf_ids : &Vec<usize>) -> String {
+    let names : Vec<String> = lf_ids.iter().map(|lf_id| to_json_string(&gen_ctx.get_lf_name(*lf_id).unwrap())).collect();
+    format!("[{:}]", names.join(", "))
+}
+
+fn action_as_json_encoding(gen_ctx : &GeneralContext, bp : &BroadcastPrimitive) -> String {
+    let origin_repr = match bp.origin_on_emission {
+        None => {
+            "null".to_string()
+        },
+        Some( origin ) => {
+            to_json_string(&gen_ctx.get_lf_name(origin).unwrap())
+        }
+    };
+    format!("{{\"kind\": {:}, \"origin\": {:}, \"message\": {:}, \"targets\": {:}}}",
+            to_json_string("action"),
+            origin_repr,
+            message_type_as_json_encoding(gen_ctx,&bp.message_type),
+            lifelines_as_json_encoding(gen_ctx,&bp.targets))
+}
+
+fn sync_actions_as_json_encoding(gen_ctx : &GeneralContext,
+                                 sync_acts : &BTreeMap<(usize,TraceActionKind),MessageTypeExpression>) -> String {
+    let mut acts_encodings = vec![];
+    for ((lf_id,act_kind),message) in sync_acts {
+        acts_encodings.push( format!("{{\"lifeline\": {:}, \"kind\": {:}, \"message\": {:}}}",
+                                     to_json_string(&gen_ctx.get_lf_name(*lf_id).unwrap()),
+                                     trace_action_kind_as_json_encoding(act_kind),
+                                     message_type_as_json_encoding(gen_ctx,message)) );
+    }
+    format!("[{:}]", acts_encodings.join(", "))
+}
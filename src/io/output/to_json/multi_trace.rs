@@ -0,0 +1,64 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use itertools::Itertools;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::{MultiTrace, Trace};
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_json::action::trace_action_as_json_encoding;
+use crate::io::output::to_json::string::to_json_string;
+
+
+/**
+Encodes a multi-trace as a JSON object with a "components" field
+which lists, for each co-localization, its "lifelines" and the "trace" of actions observed on them.
+The comment, if any, is given in a "comment" field.
+**/
+pub fn multi_trace_as_json_encoding(gen_ctx : &GeneralContext,
+                                    co_localizations : &CoLocalizations,
+                                    multi_trace : &MultiTrace,
+                                    comment : Option<&str>) -> String {
+    let mut components_strings : Vec<String> = vec![];
+    for (canal_id,coloc_lfs) in co_localizations.locs_lf_ids.iter().enumerate() {
+        let canal_trace : &Trace = multi_trace.get(canal_id).unwrap();
+        let lf_names : Vec<String> = coloc_lfs.iter().sorted()
+            .map(|lf_id| to_json_string(&gen_ctx.get_lf_name(*lf_id).unwrap()))
+            .collect();
+        let actions : Vec<String> = canal_trace.iter()
+            .map(|action| format!("        {:}", trace_action_as_json_encoding(gen_ctx,action)))
+            .collect();
+        let trace_repr = if actions.is_empty() {
+            "[]".to_string()
+        } else {
+            format!("[\n{:}\n      ]", actions.join(",\n"))
+        };
+        components_strings.push( format!("    {{\n      \"lifelines\": [{:}],\n      \"trace\": {:}\n    }}",
+                                          lf_names.join(", "),
+                                          trace_repr) );
+    }
+    let comment_repr = match comment {
+        None => {
+            "".to_string()
+        },
+        Some( got_comment ) => {
+            format!("  \"comment\": {:},\n", to_json_string(got_comment))
+        }
+    };
+    format!("{{\n{:}  \"components\": [\n{:}\n  ]\n}}", comment_repr, components_strings.join(",\n"))
+}
@@ -0,0 +1,54 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use itertools::Itertools;
+
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_json::string::to_json_string;
+
+
+/**
+Encodes a signature as a JSON object with the "lifelines", "messages" and "types" fields,
+each type being an object with a "name" and the "messages" it contains.
+**/
+pub fn signature_as_json_encoding(gen_ctx : &GeneralContext) -> String {
+    let lf_names : Vec<String> = (0..gen_ctx.get_lf_num())
+        .map(|lf_id| to_json_string(&gen_ctx.get_lf_name(lf_id).unwrap()))
+        .collect();
+    let ms_names : Vec<String> = (0..gen_ctx.get_ms_num())
+        .map(|ms_id| to_json_string(&gen_ctx.get_ms_name(ms_id).unwrap()))
+        .collect();
+    let mut types = vec![];
+    for mt_id in 0..gen_ctx.get_mt_num() {
+        let type_ms_names : Vec<String> = gen_ctx.get_mt_messages(mt_id).unwrap().iter().sorted()
+            .map(|ms_id| to_json_string(&gen_ctx.get_ms_name(*ms_id).unwrap()))
+            .collect();
+        types.push( format!("    {{\"name\": {:}, \"messages\": [{:}]}}",
+                            to_json_string(&gen_ctx.get_mt_name(mt_id).unwrap()),
+                            type_ms_names.join(", ")) );
+    }
+    let types_repr = if types.is_empty() {
+        "[]".to_string()
+    } else {
+        format!("[\n{:}\n  ]", types.join(",\n"))
+    };
+    format!("{{\n  \"lifelines\": [{:}],\n  \"messages\": [{:}],\n  \"types\": {:}\n}}",
+            lf_names.join(", "),
+            ms_names.join(", "),
+            types_repr)
+}
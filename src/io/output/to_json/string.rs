@@ -0,0 +1,52 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+/**
+Encodes a string as a JSON string literal, quotes included.
+**/
+pub fn to_json_string(content : &str) -> String {
+    let mut encoded = String::with_capacity(content.len() + 2);
+    encoded.push('"');
+    for c in content.chars() {
+        match c {
+            '"' => {
+                encoded.push_str("\\\"");
+            },
+            '\\' => {
+                encoded.push_str("\\\\");
+            },
+            '\n' => {
+                encoded.push_str("\\n");
+            },
+            '\r' => {
+                encoded.push_str("\\r");
+            },
+            '\t' => {
+                encoded.push_str("\\t");
+            },
+            c if (c as u32) < 0x20 => {
+                encoded.push_str(&format!("\\u{:04x}", c as u32));
+            },
+            c => {
+                encoded.push(c);
+            }
+        }
+    }
+    encoded.push('"');
+    encoded
+}
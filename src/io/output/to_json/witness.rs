@@ -0,0 +1,42 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::execution::semantics::position::position_as_text;
+use crate::core::execution::witness::witness::ExecutionWitness;
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_json::action::trace_action_as_json_encoding;
+use crate::io::output::to_json::string::to_json_string;
+
+
+/**
+Encodes a witness as a JSON array of steps, each with its "position" (written as in the .hwf syntax) and "action".
+**/
+pub fn witness_as_json_encoding(gen_ctx : &GeneralContext,
+                                witness : &ExecutionWitness) -> String {
+    if witness.is_empty() {
+        return "[]".to_string();
+    }
+    let mut steps_strings : Vec<String> = vec![];
+    for step in witness {
+        let pos_string = position_as_text(&step.position);
+        steps_strings.push( format!("  {{\"position\": {:}, \"action\": {:}}}",
+                                    to_json_string(&pos_string),
+                                    trace_action_as_json_encoding(gen_ctx, &step.action)) );
+    }
+    format!("[\n{:}\n]", steps_strings.join(",\n"))
+}
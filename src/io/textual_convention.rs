@@ -33,6 +33,9 @@ pub static SYNTAX_POSITION_LEFT: &'static str = "1";
 pub static SYNTAX_POSITION_RIGHT: &'static str = "2";
 pub static SYNTAX_POSITION_EPSILON: &'static str = "ε";
 
+pub static SYNTAX_TRACE_EPSILON: &'static str = "ε";
+pub static SYNTAX_TRACE_SEPARATOR: &'static str = ".";
+
 
 
 
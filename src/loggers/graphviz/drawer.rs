@@ -14,17 +14,26 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
 
 
 pub struct InteractionProcessDrawer {
     pub temp_folder : String,
     pub int_repr_sd : bool,
-    pub int_repr_tt : bool
+    pub int_repr_tt : bool,
+    // sequence diagrams are drawn as vector SVG files instead of PNG images
+    pub sd_as_svg : bool,
+    // palette, sizes and message display used when drawing interactions and multi-traces
+    pub draw_conf : DrawingConfiguration
 }
 
 impl InteractionProcessDrawer {
-    pub fn new(temp_folder: String, int_repr_sd: bool, int_repr_tt: bool) -> Self {
-        InteractionProcessDrawer { temp_folder, int_repr_sd, int_repr_tt }
+    pub fn new(temp_folder: String,
+               int_repr_sd: bool,
+               int_repr_tt: bool,
+               sd_as_svg : bool,
+               draw_conf : DrawingConfiguration) -> Self {
+        InteractionProcessDrawer { temp_folder, int_repr_sd, int_repr_tt, sd_as_svg, draw_conf }
     }
 }
 
@@ -106,14 +106,20 @@ impl InteractionProcessDrawer {
                                          name : String) -> GraphVizNode {
         let mut node_gv_options : GraphvizNodeStyle = Vec::new();
         if interaction != &Interaction::Empty {
+            let (repr,extension) = if self.sd_as_svg {
+                (InteractionGraphicalRepresentation::AsSvgSequenceDiagram,"svg")
+            } else {
+                (InteractionGraphicalRepresentation::AsSequenceDiagram,"png")
+            };
             draw_interaction(gen_ctx,
+                             &self.draw_conf,
                              interaction,
-                             &InteractionGraphicalRepresentation::AsSequenceDiagram,
+                             &repr,
                              &"temp".to_string(),
                              &self.temp_folder,
                              &name);
             // ***
-            let int_image_file_path : PathBuf = [&self.temp_folder, &format!("{}.png",name)].iter().collect();
+            let int_image_file_path : PathBuf = [&self.temp_folder, &format!("{}.{}",name,extension)].iter().collect();
             // ***
             node_gv_options.push( GraphvizNodeStyleItem::Image( int_image_file_path.into_os_string().to_str().unwrap().to_string() ) );
             node_gv_options.push(GraphvizNodeStyleItem::Label( "".to_string() ));
@@ -133,6 +139,7 @@ impl InteractionProcessDrawer {
         let mut node_gv_options : GraphvizNodeStyle = Vec::new();
         if interaction != &Interaction::Empty {
             draw_interaction(gen_ctx,
+                             &self.draw_conf,
                              interaction,
                              &InteractionGraphicalRepresentation::AsTerm,
                              &"temp".to_string(),
@@ -163,6 +170,7 @@ impl InteractionProcessDrawer {
                               name : String) -> GraphVizNode {
         // ***
         draw_multitrace(gen_ctx,
+                        &self.draw_conf,
                         co_localizations,
                         multi_trace,
                         flags,
@@ -43,7 +43,7 @@ impl InteractionProcessDrawer {
                                             name : String) -> GraphVizNode {
         let image_file_path : PathBuf = [&self.temp_folder, &format!("{}.png",name)].iter().collect();
         // ***
-        draw_string_label(image_file_path.as_path(),string_label);
+        draw_string_label(image_file_path.as_path(),&self.draw_conf,string_label);
         // ***
         let mut gv_node_options : GraphvizNodeStyle = Vec::new();
         gv_node_options.push( GraphvizNodeStyleItem::Image( image_file_path.into_os_string().to_str().unwrap().to_string() ) );
@@ -65,6 +65,7 @@ impl InteractionProcessDrawer {
         // ***
         draw_firing(image_file_path.as_path(),
                     gen_ctx,
+                    &self.draw_conf,
                     action_position,executed_action,is_simulated);
         // ***
         let mut gv_node_options : GraphvizNodeStyle = Vec::new();
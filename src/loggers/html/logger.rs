@@ -0,0 +1,379 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::any::Any;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+
+use graph_process_manager_core::delegate::priorities::GenericProcessPriorities;
+use graph_process_manager_core::handler::filter::AbstractFilter;
+use graph_process_manager_core::manager::config::{AbstractProcessConfiguration, AbstractProcessParameterization};
+use graph_process_manager_core::manager::logger::AbstractProcessLogger;
+use graph_process_manager_core::manager::verdict::AbstractGlobalVerdict;
+use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+
+use crate::loggers::html::page::{escape_html, make_html_report};
+use crate::loggers::html::printer::HtmlProcessPrinter;
+
+
+enum HtmlReportChild {
+    // the HTML description of the step and the id of the node it leads to
+    Step(String,u32),
+    // the filter elimination that prevented a step from being taken
+    Eliminated(String)
+}
+
+struct HtmlReportNode {
+    // depth at which the node is first reached, unknown for the initial node
+    depth : Option<u32>,
+    content : String,
+    children : Vec<HtmlReportChild>,
+    verdict : Option<String>,
+    proof : Option<String>
+}
+
+/**
+Records the nodes, steps, filter eliminations and verdicts of a process and, once the process terminates,
+writes a self-contained HTML report in which the process is shown as a collapsible tree.
+Nodes which are reached several times (when memoization is used) are only expanded once,
+their other occurrences linking to the first one.
+**/
+pub struct GenericHtmlLogger<Conf : AbstractProcessConfiguration> {
+    printer : Box<dyn HtmlProcessPrinter<Conf>>,
+    parent_folder : String,
+    output_file_name : String,
+    // ***
+    legend : Vec<String>,
+    root_id : Option<u32>,
+    nodes : HashMap<u32,HtmlReportNode>
+}
+
+impl<Conf : AbstractProcessConfiguration> GenericHtmlLogger<Conf> {
+
+    pub fn new(printer : Box<dyn HtmlProcessPrinter<Conf>>,
+               parent_folder : String,
+               output_file_name : String) -> Self {
+        GenericHtmlLogger{printer,
+                          parent_folder,
+                          output_file_name,
+                          legend:vec![],
+                          root_id:None,
+                          nodes:hashmap!{}}
+    }
+
+    fn get_output_path(&self) -> PathBuf {
+        let file_name = format!("{:}.html", self.output_file_name);
+        [&self.parent_folder, &file_name].iter().collect()
+    }
+
+    fn get_html_node_id(node_id : u32) -> String {
+        format!("n{:}", node_id)
+    }
+
+    /**
+    Writes the opening of a node of the tree and returns true if its children remain to be written,
+    i.e. if the node was not already expanded elsewhere in the tree.
+    **/
+    fn open_node_as_html(&self,
+                         node_id : u32,
+                         expanded : &mut HashSet<u32>,
+                         html : &mut String) -> bool {
+        let html_id = Self::get_html_node_id(node_id);
+        if expanded.contains(&node_id) {
+            html.push_str( &format!("<div class=\"link\"><a href=\"#{:}\" onclick=\"reveal('{:}')\">already reached node {}</a></div>\n",
+                                    html_id, html_id, html_id) );
+            return false;
+        }
+        expanded.insert(node_id);
+        let node = self.nodes.get(&node_id).unwrap();
+        // ***
+        let verdict_attr = match &node.verdict {
+            None => {
+                "".to_string()
+            },
+            Some(verdict) => {
+                format!(" data-verdict=\"{:}\"", escape_html(verdict))
+            }
+        };
+        html.push_str( &format!("<details class=\"node\" id=\"{:}\"{:} open>\n<summary>{:} (depth {:})", html_id, verdict_attr, html_id, node.depth.unwrap_or(0)) );
+        if let Some(verdict) = &node.verdict {
+            html.push_str( &format!(" <span class=\"verdict\">{}</span>", escape_html(verdict)) );
+        }
+        html.push_str("</summary>\n");
+        html.push_str( &format!("<div class=\"node-content\">{}</div>\n", node.content) );
+        if let Some(proof) = &node.proof {
+            html.push_str( &format!("<details class=\"proof\"><summary>local analysis</summary>\n{:}\n</details>\n", proof) );
+        }
+        true
+    }
+
+    /**
+    Writes the tree rooted in a node, written iteratively
+    so as not to overflow the stack on deep processes.
+    **/
+    fn tree_as_html(&self,
+                    root_id : u32,
+                    html : &mut String) {
+        let mut expanded : HashSet<u32> = HashSet::new();
+        let mut call_stack : Vec<(u32,usize)> = vec![];
+        if self.open_node_as_html(root_id, &mut expanded, html) {
+            call_stack.push( (root_id,0) );
+        }
+        while let Some( (node_id,child_id) ) = call_stack.pop() {
+            let node = self.nodes.get(&node_id).unwrap();
+            if child_id < node.children.len() {
+                call_stack.push( (node_id,child_id + 1) );
+                match &node.children[child_id] {
+                    HtmlReportChild::Step(step_html, target_id) => {
+                        html.push_str( &format!("<div class=\"step\">&#8627; {}</div>\n", step_html) );
+                        if self.open_node_as_html(*target_id, &mut expanded, html) {
+                            call_stack.push( (*target_id,0) );
+                        }
+                    },
+                    HtmlReportChild::Eliminated(elim) => {
+                        html.push_str( &format!("<div class=\"elim\">&#10007; {}</div>\n", escape_html(elim)) );
+                    }
+                }
+            } else {
+                html.push_str("</details>\n");
+            }
+        }
+    }
+
+}
+
+
+impl<Conf : 'static + AbstractProcessConfiguration> AbstractProcessLogger<Conf> for GenericHtmlLogger<Conf> {
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(&mut self) {
+        self.legend.clear();
+        self.root_id = None;
+        self.nodes.clear();
+    }
+
+    fn log_parameterization(&mut self,
+                            strategy: &QueueSearchStrategy,
+                            priorities: &GenericProcessPriorities<Conf::Priorities>,
+                            filters: &[Box<dyn AbstractFilter<Conf::FilterCriterion, Conf::FilterEliminationKind>>],
+                            goal: &Option<Conf::GlobalVerdict>,
+                            use_memoization: bool,
+                            parameterization: &Conf::Parameterization) {
+        let mut legend = parameterization.get_param_as_strings();
+        legend.push( format!("strategy={}", strategy) );
+        legend.push( format!("priorities={}", priorities) );
+        let filters_strs : Vec<String> = filters.iter().map(|f| f.to_string()).collect();
+        legend.push( format!("filters=[{}]", filters_strs.join(",")) );
+        match goal {
+            None => {
+                legend.push( "goal=None".to_string() );
+            },
+            Some(ref target_verdict) => {
+                legend.push( format!("goal={}", target_verdict) );
+            }
+        }
+        legend.push( format!("memoize={}", use_memoization) );
+        self.legend = legend;
+    }
+
+    fn log_filtered(&mut self,
+                    _context: &Conf::Context,
+                    parent_node_id: u32,
+                    _new_node_id: u32,
+                    elim_kind: &Conf::FilterEliminationKind) {
+        if let Some(parent) = self.nodes.get_mut(&parent_node_id) {
+            parent.children.push( HtmlReportChild::Eliminated(elim_kind.to_string()) );
+        }
+    }
+
+    fn log_new_node(&mut self,
+                    context: &Conf::Context,
+                    param: &Conf::Parameterization,
+                    new_node_id: u32,
+                    new_node: &Conf::NodeKind) {
+        if self.root_id.is_none() {
+            self.root_id = Some(new_node_id);
+        }
+        let content = self.printer.get_node_html(context,param,new_node);
+        self.nodes.insert(new_node_id, HtmlReportNode{depth:None,content,children:vec![],verdict:None,proof:None});
+    }
+
+    fn log_new_step(&mut self,
+                    context: &Conf::Context,
+                    param: &Conf::Parameterization,
+                    origin_node_id: u32,
+                    target_node_id: u32,
+                    step: &Conf::StepKind,
+                    _target_node: &Conf::NodeKind,
+                    target_depth: u32) {
+        let step_html = self.printer.get_step_html(context,param,step);
+        let target = self.nodes.get_mut(&target_node_id).unwrap();
+        if target.depth.is_none() {
+            target.depth = Some(target_depth);
+        }
+        let origin = self.nodes.get_mut(&origin_node_id).unwrap();
+        origin.children.push( HtmlReportChild::Step(step_html,target_node_id) );
+    }
+
+    fn log_verdict_on_no_child(&mut self,
+                               _context: &Conf::Context,
+                               _param: &Conf::Parameterization,
+                               parent_node_id: u32,
+                               verdict: &Conf::LocalVerdict) {
+        let node = self.nodes.get_mut(&parent_node_id).unwrap();
+        node.verdict = Some(verdict.to_string());
+    }
+
+    fn log_verdict_on_static_analysis(&mut self,
+                                      context: &Conf::Context,
+                                      param: &Conf::Parameterization,
+                                      parent_node_id: u32,
+                                      verdict: &Conf::LocalVerdict,
+                                      proof: &Conf::StaticLocalVerdictAnalysisProof) {
+        let proof_html = self.printer.get_static_analysis_html(context,param,proof);
+        let node = self.nodes.get_mut(&parent_node_id).unwrap();
+        node.verdict = Some(verdict.to_string());
+        node.proof = proof_html;
+    }
+
+    fn log_terminate(&mut self,
+                     global_verdict: &Conf::GlobalVerdict) {
+        let mut tree = String::new();
+        if let Some(root_id) = self.root_id {
+            self.tree_as_html(root_id, &mut tree);
+        }
+        // ***
+        let mut node_ids : Vec<&u32> = self.nodes.keys().collect();
+        node_ids.sort();
+        let mut verdicts_index = vec![];
+        let mut verdict_kinds = BTreeSet::new();
+        for node_id in node_ids {
+            if let Some(verdict) = &self.nodes.get(node_id).unwrap().verdict {
+                verdicts_index.push( (Self::get_html_node_id(*node_id), verdict.clone()) );
+                verdict_kinds.insert(verdict.clone());
+            }
+        }
+        // ***
+        let global_verdict_str = if Conf::GlobalVerdict::is_verdict_pertinent_for_process() {
+            Some(global_verdict.to_string())
+        } else {
+            None
+        };
+        let html = make_html_report(&self.output_file_name,
+                                    &self.legend,
+                                    &global_verdict_str,
+                                    &verdicts_index,
+                                    &verdict_kinds.into_iter().collect(),
+                                    &tree);
+        // ***
+        if !self.parent_folder.is_empty() {
+            fs::create_dir_all(&self.parent_folder).unwrap();
+        }
+        let mut file = File::create(self.get_output_path().as_path()).unwrap();
+        file.write( html.as_bytes() ).unwrap();
+    }
+
+    fn log_notify_terminal_node_reached(&mut self,
+                                        _context: &Conf::Context,
+                                        _node_id: u32) {
+        // nothing
+    }
+
+    fn log_notify_last_child_of_node_processed(&mut self,
+                                               _context: &Conf::Context,
+                                               _parent_node_id: u32) {
+        // nothing
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
+    use graph_process_manager_core::manager::manager::GenericProcessManager;
+    use crate::core::general_context::GeneralContext;
+    use crate::io::input::hcf::explo::options::HibouExploreOptions;
+    use crate::io::input::hif::interaction::parse_hif_string;
+    use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
+    use crate::loggers::html::printer::InteractionHtmlPrinter;
+    use crate::process::explo::conf::ExplorationConfig;
+    use crate::process::explo::context::ExplorationContext;
+    use crate::process::explo::node::ExplorationNodeKind;
+    use super::{GenericHtmlLogger, HtmlReportChild, HtmlReportNode};
+
+    #[test]
+    fn html_report_of_exploration_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        let int = parse_hif_string(&gen_ctx,"alt(l1 -- m1 -> l2, l2 -- m2 -> l1)".to_string()).unwrap();
+        // ***
+        let parent_folder = std::env::temp_dir().join(format!("hibou_html_{:}", std::process::id()));
+        let logger : GenericHtmlLogger<ExplorationConfig> = GenericHtmlLogger::new(Box::new(InteractionHtmlPrinter::new(DrawingConfiguration::default())),
+                                                                                   parent_folder.to_str().unwrap().to_string(),
+                                                                                   "report".to_string());
+        let explo_opts = HibouExploreOptions::default();
+        let delegate = GenericProcessDelegate::new(explo_opts.strategy,explo_opts.priorities);
+        let mut manager : GenericProcessManager<ExplorationConfig> = GenericProcessManager::new(ExplorationContext::new(gen_ctx),
+                                                                                                explo_opts.param,
+                                                                                                delegate,
+                                                                                                vec![],
+                                                                                                vec![Box::new(logger)],
+                                                                                                None,
+                                                                                                false);
+        let (node_count,_) = manager.start_process(ExplorationNodeKind::new(int,0));
+        // ***
+        let html = fs::read_to_string(parent_folder.join("report.html")).unwrap();
+        fs::remove_dir_all(&parent_folder).unwrap();
+        assert_eq!(html.matches("<details class=\"node\"").count() as u32, node_count);
+        // the initial node is drawn as a sequence diagram and the two terminal nodes are indexed by their verdict
+        assert!(html.contains("<svg"));
+        assert_eq!(html.matches("<li class=\"verdict-entry\"").count(), 2);
+    }
+
+    #[test]
+    fn deep_tree_as_html_test() {
+        let mut logger : GenericHtmlLogger<ExplorationConfig> = GenericHtmlLogger::new(Box::new(InteractionHtmlPrinter::new(DrawingConfiguration::default())),
+                                                                                       "".to_string(),
+                                                                                       "report".to_string());
+        // a chain of nodes deeper than what a recursive writing would fit on the stack
+        // whose last node steps back to the first one
+        let depth : u32 = 200000;
+        for node_id in 1..=depth {
+            let target_id = if node_id == depth { 1 } else { node_id + 1 };
+            logger.nodes.insert(node_id, HtmlReportNode{depth:Some(node_id - 1),
+                                                        content:"".to_string(),
+                                                        children:vec![HtmlReportChild::Step("".to_string(),target_id)],
+                                                        verdict:None,
+                                                        proof:None});
+        }
+        let mut html = String::new();
+        logger.tree_as_html(1, &mut html);
+        assert_eq!(html.matches("<details class=\"node\"").count() as u32, depth);
+        assert_eq!(html.matches("</details>").count() as u32, depth);
+        assert_eq!(html.matches("already reached node n1<").count(), 1);
+        assert!(html.trim_end().ends_with("</details>"));
+    }
+}
@@ -0,0 +1,135 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+
+pub fn escape_html(text : &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '&' => {
+                escaped.push_str("&amp;");
+            },
+            '<' => {
+                escaped.push_str("&lt;");
+            },
+            '>' => {
+                escaped.push_str("&gt;");
+            },
+            '"' => {
+                escaped.push_str("&quot;");
+            },
+            '\'' => {
+                escaped.push_str("&#39;");
+            },
+            _ => {
+                escaped.push(c);
+            }
+        }
+    }
+    escaped
+}
+
+
+const HTML_REPORT_STYLE : &str = "
+body { font-family: sans-serif; margin: 20px; }
+header { position: sticky; top: 0; background: white; padding: 8px 0; border-bottom: 1px solid #ccc; z-index: 1; }
+details.node { margin-left: 18px; border-left: 1px dotted #999; padding-left: 8px; }
+details.node > summary { cursor: pointer; padding: 2px 0; }
+.node-content { display: flex; flex-wrap: wrap; gap: 12px; align-items: flex-start; margin: 4px 0; }
+.diagram svg { max-width: 100%; height: auto; border: 1px solid #ddd; }
+.texts { font-family: monospace; white-space: pre; border: 1px solid #ddd; padding: 4px; }
+.empty { font-style: italic; color: #777; }
+.step { font-family: monospace; margin-left: 26px; color: #333; }
+.step .texts { display: inline-block; border: none; }
+.elim { margin-left: 26px; color: #8b5a2b; font-weight: bold; }
+.link { margin-left: 26px; font-style: italic; }
+.verdict { font-weight: bold; padding: 0 6px; border-radius: 4px; background: #eee; }
+.proof { margin: 4px 0; padding: 4px; border: 2px solid #999; }
+.match > summary { background: #ffef9e; }
+.hidden { display: none; }
+";
+
+
+const HTML_REPORT_SCRIPT : &str = "
+function reveal(id) {
+    var elt = document.getElementById(id);
+    for (var anc = elt; anc; anc = anc.parentElement) {
+        if (anc.tagName === 'DETAILS') { anc.open = true; }
+    }
+    elt.scrollIntoView();
+}
+function setAll(open) {
+    document.querySelectorAll('details.node').forEach(function(d) { d.open = open; });
+}
+function filterVerdict() {
+    var wanted = document.getElementById('verdict-filter').value.trim().toLowerCase();
+    var entries = document.querySelectorAll('li.verdict-entry');
+    entries.forEach(function(e) {
+        var matches = wanted !== '' && e.dataset.verdict.toLowerCase().indexOf(wanted) >= 0;
+        e.classList.toggle('hidden', wanted !== '' && !matches);
+    });
+    document.querySelectorAll('details.node').forEach(function(d) {
+        var verdict = d.dataset.verdict;
+        d.classList.toggle('match', wanted !== '' && verdict !== undefined && verdict.toLowerCase().indexOf(wanted) >= 0);
+    });
+}
+";
+
+
+/**
+Assembles the self-contained HTML report.
+The header contains the parameterization of the process, its global verdict
+and an index of the nodes on which a local verdict was reached, which can be filtered by verdict.
+**/
+pub fn make_html_report(title : &str,
+                        legend : &Vec<String>,
+                        global_verdict : &Option<String>,
+                        verdicts_index : &Vec<(String,String)>,
+                        verdict_kinds : &Vec<String>,
+                        tree : &str) -> String {
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\"/>\n");
+    html.push_str( &format!("<title>{}</title>\n", escape_html(title)) );
+    html.push_str( &format!("<style>{}</style>\n<script>{}</script>\n", HTML_REPORT_STYLE, HTML_REPORT_SCRIPT) );
+    html.push_str("</head>\n<body>\n<header>\n");
+    html.push_str( &format!("<h2>{}</h2>\n", escape_html(title)) );
+    html.push_str( &format!("<div>{}</div>\n", legend.iter().map(|x| escape_html(x)).collect::<Vec<String>>().join(" ; ")) );
+    if let Some(verdict) = global_verdict {
+        html.push_str( &format!("<div>global verdict : <span class=\"verdict\">{}</span></div>\n", escape_html(verdict)) );
+    }
+    html.push_str("<div>\n<button onclick=\"setAll(true)\">expand all</button>\n<button onclick=\"setAll(false)\">collapse all</button>\n");
+    html.push_str("<input id=\"verdict-filter\" list=\"verdict-kinds\" placeholder=\"search verdict\" oninput=\"filterVerdict()\"/>\n");
+    html.push_str("<datalist id=\"verdict-kinds\">");
+    for verdict in verdict_kinds {
+        html.push_str( &format!("<option value=\"{:}\"/>", escape_html(verdict)) );
+    }
+    html.push_str("</datalist>\n</div>\n");
+    html.push_str( &format!("<details><summary>{:} local verdicts</summary>\n<ul>\n", verdicts_index.len()) );
+    for (node_id,verdict) in verdicts_index {
+        html.push_str( &format!("<li class=\"verdict-entry\" data-verdict=\"{:}\"><a href=\"#{:}\" onclick=\"reveal('{:}')\">{}</a> : {}</li>\n",
+                                escape_html(verdict),
+                                node_id,
+                                node_id,
+                                node_id,
+                                escape_html(verdict)) );
+    }
+    html.push_str("</ul>\n</details>\n</header>\n<main>\n");
+    html.push_str(tree);
+    html.push_str("</main>\n</body>\n</html>\n");
+    html
+}
@@ -0,0 +1,94 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use graph_process_manager_core::manager::config::AbstractProcessConfiguration;
+use image_colored_text::ttp::TextToPrint;
+
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
+use crate::io::output::draw_interactions::interface::interaction_as_svg_string;
+use crate::loggers::html::page::escape_html;
+
+
+/**
+Describes the nodes, steps and static analyses of a process as HTML fragments
+to be included in the report written by the HTML logger.
+**/
+pub trait HtmlProcessPrinter<Conf : AbstractProcessConfiguration> {
+
+    fn get_node_html(&self,
+                     context : &Conf::Context,
+                     param : &Conf::Parameterization,
+                     node : &Conf::NodeKind) -> String;
+
+    fn get_step_html(&self,
+                     context : &Conf::Context,
+                     param : &Conf::Parameterization,
+                     step : &Conf::StepKind) -> String;
+
+    fn get_static_analysis_html(&self,
+                                context : &Conf::Context,
+                                param : &Conf::Parameterization,
+                                proof : &Conf::StaticLocalVerdictAnalysisProof) -> Option<String>;
+
+}
+
+
+/**
+Nodes are described by their residual interaction (drawn as an inline SVG sequence diagram)
+and, in the case of multi-trace analysis, by the state of the multi-trace.
+**/
+pub struct InteractionHtmlPrinter {
+    pub draw_conf : DrawingConfiguration
+}
+
+impl InteractionHtmlPrinter {
+
+    pub fn new(draw_conf : DrawingConfiguration) -> Self {
+        InteractionHtmlPrinter{draw_conf}
+    }
+
+    pub fn interaction_as_html(&self,
+                               gen_ctx : &GeneralContext,
+                               interaction : &Interaction) -> String {
+        if interaction == &Interaction::Empty {
+            "<div class=\"empty\">empty interaction</div>".to_string()
+        } else {
+            format!("<div class=\"diagram\">{}</div>", interaction_as_svg_string(gen_ctx,&self.draw_conf,interaction))
+        }
+    }
+
+    pub fn colored_texts_as_html(&self,
+                                 lines : &Vec<Vec<TextToPrint>>) -> String {
+        let mut html_lines = vec![];
+        for line in lines {
+            let mut html_line = String::new();
+            for ttp in line {
+                html_line.push_str( &format!("<span style=\"color:rgb({:},{:},{:})\">{}</span>",
+                                             ttp.color.0[0],
+                                             ttp.color.0[1],
+                                             ttp.color.0[2],
+                                             escape_html(&ttp.text)) );
+            }
+            html_lines.push(html_line);
+        }
+        format!("<div class=\"texts\">{}</div>", html_lines.join("<br/>"))
+    }
+
+}
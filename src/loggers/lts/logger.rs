@@ -0,0 +1,283 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+
+use graph_process_manager_core::delegate::priorities::GenericProcessPriorities;
+use graph_process_manager_core::handler::filter::AbstractFilter;
+use graph_process_manager_core::manager::config::AbstractProcessConfiguration;
+use graph_process_manager_core::manager::logger::AbstractProcessLogger;
+use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+
+use crate::io::output::to_json::string::to_json_string;
+use crate::loggers::lts::printer::LtsProcessPrinter;
+
+
+/**
+Records the state space explored by a process and, once the process terminates,
+writes it as a labelled transition system both in the Aldebaran '.aut' format and in JSON.
+States are renumbered from 0 (the initial state) in the order in which they are reached
+and steps which are eliminated by filters are not recorded.
+**/
+pub struct GenericLtsLogger<Conf : AbstractProcessConfiguration> {
+    printer : Box<dyn LtsProcessPrinter<Conf>>,
+    parent_folder : String,
+    output_file_name : String,
+    // ***
+    states_ids : HashMap<u32,usize>,
+    states : Vec<String>,
+    verdicts : HashMap<usize,String>,
+    transitions : Vec<(usize,String,usize)>
+}
+
+impl<Conf : AbstractProcessConfiguration> GenericLtsLogger<Conf> {
+
+    pub fn new(printer : Box<dyn LtsProcessPrinter<Conf>>,
+               parent_folder : String,
+               output_file_name : String) -> Self {
+        GenericLtsLogger{printer,
+                         parent_folder,
+                         output_file_name,
+                         states_ids:hashmap!{},
+                         states:vec![],
+                         verdicts:hashmap!{},
+                         transitions:vec![]}
+    }
+
+    fn get_output_path(&self, extension : &str) -> PathBuf {
+        let file_name = format!("{:}.{:}", self.output_file_name, extension);
+        [&self.parent_folder, &file_name].iter().collect()
+    }
+
+    fn as_aut(&self) -> String {
+        let mut lines = vec![format!("des (0, {:}, {:})", self.transitions.len(), self.states.len())];
+        for (origin,label,target) in &self.transitions {
+            // labels are quoted as is in the Aldebaran format, hence they must not contain double quotes
+            lines.push( format!("({:}, \"{:}\", {:})", origin, label.replace('"',"'"), target) );
+        }
+        lines.join("\n")
+    }
+
+    fn as_json(&self, global_verdict : &Conf::GlobalVerdict) -> String {
+        let mut states = vec![];
+        for (state_id,state_repr) in self.states.iter().enumerate() {
+            let verdict = match self.verdicts.get(&state_id) {
+                None => {
+                    "null".to_string()
+                },
+                Some( verdict ) => {
+                    to_json_string(verdict)
+                }
+            };
+            states.push( format!("    {{\"id\": {:}, \"interaction\": {:}, \"verdict\": {:}}}",
+                                 state_id,
+                                 to_json_string(state_repr),
+                                 verdict) );
+        }
+        let mut transitions = vec![];
+        for (origin,label,target) in &self.transitions {
+            transitions.push( format!("    {{\"source\": {:}, \"target\": {:}, \"label\": {:}}}",
+                                      origin,
+                                      target,
+                                      to_json_string(label)) );
+        }
+        format!("{{\n  \"initial\": 0,\n  \"verdict\": {:},\n  \"states\": [\n{:}\n  ],\n  \"transitions\": [\n{:}\n  ]\n}}",
+                to_json_string(&global_verdict.to_string()),
+                states.join(",\n"),
+                transitions.join(",\n"))
+    }
+
+}
+
+
+impl<Conf : 'static + AbstractProcessConfiguration> AbstractProcessLogger<Conf> for GenericLtsLogger<Conf> {
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(&mut self) {
+        self.states_ids.clear();
+        self.states.clear();
+        self.verdicts.clear();
+        self.transitions.clear();
+    }
+
+    fn log_parameterization(&mut self,
+                            _strategy: &QueueSearchStrategy,
+                            _priorities: &GenericProcessPriorities<Conf::Priorities>,
+                            _filters: &[Box<dyn AbstractFilter<Conf::FilterCriterion, Conf::FilterEliminationKind>>],
+                            _goal: &Option<Conf::GlobalVerdict>,
+                            _use_memoization: bool,
+                            _parameterization: &Conf::Parameterization) {
+        // nothing
+    }
+
+    fn log_filtered(&mut self,
+                    _context: &Conf::Context,
+                    _parent_node_id: u32,
+                    _new_node_id: u32,
+                    _elim_kind: &Conf::FilterEliminationKind) {
+        // nothing
+    }
+
+    fn log_new_node(&mut self,
+                    context: &Conf::Context,
+                    _param: &Conf::Parameterization,
+                    new_node_id: u32,
+                    new_node: &Conf::NodeKind) {
+        self.states_ids.insert(new_node_id,self.states.len());
+        self.states.push( self.printer.get_state_repr(context,new_node) );
+    }
+
+    fn log_new_step(&mut self,
+                    context: &Conf::Context,
+                    _param: &Conf::Parameterization,
+                    origin_node_id: u32,
+                    target_node_id: u32,
+                    step: &Conf::StepKind,
+                    _target_node: &Conf::NodeKind,
+                    _target_depth: u32) {
+        let origin = *self.states_ids.get(&origin_node_id).unwrap();
+        let target = *self.states_ids.get(&target_node_id).unwrap();
+        self.transitions.push( (origin,self.printer.get_step_label(context,step),target) );
+    }
+
+    fn log_verdict_on_no_child(&mut self,
+                               _context: &Conf::Context,
+                               _param: &Conf::Parameterization,
+                               parent_node_id: u32,
+                               verdict: &Conf::LocalVerdict) {
+        let state_id = *self.states_ids.get(&parent_node_id).unwrap();
+        self.verdicts.insert(state_id,verdict.to_string());
+    }
+
+    fn log_verdict_on_static_analysis(&mut self,
+                                      _context: &Conf::Context,
+                                      _param: &Conf::Parameterization,
+                                      parent_node_id: u32,
+                                      verdict: &Conf::LocalVerdict,
+                                      _proof: &Conf::StaticLocalVerdictAnalysisProof) {
+        let state_id = *self.states_ids.get(&parent_node_id).unwrap();
+        self.verdicts.insert(state_id,verdict.to_string());
+    }
+
+    fn log_terminate(&mut self,
+                     global_verdict: &Conf::GlobalVerdict) {
+        if !self.parent_folder.is_empty() {
+            fs::create_dir_all(&self.parent_folder).unwrap();
+        }
+        let mut aut_file = File::create(self.get_output_path("aut").as_path()).unwrap();
+        aut_file.write( self.as_aut().as_bytes() ).unwrap();
+        let mut json_file = File::create(self.get_output_path("json").as_path()).unwrap();
+        json_file.write( self.as_json(global_verdict).as_bytes() ).unwrap();
+    }
+
+    fn log_notify_terminal_node_reached(&mut self,
+                                        _context: &Conf::Context,
+                                        _node_id: u32) {
+        // nothing
+    }
+
+    fn log_notify_last_child_of_node_processed(&mut self,
+                                               _context: &Conf::Context,
+                                               _parent_node_id: u32) {
+        // nothing
+    }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
+    use graph_process_manager_core::manager::manager::GenericProcessManager;
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::BroadcastPrimitive;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use crate::io::input::hcf::explo::options::HibouExploreOptions;
+    use crate::io::input::json::value::json_value_from_text;
+    use crate::loggers::lts::printer::InteractionLtsPrinter;
+    use crate::process::explo::conf::ExplorationConfig;
+    use crate::process::explo::context::ExplorationContext;
+    use crate::process::explo::node::ExplorationNodeKind;
+    use crate::process::explo::priorities::ExplorationPriorities;
+    use crate::process::explo::step::ExplorationStepKind;
+    use super::GenericLtsLogger;
+
+    fn emission(ms_id : usize) -> Box<Interaction> {
+        Box::new(Interaction::Action(BroadcastPrimitive::new(Some(0),
+                                                             MessageTypeExpression::Singleton(ms_id),
+                                                             vec![])))
+    }
+
+    #[test]
+    fn alt_loop_lts_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        // alt(l1 -- m1 -> |, loopW(l1 -- m2 -> |))
+        let int = Interaction::Alt(emission(0),Box::new(Interaction::Loop(vec![],emission(1))));
+        let parent_folder = std::env::temp_dir().join(format!("hibou_lts_test_{:}", std::process::id()))
+            .to_str().unwrap().to_string();
+        // ***
+        let mut explo_opts = HibouExploreOptions::default();
+        explo_opts.filters = vec![];
+        explo_opts.use_memoization = true;
+        explo_opts.loggers = vec![Box::new(GenericLtsLogger::new(Box::new(InteractionLtsPrinter::new()),
+                                                                 parent_folder.clone(),
+                                                                 "lts".to_string()))];
+        let delegate : GenericProcessDelegate<ExplorationStepKind,ExplorationNodeKind,ExplorationPriorities> = GenericProcessDelegate::new(explo_opts.strategy,explo_opts.priorities);
+        let mut exploration_manager : GenericProcessManager<ExplorationConfig> = GenericProcessManager::new(ExplorationContext::new(gen_ctx),
+                                                                                                            explo_opts.param,
+                                                                                                            delegate,
+                                                                                                            explo_opts.filters,
+                                                                                                            explo_opts.loggers,
+                                                                                                            None,
+                                                                                                            explo_opts.use_memoization);
+        exploration_manager.start_process(ExplorationNodeKind::new(int,0));
+        // ***
+        // the initial state, the empty interaction after l1!m1 and the loop after l1!m2
+        // which is reached again from itself by executing l1!m2
+        let aut = fs::read_to_string(format!("{:}/lts.aut", parent_folder)).unwrap();
+        let mut lines = aut.lines();
+        assert_eq!(lines.next().unwrap(),"des (0, 3, 3)");
+        let transitions : Vec<&str> = lines.collect();
+        assert_eq!(transitions,vec!["(0, \"l1!{m1}\", 1)",
+                                    "(0, \"l1!{m2}\", 2)",
+                                    "(2, \"l1!{m2}\", 2)"]);
+        // ***
+        let json = json_value_from_text(&fs::read_to_string(format!("{:}/lts.json", parent_folder)).unwrap()).unwrap();
+        assert_eq!(json.get_field("states").unwrap().as_array().unwrap().len(),3);
+        let json_transitions = json.get_field("transitions").unwrap().as_array().unwrap();
+        assert_eq!(json_transitions.len(),3);
+        assert!(json_transitions.iter().all(|trans| trans.get_field("label").unwrap().as_str().is_some()));
+        fs::remove_dir_all(&parent_folder).unwrap();
+    }
+
+}
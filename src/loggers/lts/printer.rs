@@ -0,0 +1,48 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use graph_process_manager_core::manager::config::AbstractProcessConfiguration;
+
+
+/**
+Describes the states and transitions of a process as those of a labelled transition system.
+**/
+pub trait LtsProcessPrinter<Conf : AbstractProcessConfiguration> {
+
+    fn get_state_repr(&self,
+                      context : &Conf::Context,
+                      node : &Conf::NodeKind) -> String;
+
+    fn get_step_label(&self,
+                      context : &Conf::Context,
+                      step : &Conf::StepKind) -> String;
+
+}
+
+
+/**
+States are described by their residual interaction (in the .hif syntax)
+and transitions by the trace action they execute.
+**/
+pub struct InteractionLtsPrinter {}
+
+impl InteractionLtsPrinter {
+    pub fn new() -> Self {
+        InteractionLtsPrinter{}
+    }
+}
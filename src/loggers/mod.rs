@@ -17,4 +17,5 @@ limitations under the License.
 
 pub mod graphviz;
 pub mod tracegen;
-
+pub mod lts;
+pub mod html;
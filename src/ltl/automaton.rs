@@ -0,0 +1,227 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::HashSet;
+
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+use crate::ltl::formula::{action_satisfies_pattern, LtlNnfFormula};
+
+
+pub const LTL_AUTOMATON_INITIAL_STATE : usize = 0;
+
+/**
+A state of the automaton is labelled by the literals which the action read when entering it must satisfy.
+A finite behaviour may end in a state iff no strong obligation (next or until) remains pending in it.
+**/
+pub struct LtlAutomatonState {
+    pub literals : Vec<LtlNnfFormula>,
+    pub successors : Vec<usize>,
+    pub can_end : bool
+}
+
+impl LtlAutomatonState {
+
+    pub fn accepts_action(&self,
+                          gen_ctx : &GeneralContext,
+                          action : &TraceAction) -> bool {
+        for literal in &self.literals {
+            match literal {
+                LtlNnfFormula::Atom(pattern) => {
+                    if !action_satisfies_pattern(gen_ctx,action,pattern) {
+                        return false;
+                    }
+                },
+                LtlNnfFormula::NegAtom(pattern) => {
+                    if action_satisfies_pattern(gen_ctx,action,pattern) {
+                        return false;
+                    }
+                },
+                _ => {}
+            }
+        }
+        true
+    }
+
+}
+
+/**
+A generalized Büchi automaton built with the on-the-fly tableau construction of Gerth, Peled, Vardi and Wolper.
+State 0 is an initial pseudo-state which reads no action.
+An infinite run is accepting iff it visits each acceptance set infinitely often.
+**/
+pub struct LtlAutomaton {
+    pub states : Vec<LtlAutomatonState>,
+    pub acceptance_sets : Vec<HashSet<usize>>
+}
+
+
+struct LtlTableauNode {
+    incoming : HashSet<usize>,
+    new : Vec<LtlNnfFormula>,
+    old : HashSet<LtlNnfFormula>,
+    next : HashSet<LtlNnfFormula>
+}
+
+impl LtlTableauNode {
+
+    fn split(&self,
+             eta : LtlNnfFormula,
+             new : Vec<LtlNnfFormula>,
+             next : Option<LtlNnfFormula>) -> LtlTableauNode {
+        let mut got = LtlTableauNode{incoming:self.incoming.clone(),
+                                     new:self.new.clone(),
+                                     old:self.old.clone(),
+                                     next:self.next.clone()};
+        got.new.extend(new);
+        got.old.insert(eta);
+        if let Some(next_formula) = next {
+            got.next.insert(next_formula);
+        }
+        got
+    }
+
+}
+
+
+impl LtlAutomaton {
+
+    pub fn from_formula(formula : &LtlNnfFormula) -> LtlAutomaton {
+        let mut expanded : Vec<(HashSet<usize>,HashSet<LtlNnfFormula>,HashSet<LtlNnfFormula>)> = vec![];
+        let mut stack = vec![LtlTableauNode{incoming:hashset!{LTL_AUTOMATON_INITIAL_STATE},
+                                            new:vec![formula.clone()],
+                                            old:hashset!{},
+                                            next:hashset!{}}];
+        while let Some(mut node) = stack.pop() {
+            match node.new.pop() {
+                None => {
+                    // the node is fully expanded
+                    match expanded.iter_mut().find(|(_,old,next)| *old == node.old && *next == node.next) {
+                        Some( (incoming,_,_) ) => {
+                            incoming.extend(node.incoming);
+                        },
+                        None => {
+                            let new_id = expanded.len() + 1;
+                            stack.push( LtlTableauNode{incoming:hashset!{new_id},
+                                                       new:node.next.iter().cloned().collect(),
+                                                       old:hashset!{},
+                                                       next:hashset!{}} );
+                            expanded.push( (node.incoming,node.old,node.next) );
+                        }
+                    }
+                },
+                Some( eta ) => {
+                    if node.old.contains(&eta) {
+                        stack.push(node);
+                        continue;
+                    }
+                    match eta.clone() {
+                        LtlNnfFormula::True => {
+                            node.old.insert(eta);
+                            stack.push(node);
+                        },
+                        LtlNnfFormula::False => {
+                            // contradiction : the node is discarded
+                        },
+                        LtlNnfFormula::Atom(pattern) => {
+                            if !node.old.contains(&LtlNnfFormula::NegAtom(pattern)) {
+                                node.old.insert(eta);
+                                stack.push(node);
+                            }
+                        },
+                        LtlNnfFormula::NegAtom(pattern) => {
+                            if !node.old.contains(&LtlNnfFormula::Atom(pattern)) {
+                                node.old.insert(eta);
+                                stack.push(node);
+                            }
+                        },
+                        LtlNnfFormula::And(left,right) => {
+                            stack.push( node.split(eta,vec![*left,*right],None) );
+                        },
+                        LtlNnfFormula::Or(left,right) => {
+                            stack.push( node.split(eta.clone(),vec![*left],None) );
+                            stack.push( node.split(eta,vec![*right],None) );
+                        },
+                        LtlNnfFormula::Next(sub) => {
+                            stack.push( node.split(eta,vec![],Some(*sub)) );
+                        },
+                        LtlNnfFormula::WeakNext(sub) => {
+                            stack.push( node.split(eta,vec![],Some(*sub)) );
+                        },
+                        LtlNnfFormula::Until(left,right) => {
+                            // φ U ψ = ψ | (φ & X(φ U ψ))
+                            stack.push( node.split(eta.clone(),vec![*left],Some(eta.clone())) );
+                            stack.push( node.split(eta,vec![*right],None) );
+                        },
+                        LtlNnfFormula::Release(left,right) => {
+                            // φ R ψ = ψ & (φ | X(φ R ψ))
+                            stack.push( node.split(eta.clone(),vec![*right.clone()],Some(eta.clone())) );
+                            stack.push( node.split(eta,vec![*left,*right],None) );
+                        }
+                    }
+                }
+            }
+        }
+        // ***
+        let mut states = vec![LtlAutomatonState{literals:vec![],successors:vec![],can_end:false}];
+        let mut untils : Vec<(LtlNnfFormula,LtlNnfFormula)> = vec![];
+        for (_,old,_) in &expanded {
+            let mut literals = vec![];
+            let mut can_end = true;
+            for formula in old {
+                match formula {
+                    LtlNnfFormula::Atom(_) | LtlNnfFormula::NegAtom(_) => {
+                        literals.push(formula.clone());
+                    },
+                    LtlNnfFormula::Next(_) => {
+                        can_end = false;
+                    },
+                    LtlNnfFormula::Until(_,right) => {
+                        if !old.contains(right) {
+                            can_end = false;
+                        }
+                        let got_until = (formula.clone(),*right.clone());
+                        if !untils.contains(&got_until) {
+                            untils.push(got_until);
+                        }
+                    },
+                    _ => {}
+                }
+            }
+            states.push( LtlAutomatonState{literals,successors:vec![],can_end} );
+        }
+        for (target_id,(incoming,_,_)) in expanded.iter().enumerate() {
+            for origin_id in incoming {
+                states[*origin_id].successors.push(target_id + 1);
+            }
+        }
+        // ***
+        let mut acceptance_sets = vec![];
+        for (until,right) in untils {
+            let mut acc_set = hashset!{};
+            for (state_id,(_,old,_)) in expanded.iter().enumerate() {
+                if !old.contains(&until) || old.contains(&right) {
+                    acc_set.insert(state_id + 1);
+                }
+            }
+            acceptance_sets.push(acc_set);
+        }
+        LtlAutomaton{states,acceptance_sets}
+    }
+
+}
@@ -0,0 +1,355 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+use crate::ltl::automaton::{LTL_AUTOMATON_INITIAL_STATE, LtlAutomaton};
+use crate::ltl::formula::LtlFormula;
+use crate::ltl::graph::ExplorationGraph;
+
+
+/**
+A behaviour violating the property.
+If the cycle is empty, it is the finite accepted trace given by the prefix.
+Otherwise, it is the infinite behaviour in which the cycle is repeated forever after the prefix.
+**/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LtlCounterExample {
+    pub prefix : Vec<TraceAction>,
+    pub cycle : Vec<TraceAction>
+}
+
+impl LtlCounterExample {
+
+    pub fn is_finite(&self) -> bool {
+        self.cycle.is_empty()
+    }
+
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum LtlCheckVerdict {
+    Holds,
+    Violated(LtlCounterExample)
+}
+
+impl fmt::Display for LtlCheckVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LtlCheckVerdict::Holds => {
+                write!(f,"holds")
+            },
+            LtlCheckVerdict::Violated(_) => {
+                write!(f,"violated")
+            }
+        }
+    }
+}
+
+
+/**
+The product of the exploration graph with the automaton of the negation of the property.
+Each product state is a pair of a state of the graph and of a state of the automaton,
+identified by the order in which it is reached by a breadth-first search from the initial state.
+**/
+struct LtlProduct {
+    states : Vec<(usize,usize)>,
+    parents : Vec<Option<(usize,TraceAction)>>,
+    edges : Vec<Vec<(TraceAction,usize)>>
+}
+
+impl LtlProduct {
+
+    fn get_path_to(&self, state_id : usize) -> Vec<TraceAction> {
+        let mut path = vec![];
+        let mut current = state_id;
+        while let Some( (parent_id,action) ) = &self.parents[current] {
+            path.push(action.clone());
+            current = *parent_id;
+        }
+        path.reverse();
+        path
+    }
+
+    /**
+    Returns a shortest path from the origin to a state which verifies the predicate
+    and which stays within the given strongly connected component.
+    If the path must be non-empty, the origin itself is only considered after at least one step.
+    **/
+    fn get_path_within(&self,
+                       origin : usize,
+                       component : &HashSet<usize>,
+                       allow_empty : bool,
+                       predicate : &dyn Fn(usize) -> bool) -> Option<(usize,Vec<TraceAction>)> {
+        if allow_empty && predicate(origin) {
+            return Some( (origin,vec![]) );
+        }
+        let mut parents : HashMap<usize,(usize,TraceAction)> = hashmap!{};
+        let mut queue : VecDeque<usize> = VecDeque::new();
+        queue.push_back(origin);
+        while let Some(current) = queue.pop_front() {
+            for (action,target) in &self.edges[current] {
+                if !component.contains(target) {
+                    continue;
+                }
+                if *target != origin && parents.contains_key(target) {
+                    continue;
+                }
+                if *target != origin {
+                    parents.insert(*target,(current,action.clone()));
+                }
+                if predicate(*target) {
+                    let mut path = vec![action.clone()];
+                    let mut back = current;
+                    while back != origin {
+                        let (parent_id,parent_action) = parents.get(&back).unwrap();
+                        path.push(parent_action.clone());
+                        back = *parent_id;
+                    }
+                    path.reverse();
+                    return Some( (*target,path) );
+                }
+                if *target != origin {
+                    queue.push_back(*target);
+                }
+            }
+        }
+        None
+    }
+
+    /**
+    Tarjan's algorithm for strongly connected components, written iteratively
+    so as not to overflow the stack on large exploration graphs.
+    **/
+    fn get_strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        let states_num = self.states.len();
+        let mut index : Vec<Option<usize>> = vec![None;states_num];
+        let mut low_link : Vec<usize> = vec![0;states_num];
+        let mut on_stack : Vec<bool> = vec![false;states_num];
+        let mut stack : Vec<usize> = vec![];
+        let mut components = vec![];
+        let mut counter = 0;
+        for root in 0..states_num {
+            if index[root].is_some() {
+                continue;
+            }
+            let mut call_stack : Vec<(usize,usize)> = vec![(root,0)];
+            index[root] = Some(counter);
+            low_link[root] = counter;
+            counter += 1;
+            stack.push(root);
+            on_stack[root] = true;
+            while let Some( (state_id,edge_id) ) = call_stack.pop() {
+                if edge_id < self.edges[state_id].len() {
+                    call_stack.push( (state_id,edge_id + 1) );
+                    let target = self.edges[state_id][edge_id].1;
+                    match index[target] {
+                        None => {
+                            index[target] = Some(counter);
+                            low_link[target] = counter;
+                            counter += 1;
+                            stack.push(target);
+                            on_stack[target] = true;
+                            call_stack.push( (target,0) );
+                        },
+                        Some( target_index ) => {
+                            if on_stack[target] {
+                                low_link[state_id] = low_link[state_id].min(target_index);
+                            }
+                        }
+                    }
+                } else {
+                    if let Some( (caller_id,_) ) = call_stack.last() {
+                        low_link[*caller_id] = low_link[*caller_id].min(low_link[state_id]);
+                    }
+                    if low_link[state_id] == index[state_id].unwrap() {
+                        let mut component = vec![];
+                        loop {
+                            let member = stack.pop().unwrap();
+                            on_stack[member] = false;
+                            component.push(member);
+                            if member == state_id {
+                                break;
+                            }
+                        }
+                        components.push(component);
+                    }
+                }
+            }
+        }
+        components
+    }
+
+}
+
+
+/**
+Checks whether or not all the behaviours of the exploration graph satisfy the formula.
+Finite behaviours are those ending in an accepting state and are interpreted with the semantics of LTL on finite traces
+while infinite behaviours correspond to the paths which loop through cycles of the graph.
+Paths which end in states that are deadlocked or whose children were eliminated by filters are not behaviours.
+**/
+pub fn check_ltl_formula(gen_ctx : &GeneralContext,
+                         graph : &ExplorationGraph,
+                         formula : &LtlFormula) -> LtlCheckVerdict {
+    let negated = formula.as_nnf(true);
+    if graph.accepting[0] && negated.holds_on_empty() {
+        return LtlCheckVerdict::Violated(LtlCounterExample{prefix:vec![],cycle:vec![]});
+    }
+    let automaton = LtlAutomaton::from_formula(&negated);
+    // ***
+    let mut product = LtlProduct{states:vec![(0,LTL_AUTOMATON_INITIAL_STATE)],parents:vec![None],edges:vec![vec![]]};
+    let mut ids : HashMap<(usize,usize),usize> = hashmap!{(0,LTL_AUTOMATON_INITIAL_STATE) => 0};
+    let mut queue : VecDeque<usize> = VecDeque::new();
+    queue.push_back(0);
+    while let Some(current) = queue.pop_front() {
+        let (graph_state,aut_state) = product.states[current];
+        for (action,graph_target) in &graph.transitions[graph_state] {
+            for aut_target in &automaton.states[aut_state].successors {
+                if !automaton.states[*aut_target].accepts_action(gen_ctx,action) {
+                    continue;
+                }
+                let target_key = (*graph_target,*aut_target);
+                let target_id = match ids.get(&target_key) {
+                    Some( got_id ) => {
+                        *got_id
+                    },
+                    None => {
+                        let new_id = product.states.len();
+                        ids.insert(target_key,new_id);
+                        product.states.push(target_key);
+                        product.parents.push(Some((current,action.clone())));
+                        product.edges.push(vec![]);
+                        if graph.accepting[*graph_target] && automaton.states[*aut_target].can_end {
+                            let prefix = product.get_path_to(new_id);
+                            return LtlCheckVerdict::Violated(LtlCounterExample{prefix,cycle:vec![]});
+                        }
+                        queue.push_back(new_id);
+                        new_id
+                    }
+                };
+                product.edges[current].push( (action.clone(),target_id) );
+            }
+        }
+    }
+    // ***
+    for component in product.get_strongly_connected_components() {
+        let members : HashSet<usize> = component.iter().cloned().collect();
+        let entry = *component.iter().min().unwrap();
+        let is_cycle = component.len() > 1 || product.edges[entry].iter().any(|(_,target)| *target == entry);
+        if !is_cycle {
+            continue;
+        }
+        let satisfies_acceptance = automaton.acceptance_sets.iter().all(
+            |acc_set| component.iter().any(|member| acc_set.contains(&product.states[*member].1))
+        );
+        if !satisfies_acceptance {
+            continue;
+        }
+        // the cycle goes through each acceptance set in turn before coming back to the entry
+        let mut cycle = vec![];
+        let mut current = entry;
+        for acc_set in &automaton.acceptance_sets {
+            let in_set = |state_id : usize| acc_set.contains(&product.states[state_id].1);
+            let (reached,path) = product.get_path_within(current,&members,true,&in_set).unwrap();
+            cycle.extend(path);
+            current = reached;
+        }
+        let is_entry = |state_id : usize| state_id == entry;
+        let (_,path) = product.get_path_within(current,&members,!cycle.is_empty(),&is_entry).unwrap();
+        cycle.extend(path);
+        let prefix = product.get_path_to(entry);
+        return LtlCheckVerdict::Violated(LtlCounterExample{prefix,cycle});
+    }
+    LtlCheckVerdict::Holds
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::core::message::MessageTypeExpression;
+    use crate::io::input::ltl::interface::parse_ltl_formula;
+    use crate::ltl::graph::ExplorationGraph;
+    use super::{check_ltl_formula, LtlCheckVerdict, LtlCounterExample};
+
+    fn get_test_context() -> GeneralContext {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        return gen_ctx;
+    }
+
+    fn action(lf_id : usize, act_kind : TraceActionKind, ms_id : usize) -> TraceAction {
+        TraceAction::new(lf_id,act_kind,MessageTypeExpression::Singleton(ms_id))
+    }
+
+    /**
+    The graph of seq(loopW(seq(l1 -- m1 -> l2, l2 -- m2 -> l1)), l1 -- m2 -> l2) in which the loop is folded into a cycle.
+    **/
+    fn get_test_graph() -> ExplorationGraph {
+        let transitions = vec![
+            vec![(action(0,TraceActionKind::Emission,0),1),(action(0,TraceActionKind::Emission,1),4)],
+            vec![(action(1,TraceActionKind::Reception,0),2)],
+            vec![(action(1,TraceActionKind::Emission,1),3)],
+            vec![(action(0,TraceActionKind::Reception,1),0)],
+            vec![(action(1,TraceActionKind::Reception,1),5)],
+            vec![]
+        ];
+        ExplorationGraph{accepting:vec![false,false,false,false,false,true],transitions,filtered_num:0}
+    }
+
+    #[test]
+    fn holds_test() {
+        let gen_ctx = get_test_context();
+        let formula = parse_ltl_formula(&gen_ctx,"G(l1!m1 -> X l2?m1)").unwrap();
+        assert_eq!(check_ltl_formula(&gen_ctx,&get_test_graph(),&formula),LtlCheckVerdict::Holds);
+    }
+
+    #[test]
+    fn finite_counter_example_test() {
+        let gen_ctx = get_test_context();
+        let formula = parse_ltl_formula(&gen_ctx,"F l2?m1").unwrap();
+        let expected = LtlCounterExample{prefix:vec![action(0,TraceActionKind::Emission,1),
+                                                     action(1,TraceActionKind::Reception,1)],
+                                         cycle:vec![]};
+        assert_eq!(check_ltl_formula(&gen_ctx,&get_test_graph(),&formula),LtlCheckVerdict::Violated(expected));
+    }
+
+    #[test]
+    fn lasso_counter_example_test() {
+        let gen_ctx = get_test_context();
+        let formula = parse_ltl_formula(&gen_ctx,"F l2?m2").unwrap();
+        match check_ltl_formula(&gen_ctx,&get_test_graph(),&formula) {
+            LtlCheckVerdict::Violated(counter_example) => {
+                assert_eq!(counter_example.cycle.len(),4);
+            },
+            LtlCheckVerdict::Holds => {
+                panic!("expected a lasso counter-example");
+            }
+        }
+    }
+
+}
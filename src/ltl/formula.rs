@@ -0,0 +1,188 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+
+
+/**
+Linear Temporal Logic formulae over trace actions.
+An atomic proposition is a pattern of action (a lifeline, a kind of action and a message or message type)
+which holds at a given position iff the action at this position is included in the pattern.
+**/
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum LtlFormula {
+    True,
+    False,
+    Atom(TraceAction),
+    Not(Box<LtlFormula>),
+    And(Box<LtlFormula>,Box<LtlFormula>),
+    Or(Box<LtlFormula>,Box<LtlFormula>),
+    Implies(Box<LtlFormula>,Box<LtlFormula>),
+    Next(Box<LtlFormula>),
+    Eventually(Box<LtlFormula>),
+    Globally(Box<LtlFormula>),
+    Until(Box<LtlFormula>,Box<LtlFormula>),
+    WeakUntil(Box<LtlFormula>,Box<LtlFormula>),
+    Release(Box<LtlFormula>,Box<LtlFormula>)
+}
+
+/**
+Formulae in Negation Normal Form, in which negations only occur on atomic propositions.
+Because behaviours may be finite, the next operator has a strong version (which requires
+a next position to exist) and a weak one (which holds at the last position of a finite behaviour).
+**/
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum LtlNnfFormula {
+    True,
+    False,
+    Atom(TraceAction),
+    NegAtom(TraceAction),
+    And(Box<LtlNnfFormula>,Box<LtlNnfFormula>),
+    Or(Box<LtlNnfFormula>,Box<LtlNnfFormula>),
+    Next(Box<LtlNnfFormula>),
+    WeakNext(Box<LtlNnfFormula>),
+    Until(Box<LtlNnfFormula>,Box<LtlNnfFormula>),
+    Release(Box<LtlNnfFormula>,Box<LtlNnfFormula>)
+}
+
+
+impl LtlFormula {
+
+    pub fn as_nnf(&self, negated : bool) -> LtlNnfFormula {
+        match self {
+            LtlFormula::True => {
+                if negated { LtlNnfFormula::False } else { LtlNnfFormula::True }
+            },
+            LtlFormula::False => {
+                if negated { LtlNnfFormula::True } else { LtlNnfFormula::False }
+            },
+            LtlFormula::Atom(pattern) => {
+                if negated {
+                    LtlNnfFormula::NegAtom(pattern.clone())
+                } else {
+                    LtlNnfFormula::Atom(pattern.clone())
+                }
+            },
+            LtlFormula::Not(sub) => {
+                sub.as_nnf(!negated)
+            },
+            LtlFormula::And(left,right) => {
+                let (new_left,new_right) = (Box::new(left.as_nnf(negated)),Box::new(right.as_nnf(negated)));
+                if negated { LtlNnfFormula::Or(new_left,new_right) } else { LtlNnfFormula::And(new_left,new_right) }
+            },
+            LtlFormula::Or(left,right) => {
+                let (new_left,new_right) = (Box::new(left.as_nnf(negated)),Box::new(right.as_nnf(negated)));
+                if negated { LtlNnfFormula::And(new_left,new_right) } else { LtlNnfFormula::Or(new_left,new_right) }
+            },
+            LtlFormula::Implies(left,right) => {
+                let (new_left,new_right) = (Box::new(left.as_nnf(!negated)),Box::new(right.as_nnf(negated)));
+                if negated { LtlNnfFormula::And(new_left,new_right) } else { LtlNnfFormula::Or(new_left,new_right) }
+            },
+            LtlFormula::Next(sub) => {
+                let new_sub = Box::new(sub.as_nnf(negated));
+                if negated { LtlNnfFormula::WeakNext(new_sub) } else { LtlNnfFormula::Next(new_sub) }
+            },
+            LtlFormula::Eventually(sub) => {
+                // F φ = true U φ and its negation is false R ¬φ
+                let new_sub = Box::new(sub.as_nnf(negated));
+                if negated {
+                    LtlNnfFormula::Release(Box::new(LtlNnfFormula::False),new_sub)
+                } else {
+                    LtlNnfFormula::Until(Box::new(LtlNnfFormula::True),new_sub)
+                }
+            },
+            LtlFormula::Globally(sub) => {
+                // G φ = false R φ and its negation is true U ¬φ
+                let new_sub = Box::new(sub.as_nnf(negated));
+                if negated {
+                    LtlNnfFormula::Until(Box::new(LtlNnfFormula::True),new_sub)
+                } else {
+                    LtlNnfFormula::Release(Box::new(LtlNnfFormula::False),new_sub)
+                }
+            },
+            LtlFormula::Until(left,right) => {
+                let (new_left,new_right) = (Box::new(left.as_nnf(negated)),Box::new(right.as_nnf(negated)));
+                if negated { LtlNnfFormula::Release(new_left,new_right) } else { LtlNnfFormula::Until(new_left,new_right) }
+            },
+            LtlFormula::Release(left,right) => {
+                let (new_left,new_right) = (Box::new(left.as_nnf(negated)),Box::new(right.as_nnf(negated)));
+                if negated { LtlNnfFormula::Until(new_left,new_right) } else { LtlNnfFormula::Release(new_left,new_right) }
+            },
+            LtlFormula::WeakUntil(left,right) => {
+                // φ W ψ = ψ R (φ | ψ)
+                let as_release = LtlFormula::Release(right.clone(),
+                                                     Box::new(LtlFormula::Or(left.clone(),right.clone())));
+                as_release.as_nnf(negated)
+            }
+        }
+    }
+
+}
+
+
+impl LtlNnfFormula {
+
+    /**
+    Whether or not the formula holds on the empty suffix which follows the last action of a finite behaviour.
+    Atomic propositions and strong operators (next and until) require a position to exist
+    while weak operators (weak next and release) are vacuously satisfied.
+    **/
+    pub fn holds_on_empty(&self) -> bool {
+        match self {
+            LtlNnfFormula::True => {
+                true
+            },
+            LtlNnfFormula::False => {
+                false
+            },
+            LtlNnfFormula::Atom(_) => {
+                false
+            },
+            LtlNnfFormula::NegAtom(_) => {
+                true
+            },
+            LtlNnfFormula::And(left,right) => {
+                left.holds_on_empty() && right.holds_on_empty()
+            },
+            LtlNnfFormula::Or(left,right) => {
+                left.holds_on_empty() || right.holds_on_empty()
+            },
+            LtlNnfFormula::Next(_) => {
+                false
+            },
+            LtlNnfFormula::WeakNext(_) => {
+                true
+            },
+            LtlNnfFormula::Until(_,_) => {
+                false
+            },
+            LtlNnfFormula::Release(_,_) => {
+                true
+            }
+        }
+    }
+
+}
+
+
+pub fn action_satisfies_pattern(gen_ctx : &GeneralContext,
+                                action : &TraceAction,
+                                pattern : &TraceAction) -> bool {
+    action.is_type_included(pattern,gen_ctx)
+}
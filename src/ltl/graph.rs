@@ -0,0 +1,203 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::any::Any;
+use std::collections::HashMap;
+
+use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
+use graph_process_manager_core::delegate::priorities::GenericProcessPriorities;
+use graph_process_manager_core::handler::filter::AbstractFilter;
+use graph_process_manager_core::manager::logger::AbstractProcessLogger;
+use graph_process_manager_core::manager::manager::GenericProcessManager;
+use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::input::hcf::explo::options::HibouExploreOptions;
+use crate::process::explo::conf::{ExplorationConfig, ExplorationStaticLocalVerdictAnalysisProof};
+use crate::process::explo::context::{ExplorationContext, ExplorationParameterization};
+use crate::process::explo::filter::elim::ExplorationFilterEliminationKind;
+use crate::process::explo::filter::filter::ExplorationFilterCriterion;
+use crate::process::explo::node::ExplorationNodeKind;
+use crate::process::explo::priorities::ExplorationPriorities;
+use crate::process::explo::step::ExplorationStepKind;
+use crate::process::explo::verdict::global::ExplorationGlobalVerdict;
+use crate::process::explo::verdict::local::ExplorationLocalVerdict;
+
+
+/**
+The graph explored from an interaction, in which transitions are labelled by trace actions.
+State 0 is the initial state and a state is accepting if its interaction accepts the empty trace.
+When memoization is used, the graph may contain cycles which represent infinite behaviours.
+**/
+pub struct ExplorationGraph {
+    pub accepting : Vec<bool>,
+    pub transitions : Vec<Vec<(TraceAction,usize)>>,
+    pub filtered_num : u32
+}
+
+impl ExplorationGraph {
+
+    pub fn get_states_num(&self) -> usize {
+        self.accepting.len()
+    }
+
+    pub fn get_transitions_num(&self) -> usize {
+        self.transitions.iter().map(|outgoing| outgoing.len()).sum()
+    }
+
+}
+
+
+/**
+Records the exploration graph in memory so that it can be analysed once the process terminates.
+**/
+pub struct ExplorationGraphLogger {
+    states_ids : HashMap<u32,usize>,
+    pub graph : ExplorationGraph
+}
+
+impl ExplorationGraphLogger {
+
+    pub fn new() -> Self {
+        ExplorationGraphLogger{states_ids:hashmap!{},
+                               graph:ExplorationGraph{accepting:vec![],transitions:vec![],filtered_num:0}}
+    }
+
+}
+
+
+impl AbstractProcessLogger<ExplorationConfig> for ExplorationGraphLogger {
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(&mut self) {
+        self.states_ids.clear();
+        self.graph = ExplorationGraph{accepting:vec![],transitions:vec![],filtered_num:0};
+    }
+
+    fn log_parameterization(&mut self,
+                            _strategy: &QueueSearchStrategy,
+                            _priorities: &GenericProcessPriorities<ExplorationPriorities>,
+                            _filters: &[Box<dyn AbstractFilter<ExplorationFilterCriterion, ExplorationFilterEliminationKind>>],
+                            _goal: &Option<ExplorationGlobalVerdict>,
+                            _use_memoization: bool,
+                            _parameterization: &ExplorationParameterization) {
+        // nothing
+    }
+
+    fn log_filtered(&mut self,
+                    _context: &ExplorationContext,
+                    _parent_node_id: u32,
+                    _new_node_id: u32,
+                    _elim_kind: &ExplorationFilterEliminationKind) {
+        self.graph.filtered_num += 1;
+    }
+
+    fn log_new_node(&mut self,
+                    _context: &ExplorationContext,
+                    _param: &ExplorationParameterization,
+                    new_node_id: u32,
+                    new_node: &ExplorationNodeKind) {
+        self.states_ids.insert(new_node_id,self.graph.accepting.len());
+        self.graph.accepting.push( new_node.interaction.express_empty() );
+        self.graph.transitions.push( vec![] );
+    }
+
+    fn log_new_step(&mut self,
+                    _context: &ExplorationContext,
+                    _param: &ExplorationParameterization,
+                    origin_node_id: u32,
+                    target_node_id: u32,
+                    step: &ExplorationStepKind,
+                    _target_node: &ExplorationNodeKind,
+                    _target_depth: u32) {
+        let origin = *self.states_ids.get(&origin_node_id).unwrap();
+        let target = *self.states_ids.get(&target_node_id).unwrap();
+        match step {
+            ExplorationStepKind::Execute(frt_elt) => {
+                self.graph.transitions[origin].push( (frt_elt.target_action.clone(),target) );
+            }
+        }
+    }
+
+    fn log_verdict_on_no_child(&mut self,
+                               _context: &ExplorationContext,
+                               _param: &ExplorationParameterization,
+                               _parent_node_id: u32,
+                               _verdict: &ExplorationLocalVerdict) {
+        // nothing
+    }
+
+    fn log_verdict_on_static_analysis(&mut self,
+                                      _context: &ExplorationContext,
+                                      _param: &ExplorationParameterization,
+                                      _parent_node_id: u32,
+                                      _verdict: &ExplorationLocalVerdict,
+                                      _proof: &ExplorationStaticLocalVerdictAnalysisProof) {
+        // nothing
+    }
+
+    fn log_terminate(&mut self,
+                     _global_verdict: &ExplorationGlobalVerdict) {
+        // nothing
+    }
+
+    fn log_notify_terminal_node_reached(&mut self,
+                                        _context: &ExplorationContext,
+                                        _node_id: u32) {
+        // nothing
+    }
+
+    fn log_notify_last_child_of_node_processed(&mut self,
+                                               _context: &ExplorationContext,
+                                               _parent_node_id: u32) {
+        // nothing
+    }
+}
+
+
+/**
+Explores the interaction with the given options and returns the explored graph.
+Actions are always concretized so that atomic propositions are evaluated on concrete actions
+and memoization is always used so that the behaviours of loops are folded into cycles of the graph.
+The filters of the options (e.g. on the maximum number of loop instances) bound the exploration.
+**/
+pub fn explore_interaction_graph(gen_ctx : &GeneralContext,
+                                 interaction : &Interaction,
+                                 mut explo_opts : HibouExploreOptions) -> ExplorationGraph {
+    explo_opts.param = ExplorationParameterization::new(true);
+    explo_opts.loggers = vec![Box::new(ExplorationGraphLogger::new())];
+    let explo_ctx = ExplorationContext::new(gen_ctx.clone());
+    let delegate : GenericProcessDelegate<ExplorationStepKind,ExplorationNodeKind,ExplorationPriorities> = GenericProcessDelegate::new(explo_opts.strategy,explo_opts.priorities);
+    let mut exploration_manager : GenericProcessManager<ExplorationConfig> = GenericProcessManager::new(explo_ctx,
+                                                                                                        explo_opts.param,
+                                                                                                        delegate,
+                                                                                                        explo_opts.filters,
+                                                                                                        explo_opts.loggers,
+                                                                                                        None,
+                                                                                                        true);
+    exploration_manager.start_process(ExplorationNodeKind::new(interaction.clone(),0));
+    let logger = exploration_manager.get_logger(0).unwrap().as_any().downcast_ref::<ExplorationGraphLogger>().unwrap();
+    ExplorationGraph{accepting:logger.graph.accepting.clone(),
+                     transitions:logger.graph.transitions.clone(),
+                     filtered_num:logger.graph.filtered_num}
+}
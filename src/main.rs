@@ -37,6 +37,8 @@ pub mod process;
 pub mod loggers;
 pub mod util;
 pub mod trace_manip;
+pub mod coverage;
+pub mod ltl;
 
 // **********
 
@@ -0,0 +1,130 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::{BTreeSet, HashSet};
+
+use crate::core::execution::semantics::position::position_as_text;
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::general_context::GeneralContext;
+use crate::coverage::model::{CoverageElementKind, InteractionCoverageModel};
+use crate::io::output::to_hfiles::trace::trace_action::trace_action_as_htf_encoding;
+use crate::process::ana::loggers::plog::logger::AcceptingExplanation;
+
+
+pub fn ambiguity_as_text_lines(gen_ctx : &GeneralContext,
+                               model : &InteractionCoverageModel,
+                               multi_trace : &MultiTrace,
+                               explanations : &Vec<AcceptingExplanation>,
+                               max_num : usize) -> Vec<String> {
+    let mut lines = vec![];
+    if explanations.is_empty() {
+        lines.push( "ambiguity  : multi-trace not accepted".to_string() );
+        return lines;
+    }
+    if explanations.len() == 1 {
+        lines.push( "ambiguity  : multi-trace unambiguously accepted".to_string() );
+    } else if explanations.len() >= max_num {
+        lines.push( format!("ambiguity  : multi-trace ambiguously accepted with at least {:} distinct paths", explanations.len()) );
+    } else {
+        lines.push( format!("ambiguity  : multi-trace ambiguously accepted with {:} distinct paths", explanations.len()) );
+    }
+    // ***
+    for (expl_id,explanation) in explanations.iter().enumerate() {
+        lines.push( "".to_string() );
+        lines.push( format!("PATH #{:}", expl_id) );
+        for ((canal_id,act_idx),leaves) in explanation {
+            lines.push( format!("  {:} : {:}",
+                                action_as_text(gen_ctx,multi_trace,*canal_id,*act_idx),
+                                leaves_as_text(model,leaves)) );
+        }
+    }
+    // ***
+    if let Some(reference) = explanations.first() {
+        for (expl_id,explanation) in explanations.iter().enumerate().skip(1) {
+            lines.push( "".to_string() );
+            lines.push( format!("CHOICES DIFFERING BETWEEN PATH #0 AND PATH #{:}", expl_id) );
+            lines.append( &mut differences_as_text_lines(gen_ctx,model,multi_trace,reference,explanation,expl_id) );
+        }
+    }
+    lines
+}
+
+
+fn differences_as_text_lines(gen_ctx : &GeneralContext,
+                             model : &InteractionCoverageModel,
+                             multi_trace : &MultiTrace,
+                             reference : &AcceptingExplanation,
+                             explanation : &AcceptingExplanation,
+                             expl_id : usize) -> Vec<String> {
+    let mut lines = vec![];
+    // sync choices : actions consumed by distinct leaves or by distinct synchronizations of leaves
+    for ((canal_id,act_idx),ref_leaves) in reference {
+        let leaves = explanation.get(&(*canal_id,*act_idx)).unwrap();
+        if leaves != ref_leaves {
+            let kind = if leaves.len() > 1 || ref_leaves.len() > 1 {"sync"} else {"leaf"};
+            lines.push( format!("  {:} choice for {:} : {:} in #0 vs {:} in #{:}",
+                                kind,
+                                action_as_text(gen_ctx,multi_trace,*canal_id,*act_idx),
+                                leaves_as_text(model,ref_leaves),
+                                leaves_as_text(model,leaves),
+                                expl_id) );
+        }
+    }
+    // alt choices : alternative branches taken in one path but not in the other
+    let ref_executed : HashSet<usize> = reference.values().flatten().cloned().collect();
+    let executed : HashSet<usize> = explanation.values().flatten().cloned().collect();
+    for element in &model.elements {
+        if element.kind != CoverageElementKind::AltBranch {
+            continue;
+        }
+        let in_ref = !element.leaves.is_disjoint(&ref_executed);
+        let in_expl = !element.leaves.is_disjoint(&executed);
+        if in_ref != in_expl {
+            lines.push( format!("  alt choice : branch at position {:} taken only in #{:}",
+                                position_as_text(&element.position),
+                                if in_ref {0} else {expl_id}) );
+        }
+    }
+    lines
+}
+
+
+fn action_as_text(gen_ctx : &GeneralContext,
+                  multi_trace : &MultiTrace,
+                  canal_id : usize,
+                  act_idx : usize) -> String {
+    let action = multi_trace.get(canal_id).unwrap().get(act_idx).unwrap();
+    format!("{:} (canal {:} ; index {:})", trace_action_as_htf_encoding(gen_ctx,action), canal_id, act_idx)
+}
+
+fn leaves_as_text(model : &InteractionCoverageModel,
+                  leaves : &BTreeSet<usize>) -> String {
+    let mut leaves_texts = vec![];
+    for leaf_id in leaves {
+        for element in &model.elements {
+            if element.kind == CoverageElementKind::Action && element.leaves.contains(leaf_id) {
+                leaves_texts.push( format!("'{:}' at position {:}", element.description, position_as_text(&element.position)) );
+            }
+        }
+    }
+    if leaves_texts.len() > 1 {
+        format!("sync of [{:}]", leaves_texts.join(" & "))
+    } else {
+        leaves_texts.join("")
+    }
+}
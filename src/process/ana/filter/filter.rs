@@ -31,6 +31,7 @@ impl fmt::Display for AnalysisFilterCriterion {
     }
 }
 
+#[derive(Clone, PartialEq, Debug)]
 pub enum AnalysisFilter {
     MaxLoopInstanciation(u32),
     MaxProcessDepth(u32),
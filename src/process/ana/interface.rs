@@ -0,0 +1,98 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
+use graph_process_manager_core::handler::filter::AbstractFilter;
+use graph_process_manager_core::manager::manager::GenericProcessManager;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::flags::MultiTraceAnalysisFlags;
+use crate::core::execution::trace::multitrace::{multi_trace_length, MultiTrace};
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+use crate::process::ana::conf::AnalysisConfig;
+use crate::process::ana::context::AnalysisContext;
+use crate::process::ana::filter::elim::AnalysisFilterEliminationKind;
+use crate::process::ana::filter::filter::AnalysisFilterCriterion;
+use crate::process::ana::node::node::AnalysisNodeKind;
+use crate::process::ana::priorities::AnalysisPriorities;
+use crate::process::ana::step::AnalysisStepKind;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+
+
+/**
+Sets up the manager of the analysis of a multi-trace w.r.t. an interaction with the given options
+and returns it along with the initial node from which the process is to be started.
+The loggers given in the options can then be retrieved from the manager once the process is over.
+**/
+pub fn make_analysis_manager(gen_ctx : &GeneralContext,
+                             co_localizations : &CoLocalizations,
+                             multi_trace : &MultiTrace,
+                             interaction : &Interaction,
+                             ana_opts : HibouAnalyzeOptions) -> (GenericProcessManager<AnalysisConfig>,AnalysisNodeKind) {
+    let multi_trace_length = multi_trace_length(multi_trace);
+    let ana_ctx = AnalysisContext::new(gen_ctx.clone(),
+                                       co_localizations.clone(),
+                                       multi_trace.clone(),
+                                       multi_trace_length);
+    let delegate : GenericProcessDelegate<AnalysisStepKind,AnalysisNodeKind,AnalysisPriorities> = GenericProcessDelegate::new(ana_opts.strategy,ana_opts.priorities);
+    // ***
+    let init_flags : MultiTraceAnalysisFlags;
+    match &ana_opts.ana_param.use_simulation {
+        None => {
+            init_flags = MultiTraceAnalysisFlags::new_init(co_localizations.num_colocs(),
+                                                           0,
+                                                           0);
+        },
+        Some( sim_config ) => {
+            init_flags = MultiTraceAnalysisFlags::new_init(co_localizations.num_colocs(),
+                                                           sim_config.get_reset_rem_loop(multi_trace_length,interaction),
+                                                           sim_config.get_reset_rem_act(multi_trace_length,interaction));
+        }
+    }
+    let mut filters : Vec<Box<dyn AbstractFilter<AnalysisFilterCriterion,AnalysisFilterEliminationKind>>> = Vec::new();
+    for filter in ana_opts.filters {
+        filters.push( Box::new(filter) );
+    }
+    let analysis_manager : GenericProcessManager<AnalysisConfig> = GenericProcessManager::new(ana_ctx,
+                                                                                              ana_opts.ana_param,
+                                                                                              delegate,
+                                                                                              filters,
+                                                                                              ana_opts.loggers,
+                                                                                              ana_opts.goal,
+                                                                                              ana_opts.use_memoization);
+    // ***
+    let init_node = AnalysisNodeKind::new(interaction.clone(),init_flags,0);
+    (analysis_manager,init_node)
+}
+
+
+/**
+Runs the analysis of a multi-trace w.r.t. an interaction with the given options
+and returns the number of nodes of the analysis graph and the global verdict.
+Utilities which analyze many multi-traces in a row may provide options without loggers.
+**/
+pub fn get_analysis_verdict(gen_ctx : &GeneralContext,
+                            co_localizations : &CoLocalizations,
+                            multi_trace : &MultiTrace,
+                            interaction : &Interaction,
+                            ana_opts : HibouAnalyzeOptions) -> (u32,AnalysisGlobalVerdict) {
+    let (mut analysis_manager,init_node) = make_analysis_manager(gen_ctx,co_localizations,multi_trace,interaction,ana_opts);
+    return analysis_manager.start_process(init_node);
+}
\ No newline at end of file
@@ -81,7 +81,9 @@ impl GraphVizProcessDrawer<AnalysisConfig> for InteractionProcessDrawer {
         let (static_ana_id,static_anchor_id) = self.get_static_analysis_ids(parent_state_id);
         let drawer = InteractionProcessDrawer::new("./temp".to_string(),
                                                    self.int_repr_sd,
-                                                   self.int_repr_tt);
+                                                   self.int_repr_tt,
+                                                   false,
+                                                   self.draw_conf.clone());
         let sub_graphic_logger: GenericGraphVizLogger<AnalysisConfig> = GenericGraphVizLogger::new(Box::new(drawer),
                                                                                                    GraphVizOutputFormat::png,
                                                                                                    GraphVizProcessLoggerLayout::Vertical,
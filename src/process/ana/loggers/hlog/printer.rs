@@ -0,0 +1,94 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::io::output::draw_traces::interface::multitrace_as_colored_texts;
+use crate::io::output::draw_transitions::draw_firing::firing_as_colored_text;
+use crate::loggers::html::printer::{HtmlProcessPrinter, InteractionHtmlPrinter};
+use crate::process::ana::conf::{AnalysisConfig, AnalysisStaticLocalVerdictAnalysisProof};
+use crate::process::ana::context::AnalysisContext;
+use crate::process::ana::node::node::AnalysisNodeKind;
+use crate::process::ana::param::anakind::{SimulationActionCriterion, SimulationLoopCriterion};
+use crate::process::ana::param::param::AnalysisParameterization;
+use crate::process::ana::step::AnalysisStepKind;
+
+
+fn get_simulation_flags(param : &AnalysisParameterization) -> (bool,bool,bool) {
+    match &param.use_simulation {
+        None => {(false,false,false)},
+        Some(sim_conf) => {
+            (true,sim_conf.loop_crit != SimulationLoopCriterion::None,sim_conf.act_crit != SimulationActionCriterion::None)
+        }
+    }
+}
+
+
+impl HtmlProcessPrinter<AnalysisConfig> for InteractionHtmlPrinter {
+
+    fn get_node_html(&self,
+                     context: &AnalysisContext,
+                     param: &AnalysisParameterization,
+                     node: &AnalysisNodeKind) -> String {
+        let (has_simulation,sim_crit_loop,sim_crit_act) = get_simulation_flags(param);
+        let mu_texts = multitrace_as_colored_texts(&context.gen_ctx,
+                                                   &self.draw_conf,
+                                                   &context.co_localizations,
+                                                   &context.multi_trace,
+                                                   &node.flags,
+                                                   has_simulation,
+                                                   sim_crit_loop,
+                                                   sim_crit_act);
+        format!("{:}{:}",
+                self.colored_texts_as_html(&mu_texts),
+                self.interaction_as_html(&context.gen_ctx,&node.interaction))
+    }
+
+    fn get_step_html(&self,
+                     context: &AnalysisContext,
+                     _param: &AnalysisParameterization,
+                     step: &AnalysisStepKind) -> String {
+        match step {
+            AnalysisStepKind::Execute(frt_elt,is_simu) => {
+                let firing = firing_as_colored_text(&context.gen_ctx,
+                                                    &self.draw_conf,
+                                                    &frt_elt.position,
+                                                    &frt_elt.target_action,
+                                                    is_simu.is_some());
+                self.colored_texts_as_html(&vec![firing])
+            }
+        }
+    }
+
+    fn get_static_analysis_html(&self,
+                                context: &AnalysisContext,
+                                param: &AnalysisParameterization,
+                                proof: &AnalysisStaticLocalVerdictAnalysisProof) -> Option<String> {
+        let (has_simulation,sim_crit_loop,sim_crit_act) = get_simulation_flags(param);
+        let mu_texts = multitrace_as_colored_texts(&context.gen_ctx,
+                                                   &self.draw_conf,
+                                                   &proof.local_coloc,
+                                                   &proof.local_multi_trace,
+                                                   &proof.local_flags,
+                                                   has_simulation,
+                                                   sim_crit_loop,
+                                                   sim_crit_act);
+        // the local analysis is performed on the interaction projected on the lifelines of the local multi-trace
+        Some( format!("<div class=\"node-content\">{:}{}</div>",
+                      self.colored_texts_as_html(&mu_texts),
+                      self.interaction_as_html(&context.gen_ctx,&proof.local_interaction)) )
+    }
+
+}
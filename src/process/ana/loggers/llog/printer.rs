@@ -0,0 +1,46 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::io::output::draw_traces::interface::text_repr_trace_action;
+use crate::io::output::to_hfiles::interaction::to_hif::interaction_as_hif_encoding;
+use crate::loggers::lts::printer::{InteractionLtsPrinter, LtsProcessPrinter};
+use crate::process::ana::conf::AnalysisConfig;
+use crate::process::ana::context::AnalysisContext;
+use crate::process::ana::node::node::AnalysisNodeKind;
+use crate::process::ana::step::AnalysisStepKind;
+
+
+impl LtsProcessPrinter<AnalysisConfig> for InteractionLtsPrinter {
+
+    fn get_state_repr(&self,
+                      context: &AnalysisContext,
+                      node: &AnalysisNodeKind) -> String {
+        interaction_as_hif_encoding(&context.gen_ctx,&node.interaction)
+    }
+
+    fn get_step_label(&self,
+                      context: &AnalysisContext,
+                      step: &AnalysisStepKind) -> String {
+        match step {
+            AnalysisStepKind::Execute(frt_elt,_) => {
+                text_repr_trace_action(&frt_elt.target_action,&context.gen_ctx)
+            }
+        }
+    }
+
+}
@@ -16,4 +16,8 @@ limitations under the License.
 
 
 pub mod glog;
-pub mod slog;
\ No newline at end of file
+pub mod slog;
+pub mod wlog;
+pub mod llog;
+pub mod hlog;
+pub mod plog;
\ No newline at end of file
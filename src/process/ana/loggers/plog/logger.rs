@@ -0,0 +1,358 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::any::Any;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use graph_process_manager_core::delegate::priorities::GenericProcessPriorities;
+use graph_process_manager_core::handler::filter::AbstractFilter;
+use graph_process_manager_core::manager::logger::AbstractProcessLogger;
+use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+
+use crate::core::execution::semantics::position::Position;
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::coverage::model::InteractionCoverageModel;
+use crate::coverage::step::execute_tagged_step;
+use crate::process::ana::conf::{AnalysisConfig, AnalysisStaticLocalVerdictAnalysisProof};
+use crate::process::ana::context::AnalysisContext;
+use crate::process::ana::filter::elim::AnalysisFilterEliminationKind;
+use crate::process::ana::filter::filter::AnalysisFilterCriterion;
+use crate::process::ana::node::node::AnalysisNodeKind;
+use crate::process::ana::param::param::AnalysisParameterization;
+use crate::process::ana::priorities::AnalysisPriorities;
+use crate::process::ana::step::AnalysisStepKind;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::process::ana::verdict::local::AnalysisLocalVerdict;
+
+
+/**
+An accepting explanation of a multi-trace associates to every action of the multi-trace
+(identified by its canal and its index on this canal) the action leaves of the original interaction
+which are executed to consume it (several leaves in case of synchronization).
+Accepting paths which only differ in the interleaving of actions on distinct canals
+yield the same explanation and are therefore not considered as distinct.
+**/
+pub type AcceptingExplanation = BTreeMap<(usize,usize),BTreeSet<usize>>;
+
+struct AcceptingPathStep {
+    target_node_id : u32,
+    position : Position,
+    canal_id : usize,
+    act_idx : usize,
+    action : TraceAction
+}
+
+type ExplanationsMemo = HashMap<(u32,Interaction),BTreeSet<AcceptingExplanation>>;
+
+type CoveredLeavesMemo = HashMap<(u32,Interaction),Option<HashSet<usize>>>;
+
+/**
+Keeps track of the analysis graph (including steps towards memoized nodes) and of the nodes with a 'Cov' verdict
+so as to retrieve, once the analysis is over, the paths accepting the multi-trace.
+Simulation steps are not retained given that a 'Cov' verdict cannot be reached through them.
+Those paths are then replayed on the tagged interaction of a coverage model
+to know which action leaves of the original interaction each of them executes.
+**/
+pub struct AnalysisAcceptingPathsLogger {
+    model : InteractionCoverageModel,
+    max_num : usize,
+    init_node_id : Option<u32>,
+    steps : HashMap<u32,Vec<AcceptingPathStep>>,
+    covering : HashSet<u32>,
+    // distinct accepting explanations, up to the maximum number
+    pub explanations : Vec<AcceptingExplanation>,
+    // action leaves executed on at least one accepting path, None if the multi-trace is not accepted
+    pub covered_leaves : Option<HashSet<usize>>
+}
+
+impl AnalysisAcceptingPathsLogger {
+
+    pub fn new(model : InteractionCoverageModel,
+               max_num : usize) -> AnalysisAcceptingPathsLogger {
+        AnalysisAcceptingPathsLogger{model,
+            max_num,
+            init_node_id:None,
+            steps:HashMap::new(),
+            covering:HashSet::new(),
+            explanations:vec![],
+            covered_leaves:None}
+    }
+
+    fn get_explanations(&self,
+                        node_id : u32,
+                        tagged_interaction : &Interaction,
+                        memo : &mut ExplanationsMemo) -> BTreeSet<AcceptingExplanation> {
+        let key = (node_id,tagged_interaction.clone());
+        if let Some(got) = memo.get(&key) {
+            return got.clone();
+        }
+        // ***
+        let mut explanations : BTreeSet<AcceptingExplanation> = BTreeSet::new();
+        if self.covering.contains(&node_id) {
+            explanations.insert( BTreeMap::new() );
+        }
+        if let Some(node_steps) = self.steps.get(&node_id) {
+            for step in node_steps {
+                let (step_leaves,new_int) = execute_tagged_step(&self.model,tagged_interaction,&step.position,&step.action);
+                let step_leaves : BTreeSet<usize> = step_leaves.into_iter().collect();
+                for mut suffix in self.get_explanations(step.target_node_id,&new_int,memo) {
+                    // keeping at most max_num suffixes for each node is enough given that
+                    // distinct suffixes yield distinct explanations once prefixed with the same step
+                    suffix.insert((step.canal_id,step.act_idx),step_leaves.clone());
+                    explanations.insert(suffix);
+                    if explanations.len() >= self.max_num {
+                        memo.insert(key,explanations.clone());
+                        return explanations;
+                    }
+                }
+            }
+        }
+        // ***
+        memo.insert(key,explanations.clone());
+        explanations
+    }
+
+    fn get_covered_leaves(&self,
+                          node_id : u32,
+                          tagged_interaction : &Interaction,
+                          memo : &mut CoveredLeavesMemo) -> Option<HashSet<usize>> {
+        let key = (node_id,tagged_interaction.clone());
+        if let Some(got) = memo.get(&key) {
+            return got.clone();
+        }
+        // ***
+        let mut covered : Option<HashSet<usize>> = None;
+        if self.covering.contains(&node_id) {
+            covered = Some( hashset!{} );
+        }
+        if let Some(node_steps) = self.steps.get(&node_id) {
+            for step in node_steps {
+                let (step_leaves,new_int) = execute_tagged_step(&self.model,tagged_interaction,&step.position,&step.action);
+                if let Some(child_covered) = self.get_covered_leaves(step.target_node_id,&new_int,memo) {
+                    let mut got = covered.unwrap_or(hashset!{});
+                    got.extend(child_covered);
+                    got.extend(step_leaves);
+                    covered = Some(got);
+                }
+            }
+        }
+        // ***
+        memo.insert(key,covered.clone());
+        covered
+    }
+
+}
+
+impl AbstractProcessLogger<AnalysisConfig> for AnalysisAcceptingPathsLogger {
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(&mut self) {
+        self.init_node_id = None;
+        self.steps.clear();
+        self.covering.clear();
+        self.explanations = vec![];
+        self.covered_leaves = None;
+    }
+
+    fn log_parameterization(&mut self,
+                            _strategy: &QueueSearchStrategy,
+                            _priorities: &GenericProcessPriorities<AnalysisPriorities>,
+                            _filters: &[Box<dyn AbstractFilter<AnalysisFilterCriterion, AnalysisFilterEliminationKind>>],
+                            _goal: &Option<AnalysisGlobalVerdict>,
+                            _use_memoization: bool,
+                            _parameterization: &AnalysisParameterization) {
+        // nothing
+    }
+
+    fn log_filtered(&mut self,
+                    _context: &AnalysisContext,
+                    _parent_node_id: u32,
+                    _new_node_id: u32,
+                    _elim_kind: &AnalysisFilterEliminationKind) {
+        // nothing
+    }
+
+    fn log_new_node(&mut self,
+                    _context: &AnalysisContext,
+                    _param: &AnalysisParameterization,
+                    new_node_id: u32,
+                    _new_node: &AnalysisNodeKind) {
+        if self.init_node_id.is_none() {
+            self.init_node_id = Some(new_node_id);
+        }
+    }
+
+    fn log_new_step(&mut self,
+                    context: &AnalysisContext,
+                    _param: &AnalysisParameterization,
+                    origin_node_id: u32,
+                    target_node_id: u32,
+                    step: &AnalysisStepKind,
+                    target_node: &AnalysisNodeKind,
+                    _target_depth: u32) {
+        match step {
+            AnalysisStepKind::Execute(frt_elt,None) => {
+                // the executed action is the one which has just been consumed on its canal
+                let canal_id = context.co_localizations.get_lf_coloc_id(frt_elt.target_action.lf_id).unwrap();
+                let act_idx = target_node.flags.canals.get(canal_id).unwrap().consumed - 1;
+                let action = context.multi_trace.get(canal_id).unwrap().get(act_idx).unwrap().clone();
+                let path_step = AcceptingPathStep{target_node_id,position:frt_elt.position.clone(),canal_id,act_idx,action};
+                self.steps.entry(origin_node_id).or_insert_with(Vec::new).push(path_step);
+            },
+            AnalysisStepKind::Execute(_,Some(_)) => {
+                // nothing
+            }
+        }
+    }
+
+    fn log_verdict_on_no_child(&mut self,
+                               _context: &AnalysisContext,
+                               _param: &AnalysisParameterization,
+                               parent_node_id: u32,
+                               verdict: &AnalysisLocalVerdict) {
+        if matches!(verdict, AnalysisLocalVerdict::Cov) {
+            self.covering.insert(parent_node_id);
+        }
+    }
+
+    fn log_verdict_on_static_analysis(&mut self,
+                                      _context: &AnalysisContext,
+                                      _param: &AnalysisParameterization,
+                                      _parent_node_id: u32,
+                                      _verdict: &AnalysisLocalVerdict,
+                                      _proof: &AnalysisStaticLocalVerdictAnalysisProof) {
+        // nothing
+    }
+
+    fn log_terminate(&mut self,
+                     _global_verdict: &AnalysisGlobalVerdict) {
+        if let Some(init_node_id) = self.init_node_id {
+            let tagged_interaction = self.model.tagged_interaction.clone();
+            let explanations = self.get_explanations(init_node_id,&tagged_interaction,&mut HashMap::new());
+            self.explanations = explanations.into_iter().collect();
+            self.covered_leaves = self.get_covered_leaves(init_node_id,&tagged_interaction,&mut HashMap::new());
+        }
+    }
+
+    fn log_notify_terminal_node_reached(&mut self,
+                                        _context: &AnalysisContext,
+                                        _node_id: u32) {
+        // nothing
+    }
+
+    fn log_notify_last_child_of_node_processed(&mut self,
+                                               _context: &AnalysisContext,
+                                               _parent_node_id: u32) {
+        // nothing
+    }
+
+}
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::core::execution::trace::multitrace::MultiTrace;
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::BroadcastPrimitive;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use crate::coverage::model::InteractionCoverageModel;
+    use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+    use crate::process::ana::interface::make_analysis_manager;
+    use super::{AcceptingExplanation, AnalysisAcceptingPathsLogger};
+
+    fn message(orig_lf_id : usize, ms_id : usize, tar_lf_id : usize) -> Box<Interaction> {
+        Box::new(Interaction::Action(BroadcastPrimitive::new(Some(orig_lf_id),
+                                                             MessageTypeExpression::Singleton(ms_id),
+                                                             vec![tar_lf_id])))
+    }
+
+    fn action(lf_id : usize, act_kind : TraceActionKind, ms_id : usize) -> TraceAction {
+        TraceAction::new(lf_id,act_kind,MessageTypeExpression::Singleton(ms_id))
+    }
+
+    fn get_explanations(gen_ctx : &GeneralContext,
+                        int : &Interaction,
+                        multi_trace : &MultiTrace,
+                        max_num : usize) -> Vec<AcceptingExplanation> {
+        let model = InteractionCoverageModel::new(gen_ctx,int);
+        let mut ana_opts = HibouAnalyzeOptions::default();
+        ana_opts.goal = None;
+        ana_opts.loggers.push( Box::new(AnalysisAcceptingPathsLogger::new(model,max_num)) );
+        let (mut analysis_manager,init_node) = make_analysis_manager(gen_ctx,
+                                                                     &CoLocalizations::get_discrete_partition(2),
+                                                                     multi_trace,
+                                                                     int,
+                                                                     ana_opts);
+        analysis_manager.start_process(init_node);
+        analysis_manager.get_logger(0).unwrap()
+            .as_any().downcast_ref::<AnalysisAcceptingPathsLogger>().unwrap()
+            .explanations.clone()
+    }
+
+    fn get_context() -> GeneralContext {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        gen_ctx
+    }
+
+    #[test]
+    fn ambiguous_multi_trace_test() {
+        let gen_ctx = get_context();
+        // alt(l1 -- {m1,m2} -> l2, l1 -- m1 -> l2)
+        // identical alt branches would be merged by the frontier, hence the distinct message types
+        let m1_or_m2 = MessageTypeExpression::Union(Box::new(MessageTypeExpression::Singleton(0)),
+                                                    Box::new(MessageTypeExpression::Singleton(1)));
+        let int = Interaction::Alt(Box::new(Interaction::Action(BroadcastPrimitive::new(Some(0),m1_or_m2,vec![1]))),
+                                   message(0,0,1));
+        let multi_trace : MultiTrace = vec![vec![action(0,TraceActionKind::Emission,0)],
+                                            vec![action(1,TraceActionKind::Reception,0)]];
+        let explanations = get_explanations(&gen_ctx,&int,&multi_trace,10);
+        // each alt branch explains the multi-trace
+        assert_eq!(explanations.len(),2);
+        assert_ne!(explanations.get(0),explanations.get(1));
+        // the maximum number of explanations is respected
+        assert_eq!(get_explanations(&gen_ctx,&int,&multi_trace,1).len(),1);
+    }
+
+    #[test]
+    fn unambiguous_multi_trace_test() {
+        let gen_ctx = get_context();
+        // seq(l1 -- m1 -> l2, l1 -- m2 -> l2)
+        let int = Interaction::CoReg(vec![],message(0,0,1),message(0,1,1));
+        let accepted : MultiTrace = vec![vec![action(0,TraceActionKind::Emission,0),action(0,TraceActionKind::Emission,1)],
+                                         vec![action(1,TraceActionKind::Reception,0),action(1,TraceActionKind::Reception,1)]];
+        assert_eq!(get_explanations(&gen_ctx,&int,&accepted,10).len(),1);
+        // no explanation for a multi-trace which is not accepted
+        let rejected : MultiTrace = vec![vec![action(0,TraceActionKind::Emission,1)],
+                                         vec![action(1,TraceActionKind::Reception,1)]];
+        assert!(get_explanations(&gen_ctx,&int,&rejected,10).is_empty());
+    }
+
+}
@@ -0,0 +1,248 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::any::Any;
+use std::collections::HashMap;
+
+use graph_process_manager_core::delegate::priorities::GenericProcessPriorities;
+use graph_process_manager_core::handler::filter::AbstractFilter;
+use graph_process_manager_core::manager::logger::AbstractProcessLogger;
+use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+
+use crate::core::execution::witness::witness::{ExecutionWitness, ExecutionWitnessStep};
+use crate::process::ana::conf::{AnalysisConfig, AnalysisStaticLocalVerdictAnalysisProof};
+use crate::process::ana::context::AnalysisContext;
+use crate::process::ana::filter::elim::AnalysisFilterEliminationKind;
+use crate::process::ana::filter::filter::AnalysisFilterCriterion;
+use crate::process::ana::node::node::AnalysisNodeKind;
+use crate::process::ana::param::param::AnalysisParameterization;
+use crate::process::ana::priorities::AnalysisPriorities;
+use crate::process::ana::step::AnalysisStepKind;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::process::ana::verdict::local::AnalysisLocalVerdict;
+
+
+/**
+Keeps track, for each node, of the step via which it was first reached
+so as to retrieve the execution path leading to the first node with a 'Cov' verdict.
+Simulation steps are not retained given that a 'Cov' verdict cannot be reached through them.
+If a memoized node which was first reached through a simulation step is later reached through a step without simulation,
+the latter replaces it, provided that it reaches the node with the same number of consumed actions on every canal
+(otherwise the path would skip the actions consumed in-between).
+Given that steps without simulation consume an action, there cannot be cycles among the retained steps.
+**/
+pub struct AnalysisWitnessLogger {
+    // for each node : its parent, the step from the parent (if not a simulation step)
+    // and the number of actions consumed on each canal when reaching it
+    parents : HashMap<u32,(u32,Option<ExecutionWitnessStep>,Vec<usize>)>,
+    pub witness : Option<ExecutionWitness>
+}
+
+impl AnalysisWitnessLogger {
+
+    pub fn new() -> AnalysisWitnessLogger {
+        AnalysisWitnessLogger{parents:HashMap::new(),witness:None}
+    }
+
+    fn retrieve_witness(&self, node_id : u32) -> Option<ExecutionWitness> {
+        let mut witness : ExecutionWitness = vec![];
+        let mut current_id = node_id;
+        while let Some((parent_id,got_step,_)) = self.parents.get(&current_id) {
+            match got_step {
+                None => {
+                    return None;
+                },
+                Some( step ) => {
+                    witness.push( step.clone() );
+                }
+            }
+            current_id = *parent_id;
+        }
+        witness.reverse();
+        Some(witness)
+    }
+
+}
+
+impl AbstractProcessLogger<AnalysisConfig> for AnalysisWitnessLogger {
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(&mut self) {
+        self.parents.clear();
+        self.witness = None;
+    }
+
+    fn log_parameterization(&mut self,
+                            _strategy: &QueueSearchStrategy,
+                            _priorities: &GenericProcessPriorities<AnalysisPriorities>,
+                            _filters: &[Box<dyn AbstractFilter<AnalysisFilterCriterion, AnalysisFilterEliminationKind>>],
+                            _goal: &Option<AnalysisGlobalVerdict>,
+                            _use_memoization: bool,
+                            _parameterization: &AnalysisParameterization) {
+        // nothing
+    }
+
+    fn log_filtered(&mut self,
+                    _context: &AnalysisContext,
+                    _parent_node_id: u32,
+                    _new_node_id: u32,
+                    _elim_kind: &AnalysisFilterEliminationKind) {
+        // nothing
+    }
+
+    fn log_new_node(&mut self,
+                    _context: &AnalysisContext,
+                    _param: &AnalysisParameterization,
+                    _new_node_id: u32,
+                    _new_node: &AnalysisNodeKind) {
+        // nothing
+    }
+
+    fn log_new_step(&mut self,
+                    context: &AnalysisContext,
+                    _param: &AnalysisParameterization,
+                    origin_node_id: u32,
+                    target_node_id: u32,
+                    step: &AnalysisStepKind,
+                    target_node: &AnalysisNodeKind,
+                    _target_depth: u32) {
+        let got_step = match step {
+            AnalysisStepKind::Execute(frt_elt,None) => {
+                // the executed action is the one which has just been consumed on its canal
+                let canal_id = context.co_localizations.get_lf_coloc_id(frt_elt.target_action.lf_id).unwrap();
+                let consumed = target_node.flags.canals.get(canal_id).unwrap().consumed;
+                let action = context.multi_trace.get(canal_id).unwrap().get(consumed - 1).unwrap();
+                Some( ExecutionWitnessStep::new(frt_elt.position.clone(),action.clone()) )
+            },
+            AnalysisStepKind::Execute(_,Some(_)) => {
+                None
+            }
+        };
+        let consumed : Vec<usize> = target_node.flags.canals.iter().map(|canal| canal.consumed).collect();
+        match self.parents.get(&target_node_id) {
+            None => {
+                self.parents.insert(target_node_id,(origin_node_id,got_step,consumed));
+            },
+            Some( (_,None,memo_consumed) ) => {
+                // the memoized node was first reached through a simulation step
+                if got_step.is_some() && *memo_consumed == consumed {
+                    self.parents.insert(target_node_id,(origin_node_id,got_step,consumed));
+                }
+            },
+            Some( (_,Some(_),_) ) => {
+                // the memoized node was already reached without simulation
+            }
+        }
+    }
+
+    fn log_verdict_on_no_child(&mut self,
+                               _context: &AnalysisContext,
+                               _param: &AnalysisParameterization,
+                               parent_node_id: u32,
+                               verdict: &AnalysisLocalVerdict) {
+        if self.witness.is_none() && matches!(verdict, AnalysisLocalVerdict::Cov) {
+            self.witness = self.retrieve_witness(parent_node_id);
+        }
+    }
+
+    fn log_verdict_on_static_analysis(&mut self,
+                                      _context: &AnalysisContext,
+                                      _param: &AnalysisParameterization,
+                                      _parent_node_id: u32,
+                                      _verdict: &AnalysisLocalVerdict,
+                                      _proof: &AnalysisStaticLocalVerdictAnalysisProof) {
+        // nothing
+    }
+
+    fn log_terminate(&mut self,
+                     _global_verdict: &AnalysisGlobalVerdict) {
+        // nothing
+    }
+
+    fn log_notify_terminal_node_reached(&mut self,
+                                        _context: &AnalysisContext,
+                                        _node_id: u32) {
+        // nothing
+    }
+
+    fn log_notify_last_child_of_node_processed(&mut self,
+                                               _context: &AnalysisContext,
+                                               _parent_node_id: u32) {
+        // nothing
+    }
+
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use graph_process_manager_core::manager::logger::AbstractProcessLogger;
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::core::execution::semantics::frontier::FrontierElement;
+    use crate::core::execution::semantics::position::Position;
+    use crate::core::execution::trace::flags::{MultiTraceAnalysisFlags, TraceAnalysisFlags};
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::execution::witness::witness::ExecutionWitnessStep;
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use crate::process::ana::context::AnalysisContext;
+    use crate::process::ana::node::node::AnalysisNodeKind;
+    use crate::process::ana::param::param::AnalysisParameterization;
+    use crate::process::ana::step::{AnalysisStepKind, SimulationStepKind};
+    use crate::process::ana::verdict::local::AnalysisLocalVerdict;
+    use super::AnalysisWitnessLogger;
+
+    fn node(consumed : usize) -> AnalysisNodeKind {
+        AnalysisNodeKind::new(Interaction::Empty,
+                              MultiTraceAnalysisFlags::new(vec![TraceAnalysisFlags::new(consumed,false,false,0,0)],0,0),
+                              0)
+    }
+
+    #[test]
+    fn simulated_parent_replaced_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        let action = TraceAction::new(0,TraceActionKind::Emission,MessageTypeExpression::Singleton(0));
+        let context = AnalysisContext::new(gen_ctx,CoLocalizations::get_discrete_partition(1),vec![vec![action.clone()]],1);
+        let param = AnalysisParameterization::new(None,false);
+        let execute = |position : Position, simulation : Option<SimulationStepKind>| {
+            AnalysisStepKind::Execute(FrontierElement::new(position,action.clone(),0),simulation)
+        };
+        // ***
+        let mut logger = AnalysisWitnessLogger::new();
+        logger.log_initialize();
+        // node 3 is first reached through a simulation step from node 2
+        logger.log_new_step(&context,&param,1,2,&execute(Position::Left(Box::new(Position::Epsilon(None))),None),&node(1),1);
+        logger.log_new_step(&context,&param,2,3,&execute(Position::Epsilon(None),Some(SimulationStepKind::AfterEnd)),&node(1),2);
+        // then it is reached without simulation from node 1
+        logger.log_new_step(&context,&param,1,3,&execute(Position::Right(Box::new(Position::Epsilon(None))),None),&node(1),1);
+        // which does not replace the path through node 2 for node 2 itself
+        logger.log_new_step(&context,&param,1,2,&execute(Position::Right(Box::new(Position::Epsilon(None))),None),&node(1),1);
+        // ***
+        logger.log_verdict_on_no_child(&context,&param,3,&AnalysisLocalVerdict::Cov);
+        assert_eq!(logger.witness,
+                   Some(vec![ExecutionWitnessStep::new(Position::Right(Box::new(Position::Epsilon(None))),action.clone())]));
+        assert_eq!(logger.retrieve_witness(2),
+                   Some(vec![ExecutionWitnessStep::new(Position::Left(Box::new(Position::Epsilon(None))),action)]));
+    }
+}
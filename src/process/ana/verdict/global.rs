@@ -21,7 +21,7 @@ use graph_process_manager_core::manager::verdict::AbstractGlobalVerdict;
 use crate::process::ana::verdict::inconc::InconcReason;
 use crate::process::ana::verdict::local::AnalysisLocalVerdict;
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub enum AnalysisGlobalVerdict{
     Fail,
     WeakFail,
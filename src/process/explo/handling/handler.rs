@@ -20,9 +20,7 @@ use graph_process_manager_core::handler::handler::AbstractProcessHandler;
 use graph_process_manager_core::queued_steps::step::GenericStep;
 
 use crate::core::execution::semantics::execute::execute_interaction;
-use crate::core::execution::semantics::frontier::{FrontierElement, global_frontier};
-use crate::core::execution::trace::trace::TraceAction;
-use crate::core::message::MessageTypeExpression;
+use crate::core::execution::semantics::frontier::{concretize_frontier, global_frontier};
 use crate::process::explo::conf::{ExplorationConfig, ExplorationStaticLocalVerdictAnalysisProof};
 use crate::process::explo::context::{ExplorationContext, ExplorationParameterization};
 use crate::process::explo::filter::filter::ExplorationFilterCriterion;
@@ -77,24 +75,7 @@ impl AbstractProcessHandler<ExplorationConfig> for ExplorationProcessHandler {
         glob_front.reverse();
         // ***
         let new_front = if param.concretize {
-            let mut got = vec![];
-            for frt_elt in glob_front {
-                let resolved = frt_elt.target_action.message.resolve(&context.gen_ctx);
-                assert!(!resolved.is_empty());
-                if resolved.len() == 1 {
-                    got.push(frt_elt)
-                } else {
-                    for ms_id in resolved {
-                        let new_frt_elt = FrontierElement::new(frt_elt.position.clone(),
-                        TraceAction::new(frt_elt.target_action.lf_id,
-                                         frt_elt.target_action.act_kind,
-                                         MessageTypeExpression::Singleton(ms_id)),
-                                                               frt_elt.max_loop_depth);
-                        got.push(new_frt_elt)
-                    }
-                }
-            }
-            got
+            concretize_frontier(glob_front,&context.gen_ctx)
         } else {
             glob_front
         };
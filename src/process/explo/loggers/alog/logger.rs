@@ -0,0 +1,181 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+
+use graph_process_manager_core::delegate::priorities::GenericProcessPriorities;
+use graph_process_manager_core::handler::filter::AbstractFilter;
+use graph_process_manager_core::manager::logger::AbstractProcessLogger;
+use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+use graph_process_manager_loggers::stepstrace::printer::StepsTraceProcessPrinter;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::loggers::tracegen::object::TraceGenLoggerObject;
+use crate::loggers::tracegen::printer::{MultiTraceProcessPrinter, TracegenProcessLoggerGeneration};
+use crate::process::explo::conf::{ExplorationConfig, ExplorationStaticLocalVerdictAnalysisProof};
+use crate::process::explo::context::{ExplorationContext, ExplorationParameterization};
+use crate::process::explo::filter::elim::ExplorationFilterEliminationKind;
+use crate::process::explo::filter::filter::ExplorationFilterCriterion;
+use crate::process::explo::node::ExplorationNodeKind;
+use crate::process::explo::priorities::ExplorationPriorities;
+use crate::process::explo::step::ExplorationStepKind;
+use crate::process::explo::verdict::global::ExplorationGlobalVerdict;
+use crate::process::explo::verdict::local::ExplorationLocalVerdict;
+
+
+/**
+Keeps in memory the distinct multi-traces which are accepted by the explored interaction,
+i.e. those of the paths leading to nodes whose interaction expresses the empty execution.
+The multi-traces are built by the tracegen printer in its 'accepted' mode, as in the tracegen logger,
+which cannot be used as is because it prints them into files and only along the first path reaching each node
+whereas memoized nodes may also be accepting via other paths.
+The multi-trace of a node is only kept until all of its children are processed.
+**/
+pub struct ExplorationAcceptedMultiTracesLogger {
+    printer : Box<dyn StepsTraceProcessPrinter<ExplorationConfig,TraceGenLoggerObject>>,
+    init_node_reached : bool,
+    multi_traces : HashMap<u32,TraceGenLoggerObject>,
+    got : HashSet<MultiTrace>,
+    // distinct accepted multi-traces, in the order in which they are reached
+    pub accepted : Vec<MultiTrace>
+}
+
+impl ExplorationAcceptedMultiTracesLogger {
+
+    pub fn new(co_localizations : CoLocalizations) -> ExplorationAcceptedMultiTracesLogger {
+        ExplorationAcceptedMultiTracesLogger{
+            printer:Box::new(MultiTraceProcessPrinter::new(co_localizations,TracegenProcessLoggerGeneration::accepted)),
+            init_node_reached:false,
+            multi_traces:HashMap::new(),
+            got:HashSet::new(),
+            accepted:vec![]}
+    }
+
+    fn add_if_accepted(&mut self,
+                       context : &ExplorationContext,
+                       param : &ExplorationParameterization,
+                       node : &ExplorationNodeKind,
+                       node_depth : u32,
+                       object : &TraceGenLoggerObject) {
+        if self.printer.should_print_on_node_reached(context,param,node,node_depth)
+            && self.got.insert(object.mu.clone()) {
+            self.accepted.push(object.mu.clone());
+        }
+    }
+
+}
+
+impl AbstractProcessLogger<ExplorationConfig> for ExplorationAcceptedMultiTracesLogger {
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(&mut self) {
+        self.init_node_reached = false;
+        self.multi_traces.clear();
+        self.got.clear();
+        self.accepted = vec![];
+    }
+
+    fn log_parameterization(&mut self,
+                            _strategy: &QueueSearchStrategy,
+                            _priorities: &GenericProcessPriorities<ExplorationPriorities>,
+                            _filters: &[Box<dyn AbstractFilter<ExplorationFilterCriterion, ExplorationFilterEliminationKind>>],
+                            _goal: &Option<ExplorationGlobalVerdict>,
+                            _use_memoization: bool,
+                            _parameterization: &ExplorationParameterization) {
+        // nothing
+    }
+
+    fn log_filtered(&mut self,
+                    _context: &ExplorationContext,
+                    _parent_node_id: u32,
+                    _new_node_id: u32,
+                    _elim_kind: &ExplorationFilterEliminationKind) {
+        // nothing
+    }
+
+    fn log_new_node(&mut self,
+                    context: &ExplorationContext,
+                    param: &ExplorationParameterization,
+                    new_node_id: u32,
+                    new_node: &ExplorationNodeKind) {
+        if !self.init_node_reached {
+            // the initial node is reached via the empty multi-trace
+            self.init_node_reached = true;
+            let empty = self.printer.get_initial_object(context,param,new_node);
+            self.add_if_accepted(context,param,new_node,0,&empty);
+            self.multi_traces.insert(new_node_id,empty);
+        }
+    }
+
+    fn log_new_step(&mut self,
+                    context: &ExplorationContext,
+                    param: &ExplorationParameterization,
+                    origin_node_id: u32,
+                    target_node_id: u32,
+                    step: &ExplorationStepKind,
+                    target_node: &ExplorationNodeKind,
+                    target_depth: u32) {
+        let object = self.printer.add_step_to_object(context,
+                                                     param,
+                                                     self.multi_traces.get(&origin_node_id).unwrap(),
+                                                     step);
+        // a memoized target is accepting via this new multi-trace as well
+        self.add_if_accepted(context,param,target_node,target_depth,&object);
+        self.multi_traces.entry(target_node_id).or_insert(object);
+    }
+
+    fn log_verdict_on_no_child(&mut self,
+                               _context: &ExplorationContext,
+                               _param: &ExplorationParameterization,
+                               parent_node_id: u32,
+                               _verdict: &ExplorationLocalVerdict) {
+        self.multi_traces.remove(&parent_node_id);
+    }
+
+    fn log_verdict_on_static_analysis(&mut self,
+                                      _context: &ExplorationContext,
+                                      _param: &ExplorationParameterization,
+                                      _parent_node_id: u32,
+                                      _verdict: &ExplorationLocalVerdict,
+                                      _proof: &ExplorationStaticLocalVerdictAnalysisProof) {
+        // nothing
+    }
+
+    fn log_terminate(&mut self,
+                     _global_verdict: &ExplorationGlobalVerdict) {
+        self.multi_traces.clear();
+    }
+
+    fn log_notify_terminal_node_reached(&mut self,
+                                        _context: &ExplorationContext,
+                                        _node_id: u32) {
+        // nothing
+    }
+
+    fn log_notify_last_child_of_node_processed(&mut self,
+                                               _context: &ExplorationContext,
+                                               parent_node_id: u32) {
+        self.multi_traces.remove(&parent_node_id);
+    }
+
+}
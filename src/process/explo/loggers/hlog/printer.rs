@@ -0,0 +1,58 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::io::output::draw_transitions::draw_firing::firing_as_colored_text;
+use crate::loggers::html::printer::{HtmlProcessPrinter, InteractionHtmlPrinter};
+use crate::process::explo::conf::{ExplorationConfig, ExplorationStaticLocalVerdictAnalysisProof};
+use crate::process::explo::context::{ExplorationContext, ExplorationParameterization};
+use crate::process::explo::node::ExplorationNodeKind;
+use crate::process::explo::step::ExplorationStepKind;
+
+
+impl HtmlProcessPrinter<ExplorationConfig> for InteractionHtmlPrinter {
+
+    fn get_node_html(&self,
+                     context: &ExplorationContext,
+                     _param: &ExplorationParameterization,
+                     node: &ExplorationNodeKind) -> String {
+        self.interaction_as_html(&context.gen_ctx,&node.interaction)
+    }
+
+    fn get_step_html(&self,
+                     context: &ExplorationContext,
+                     _param: &ExplorationParameterization,
+                     step: &ExplorationStepKind) -> String {
+        match step {
+            ExplorationStepKind::Execute(frt_elt) => {
+                let firing = firing_as_colored_text(&context.gen_ctx,
+                                                    &self.draw_conf,
+                                                    &frt_elt.position,
+                                                    &frt_elt.target_action,
+                                                    false);
+                self.colored_texts_as_html(&vec![firing])
+            }
+        }
+    }
+
+    fn get_static_analysis_html(&self,
+                                _context: &ExplorationContext,
+                                _param: &ExplorationParameterization,
+                                _proof: &ExplorationStaticLocalVerdictAnalysisProof) -> Option<String> {
+        None
+    }
+
+}
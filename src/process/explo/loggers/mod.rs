@@ -17,3 +17,7 @@ limitations under the License.
 
 pub mod glog;
 pub mod slog;
+
+pub mod llog;
+pub mod hlog;
+pub mod alog;
\ No newline at end of file
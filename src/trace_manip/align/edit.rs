@@ -0,0 +1,72 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::execution::semantics::position::Position;
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_hfiles::trace::trace_action::trace_action_as_htf_encoding;
+
+
+/**
+An edit move of an alignment between a multi-trace and an interaction.
+Matching a trace action against the model is free while deleting a trace action
+or inserting (i.e. simulating) a model action each costs one.
+**/
+#[derive(Clone, PartialEq, Debug)]
+pub enum AlignmentEditMove {
+    Match(usize,TraceAction,Position),
+    Delete(usize,TraceAction),
+    Insert(usize,TraceAction,Position)
+}
+
+impl AlignmentEditMove {
+
+    pub fn get_cost(&self) -> u32 {
+        match self {
+            AlignmentEditMove::Match(_,_,_) => {
+                0
+            },
+            _ => {
+                1
+            }
+        }
+    }
+
+    pub fn as_text(&self, gen_ctx : &GeneralContext) -> String {
+        match self {
+            AlignmentEditMove::Match(canal_id,action,position) => {
+                format!("match  {:} on canal {:} at position {:}",
+                        trace_action_as_htf_encoding(gen_ctx,action),
+                        canal_id,
+                        position)
+            },
+            AlignmentEditMove::Delete(canal_id,action) => {
+                format!("delete {:} on canal {:}",
+                        trace_action_as_htf_encoding(gen_ctx,action),
+                        canal_id)
+            },
+            AlignmentEditMove::Insert(canal_id,action,position) => {
+                format!("insert {:} on canal {:} at position {:}",
+                        trace_action_as_htf_encoding(gen_ctx,action),
+                        canal_id,
+                        position)
+            }
+        }
+    }
+
+}
+
@@ -0,0 +1,311 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use itertools::Itertools;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::semantics::execute::execute_interaction;
+use crate::core::execution::semantics::frontier::global_frontier;
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::core::message::MessageTypeExpression;
+use crate::trace_manip::align::edit::AlignmentEditMove;
+
+
+pub struct MultiTraceAlignment {
+    pub cost : u32,
+    pub edit_script : Vec<AlignmentEditMove>,
+    pub repaired : MultiTrace,
+    pub explored_states : u32
+}
+
+struct AlignmentSearchNode {
+    interaction : Interaction,
+    consumed : Vec<usize>,
+    cost : u32,
+    parent : Option<(usize,AlignmentEditMove)>
+}
+
+/**
+Dijkstra-like search on states made of an interaction term and of the number of consumed actions
+in each canal of the multi-trace.
+The first state reached which has consumed the whole multi-trace
+and whose interaction can express the empty trace gives an alignment of minimal cost.
+If a maximum cost is given the search stops once all alignments of lesser cost have been explored.
+**/
+pub fn align_multi_trace(gen_ctx : &GeneralContext,
+                         co_localizations : &CoLocalizations,
+                         multi_trace : &MultiTrace,
+                         interaction : &Interaction,
+                         max_cost : Option<u32>) -> Option<MultiTraceAlignment> {
+    let mut nodes : Vec<AlignmentSearchNode> = vec![];
+    let mut best_costs : HashMap<(Interaction,Vec<usize>),u32> = HashMap::new();
+    let mut queue : BinaryHeap<Reverse<(u32,usize)>> = BinaryHeap::new();
+    // ***
+    let init_consumed : Vec<usize> = vec![0;multi_trace.len()];
+    best_costs.insert( (interaction.clone(),init_consumed.clone()), 0 );
+    nodes.push( AlignmentSearchNode{interaction:interaction.clone(),consumed:init_consumed,cost:0,parent:None} );
+    queue.push( Reverse((0,0)) );
+    // ***
+    let mut explored_states : u32 = 0;
+    while let Some(Reverse((cost,node_id))) = queue.pop() {
+        let (node_int,node_consumed) = {
+            let node = nodes.get(node_id).unwrap();
+            (node.interaction.clone(),node.consumed.clone())
+        };
+        if *best_costs.get(&(node_int.clone(),node_consumed.clone())).unwrap() < cost {
+            // this node was reached afterwards via a cheaper path
+            continue;
+        }
+        explored_states += 1;
+        // ***
+        if node_int.express_empty() && is_multi_trace_consumed(multi_trace,&node_consumed) {
+            return Some( retrieve_alignment(&nodes,node_id,multi_trace.len(),explored_states) );
+        }
+        // ***
+        for edit in get_edit_moves(gen_ctx,co_localizations,multi_trace,&node_int,&node_consumed) {
+            let new_cost = cost + edit.get_cost();
+            if let Some(max) = max_cost {
+                if new_cost > max {
+                    continue;
+                }
+            }
+            let mut new_consumed = node_consumed.clone();
+            let new_int = match &edit {
+                AlignmentEditMove::Match(canal_id,action,position) => {
+                    *new_consumed.get_mut(*canal_id).unwrap() += 1;
+                    execute_interaction(&node_int,position,action,gen_ctx)
+                },
+                AlignmentEditMove::Delete(canal_id,_) => {
+                    *new_consumed.get_mut(*canal_id).unwrap() += 1;
+                    node_int.clone()
+                },
+                AlignmentEditMove::Insert(_,action,position) => {
+                    execute_interaction(&node_int,position,action,gen_ctx)
+                }
+            };
+            // ***
+            let key = (new_int,new_consumed);
+            let is_improvement = match best_costs.get(&key) {
+                None => {
+                    true
+                },
+                Some( known_cost ) => {
+                    new_cost < *known_cost
+                }
+            };
+            if is_improvement {
+                best_costs.insert(key.clone(),new_cost);
+                let (new_int,new_consumed) = key;
+                let new_node_id = nodes.len();
+                nodes.push( AlignmentSearchNode{interaction:new_int,
+                                                consumed:new_consumed,
+                                                cost:new_cost,
+                                                parent:Some((node_id,edit))} );
+                queue.push( Reverse((new_cost,new_node_id)) );
+            }
+        }
+    }
+    return None;
+}
+
+
+fn is_multi_trace_consumed(multi_trace : &MultiTrace,
+                           consumed : &Vec<usize>) -> bool {
+    for (canal_id,trace) in multi_trace.iter().enumerate() {
+        if *consumed.get(canal_id).unwrap() < trace.len() {
+            return false;
+        }
+    }
+    return true;
+}
+
+
+fn get_edit_moves(gen_ctx : &GeneralContext,
+                  co_localizations : &CoLocalizations,
+                  multi_trace : &MultiTrace,
+                  interaction : &Interaction,
+                  consumed : &Vec<usize>) -> Vec<AlignmentEditMove> {
+    let mut edits = vec![];
+    // ***
+    let mut head_actions : HashSet<&TraceAction> = HashSet::new();
+    for (canal_id,trace) in multi_trace.iter().enumerate() {
+        let canal_consumed = *consumed.get(canal_id).unwrap();
+        if trace.len() > canal_consumed {
+            let trace_head = trace.get(canal_consumed).unwrap();
+            head_actions.insert(trace_head);
+            edits.push( AlignmentEditMove::Delete(canal_id,trace_head.clone()) );
+        }
+    }
+    // ***
+    for frt_elt in global_frontier(interaction,gen_ctx,&Some(head_actions)) {
+        let canal_id = co_localizations.get_lf_coloc_id(frt_elt.target_action.lf_id).unwrap();
+        // the matched action is the concrete one at the head of the canal
+        let trace_head = multi_trace.get(canal_id).unwrap().get(*consumed.get(canal_id).unwrap()).unwrap();
+        edits.push( AlignmentEditMove::Match(canal_id,trace_head.clone(),frt_elt.position) );
+    }
+    // ***
+    for frt_elt in global_frontier(interaction,gen_ctx,&None) {
+        let canal_id = co_localizations.get_lf_coloc_id(frt_elt.target_action.lf_id).unwrap();
+        for ms_id in frt_elt.target_action.message.resolve(gen_ctx).iter().sorted() {
+            let concrete = TraceAction::new(frt_elt.target_action.lf_id,
+                                            frt_elt.target_action.act_kind,
+                                            MessageTypeExpression::Singleton(*ms_id));
+            edits.push( AlignmentEditMove::Insert(canal_id,concrete,frt_elt.position.clone()) );
+        }
+    }
+    // ***
+    edits
+}
+
+
+fn retrieve_alignment(nodes : &Vec<AlignmentSearchNode>,
+                      goal_node_id : usize,
+                      canals_num : usize,
+                      explored_states : u32) -> MultiTraceAlignment {
+    let cost = nodes.get(goal_node_id).unwrap().cost;
+    let mut edit_script : Vec<AlignmentEditMove> = vec![];
+    let mut current_id = goal_node_id;
+    while let Some((parent_id,edit)) = &nodes.get(current_id).unwrap().parent {
+        edit_script.push(edit.clone());
+        current_id = *parent_id;
+    }
+    edit_script.reverse();
+    // ***
+    let mut repaired : MultiTrace = vec![vec![];canals_num];
+    for edit in &edit_script {
+        match edit {
+            AlignmentEditMove::Match(canal_id,action,_) => {
+                repaired.get_mut(*canal_id).unwrap().push(action.clone());
+            },
+            AlignmentEditMove::Insert(canal_id,action,_) => {
+                repaired.get_mut(*canal_id).unwrap().push(action.clone());
+            },
+            AlignmentEditMove::Delete(_,_) => {
+                // deleted actions do not appear in the repaired multi-trace
+            }
+        }
+    }
+    // ***
+    MultiTraceAlignment{cost,edit_script,repaired,explored_states}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::core::execution::trace::multitrace::MultiTrace;
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::BroadcastPrimitive;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+    use crate::process::ana::interface::get_analysis_verdict;
+    use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+    use crate::trace_manip::align::edit::AlignmentEditMove;
+    use super::align_multi_trace;
+
+    fn get_test_context() -> GeneralContext {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_lf("l3".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        return gen_ctx;
+    }
+
+    fn message(orig_lf_id : usize, ms_id : usize, tar_lf_id : usize) -> Box<Interaction> {
+        Box::new(Interaction::Action(BroadcastPrimitive::new(Some(orig_lf_id),
+                                                             MessageTypeExpression::Singleton(ms_id),
+                                                             vec![tar_lf_id])))
+    }
+
+    fn action(lf_id : usize, act_kind : TraceActionKind, ms_id : usize) -> TraceAction {
+        TraceAction::new(lf_id,act_kind,MessageTypeExpression::Singleton(ms_id))
+    }
+
+    fn get_test_interaction() -> Interaction {
+        // seq(l1 -- m1 -> l2, l2 -- m2 -> l3)
+        Interaction::CoReg(vec![],message(0,0,1),message(1,1,2))
+    }
+
+    fn get_non_matches(edit_script : &Vec<AlignmentEditMove>) -> Vec<AlignmentEditMove> {
+        edit_script.iter().filter(|edit| edit.get_cost() > 0).cloned().collect()
+    }
+
+    #[test]
+    fn align_missing_action_test() {
+        let gen_ctx = get_test_context();
+        let co_localizations = CoLocalizations::get_discrete_partition(3);
+        let int = get_test_interaction();
+        // the reception l2?m1 is missing
+        let multi_trace : MultiTrace = vec![vec![action(0,TraceActionKind::Emission,0)],
+                                            vec![action(1,TraceActionKind::Emission,1)],
+                                            vec![action(2,TraceActionKind::Reception,1)]];
+        assert!(align_multi_trace(&gen_ctx,&co_localizations,&multi_trace,&int,Some(0)).is_none());
+        let alignment = align_multi_trace(&gen_ctx,&co_localizations,&multi_trace,&int,None).unwrap();
+        assert_eq!(alignment.cost,1);
+        // ***
+        let non_matches = get_non_matches(&alignment.edit_script);
+        assert_eq!(non_matches.len(),1);
+        match non_matches.get(0).unwrap() {
+            AlignmentEditMove::Insert(canal_id,inserted,_) => {
+                assert_eq!(*canal_id,1);
+                assert_eq!(*inserted,action(1,TraceActionKind::Reception,0));
+            },
+            other => {
+                panic!("expected an insertion, got {:?}", other);
+            }
+        }
+        // ***
+        let expected : MultiTrace = vec![vec![action(0,TraceActionKind::Emission,0)],
+                                         vec![action(1,TraceActionKind::Reception,0),action(1,TraceActionKind::Emission,1)],
+                                         vec![action(2,TraceActionKind::Reception,1)]];
+        assert_eq!(alignment.repaired,expected);
+        let (_,verdict) = get_analysis_verdict(&gen_ctx,&co_localizations,&alignment.repaired,&int,HibouAnalyzeOptions::default());
+        assert_eq!(verdict,AnalysisGlobalVerdict::Pass);
+    }
+
+    #[test]
+    fn align_unexpected_action_test() {
+        let gen_ctx = get_test_context();
+        let co_localizations = CoLocalizations::get_discrete_partition(3);
+        let int = get_test_interaction();
+        // the emission l1!m2 is not specified
+        let multi_trace : MultiTrace = vec![vec![action(0,TraceActionKind::Emission,0),action(0,TraceActionKind::Emission,1)],
+                                            vec![action(1,TraceActionKind::Reception,0),action(1,TraceActionKind::Emission,1)],
+                                            vec![action(2,TraceActionKind::Reception,1)]];
+        let alignment = align_multi_trace(&gen_ctx,&co_localizations,&multi_trace,&int,Some(3)).unwrap();
+        assert_eq!(alignment.cost,1);
+        assert_eq!(get_non_matches(&alignment.edit_script),
+                   vec![AlignmentEditMove::Delete(0,action(0,TraceActionKind::Emission,1))]);
+        // ***
+        let mut expected = multi_trace.clone();
+        expected.get_mut(0).unwrap().pop();
+        assert_eq!(alignment.repaired,expected);
+        let (_,verdict) = get_analysis_verdict(&gen_ctx,&co_localizations,&alignment.repaired,&int,HibouAnalyzeOptions::default());
+        assert_eq!(verdict,AnalysisGlobalVerdict::Pass);
+    }
+}
@@ -0,0 +1,204 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::time::{Duration, Instant};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::trace_manip::campaign::operator::CampaignMutationOperator;
+
+
+pub struct CampaignOperatorStatistics {
+    pub operator : CampaignMutationOperator,
+    pub killed : u32,
+    pub survived : u32,
+    pub inconclusive : u32,
+    pub not_applicable : u32,
+    pub total_time : Duration,
+    pub max_time : Duration
+}
+
+impl CampaignOperatorStatistics {
+
+    pub fn new(operator : CampaignMutationOperator) -> CampaignOperatorStatistics {
+        return CampaignOperatorStatistics{operator,
+            killed:0,
+            survived:0,
+            inconclusive:0,
+            not_applicable:0,
+            total_time:Duration::ZERO,
+            max_time:Duration::ZERO};
+    }
+
+    pub fn get_analyzed_num(&self) -> u32 {
+        return self.killed + self.survived + self.inconclusive;
+    }
+
+    /**
+    Proportion of killed mutants among analyzed mutants (None if no mutant could be analyzed).
+    **/
+    pub fn get_kill_rate(&self) -> Option<f64> {
+        let analyzed = self.get_analyzed_num();
+        if analyzed == 0 {
+            return None;
+        }
+        return Some( (self.killed as f64) / (analyzed as f64) );
+    }
+
+    pub fn get_mean_time(&self) -> Option<Duration> {
+        let analyzed = self.get_analyzed_num();
+        if analyzed == 0 {
+            return None;
+        }
+        return Some( self.total_time / analyzed );
+    }
+
+}
+
+
+pub struct MutationCampaignConfiguration {
+    pub operators : Vec<CampaignMutationOperator>,
+    pub num_mutants : u32,
+    pub seed : u64
+}
+
+/**
+For each multi-trace and each operator, generates "num_mutants" mutants and analyzes them.
+A mutant is killed if the analysis yields a Fail or WeakFail verdict and survives if it yields a Pass or WeakPass verdict.
+Mutants which survive are passed to "on_survivor" along with the index of the original multi-trace and the operator.
+Given that a single random number generator is used throughout the campaign, it is reproducible from its seed.
+**/
+pub fn run_mutation_campaign(gen_ctx : &GeneralContext,
+                             interaction : &Interaction,
+                             multi_traces : &Vec<(CoLocalizations,MultiTrace)>,
+                             config : &MutationCampaignConfiguration,
+                             analyze : &mut dyn FnMut(&CoLocalizations,&MultiTrace) -> AnalysisGlobalVerdict,
+                             on_survivor : &mut dyn FnMut(usize,&CampaignMutationOperator,&CoLocalizations,&MultiTrace)) -> Vec<CampaignOperatorStatistics> {
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let mut statistics : Vec<CampaignOperatorStatistics> = config.operators.iter()
+        .map(|operator| CampaignOperatorStatistics::new(operator.clone()))
+        .collect();
+    for (mt_idx,(co_localizations,multi_trace)) in multi_traces.iter().enumerate() {
+        for stats in statistics.iter_mut() {
+            for _ in 0..config.num_mutants {
+                match stats.operator.apply(&mut rng,gen_ctx,co_localizations,multi_trace,interaction) {
+                    None => {
+                        stats.not_applicable += 1;
+                    },
+                    Some( mutant ) => {
+                        let now = Instant::now();
+                        let verdict = analyze(co_localizations,&mutant);
+                        let elapsed = now.elapsed();
+                        stats.total_time += elapsed;
+                        stats.max_time = stats.max_time.max(elapsed);
+                        match verdict {
+                            AnalysisGlobalVerdict::Fail | AnalysisGlobalVerdict::WeakFail => {
+                                stats.killed += 1;
+                            },
+                            AnalysisGlobalVerdict::Pass | AnalysisGlobalVerdict::WeakPass => {
+                                stats.survived += 1;
+                                on_survivor(mt_idx,&stats.operator,co_localizations,&mutant);
+                            },
+                            AnalysisGlobalVerdict::Inconc(_) => {
+                                stats.inconclusive += 1;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    return statistics;
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::core::execution::trace::multitrace::MultiTrace;
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::BroadcastPrimitive;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+    use crate::process::ana::interface::get_analysis_verdict;
+    use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+    use crate::trace_manip::campaign::operator::CampaignMutationOperator;
+    use crate::trace_manip::mutate::model_aware::ModelAwareMutationOperator;
+    use super::{MutationCampaignConfiguration, run_mutation_campaign};
+
+    #[test]
+    fn campaign_statistics_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        // alt(l1 -- m1 -> |, l2 -- m1 -> |)
+        let int = Interaction::Alt(Box::new(Interaction::Action(BroadcastPrimitive::new(Some(0),MessageTypeExpression::Singleton(0),vec![]))),
+                                   Box::new(Interaction::Action(BroadcastPrimitive::new(Some(1),MessageTypeExpression::Singleton(0),vec![]))));
+        // both lifelines on the same co-localization so that the emission can be moved from one to the other
+        let multi_traces : Vec<(CoLocalizations,MultiTrace)> = vec![
+            (CoLocalizations::get_trivial_partition(2),vec![vec![TraceAction::new(0,TraceActionKind::Emission,MessageTypeExpression::Singleton(0))]])
+        ];
+        let config = MutationCampaignConfiguration{
+            operators:vec![CampaignMutationOperator::ModelAware(ModelAwareMutationOperator::Duplicate),
+                           CampaignMutationOperator::ModelAware(ModelAwareMutationOperator::MoveLifeline),
+                           CampaignMutationOperator::SwapActions],
+            num_mutants:5,
+            seed:0};
+        let mut analyze = |co_localizations : &CoLocalizations, mutant : &MultiTrace| -> AnalysisGlobalVerdict {
+            let (_,verdict) = get_analysis_verdict(&gen_ctx,co_localizations,mutant,&int,HibouAnalyzeOptions::default());
+            verdict
+        };
+        let mut survivors : Vec<(usize,CampaignMutationOperator,MultiTrace)> = vec![];
+        let mut on_survivor = |mt_idx : usize, operator : &CampaignMutationOperator, _ : &CoLocalizations, mutant : &MultiTrace| {
+            survivors.push( (mt_idx,operator.clone(),mutant.clone()) );
+        };
+        let statistics = run_mutation_campaign(&gen_ctx,&int,&multi_traces,&config,&mut analyze,&mut on_survivor);
+        assert_eq!(statistics.len(),3);
+        // a duplicated emission is never accepted
+        let duplicate = statistics.get(0).unwrap();
+        assert_eq!((duplicate.killed,duplicate.survived,duplicate.inconclusive,duplicate.not_applicable),(5,0,0,0));
+        assert_eq!(duplicate.get_kill_rate(),Some(1.0));
+        // the emission moved to the other lifeline is always accepted
+        let move_lifeline = statistics.get(1).unwrap();
+        assert_eq!((move_lifeline.killed,move_lifeline.survived,move_lifeline.inconclusive,move_lifeline.not_applicable),(0,5,0,0));
+        assert_eq!(move_lifeline.get_kill_rate(),Some(0.0));
+        // there is nothing to swap in a single action
+        let swap = statistics.get(2).unwrap();
+        assert_eq!((swap.killed,swap.survived,swap.inconclusive,swap.not_applicable),(0,0,0,5));
+        assert_eq!(swap.get_kill_rate(),None);
+        // ***
+        let moved : MultiTrace = vec![vec![TraceAction::new(1,TraceActionKind::Emission,MessageTypeExpression::Singleton(0))]];
+        assert_eq!(survivors.len(),5);
+        for (mt_idx,operator,mutant) in survivors {
+            assert_eq!(mt_idx,0);
+            assert_eq!(operator,CampaignMutationOperator::ModelAware(ModelAwareMutationOperator::MoveLifeline));
+            assert_eq!(mutant,moved);
+        }
+    }
+
+}
@@ -0,0 +1,119 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use rand::Rng;
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::{multi_trace_length, MultiTrace};
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::trace_manip::mutate::insert_noise::mutate_by_inserting_noise;
+use crate::trace_manip::mutate::model_aware::{mutate_model_aware, ModelAwareMutationOperator};
+use crate::trace_manip::mutate::remove_actions::mutate_by_removing_actions;
+use crate::trace_manip::mutate::swap_actions::mutate_by_swapping_actions;
+
+
+/**
+Mutation operators which can be applied to a single multi-trace during a campaign.
+Swapping components is left aside given that it requires two multi-traces.
+**/
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum CampaignMutationOperator {
+    InsertNoise,
+    RemoveActions,
+    SwapActions,
+    ModelAware(ModelAwareMutationOperator)
+}
+
+impl CampaignMutationOperator {
+
+    pub fn get_all_operators() -> Vec<CampaignMutationOperator> {
+        return vec![CampaignMutationOperator::InsertNoise,
+                    CampaignMutationOperator::RemoveActions,
+                    CampaignMutationOperator::SwapActions,
+                    CampaignMutationOperator::ModelAware(ModelAwareMutationOperator::ReplaceWithSameType),
+                    CampaignMutationOperator::ModelAware(ModelAwareMutationOperator::ReplaceWithOtherType),
+                    CampaignMutationOperator::ModelAware(ModelAwareMutationOperator::FlipKind),
+                    CampaignMutationOperator::ModelAware(ModelAwareMutationOperator::MoveLifeline),
+                    CampaignMutationOperator::ModelAware(ModelAwareMutationOperator::Duplicate),
+                    CampaignMutationOperator::ModelAware(ModelAwareMutationOperator::InsertModelNoise)];
+    }
+
+    pub fn from_name(name : &str) -> Option<CampaignMutationOperator> {
+        match name {
+            "insert_noise" => Some(CampaignMutationOperator::InsertNoise),
+            "remove_actions" => Some(CampaignMutationOperator::RemoveActions),
+            "swap_actions" => Some(CampaignMutationOperator::SwapActions),
+            _ => ModelAwareMutationOperator::from_name(name).map(CampaignMutationOperator::ModelAware)
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            CampaignMutationOperator::InsertNoise => "insert_noise",
+            CampaignMutationOperator::RemoveActions => "remove_actions",
+            CampaignMutationOperator::SwapActions => "swap_actions",
+            CampaignMutationOperator::ModelAware(operator) => operator.get_name()
+        }
+    }
+
+    /**
+    Applies the operator once.
+    Returns None if the operator is not applicable to the multi-trace.
+    **/
+    pub fn apply<R : Rng>(&self,
+                          rng : &mut R,
+                          gen_ctx : &GeneralContext,
+                          co_localizations : &CoLocalizations,
+                          multi_trace : &MultiTrace,
+                          interaction : &Interaction) -> Option<MultiTrace> {
+        match self {
+            CampaignMutationOperator::InsertNoise => {
+                if multi_trace.is_empty() {
+                    return None;
+                }
+                return Some(mutate_by_inserting_noise(rng,gen_ctx,co_localizations,multi_trace,1,false,false));
+            },
+            CampaignMutationOperator::RemoveActions => {
+                if multi_trace_length(multi_trace) == 0 {
+                    return None;
+                }
+                // the component is drawn at random and may be empty
+                loop {
+                    let mutant = mutate_by_removing_actions(rng,gen_ctx,co_localizations,multi_trace,1);
+                    if &mutant != multi_trace {
+                        return Some(mutant);
+                    }
+                }
+            },
+            CampaignMutationOperator::SwapActions => {
+                if !multi_trace.iter().any(|component| component.len() > 1) {
+                    return None;
+                }
+                return Some(mutate_by_swapping_actions(rng,multi_trace,1));
+            },
+            CampaignMutationOperator::ModelAware(operator) => {
+                let (mutant,num_applied) = mutate_model_aware(rng,gen_ctx,co_localizations,multi_trace,interaction,operator,1);
+                if num_applied == 0 {
+                    return None;
+                }
+                return Some(mutant);
+            }
+        }
+    }
+
+}
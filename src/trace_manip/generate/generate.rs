@@ -0,0 +1,105 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::fs;
+use std::path::PathBuf;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::coverage::model::InteractionCoverageModel;
+use crate::io::file_extensions::{HibouFileFormat, HIBOU_TRACE_FILE_EXTENSION};
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file_with_comment;
+use crate::trace_manip::generate::walk::random_walk;
+
+
+pub struct RandomWalkGenerationConfiguration {
+    pub num_traces : u32,
+    pub target_length : u32,
+    pub bias : bool,
+    pub seed : u64
+}
+
+impl RandomWalkGenerationConfiguration {
+    pub fn new(num_traces: u32, target_length: u32, bias: bool, seed: u64) -> Self {
+        Self { num_traces, target_length, bias, seed }
+    }
+}
+
+/**
+Generates multi-traces via seeded random walks over the semantics of the interaction
+and writes them in the parent folder.
+Returns the number of generated multi-traces which are accepted by the interaction
+(the others being prefixes of accepted multi-traces, cut at the target length).
+**/
+pub fn generate_random_walk_traces(gen_ctx : &GeneralContext,
+                                   co_localizations : &CoLocalizations,
+                                   interaction : &Interaction,
+                                   int_name : &str,
+                                   parent_folder : Option<&str>,
+                                   file_name_prefix_opt : Option<&str>,
+                                   file_format : HibouFileFormat,
+                                   config : &RandomWalkGenerationConfiguration) -> u32 {
+    let dir_name : String;
+    match parent_folder {
+        None => {
+            dir_name = format!("./{:}_generated", int_name);
+        },
+        Some( parent ) => {
+            dir_name = parent.to_string();
+        }
+    }
+    let file_name_prefix : String;
+    match file_name_prefix_opt {
+        None => {
+            file_name_prefix = "trace".to_string();
+        },
+        Some( got_fnp ) => {
+            file_name_prefix = got_fnp.to_string();
+        }
+    }
+    // creates directory
+    fs::create_dir_all(&dir_name).unwrap();
+    // ***
+    let model = InteractionCoverageModel::new(gen_ctx,interaction);
+    let mut leaves_hits : Vec<u32> = vec![0;model.leaves_num];
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let mut accepted_num : u32 = 0;
+    for trace_id in 1..(config.num_traces + 1) {
+        let walk = random_walk(&model,
+                               co_localizations,
+                               config.target_length,
+                               config.bias,
+                               &mut leaves_hits,
+                               &mut rng);
+        if walk.accepted {
+            accepted_num += 1;
+        }
+        let file_name = format!("{:}{:}.{:}", file_name_prefix, trace_id, file_format.get_extension(HIBOU_TRACE_FILE_EXTENSION));
+        let path : PathBuf = [&dir_name, &file_name].iter().collect();
+        write_multi_trace_into_file_with_comment(path.as_path(),
+                                                 gen_ctx,
+                                                 co_localizations,
+                                                 &walk.multi_trace,
+                                                 &format!("random walk {:} generated with seed {:}", trace_id, config.seed));
+    }
+    accepted_num
+}
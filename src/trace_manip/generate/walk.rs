@@ -0,0 +1,206 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use rand::Rng;
+use rand::rngs::StdRng;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::semantics::execute::execute_interaction;
+use crate::core::execution::semantics::frontier::{global_frontier, FrontierElement};
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::message::MessageTypeExpression;
+use crate::coverage::model::InteractionCoverageModel;
+use crate::coverage::step::get_actions_at_position;
+
+
+pub struct RandomWalk {
+    pub multi_trace : MultiTrace,
+    pub accepted : bool
+}
+
+/**
+Performs a random walk over the operational semantics of the (tagged) interaction of the model.
+At each step, one element of the frontier is chosen and executed with a concrete message
+or, if the current interaction can terminate, the walk may stop,
+termination being chosen with the same probability as any frontier element.
+The walk also stops once the target length is reached.
+If 'bias' is set, the frontier elements which stem from the least executed action leaves
+(according to 'leaves_hits', which is updated during the walk) are chosen in priority.
+**/
+pub fn random_walk(model : &InteractionCoverageModel,
+                   co_localizations : &CoLocalizations,
+                   target_length : u32,
+                   bias : bool,
+                   leaves_hits : &mut Vec<u32>,
+                   rng : &mut StdRng) -> RandomWalk {
+    let mut multi_trace : MultiTrace = vec![vec![];co_localizations.num_colocs()];
+    let mut interaction = model.tagged_interaction.clone();
+    let mut length : u32 = 0;
+    while length < target_length {
+        let mut frontier = global_frontier(&interaction,&model.tagged_gen_ctx,&None);
+        // the frontier is sorted so that a given seed always yields the same walk
+        frontier.sort_by(|frt_elt1,frt_elt2| (&frt_elt1.position,&frt_elt1.target_action).cmp(&(&frt_elt2.position,&frt_elt2.target_action)));
+        if interaction.express_empty() && rng.gen_range(0..(frontier.len() + 1)) == 0 {
+            break;
+        }
+        if frontier.is_empty() {
+            break;
+        }
+        // ***
+        let mut candidates : Vec<(&FrontierElement,Vec<usize>)> = vec![];
+        for frt_elt in &frontier {
+            let mut elt_leaves : Vec<usize> = vec![];
+            for bp in get_actions_at_position(&interaction,&frt_elt.position) {
+                elt_leaves.extend( model.get_message_tags(&bp.message_type) );
+            }
+            elt_leaves.sort();
+            candidates.push( (frt_elt,elt_leaves) );
+        }
+        if bias {
+            let get_score = |elt_leaves : &Vec<usize>| -> u32 {
+                elt_leaves.iter().map(|leaf_id| *leaves_hits.get(*leaf_id).unwrap()).min().unwrap_or(0)
+            };
+            let min_score = candidates.iter().map(|(_,elt_leaves)| get_score(elt_leaves)).min().unwrap();
+            candidates.retain(|(_,elt_leaves)| get_score(elt_leaves) == min_score);
+        }
+        let (frt_elt,elt_leaves) = candidates.get(rng.gen_range(0..candidates.len())).unwrap();
+        // ***
+        let mut messages : Vec<usize> = frt_elt.target_action.message.resolve(&model.tagged_gen_ctx).into_iter().collect();
+        messages.sort();
+        let ms_id = *messages.get(rng.gen_range(0..messages.len())).unwrap();
+        let concrete_action = TraceAction::new(frt_elt.target_action.lf_id,
+                                               frt_elt.target_action.act_kind,
+                                               MessageTypeExpression::Singleton(ms_id));
+        // the executed action carries the tags so that the residual receptions keep them
+        let tagged_action = TraceAction::new(frt_elt.target_action.lf_id,
+                                             frt_elt.target_action.act_kind,
+                                             model.tag_message(&concrete_action.message,
+                                                               &elt_leaves.iter().cloned().collect()));
+        interaction = execute_interaction(&interaction,
+                                          &frt_elt.position,
+                                          &tagged_action,
+                                          &model.tagged_gen_ctx);
+        for leaf_id in elt_leaves {
+            *leaves_hits.get_mut(*leaf_id).unwrap() += 1;
+        }
+        // ***
+        let canal_id = co_localizations.get_lf_coloc_id(concrete_action.lf_id).unwrap();
+        multi_trace.get_mut(canal_id).unwrap().push(concrete_action);
+        length += 1;
+    }
+    let accepted = interaction.express_empty();
+    RandomWalk{multi_trace,accepted}
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::core::execution::trace::multitrace::MultiTrace;
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::BroadcastPrimitive;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use crate::coverage::model::InteractionCoverageModel;
+    use super::{random_walk, RandomWalk};
+
+    fn message(orig_lf_id : usize, ms_id : usize, tar_lf_id : usize) -> Box<Interaction> {
+        Box::new(Interaction::Action(BroadcastPrimitive::new(Some(orig_lf_id),
+                                                             MessageTypeExpression::Singleton(ms_id),
+                                                             vec![tar_lf_id])))
+    }
+
+    fn get_context() -> GeneralContext {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        gen_ctx
+    }
+
+    fn walk(model : &InteractionCoverageModel,
+            target_length : u32,
+            bias : bool,
+            seed : u64) -> (RandomWalk,Vec<u32>) {
+        let mut leaves_hits = vec![0;model.leaves_num];
+        let mut rng = StdRng::seed_from_u64(seed);
+        let walk = random_walk(model,&CoLocalizations::get_discrete_partition(2),target_length,bias,&mut leaves_hits,&mut rng);
+        (walk,leaves_hits)
+    }
+
+    fn walk_length(multi_trace : &MultiTrace) -> u32 {
+        multi_trace.iter().map(|canal_trace| canal_trace.len() as u32).sum()
+    }
+
+    #[test]
+    fn same_seed_same_walk_test() {
+        let gen_ctx = get_context();
+        // par(alt(l1 -- m1 -> l2, l1 -- m2 -> l2), loopW(l2 -- m1 -> l1))
+        let int = Interaction::CoReg(vec![0,1],
+                                     Box::new(Interaction::Alt(message(0,0,1),message(0,1,1))),
+                                     Box::new(Interaction::Loop(vec![],message(1,0,0))));
+        let model = InteractionCoverageModel::new(&gen_ctx,&int);
+        for seed in 0..20 {
+            for bias in [false,true] {
+                let (walk1,hits1) = walk(&model,8,bias,seed);
+                let (walk2,hits2) = walk(&model,8,bias,seed);
+                assert_eq!(walk1.multi_trace,walk2.multi_trace);
+                assert_eq!(walk1.accepted,walk2.accepted);
+                assert_eq!(hits1,hits2);
+            }
+        }
+    }
+
+    #[test]
+    fn length_and_accepting_stop_test() {
+        let gen_ctx = get_context();
+        // loopW(l1 -- m1 -> l2) can always be continued
+        let looping = Interaction::Loop(vec![],message(0,0,1));
+        let model = InteractionCoverageModel::new(&gen_ctx,&looping);
+        for seed in 0..20 {
+            let (got,_) = walk(&model,4,false,seed);
+            let length = walk_length(&got.multi_trace);
+            assert!(length <= 4);
+            // hence the walk only stops before the target length by choosing to terminate
+            if length < 4 {
+                assert!(got.accepted);
+            }
+        }
+        // l1 -- m1 -> l2 is only accepted once entirely executed
+        let single = *message(0,0,1);
+        let model = InteractionCoverageModel::new(&gen_ctx,&single);
+        for seed in 0..20 {
+            let (got,hits) = walk(&model,10,false,seed);
+            assert_eq!(walk_length(&got.multi_trace),2);
+            assert!(got.accepted);
+            assert_eq!(hits,vec![2]);
+            // the walk stops before the end of the execution once the target length is reached
+            let (got,_) = walk(&model,1,false,seed);
+            assert_eq!(walk_length(&got.multi_trace),1);
+            assert!(!got.accepted);
+        }
+    }
+
+}
@@ -0,0 +1,156 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use crate::core::general_context::GeneralContext;
+use crate::core::message::MessageTypeExpression;
+use crate::io::input::error::HibouParsingError;
+use crate::trace_manip::import::mapping::TraceImportMapping;
+use crate::trace_manip::import::records::LogRecord;
+
+
+/**
+Summary of an import :
+the number of imported actions and, for each lifeline or message
+which is not declared in the signature, the lines of the log in which it occurs.
+Events on such lifelines or with such messages are not imported.
+**/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TraceImportReport {
+    pub imported_num : u32,
+    pub unknown_lifelines : BTreeMap<String,Vec<usize>>,
+    pub unknown_messages : BTreeMap<String,Vec<usize>>
+}
+
+impl TraceImportReport {
+
+    pub fn is_complete(&self) -> bool {
+        self.unknown_lifelines.is_empty() && self.unknown_messages.is_empty()
+    }
+
+}
+
+
+/**
+Orders the records according to the timestamp field of the mapping, if any.
+Timestamps are compared as numbers if they all are numbers and as strings otherwise
+(which suits ISO 8601 dates).
+The order of the log is kept between records with equal timestamps.
+**/
+fn sort_records_by_timestamp(timestamp_field : &str,
+                             records : &mut Vec<LogRecord>) -> Result<(),HibouParsingError> {
+    for record in records.iter() {
+        record.get_field(timestamp_field)?;
+    }
+    let all_numeric = records.iter().all(|record| record.fields.get(timestamp_field).unwrap().parse::<f64>().is_ok());
+    records.sort_by(|r1,r2| {
+        let t1 = r1.fields.get(timestamp_field).unwrap();
+        let t2 = r2.fields.get(timestamp_field).unwrap();
+        if all_numeric {
+            t1.parse::<f64>().unwrap().partial_cmp(&t2.parse::<f64>().unwrap()).unwrap_or(Ordering::Equal)
+        } else {
+            t1.cmp(t2)
+        }
+    });
+    return Ok(());
+}
+
+
+fn action_kind_from_direction(mapping : &TraceImportMapping,
+                              record : &LogRecord) -> Result<TraceActionKind,HibouParsingError> {
+    let direction = record.get_field(&mapping.direction_field)?;
+    if mapping.emission_values.iter().any(|value| value == direction) {
+        return Ok( TraceActionKind::Emission );
+    }
+    if mapping.reception_values.iter().any(|value| value == direction) {
+        return Ok( TraceActionKind::Reception );
+    }
+    return Err( HibouParsingError::MatchError(format!("line {:?} : direction '{}' is neither an emission ({}) nor a reception ({})",
+                                                      record.line,
+                                                      direction,
+                                                      mapping.emission_values.join(","),
+                                                      mapping.reception_values.join(","))) );
+}
+
+
+pub fn import_multi_trace(gen_ctx : &GeneralContext,
+                          mapping : &TraceImportMapping,
+                          mut records : Vec<LogRecord>) -> Result<(MultiTrace,TraceImportReport),HibouParsingError> {
+    if let Some(timestamp_field) = &mapping.timestamp_field {
+        sort_records_by_timestamp(timestamp_field,&mut records)?;
+    }
+    // ***
+    let mut multi_trace : MultiTrace = mapping.co_localizations.locs_lf_ids.iter().map(|_| vec![]).collect();
+    let mut report = TraceImportReport{imported_num:0,
+        unknown_lifelines:BTreeMap::new(),
+        unknown_messages:BTreeMap::new()};
+    for record in &records {
+        let act_kind = action_kind_from_direction(mapping,record)?;
+        let lf_name = record.get_field(&mapping.lifeline_field)?;
+        let ms_name = record.get_field(&mapping.message_field)?;
+        let lf_id_opt = gen_ctx.get_lf_id(lf_name);
+        let ms_id_opt = gen_ctx.get_ms_id(ms_name);
+        if lf_id_opt.is_none() {
+            report.unknown_lifelines.entry(lf_name.to_string()).or_insert(vec![]).push(record.line);
+        }
+        if ms_id_opt.is_none() {
+            report.unknown_messages.entry(ms_name.to_string()).or_insert(vec![]).push(record.line);
+        }
+        if let (Some(lf_id),Some(ms_id)) = (lf_id_opt,ms_id_opt) {
+            let canal_id = mapping.co_localizations.get_lf_coloc_id(lf_id).unwrap();
+            multi_trace[canal_id].push( TraceAction::new(lf_id,act_kind,MessageTypeExpression::Singleton(ms_id)) );
+            report.imported_num += 1;
+        }
+    }
+    return Ok( (multi_trace,report) );
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::core::message::MessageTypeExpression;
+    use crate::trace_manip::import::mapping::{LogFormat, TraceImportMapping};
+    use crate::trace_manip::import::records::log_records_from_text;
+    use super::import_multi_trace;
+
+    #[test]
+    fn csv_import_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        let mut mapping = TraceImportMapping::new_default(CoLocalizations::get_trivial_partition(2));
+        mapping.timestamp_field = Some("time".to_string());
+        // the reception is logged before the emission but has a later timestamp
+        // and the second column is quoted as it contains the separator
+        let log = "time,lifeline,direction,message\n10,l2,?,m1\n2,\"l1\",send,m1\n3,l3,!,m1\n4,l1,!,\"m,2\"\n";
+        let records = log_records_from_text(&LogFormat::Csv(','),log).unwrap();
+        let (multi_trace,report) = import_multi_trace(&gen_ctx,&mapping,records).unwrap();
+        assert_eq!(multi_trace, vec![vec![TraceAction::new(0,TraceActionKind::Emission,MessageTypeExpression::Singleton(0)),
+                                          TraceAction::new(1,TraceActionKind::Reception,MessageTypeExpression::Singleton(0))]]);
+        assert_eq!(report.imported_num, 2);
+        assert_eq!(report.unknown_lifelines.get("l3"), Some(&vec![4]));
+        assert_eq!(report.unknown_messages.get("m,2"), Some(&vec![5]));
+    }
+}
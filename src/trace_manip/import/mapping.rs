@@ -0,0 +1,68 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use crate::core::colocalizations::CoLocalizations;
+
+
+/**
+Syntax of the logged events :
+- either comma separated values, the first line naming the columns
+- or JSON lines, each line being a JSON object
+**/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum LogFormat {
+    Csv(char), // separator
+    JsonLines
+}
+
+/**
+Describes how the events of a log are turned into trace actions :
+- the fields (CSV columns or JSON members) holding the lifeline, the message and the direction
+- the values of the direction field which denote emissions and receptions
+- an optional field used to order the events (they are kept in the order of the log otherwise)
+- the co-localizations of the lifelines, i.e. the components of the imported multi-trace
+**/
+#[derive(Clone, PartialEq, Debug)]
+pub struct TraceImportMapping {
+    pub format : Option<LogFormat>,
+    pub lifeline_field : String,
+    pub message_field : String,
+    pub direction_field : String,
+    pub emission_values : Vec<String>,
+    pub reception_values : Vec<String>,
+    pub timestamp_field : Option<String>,
+    pub co_localizations : CoLocalizations
+}
+
+impl TraceImportMapping {
+
+    /**
+    The default mapping reads the 'lifeline', 'message' and 'direction' fields,
+    with '!' or 'send' for emissions and '?' or 'receive' for receptions,
+    and puts each lifeline in its own component.
+    **/
+    pub fn new_default(co_localizations : CoLocalizations) -> TraceImportMapping {
+        return TraceImportMapping{format:None,
+            lifeline_field:"lifeline".to_string(),
+            message_field:"message".to_string(),
+            direction_field:"direction".to_string(),
+            emission_values:vec!["!".to_string(),"send".to_string()],
+            reception_values:vec!["?".to_string(),"receive".to_string()],
+            timestamp_field:None,
+            co_localizations};
+    }
+
+}
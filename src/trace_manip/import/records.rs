@@ -0,0 +1,161 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::HashMap;
+
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::json::value::{json_value_from_text, JsonValue};
+use crate::trace_manip::import::mapping::LogFormat;
+
+
+/**
+A logged event, as the values of its fields, together with its line in the log.
+**/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LogRecord {
+    pub line : usize,
+    pub fields : HashMap<String,String>
+}
+
+impl LogRecord {
+
+    pub fn get_field(&self, field : &str) -> Result<&str,HibouParsingError> {
+        match self.fields.get(field) {
+            None => {
+                Err( HibouParsingError::MatchError(format!("line {:?} : missing field '{}'", self.line, field)) )
+            },
+            Some( value ) => {
+                Ok( value )
+            }
+        }
+    }
+
+}
+
+
+pub fn log_records_from_text(format : &LogFormat,
+                             log_str : &str) -> Result<Vec<LogRecord>,HibouParsingError> {
+    match format {
+        LogFormat::Csv(separator) => {
+            csv_records_from_text(*separator,log_str)
+        },
+        LogFormat::JsonLines => {
+            json_lines_records_from_text(log_str)
+        }
+    }
+}
+
+
+fn csv_records_from_text(separator : char,
+                         log_str : &str) -> Result<Vec<LogRecord>,HibouParsingError> {
+    let mut header : Option<Vec<String>> = None;
+    let mut records = vec![];
+    for (line_id,line) in log_str.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let values = split_csv_line(separator,line);
+        match &header {
+            None => {
+                header = Some(values);
+            },
+            Some( columns ) => {
+                if values.len() != columns.len() {
+                    return Err( HibouParsingError::MatchError(format!("line {:?} : expected {:?} values and got {:?}",
+                                                                      line_id + 1, columns.len(), values.len())) );
+                }
+                let fields : HashMap<String,String> = columns.iter().cloned().zip(values.into_iter()).collect();
+                records.push( LogRecord{line:line_id + 1,fields} );
+            }
+        }
+    }
+    return Ok( records );
+}
+
+/**
+Splits a line of comma separated values.
+Values may be enclosed within double quotes, in which case they may contain
+the separator and doubled double quotes stand for double quotes.
+**/
+fn split_csv_line(separator : char, line : &str) -> Vec<String> {
+    let mut values = vec![];
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        if quoted {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    current.push('"');
+                } else {
+                    quoted = false;
+                }
+            } else {
+                current.push(c);
+            }
+        } else if c == '"' {
+            quoted = true;
+        } else if c == separator {
+            values.push( current.trim().to_string() );
+            current = String::new();
+        } else {
+            current.push(c);
+        }
+    }
+    values.push( current.trim().to_string() );
+    return values;
+}
+
+
+fn json_lines_records_from_text(log_str : &str) -> Result<Vec<LogRecord>,HibouParsingError> {
+    let mut records = vec![];
+    for (line_id,line) in log_str.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let members = match json_value_from_text(line) {
+            Err(e) => {
+                return Err( HibouParsingError::MatchError(format!("line {:?} : {}", line_id + 1, e)) );
+            },
+            Ok( JsonValue::Object(members) ) => {
+                members
+            },
+            Ok( _ ) => {
+                return Err( HibouParsingError::MatchError(format!("line {:?} : expected a JSON object", line_id + 1)) );
+            }
+        };
+        let mut fields = hashmap!{};
+        for (key,value) in members {
+            match value {
+                JsonValue::String(content) => {
+                    fields.insert(key,content);
+                },
+                JsonValue::Number(number) => {
+                    fields.insert(key,number.to_string());
+                },
+                JsonValue::Bool(boolean) => {
+                    fields.insert(key,boolean.to_string());
+                },
+                _ => {
+                    // nested values cannot be mapped to lifelines, messages or directions
+                }
+            }
+        }
+        records.push( LogRecord{line:line_id + 1,fields} );
+    }
+    return Ok( records );
+}
@@ -0,0 +1,127 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::trace_manip::mutate::remove_actions::remove_chunk_from_component;
+
+
+pub struct MultiTraceMinimization {
+    pub minimized : MultiTrace,
+    pub tested_candidates : u32
+}
+
+/**
+Delta-debugging minimization of a multi-trace w.r.t. a property (e.g. "the analysis yields a Fail verdict")
+which is assumed to hold for the initial multi-trace.
+Chunks of consecutive actions are removed from each component, starting with halves
+and refining down to single actions, and a removal is kept only if the property still holds.
+The process is repeated until no single action can be removed,
+so that the result is 1-minimal : removing any of its actions breaks the property.
+**/
+pub fn minimize_multi_trace(multi_trace : &MultiTrace,
+                            still_holds : &mut dyn FnMut(&MultiTrace) -> bool) -> MultiTraceMinimization {
+    let mut minimized = multi_trace.clone();
+    let mut tested_candidates : u32 = 0;
+    let mut reduced = true;
+    while reduced {
+        reduced = false;
+        for coloc_id in 0..minimized.len() {
+            let mut chunk_length = (minimized.get(coloc_id).unwrap().len() / 2).max(1);
+            loop {
+                let mut start = 0;
+                while start < minimized.get(coloc_id).unwrap().len() {
+                    let candidate = remove_chunk_from_component(&minimized,coloc_id,start,chunk_length);
+                    tested_candidates += 1;
+                    if still_holds(&candidate) {
+                        minimized = candidate;
+                        reduced = true;
+                    } else {
+                        start += chunk_length;
+                    }
+                }
+                if chunk_length == 1 {
+                    break;
+                }
+                chunk_length = chunk_length / 2;
+            }
+        }
+    }
+    MultiTraceMinimization{minimized,tested_candidates}
+}
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::core::execution::trace::multitrace::{multi_trace_length, MultiTrace};
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::BroadcastPrimitive;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+    use crate::process::ana::interface::get_analysis_verdict;
+    use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+    use crate::trace_manip::mutate::remove_actions::remove_chunk_from_component;
+    use super::minimize_multi_trace;
+
+    fn message(orig_lf_id : usize, ms_id : usize, tar_lf_id : usize) -> Box<Interaction> {
+        Box::new(Interaction::Action(BroadcastPrimitive::new(Some(orig_lf_id),
+                                                             MessageTypeExpression::Singleton(ms_id),
+                                                             vec![tar_lf_id])))
+    }
+
+    fn action(lf_id : usize, act_kind : TraceActionKind, ms_id : usize) -> TraceAction {
+        TraceAction::new(lf_id,act_kind,MessageTypeExpression::Singleton(ms_id))
+    }
+
+    #[test]
+    fn minimize_failing_multi_trace_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_lf("l3".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        let co_localizations = CoLocalizations::get_discrete_partition(3);
+        // seq(l1 -- m1 -> l2, l2 -- m2 -> l3)
+        let int = Interaction::CoReg(vec![],message(0,0,1),message(1,1,2));
+        // the emission l1!m2 is not specified
+        let multi_trace : MultiTrace = vec![vec![action(0,TraceActionKind::Emission,0),action(0,TraceActionKind::Emission,1)],
+                                            vec![action(1,TraceActionKind::Reception,0),action(1,TraceActionKind::Emission,1)],
+                                            vec![action(2,TraceActionKind::Reception,1)]];
+        let fails = |candidate : &MultiTrace| -> bool {
+            let (_,verdict) = get_analysis_verdict(&gen_ctx,&co_localizations,candidate,&int,HibouAnalyzeOptions::default());
+            verdict == AnalysisGlobalVerdict::Fail
+        };
+        assert!(fails(&multi_trace));
+        let minimization = minimize_multi_trace(&multi_trace,&mut |candidate| fails(candidate));
+        assert!(fails(&minimization.minimized));
+        assert_eq!(multi_trace_length(&minimization.minimized),1);
+        // 1-minimality : removing any single action makes the analysis no longer fail
+        for coloc_id in 0..minimization.minimized.len() {
+            for act_id in 0..minimization.minimized.get(coloc_id).unwrap().len() {
+                let candidate = remove_chunk_from_component(&minimization.minimized,coloc_id,act_id,1);
+                assert_eq!(multi_trace_length(&candidate),multi_trace_length(&minimization.minimized) - 1);
+                assert!(!fails(&candidate));
+            }
+        }
+    }
+}
@@ -15,4 +15,14 @@ limitations under the License.
 */
 
 pub mod slice;
-pub mod mutate;
\ No newline at end of file
+pub mod mutate;
+pub mod align;
+pub mod minimize;
+pub mod generate;
+pub mod campaign;
+pub mod testgen;
+pub mod query;
+pub mod import;
+
+
+
@@ -17,8 +17,8 @@ limitations under the License.
 
 use std::collections::HashSet;
 use std::path::PathBuf;
-use rand::Rng;
-use rand::rngs::ThreadRng;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
 use rand::distributions::{Distribution, Uniform};
 use crate::core::colocalizations::CoLocalizations;
@@ -26,8 +26,8 @@ use crate::core::execution::trace::multitrace::{MultiTrace, Trace};
 use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
 use crate::core::general_context::GeneralContext;
 use crate::core::message::MessageTypeExpression;
-use crate::io::file_extensions::HIBOU_TRACE_FILE_EXTENSION;
-use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file;
+use crate::io::file_extensions::{HibouFileFormat, HIBOU_TRACE_FILE_EXTENSION};
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file_with_comment;
 
 
 pub fn generate_insert_noise_mutant(gen_ctx : &GeneralContext,
@@ -35,10 +35,12 @@ pub fn generate_insert_noise_mutant(gen_ctx : &GeneralContext,
                                     multi_trace : &MultiTrace,
                                     parent_folder : Option<&str>,
                                     mutant_name : &str,
+                                    file_format : HibouFileFormat,
                                     max_num_inserts : u32,
                                     one_per_compo_max : bool,
-                                    only_at_end : bool) -> String{
-    let file_name = format!("{:}.{:}", mutant_name, HIBOU_TRACE_FILE_EXTENSION);
+                                    only_at_end : bool,
+                                    seed : u64) -> String{
+    let file_name = format!("{:}.{:}", mutant_name, file_format.get_extension(HIBOU_TRACE_FILE_EXTENSION));
     let path : PathBuf;
     let file_path : String;
     match parent_folder {
@@ -50,19 +52,23 @@ pub fn generate_insert_noise_mutant(gen_ctx : &GeneralContext,
         }
     }
     // ***
-    let mutant_mt = mutate_by_inserting_noise(gen_ctx,co_localizations,multi_trace,max_num_inserts,one_per_compo_max,only_at_end);
-    write_multi_trace_into_file(path.as_path(),
-                                gen_ctx,
-                                co_localizations,
-                                &mutant_mt);
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mutant_mt = mutate_by_inserting_noise(&mut rng,gen_ctx,co_localizations,multi_trace,max_num_inserts,one_per_compo_max,only_at_end);
+    write_multi_trace_into_file_with_comment(path.as_path(),
+                                             gen_ctx,
+                                             co_localizations,
+                                             &mutant_mt,
+                                             &format!("mutant generated with seed {:}", seed));
     return path.into_os_string().to_str().unwrap().to_string();
 }
 
 
-fn generate_random_action_on_coloc(rng : &mut ThreadRng, gen_ctx : &GeneralContext, coloc_lfs : &HashSet<usize>) -> TraceAction {
+fn generate_random_action_on_coloc<R : Rng>(rng : &mut R, gen_ctx : &GeneralContext, coloc_lfs : &HashSet<usize>) -> TraceAction {
     let lf_id : usize;
     {
-        let coloc_lfs_as_vec : Vec<usize> = coloc_lfs.iter().cloned().collect();
+        let mut coloc_lfs_as_vec : Vec<usize> = coloc_lfs.iter().cloned().collect();
+        // sorted so that a given seed always yields the same mutant
+        coloc_lfs_as_vec.sort();
         let rng_lf_id_idx = Uniform::from(0..coloc_lfs_as_vec.len() );
         let got_id : usize = rng_lf_id_idx.sample(rng);
         lf_id = *coloc_lfs_as_vec.get(got_id).unwrap();
@@ -82,7 +88,8 @@ fn generate_random_action_on_coloc(rng : &mut ThreadRng, gen_ctx : &GeneralConte
 }
 
 
-fn mutate_by_inserting_noise(gen_ctx : &GeneralContext,
+pub fn mutate_by_inserting_noise<R : Rng>(rng : &mut R,
+                               gen_ctx : &GeneralContext,
                                co_localizations : &CoLocalizations,
                                multi_trace : &MultiTrace,
                                    max_num_inserts : u32,
@@ -96,22 +103,21 @@ fn mutate_by_inserting_noise(gen_ctx : &GeneralContext,
         rem_num_inserts = max_num_inserts;
     }
     // ***
-    let mut rng = rand::thread_rng();
     let rng_coloc_indices = Uniform::from(0..multi_trace.len() );
     // ***
     let mut seen_colocs : HashSet<usize> = hashset!{};
     let mut mutated_mt = multi_trace.clone();
     while rem_num_inserts > 0 {
-        let coloc_id : usize = rng_coloc_indices.sample(&mut rng);
+        let coloc_id : usize = rng_coloc_indices.sample(rng);
         if one_per_compo_max && seen_colocs.contains(&coloc_id) {
             // nothing
         } else {
             let component : &mut Trace = mutated_mt.get_mut(coloc_id).unwrap();
-            let to_insert = generate_random_action_on_coloc(&mut rng,gen_ctx,co_localizations.get_coloc_lfs_ids(coloc_id));
+            let to_insert = generate_random_action_on_coloc(rng,gen_ctx,co_localizations.get_coloc_lfs_ids(coloc_id));
             if only_at_end {
                 component.push(to_insert);
             } else {
-                mutate_vector_by_insert(&mut rng,component,to_insert);
+                mutate_vector_by_insert(rng,component,to_insert);
             }
             rem_num_inserts -= 1;
         }
@@ -121,7 +127,7 @@ fn mutate_by_inserting_noise(gen_ctx : &GeneralContext,
 
 
 
-fn mutate_vector_by_insert<T>(rng : &mut ThreadRng, vector : &mut Vec<T>, to_insert : T) {
+fn mutate_vector_by_insert<T,R : Rng>(rng : &mut R, vector : &mut Vec<T>, to_insert : T) {
     let vec_length = vector.len();
     if vec_length > 0 {
         let mut vec_indices : Vec<usize> = (0..vec_length).collect();
@@ -138,7 +144,9 @@ fn mutate_vector_by_insert<T>(rng : &mut ThreadRng, vector : &mut Vec<T>, to_ins
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
-    use super::{mutate_vector_by_insert};
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::trace_manip::mutate::tests::{assert_mutants_determined_by_seed, get_seed_test_context, get_seed_test_multi_trace};
+    use super::{mutate_by_inserting_noise, mutate_vector_by_insert};
 
     #[test]
     fn mutate_insert_test() {
@@ -152,4 +160,12 @@ mod tests {
         let expected : HashSet<Vec<u32>> = hashset!{vec![4,0,1,2],vec![0,4,1,2],vec![0,1,4,2],vec![0,1,2,4]};
         assert!(expected.contains(&myvec));
     }
+
+    #[test]
+    fn seeded_mutants_test() {
+        let gen_ctx = get_seed_test_context();
+        let coloc = CoLocalizations::get_discrete_partition(2);
+        let multi_trace = get_seed_test_multi_trace();
+        assert_mutants_determined_by_seed(|rng| mutate_by_inserting_noise(rng,&gen_ctx,&coloc,&multi_trace,3,false,false));
+    }
 }
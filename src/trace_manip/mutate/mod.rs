@@ -20,3 +20,102 @@ pub mod swap_actions;
 pub mod swap_component;
 pub mod insert_noise;
 pub mod remove_actions;
+pub mod model_aware;
+
+
+
+
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::collections::HashSet;
+    use std::fs;
+    use std::path::PathBuf;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::core::execution::trace::multitrace::MultiTrace;
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use crate::io::file_extensions::HibouFileFormat;
+    use crate::io::input::htf::interface::parse_htf_file;
+    use super::insert_noise::{generate_insert_noise_mutant, mutate_by_inserting_noise};
+    use super::model_aware::{generate_model_aware_mutant, mutate_model_aware, ModelAwareMutationOperator};
+    use super::remove_actions::{generate_remove_actions_mutant, mutate_by_removing_actions};
+    use super::swap_actions::{generate_swap_actions_mutant, mutate_by_swapping_actions};
+    use super::swap_component::{generate_swap_components_mutant, mutate_by_swapping_components};
+
+    pub(crate) fn get_seed_test_context() -> GeneralContext {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        gen_ctx
+    }
+
+    pub(crate) fn get_seed_test_multi_trace() -> MultiTrace {
+        vec![
+            vec![TraceAction::new(0,TraceActionKind::Emission,MessageTypeExpression::Singleton(0)),
+                 TraceAction::new(0,TraceActionKind::Reception,MessageTypeExpression::Singleton(1)),
+                 TraceAction::new(0,TraceActionKind::Emission,MessageTypeExpression::Singleton(1))],
+            vec![TraceAction::new(1,TraceActionKind::Reception,MessageTypeExpression::Singleton(0)),
+                 TraceAction::new(1,TraceActionKind::Emission,MessageTypeExpression::Singleton(1)),
+                 TraceAction::new(1,TraceActionKind::Reception,MessageTypeExpression::Singleton(1))]]
+    }
+
+    /**
+    Checks that the same seed always yields the same mutant and that different seeds may yield different ones.
+    **/
+    pub(crate) fn assert_mutants_determined_by_seed<F : Fn(&mut StdRng) -> MultiTrace>(mutate : F) {
+        let mut mutants : HashSet<MultiTrace> = HashSet::new();
+        for seed in 0..20 {
+            let mutant = mutate(&mut StdRng::seed_from_u64(seed));
+            assert_eq!(mutate(&mut StdRng::seed_from_u64(seed)),mutant);
+            mutants.insert(mutant);
+        }
+        assert!(mutants.len() > 1);
+    }
+
+    /**
+    Reads back a written mutant, checking that its first line is the comment recording how it was generated.
+    **/
+    fn read_seeded_mutant(gen_ctx : &GeneralContext, path : &str, comment : &str) -> MultiTrace {
+        let content = fs::read_to_string(path).unwrap();
+        assert_eq!(content.lines().next().unwrap(),format!("/* {:} */", comment));
+        parse_htf_file(gen_ctx,path).unwrap().1
+    }
+
+    #[test]
+    fn seed_comment_written_test() {
+        let gen_ctx = get_seed_test_context();
+        let coloc = CoLocalizations::get_discrete_partition(2);
+        let multi_trace = get_seed_test_multi_trace();
+        let dir : PathBuf = std::env::temp_dir().join(format!("hibou_mutate_seed_test_{:}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let parent = Some(dir.to_str().unwrap());
+        let seed : u64 = 42;
+        // ***
+        let path = generate_insert_noise_mutant(&gen_ctx,&coloc,&multi_trace,parent,"noise",HibouFileFormat::Native,3,false,false,seed);
+        assert_eq!(read_seeded_mutant(&gen_ctx,&path,"mutant generated with seed 42"),
+                   mutate_by_inserting_noise(&mut StdRng::seed_from_u64(seed),&gen_ctx,&coloc,&multi_trace,3,false,false));
+        let path = generate_remove_actions_mutant(&gen_ctx,&coloc,&multi_trace,parent,"remove",HibouFileFormat::Native,2,seed);
+        assert_eq!(read_seeded_mutant(&gen_ctx,&path,"mutant generated with seed 42"),
+                   mutate_by_removing_actions(&mut StdRng::seed_from_u64(seed),&gen_ctx,&coloc,&multi_trace,2));
+        let path = generate_swap_actions_mutant(&gen_ctx,&coloc,&multi_trace,parent,"swap",HibouFileFormat::Native,2,seed);
+        assert_eq!(read_seeded_mutant(&gen_ctx,&path,"mutant generated with seed 42"),
+                   mutate_by_swapping_actions(&mut StdRng::seed_from_u64(seed),&multi_trace,2));
+        let other : MultiTrace = vec![vec![],vec![TraceAction::new(1,TraceActionKind::Reception,MessageTypeExpression::Singleton(0))]];
+        let path = generate_swap_components_mutant(&gen_ctx,&coloc,&multi_trace,&other,parent,"compo",HibouFileFormat::Native,1,seed);
+        assert_eq!(read_seeded_mutant(&gen_ctx,&path,"mutant generated with seed 42"),
+                   mutate_by_swapping_components(&mut StdRng::seed_from_u64(seed),&multi_trace,&other,1));
+        let operator = ModelAwareMutationOperator::from_name("flip_kind").unwrap();
+        let (path,_) = generate_model_aware_mutant(&gen_ctx,&coloc,&multi_trace,&Interaction::Empty,parent,"model",HibouFileFormat::Native,&operator,2,seed);
+        assert_eq!(read_seeded_mutant(&gen_ctx,&path,"mutant generated with seed 42 using operator flip_kind"),
+                   mutate_model_aware(&mut StdRng::seed_from_u64(seed),&gen_ctx,&coloc,&multi_trace,&Interaction::Empty,&operator,2).0);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+}
@@ -0,0 +1,345 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::from_model::from_model::InteractionInterpretableAsTraceActions;
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::core::message::MessageTypeExpression;
+use crate::io::file_extensions::{HibouFileFormat, HIBOU_TRACE_FILE_EXTENSION};
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file_with_comment;
+
+
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum ModelAwareMutationOperator {
+    ReplaceWithSameType,
+    ReplaceWithOtherType,
+    FlipKind,
+    MoveLifeline,
+    Duplicate,
+    InsertModelNoise
+}
+
+impl ModelAwareMutationOperator {
+
+    pub fn from_name(name : &str) -> Option<ModelAwareMutationOperator> {
+        match name {
+            "replace_same_type" => Some(ModelAwareMutationOperator::ReplaceWithSameType),
+            "replace_other_type" => Some(ModelAwareMutationOperator::ReplaceWithOtherType),
+            "flip_kind" => Some(ModelAwareMutationOperator::FlipKind),
+            "move_lifeline" => Some(ModelAwareMutationOperator::MoveLifeline),
+            "duplicate" => Some(ModelAwareMutationOperator::Duplicate),
+            "model_noise" => Some(ModelAwareMutationOperator::InsertModelNoise),
+            _ => None
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            ModelAwareMutationOperator::ReplaceWithSameType => "replace_same_type",
+            ModelAwareMutationOperator::ReplaceWithOtherType => "replace_other_type",
+            ModelAwareMutationOperator::FlipKind => "flip_kind",
+            ModelAwareMutationOperator::MoveLifeline => "move_lifeline",
+            ModelAwareMutationOperator::Duplicate => "duplicate",
+            ModelAwareMutationOperator::InsertModelNoise => "model_noise"
+        }
+    }
+
+}
+
+
+/**
+Applies up to "max_num_mutations" times the given operator and writes the mutant.
+Returns the path of the mutant and the number of mutations that could actually be applied.
+**/
+pub fn generate_model_aware_mutant(gen_ctx : &GeneralContext,
+                                   co_localizations : &CoLocalizations,
+                                   multi_trace : &MultiTrace,
+                                   interaction : &Interaction,
+                                   parent_folder : Option<&str>,
+                                   mutant_name : &str,
+                                   file_format : HibouFileFormat,
+                                   operator : &ModelAwareMutationOperator,
+                                   max_num_mutations : u32,
+                                   seed : u64) -> (String,u32) {
+    let file_name = format!("{:}.{:}", mutant_name, file_format.get_extension(HIBOU_TRACE_FILE_EXTENSION));
+    let path : PathBuf;
+    match parent_folder {
+        None => {
+            path = [&file_name].iter().collect();
+        },
+        Some( parent ) => {
+            path = [parent, &file_name].iter().collect();
+        }
+    }
+    // ***
+    let mut rng = StdRng::seed_from_u64(seed);
+    let (mutant_mt,num_applied) = mutate_model_aware(&mut rng,gen_ctx,co_localizations,multi_trace,interaction,operator,max_num_mutations);
+    write_multi_trace_into_file_with_comment(path.as_path(),
+                                             gen_ctx,
+                                             co_localizations,
+                                             &mutant_mt,
+                                             &format!("mutant generated with seed {:} using operator {:}", seed, operator.get_name()));
+    return (path.into_os_string().to_str().unwrap().to_string(),num_applied);
+}
+
+
+pub fn mutate_model_aware<R : Rng>(rng : &mut R,
+                                   gen_ctx : &GeneralContext,
+                                   co_localizations : &CoLocalizations,
+                                   multi_trace : &MultiTrace,
+                                   interaction : &Interaction,
+                                   operator : &ModelAwareMutationOperator,
+                                   max_num_mutations : u32) -> (MultiTrace,u32) {
+    // the model alphabet, sorted so that a given seed always yields the same mutant
+    let mut alphabet : Vec<TraceAction> = vec![];
+    if let ModelAwareMutationOperator::InsertModelNoise = operator {
+        for model_act in interaction.get_all_trace_actions() {
+            let mut ms_ids : Vec<usize> = model_act.message.resolve(gen_ctx).into_iter().collect();
+            ms_ids.sort();
+            for ms_id in ms_ids {
+                alphabet.push(TraceAction::new(model_act.lf_id,model_act.act_kind,MessageTypeExpression::Singleton(ms_id)));
+            }
+        }
+        alphabet.sort_by_key(|act| (act.lf_id,act.act_kind,get_action_ms_id(gen_ctx,act)));
+        alphabet.dedup();
+    }
+    // ***
+    let mut mutated_mt = multi_trace.clone();
+    let mut num_applied : u32 = 0;
+    while num_applied < max_num_mutations {
+        let applied = match operator {
+            ModelAwareMutationOperator::InsertModelNoise => {
+                mutate_by_inserting_model_noise(rng,co_localizations,&mut mutated_mt,&alphabet)
+            },
+            _ => {
+                mutate_at_random_position(rng,gen_ctx,co_localizations,&mut mutated_mt,operator)
+            }
+        };
+        if !applied {
+            break;
+        }
+        num_applied += 1;
+    }
+    return (mutated_mt,num_applied);
+}
+
+
+fn get_action_ms_id(gen_ctx : &GeneralContext, action : &TraceAction) -> usize {
+    let mut ms_ids : Vec<usize> = action.message.resolve(gen_ctx).into_iter().collect();
+    ms_ids.sort();
+    return *ms_ids.first().unwrap();
+}
+
+/**
+Messages which are in a common named message type with the given message (same type)
+or in no common named message type with it (other type).
+**/
+fn get_replacement_messages(gen_ctx : &GeneralContext,
+                            ms_id : usize,
+                            same_type : bool) -> Vec<usize> {
+    let mut related : HashSet<usize> = hashset!{};
+    for mt_id in 0..gen_ctx.get_mt_num() {
+        let mt_msgs = gen_ctx.get_mt_messages(mt_id).unwrap();
+        if mt_msgs.contains(&ms_id) {
+            related.extend(mt_msgs);
+        }
+    }
+    let mut candidates : Vec<usize> = (0..gen_ctx.get_ms_num())
+        .filter(|other_id| *other_id != ms_id && related.contains(other_id) == same_type)
+        .collect();
+    candidates.sort();
+    return candidates;
+}
+
+/**
+Returns the actions which may replace the given one according to the operator.
+For "Duplicate", it returns the action itself.
+**/
+fn get_action_replacements(gen_ctx : &GeneralContext,
+                           co_localizations : &CoLocalizations,
+                           coloc_id : usize,
+                           action : &TraceAction,
+                           operator : &ModelAwareMutationOperator) -> Vec<TraceAction> {
+    match operator {
+        ModelAwareMutationOperator::ReplaceWithSameType => {
+            get_replacement_messages(gen_ctx, get_action_ms_id(gen_ctx,action), true)
+                .into_iter()
+                .map(|ms_id| TraceAction::new(action.lf_id,action.act_kind,MessageTypeExpression::Singleton(ms_id)))
+                .collect()
+        },
+        ModelAwareMutationOperator::ReplaceWithOtherType => {
+            get_replacement_messages(gen_ctx, get_action_ms_id(gen_ctx,action), false)
+                .into_iter()
+                .map(|ms_id| TraceAction::new(action.lf_id,action.act_kind,MessageTypeExpression::Singleton(ms_id)))
+                .collect()
+        },
+        ModelAwareMutationOperator::FlipKind => {
+            let flipped = match action.act_kind {
+                TraceActionKind::Emission => TraceActionKind::Reception,
+                TraceActionKind::Reception => TraceActionKind::Emission
+            };
+            vec![TraceAction::new(action.lf_id,flipped,action.message.clone())]
+        },
+        ModelAwareMutationOperator::MoveLifeline => {
+            let mut other_lfs : Vec<usize> = co_localizations.get_coloc_lfs_ids(coloc_id)
+                .iter()
+                .filter(|lf_id| **lf_id != action.lf_id)
+                .cloned()
+                .collect();
+            other_lfs.sort();
+            other_lfs.into_iter()
+                .map(|lf_id| TraceAction::new(lf_id,action.act_kind,action.message.clone()))
+                .collect()
+        },
+        ModelAwareMutationOperator::Duplicate => {
+            vec![action.clone()]
+        },
+        ModelAwareMutationOperator::InsertModelNoise => {
+            vec![]
+        }
+    }
+}
+
+fn mutate_at_random_position<R : Rng>(rng : &mut R,
+                                      gen_ctx : &GeneralContext,
+                                      co_localizations : &CoLocalizations,
+                                      multi_trace : &mut MultiTrace,
+                                      operator : &ModelAwareMutationOperator) -> bool {
+    // all the (component, position, replacement) triples the operator can produce
+    let mut sites : Vec<(usize,usize,TraceAction)> = vec![];
+    for (coloc_id,component) in multi_trace.iter().enumerate() {
+        for (act_idx,action) in component.iter().enumerate() {
+            for replacement in get_action_replacements(gen_ctx,co_localizations,coloc_id,action,operator) {
+                sites.push((coloc_id,act_idx,replacement));
+            }
+        }
+    }
+    match sites.choose(rng) {
+        None => {
+            return false;
+        },
+        Some( (coloc_id,act_idx,replacement) ) => {
+            let component = multi_trace.get_mut(*coloc_id).unwrap();
+            if let ModelAwareMutationOperator::Duplicate = operator {
+                component.insert(*act_idx + 1, replacement.clone());
+            } else {
+                component[*act_idx] = replacement.clone();
+            }
+            return true;
+        }
+    }
+}
+
+fn mutate_by_inserting_model_noise<R : Rng>(rng : &mut R,
+                                            co_localizations : &CoLocalizations,
+                                            multi_trace : &mut MultiTrace,
+                                            alphabet : &Vec<TraceAction>) -> bool {
+    let insertable : Vec<(usize,&TraceAction)> = alphabet.iter()
+        .filter_map(|act| co_localizations.get_lf_coloc_id(act.lf_id).map(|coloc_id| (coloc_id,act)))
+        .filter(|(coloc_id,_)| *coloc_id < multi_trace.len())
+        .collect();
+    match insertable.choose(rng) {
+        None => {
+            return false;
+        },
+        Some( (coloc_id,action) ) => {
+            let component = multi_trace.get_mut(*coloc_id).unwrap();
+            let position = rng.gen_range(0..=component.len());
+            component.insert(position, (*action).clone());
+            return true;
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::execution::trace::multitrace::MultiTrace;
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::BroadcastPrimitive;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use crate::trace_manip::mutate::tests::assert_mutants_determined_by_seed;
+    use super::{mutate_model_aware, ModelAwareMutationOperator};
+
+    fn get_test_context() -> GeneralContext {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("a".to_string());
+        gen_ctx.add_lf("b".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        gen_ctx.add_msg("m3".to_string());
+        gen_ctx.add_mt("t".to_string(), hashset!{0,1});
+        return gen_ctx;
+    }
+
+    #[test]
+    fn replace_same_type_test() {
+        let gen_ctx = get_test_context();
+        let coloc = CoLocalizations::get_discrete_partition(2);
+        let mt = vec![vec![TraceAction::new(0,TraceActionKind::Emission,MessageTypeExpression::Singleton(0))],vec![]];
+        let mut rng = StdRng::seed_from_u64(0);
+        let (mutant,applied) = mutate_model_aware(&mut rng,&gen_ctx,&coloc,&mt,&Interaction::Empty,&ModelAwareMutationOperator::ReplaceWithSameType,1);
+        assert_eq!(applied,1);
+        let expected = vec![vec![TraceAction::new(0,TraceActionKind::Emission,MessageTypeExpression::Singleton(1))],vec![]];
+        assert_eq!(mutant,expected);
+    }
+
+    #[test]
+    fn move_lifeline_test() {
+        let gen_ctx = get_test_context();
+        let mt = vec![vec![TraceAction::new(0,TraceActionKind::Reception,MessageTypeExpression::Singleton(2))]];
+        let mut rng = StdRng::seed_from_u64(0);
+        // no other lifeline on the same co-localization
+        let (_,applied) = mutate_model_aware(&mut rng,&gen_ctx,&CoLocalizations::get_discrete_partition(2),&mt,&Interaction::Empty,&ModelAwareMutationOperator::MoveLifeline,1);
+        assert_eq!(applied,0);
+        let (mutant,applied) = mutate_model_aware(&mut rng,&gen_ctx,&CoLocalizations::get_trivial_partition(2),&mt,&Interaction::Empty,&ModelAwareMutationOperator::MoveLifeline,1);
+        assert_eq!(applied,1);
+        let expected = vec![vec![TraceAction::new(1,TraceActionKind::Reception,MessageTypeExpression::Singleton(2))]];
+        assert_eq!(mutant,expected);
+    }
+
+    #[test]
+    fn seeded_mutants_test() {
+        let gen_ctx = get_test_context();
+        let coloc = CoLocalizations::get_trivial_partition(2);
+        let mt : MultiTrace = vec![vec![TraceAction::new(0,TraceActionKind::Emission,MessageTypeExpression::Singleton(0)),
+                                        TraceAction::new(1,TraceActionKind::Reception,MessageTypeExpression::Singleton(0)),
+                                        TraceAction::new(1,TraceActionKind::Emission,MessageTypeExpression::Singleton(2))]];
+        // alt(a -- t -> b, b -- m3 -> a)
+        let int = Interaction::Alt(Box::new(Interaction::Action(BroadcastPrimitive::new(Some(0),MessageTypeExpression::NamedType(0),vec![1]))),
+                                   Box::new(Interaction::Action(BroadcastPrimitive::new(Some(1),MessageTypeExpression::Singleton(2),vec![0]))));
+        for name in ["replace_same_type","replace_other_type","flip_kind","move_lifeline","duplicate","model_noise"] {
+            let operator = ModelAwareMutationOperator::from_name(name).unwrap();
+            assert_mutants_determined_by_seed(|rng| mutate_model_aware(rng,&gen_ctx,&coloc,&mt,&int,&operator,2).0);
+        }
+    }
+}
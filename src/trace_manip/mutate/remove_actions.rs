@@ -16,15 +16,16 @@ limitations under the License.
 
 
 use std::path::PathBuf;
-use rand::rngs::ThreadRng;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
 use rand::distributions::{Distribution, Uniform};
 
 use crate::core::colocalizations::CoLocalizations;
 use crate::core::execution::trace::multitrace::{MultiTrace, Trace};
 use crate::core::general_context::GeneralContext;
-use crate::io::file_extensions::HIBOU_TRACE_FILE_EXTENSION;
-use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file;
+use crate::io::file_extensions::{HibouFileFormat, HIBOU_TRACE_FILE_EXTENSION};
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file_with_comment;
 
 
 pub fn generate_remove_actions_mutant(gen_ctx : &GeneralContext,
@@ -32,8 +33,10 @@ pub fn generate_remove_actions_mutant(gen_ctx : &GeneralContext,
                                     multi_trace : &MultiTrace,
                                     parent_folder : Option<&str>,
                                     mutant_name : &str,
-                                    max_num_removes : u32) -> String {
-    let file_name = format!("{:}.{:}", mutant_name, HIBOU_TRACE_FILE_EXTENSION);
+                                    file_format : HibouFileFormat,
+                                    max_num_removes : u32,
+                                    seed : u64) -> String {
+    let file_name = format!("{:}.{:}", mutant_name, file_format.get_extension(HIBOU_TRACE_FILE_EXTENSION));
     let path : PathBuf = match parent_folder {
         None => {
             [&file_name].iter().collect()
@@ -43,33 +46,35 @@ pub fn generate_remove_actions_mutant(gen_ctx : &GeneralContext,
         }
     };
     // ***
-    let mutant_mt = mutate_by_removing_actions(gen_ctx,co_localizations,multi_trace,max_num_removes);
-    write_multi_trace_into_file(path.as_path(),
-                                gen_ctx,
-                                co_localizations,
-                                &mutant_mt);
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mutant_mt = mutate_by_removing_actions(&mut rng,gen_ctx,co_localizations,multi_trace,max_num_removes);
+    write_multi_trace_into_file_with_comment(path.as_path(),
+                                             gen_ctx,
+                                             co_localizations,
+                                             &mutant_mt,
+                                             &format!("mutant generated with seed {:}", seed));
     // ***
     path.into_os_string().to_str().unwrap().to_string()
 }
 
 
 
-fn mutate_by_removing_actions(gen_ctx : &GeneralContext,
+pub fn mutate_by_removing_actions<R : Rng>(rng : &mut R,
+                             gen_ctx : &GeneralContext,
                              co_localizations : &CoLocalizations,
                              multi_trace : &MultiTrace,
                              max_num_removes : u32) -> MultiTrace {
     // ***
     let mut rem_num_removes = max_num_removes;
     // ***
-    let mut rng = rand::thread_rng();
     let rng_coloc_indices = Uniform::from(0..multi_trace.len() );
     // ***
     let mut mutated_mt = multi_trace.clone();
     while rem_num_removes > 0 {
-        let coloc_id : usize = rng_coloc_indices.sample(&mut rng);
+        let coloc_id : usize = rng_coloc_indices.sample(rng);
         // ***
         let component : &mut Trace = mutated_mt.get_mut(coloc_id).unwrap();
-        mutate_vector_by_removal(&mut rng,component);
+        mutate_vector_by_removal(rng,component);
         rem_num_removes -= 1;
     }
     // ***
@@ -78,12 +83,36 @@ fn mutate_by_removing_actions(gen_ctx : &GeneralContext,
 
 
 
-fn mutate_vector_by_removal<T>(rng : &mut ThreadRng, vector : &mut Vec<T>) {
+fn mutate_vector_by_removal<T,R : Rng>(rng : &mut R, vector : &mut Vec<T>) {
     let vec_length = vector.len();
     if vec_length > 0 {
         let mut vec_indices : Vec<usize> = (0..vec_length).collect();
         vec_indices.shuffle(rng);
-        vector.remove(*vec_indices.get(0).unwrap());
+        remove_vector_chunk(vector,*vec_indices.get(0).unwrap(),1);
+    }
+}
+
+
+/**
+Returns a copy of the multi-trace in which a chunk of consecutive actions
+(at most 'length' actions starting at index 'start') is removed from the component 'coloc_id'.
+**/
+pub fn remove_chunk_from_component(multi_trace : &MultiTrace,
+                                   coloc_id : usize,
+                                   start : usize,
+                                   length : usize) -> MultiTrace {
+    let mut mutated_mt = multi_trace.clone();
+    let component : &mut Trace = mutated_mt.get_mut(coloc_id).unwrap();
+    remove_vector_chunk(component,start,length);
+    mutated_mt
+}
+
+
+fn remove_vector_chunk<T>(vector : &mut Vec<T>, start : usize, length : usize) {
+    let vec_length = vector.len();
+    if start < vec_length {
+        let end = (start + length).min(vec_length);
+        vector.drain(start..end);
     }
 }
 
@@ -93,7 +122,9 @@ fn mutate_vector_by_removal<T>(rng : &mut ThreadRng, vector : &mut Vec<T>) {
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
-    use super::{mutate_vector_by_removal};
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::trace_manip::mutate::tests::{assert_mutants_determined_by_seed, get_seed_test_context, get_seed_test_multi_trace};
+    use super::{mutate_by_removing_actions, mutate_vector_by_removal, remove_vector_chunk};
 
     #[test]
     fn mutate_remove_test() {
@@ -108,4 +139,22 @@ mod tests {
         assert!(expected.contains(&myvec));
     }
 
+    #[test]
+    fn remove_chunk_test() {
+        let mut myvec : Vec<u32> = vec![0,1,2,3,4];
+        remove_vector_chunk(&mut myvec, 1, 2);
+        assert_eq!(myvec, vec![0,3,4]);
+        remove_vector_chunk(&mut myvec, 2, 5);
+        assert_eq!(myvec, vec![0,3]);
+        remove_vector_chunk(&mut myvec, 2, 1);
+        assert_eq!(myvec, vec![0,3]);
+    }
+
+    #[test]
+    fn seeded_mutants_test() {
+        let gen_ctx = get_seed_test_context();
+        let coloc = CoLocalizations::get_discrete_partition(2);
+        let multi_trace = get_seed_test_multi_trace();
+        assert_mutants_determined_by_seed(|rng| mutate_by_removing_actions(rng,&gen_ctx,&coloc,&multi_trace,2));
+    }
 }
\ No newline at end of file
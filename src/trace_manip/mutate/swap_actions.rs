@@ -19,14 +19,15 @@ limitations under the License.
 
 use std::path::PathBuf;
 use std::ptr;
-use rand::rngs::ThreadRng;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
 use rand::distributions::{Distribution, Uniform};
 use crate::core::colocalizations::CoLocalizations;
 use crate::core::execution::trace::multitrace::{MultiTrace, Trace};
 use crate::core::general_context::GeneralContext;
-use crate::io::file_extensions::HIBOU_TRACE_FILE_EXTENSION;
-use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file;
+use crate::io::file_extensions::{HibouFileFormat, HIBOU_TRACE_FILE_EXTENSION};
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file_with_comment;
 
 
 pub fn generate_swap_actions_mutant(gen_ctx : &GeneralContext,
@@ -34,8 +35,10 @@ pub fn generate_swap_actions_mutant(gen_ctx : &GeneralContext,
                        multi_trace : &MultiTrace,
                        parent_folder : Option<&str>,
                        mutant_name : &str,
-                       max_num_swaps : u32) -> String {
-    let file_name = format!("{:}.{:}", mutant_name, HIBOU_TRACE_FILE_EXTENSION);
+                       file_format : HibouFileFormat,
+                       max_num_swaps : u32,
+                       seed : u64) -> String {
+    let file_name = format!("{:}.{:}", mutant_name, file_format.get_extension(HIBOU_TRACE_FILE_EXTENSION));
     let path : PathBuf;
     let file_path : String;
     match parent_folder {
@@ -47,28 +50,29 @@ pub fn generate_swap_actions_mutant(gen_ctx : &GeneralContext,
         }
     }
     // ***
-    let mutant_mt = mutate_by_swapping_actions(multi_trace,max_num_swaps);
-    write_multi_trace_into_file(path.as_path(),
-                                gen_ctx,
-                                co_localizations,
-                                &mutant_mt);
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mutant_mt = mutate_by_swapping_actions(&mut rng,multi_trace,max_num_swaps);
+    write_multi_trace_into_file_with_comment(path.as_path(),
+                                             gen_ctx,
+                                             co_localizations,
+                                             &mutant_mt,
+                                             &format!("mutant generated with seed {:}", seed));
     return path.into_os_string().to_str().unwrap().to_string();
 }
 
 
-fn mutate_by_swapping_actions(multi_trace : &MultiTrace, max_num_swaps : u32) -> MultiTrace {
+pub fn mutate_by_swapping_actions<R : Rng>(rng : &mut R, multi_trace : &MultiTrace, max_num_swaps : u32) -> MultiTrace {
     // ***
     let mut rem_num_swaps = max_num_swaps.min(multi_trace_max_swap(multi_trace));
     // ***
-    let mut rng = rand::thread_rng();
     let rng_coloc_indices = Uniform::from(0..multi_trace.len() );
     // ***
     let mut mutated_mt = multi_trace.clone();
     while rem_num_swaps > 0 {
-        let coloc_id : usize = rng_coloc_indices.sample(&mut rng);
+        let coloc_id : usize = rng_coloc_indices.sample(rng);
         let component : &mut Trace = mutated_mt.get_mut(coloc_id).unwrap();
         // ***
-        if mutate_vector_by_swapping(&mut rng, component) {
+        if mutate_vector_by_swapping(rng, component) {
             rem_num_swaps -= 1;
         }
     }
@@ -86,7 +90,7 @@ fn multi_trace_max_swap(multi_trace : &MultiTrace) -> u32 {
 }
 
 
-fn mutate_vector_by_swapping<T>(rng : &mut ThreadRng,vector : &mut Vec<T>) -> bool {
+fn mutate_vector_by_swapping<T,R : Rng>(rng : &mut R,vector : &mut Vec<T>) -> bool {
     let vec_length = vector.len();
     if vec_length > 1 {
         let mut vec_indices : Vec<usize> = (0..vec_length).collect();
@@ -109,7 +113,8 @@ fn vector_swap<T>(vector : &mut Vec<T>, a: usize, b: usize) {
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
-    use super::{vector_swap, mutate_vector_by_swapping};
+    use crate::trace_manip::mutate::tests::{assert_mutants_determined_by_seed, get_seed_test_multi_trace};
+    use super::{vector_swap, mutate_by_swapping_actions, mutate_vector_by_swapping};
 
     #[test]
     fn swap_test() {
@@ -132,4 +137,10 @@ mod tests {
         let as_hashset: HashSet<u32> = myvec.iter().cloned().collect();
         assert_eq!(as_hashset,expected)
     }
+
+    #[test]
+    fn seeded_mutants_test() {
+        let multi_trace = get_seed_test_multi_trace();
+        assert_mutants_determined_by_seed(|rng| mutate_by_swapping_actions(rng,&multi_trace,2));
+    }
 }
\ No newline at end of file
@@ -17,14 +17,15 @@ limitations under the License.
 
 
 use std::path::PathBuf;
-use rand::rngs::ThreadRng;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
 use rand::distributions::{Distribution, Uniform};
 use crate::core::colocalizations::CoLocalizations;
 use crate::core::execution::trace::multitrace::{MultiTrace};
 use crate::core::general_context::GeneralContext;
-use crate::io::file_extensions::HIBOU_TRACE_FILE_EXTENSION;
-use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file;
+use crate::io::file_extensions::{HibouFileFormat, HIBOU_TRACE_FILE_EXTENSION};
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file_with_comment;
 
 
 pub fn generate_swap_components_mutant(gen_ctx : &GeneralContext,
@@ -33,8 +34,10 @@ pub fn generate_swap_components_mutant(gen_ctx : &GeneralContext,
                                        mu2 : &MultiTrace,
                                     parent_folder : Option<&str>,
                                     mutant_name : &str,
-                                    max_num_swaps : u32) -> String{
-    let file_name = format!("{:}.{:}", mutant_name, HIBOU_TRACE_FILE_EXTENSION);
+                                    file_format : HibouFileFormat,
+                                    max_num_swaps : u32,
+                                    seed : u64) -> String{
+    let file_name = format!("{:}.{:}", mutant_name, file_format.get_extension(HIBOU_TRACE_FILE_EXTENSION));
     let path : PathBuf;
     let file_path : String;
     match parent_folder {
@@ -46,21 +49,22 @@ pub fn generate_swap_components_mutant(gen_ctx : &GeneralContext,
         }
     }
     // ***
-    let mutant_mt = mutate_by_swapping_components(mu1,mu2,max_num_swaps);
-    write_multi_trace_into_file(path.as_path(),
-                                gen_ctx,
-                                co_localizations,
-                                &mutant_mt);
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mutant_mt = mutate_by_swapping_components(&mut rng,mu1,mu2,max_num_swaps);
+    write_multi_trace_into_file_with_comment(path.as_path(),
+                                             gen_ctx,
+                                             co_localizations,
+                                             &mutant_mt,
+                                             &format!("mutant generated with seed {:}", seed));
     return path.into_os_string().to_str().unwrap().to_string();;
 }
 
 
-fn mutate_by_swapping_components(multi_trace_1 : &MultiTrace, multi_trace_2 : &MultiTrace, max_num_swaps : u32) -> MultiTrace {
+pub fn mutate_by_swapping_components<R : Rng>(rng : &mut R, multi_trace_1 : &MultiTrace, multi_trace_2 : &MultiTrace, max_num_swaps : u32) -> MultiTrace {
     let num_compos = multi_trace_1.len() as u32;
     // ***
-    let mut rng = rand::thread_rng();
     let mut compos_indices : Vec<usize> = (0..(num_compos as usize)).collect();
-    compos_indices.shuffle(&mut rng);
+    compos_indices.shuffle(rng);
     // ***
     let mut rem_num_swaps = max_num_swaps.max(num_compos - 1);
     // ***
@@ -74,7 +78,7 @@ fn mutate_by_swapping_components(multi_trace_1 : &MultiTrace, multi_trace_2 : &M
 }
 
 
-fn mutate_merge_vectors_by_exchange<T : Clone>(rng : &mut ThreadRng, vec_1 : &mut Vec<T>, vec_2 : &Vec<T>) {
+fn mutate_merge_vectors_by_exchange<T : Clone,R : Rng>(rng : &mut R, vec_1 : &mut Vec<T>, vec_2 : &Vec<T>) {
     assert_eq!(vec_1.len(),vec_2.len());
     let vec_length = vec_1.len();
     if vec_length >= 1 {
@@ -95,7 +99,11 @@ fn vectors_exchange<T : Clone>(vec_1 : &mut Vec<T>, vec_2 : &Vec<T>, index : usi
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
-    use super::{vectors_exchange,mutate_merge_vectors_by_exchange};
+    use crate::core::execution::trace::multitrace::MultiTrace;
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::message::MessageTypeExpression;
+    use crate::trace_manip::mutate::tests::{assert_mutants_determined_by_seed, get_seed_test_multi_trace};
+    use super::{vectors_exchange,mutate_by_swapping_components,mutate_merge_vectors_by_exchange};
 
     #[test]
     fn exchange_test() {
@@ -117,4 +125,11 @@ mod tests {
         let expected : HashSet<Vec<u32>> = hashset!{vec![4,1,2],vec![0,5,2],vec![0,1,6]};
         assert!(expected.contains(&vec_1));
     }
+
+    #[test]
+    fn seeded_mutants_test() {
+        let multi_trace_1 = get_seed_test_multi_trace();
+        let multi_trace_2 : MultiTrace = vec![vec![],vec![TraceAction::new(1,TraceActionKind::Reception,MessageTypeExpression::Singleton(0))]];
+        assert_mutants_determined_by_seed(|rng| mutate_by_swapping_components(rng,&multi_trace_1,&multi_trace_2,1));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,221 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::general_context::GeneralContext;
+use crate::trace_manip::query::query::{action_matches_query_actions, TraceQuery, TraceQueryPattern, TraceQueryScope};
+
+
+/**
+An action violating a query, located by the index of its component trace
+and by its position within this component trace.
+**/
+#[derive(Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+pub struct TraceQueryViolation {
+    pub canal_id : usize,
+    pub position : usize
+}
+
+
+/**
+Returns the positions, among the given ones, of the actions which violate the pattern.
+**/
+fn evaluate_pattern_on_positions(gen_ctx : &GeneralContext,
+                                 pattern : &TraceQueryPattern,
+                                 multi_trace : &MultiTrace,
+                                 canal_id : usize,
+                                 positions : &Vec<usize>) -> Vec<usize> {
+    let trace = &multi_trace[canal_id];
+    let mut violating = vec![];
+    match pattern {
+        TraceQueryPattern::Response(triggers,responses) => {
+            // a trigger is violating if no response occurs after it
+            let mut awaiting : Vec<usize> = vec![];
+            for position in positions {
+                let action = &trace[*position];
+                if action_matches_query_actions(gen_ctx,action,responses) {
+                    awaiting.clear();
+                }
+                if action_matches_query_actions(gen_ctx,action,triggers) {
+                    awaiting.push(*position);
+                }
+            }
+            violating = awaiting;
+        },
+        TraceQueryPattern::Precedence(causes,effects) => {
+            let mut got_cause = false;
+            for position in positions {
+                let action = &trace[*position];
+                if !got_cause && action_matches_query_actions(gen_ctx,action,effects) {
+                    violating.push(*position);
+                }
+                if action_matches_query_actions(gen_ctx,action,causes) {
+                    got_cause = true;
+                }
+            }
+        },
+        TraceQueryPattern::Absence(forbidden) => {
+            for position in positions {
+                if action_matches_query_actions(gen_ctx,&trace[*position],forbidden) {
+                    violating.push(*position);
+                }
+            }
+        },
+        TraceQueryPattern::BoundedExistence(bounded,max_occurrences) => {
+            let mut occurrences : u32 = 0;
+            for position in positions {
+                if action_matches_query_actions(gen_ctx,&trace[*position],bounded) {
+                    occurrences += 1;
+                    if occurrences > *max_occurrences {
+                        violating.push(*position);
+                    }
+                }
+            }
+        }
+    }
+    violating
+}
+
+
+/**
+Evaluates the query on a multi-trace and returns the actions which violate it, sorted by component and position.
+With the lifeline scope, the pattern is evaluated separately on the sub-sequence of the actions of each lifeline
+within each component trace.
+**/
+pub fn evaluate_trace_query(gen_ctx : &GeneralContext,
+                            query : &TraceQuery,
+                            multi_trace : &MultiTrace) -> Vec<TraceQueryViolation> {
+    let mut violations = vec![];
+    for (canal_id,trace) in multi_trace.iter().enumerate() {
+        let mut sequences : Vec<Vec<usize>> = vec![];
+        match query.scope {
+            TraceQueryScope::Component => {
+                sequences.push( (0..trace.len()).collect() );
+            },
+            TraceQueryScope::Lifeline => {
+                for lf_id in 0..gen_ctx.get_lf_num() {
+                    let positions : Vec<usize> = (0..trace.len()).filter(|pos| trace[*pos].lf_id == lf_id).collect();
+                    if !positions.is_empty() {
+                        sequences.push(positions);
+                    }
+                }
+            }
+        }
+        for positions in sequences {
+            for position in evaluate_pattern_on_positions(gen_ctx,&query.pattern,multi_trace,canal_id,&positions) {
+                violations.push( TraceQueryViolation{canal_id,position} );
+            }
+        }
+    }
+    violations.sort();
+    violations
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::core::message::MessageTypeExpression;
+    use crate::trace_manip::query::query::{TraceQuery, TraceQueryPattern, TraceQueryScope};
+    use super::{evaluate_trace_query, TraceQueryViolation};
+
+    fn action(lf_id : usize, act_kind : TraceActionKind, ms_id : usize) -> TraceAction {
+        TraceAction::new(lf_id,act_kind,MessageTypeExpression::Singleton(ms_id))
+    }
+
+    #[test]
+    fn response_scope_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        // l1!m1.l2?m1.l1!m1
+        let multi_trace = vec![vec![action(0,TraceActionKind::Emission,0),
+                                    action(1,TraceActionKind::Reception,0),
+                                    action(0,TraceActionKind::Emission,0)]];
+        let pattern = TraceQueryPattern::Response(vec![action(0,TraceActionKind::Emission,0)],
+                                                  vec![action(1,TraceActionKind::Reception,0)]);
+        // only the last emission is not followed by a reception
+        let per_component = TraceQuery::new(pattern.clone(),TraceQueryScope::Component);
+        assert_eq!(evaluate_trace_query(&gen_ctx,&per_component,&multi_trace),
+                   vec![TraceQueryViolation{canal_id:0,position:2}]);
+        // the actions of l2 are not seen from l1
+        let per_lifeline = TraceQuery::new(pattern,TraceQueryScope::Lifeline);
+        assert_eq!(evaluate_trace_query(&gen_ctx,&per_lifeline,&multi_trace),
+                   vec![TraceQueryViolation{canal_id:0,position:0},TraceQueryViolation{canal_id:0,position:2}]);
+    }
+
+    #[test]
+    fn precedence_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        // l1!m2.l1!m1.l1!m2
+        let multi_trace = vec![vec![action(0,TraceActionKind::Emission,1),
+                                    action(0,TraceActionKind::Emission,0),
+                                    action(0,TraceActionKind::Emission,1)]];
+        // only the first m2 is not preceded by an m1
+        let query = TraceQuery::new(TraceQueryPattern::Precedence(vec![action(0,TraceActionKind::Emission,0)],
+                                                                  vec![action(0,TraceActionKind::Emission,1)]),
+                                    TraceQueryScope::Component);
+        assert_eq!(evaluate_trace_query(&gen_ctx,&query,&multi_trace),
+                   vec![TraceQueryViolation{canal_id:0,position:0}]);
+    }
+
+    #[test]
+    fn absence_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        // l1!m1 on a first component and l2?m1.l2?m1 on a second one
+        let multi_trace = vec![vec![action(0,TraceActionKind::Emission,0)],
+                               vec![action(1,TraceActionKind::Reception,0),
+                                    action(1,TraceActionKind::Reception,0)]];
+        let query = TraceQuery::new(TraceQueryPattern::Absence(vec![action(1,TraceActionKind::Reception,0)]),
+                                    TraceQueryScope::Component);
+        assert_eq!(evaluate_trace_query(&gen_ctx,&query,&multi_trace),
+                   vec![TraceQueryViolation{canal_id:1,position:0},TraceQueryViolation{canal_id:1,position:1}]);
+    }
+
+    #[test]
+    fn bounded_existence_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        // l1!m1.l2?m1.l1!m1.l2?m1.l1!m1
+        let multi_trace = vec![vec![action(0,TraceActionKind::Emission,0),
+                                    action(1,TraceActionKind::Reception,0),
+                                    action(0,TraceActionKind::Emission,0),
+                                    action(1,TraceActionKind::Reception,0),
+                                    action(0,TraceActionKind::Emission,0)]];
+        // the emissions beyond the second one are violating
+        let query = TraceQuery::new(TraceQueryPattern::BoundedExistence(vec![action(0,TraceActionKind::Emission,0)],2),
+                                    TraceQueryScope::Component);
+        assert_eq!(evaluate_trace_query(&gen_ctx,&query,&multi_trace),
+                   vec![TraceQueryViolation{canal_id:0,position:4}]);
+        let unbounded = TraceQuery::new(TraceQueryPattern::BoundedExistence(vec![action(0,TraceActionKind::Emission,0)],3),
+                                        TraceQueryScope::Component);
+        assert!(evaluate_trace_query(&gen_ctx,&unbounded,&multi_trace).is_empty());
+    }
+
+}
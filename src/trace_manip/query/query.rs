@@ -0,0 +1,74 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+
+
+/**
+A set of patterns of actions.
+An action matches it if it is included in any of the patterns
+(a pattern being a lifeline, a kind of action and a message or message type).
+**/
+pub type TraceQueryActions = Vec<TraceAction>;
+
+pub fn action_matches_query_actions(gen_ctx : &GeneralContext,
+                                    action : &TraceAction,
+                                    query_actions : &TraceQueryActions) -> bool {
+    query_actions.iter().any(|pattern| action.is_type_included(pattern,gen_ctx))
+}
+
+/**
+Specification patterns which are evaluated on the successive actions of a trace :
+- response(P,Q) : every P is eventually followed by a Q
+- precedence(P,Q) : every Q is preceded by a P
+- absence(P) : no P occurs
+- bounded_existence(P,k) : P occurs at most k times
+**/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TraceQueryPattern {
+    Response(TraceQueryActions,TraceQueryActions),
+    Precedence(TraceQueryActions,TraceQueryActions),
+    Absence(TraceQueryActions),
+    BoundedExistence(TraceQueryActions,u32)
+}
+
+/**
+Whether a pattern is evaluated on each component trace of a multi-trace
+or separately on the actions of each lifeline.
+**/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TraceQueryScope {
+    Component,
+    Lifeline
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TraceQuery {
+    pub pattern : TraceQueryPattern,
+    pub scope : TraceQueryScope
+}
+
+impl TraceQuery {
+
+    pub fn new(pattern : TraceQueryPattern,
+               scope : TraceQueryScope) -> TraceQuery {
+        TraceQuery{pattern,scope}
+    }
+
+}
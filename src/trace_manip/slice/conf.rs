@@ -25,7 +25,7 @@ pub enum SliceKind {
 
 pub enum SliceGenerationSelection {
     Exhaustive, // all the slices
-    Random(u32,bool) // a number 'x' of random slices, wider or any
+    Random(u32,bool,u64) // a number 'x' of random slices, wider or any, generated from a given seed
 }
 
 
@@ -24,7 +24,7 @@ use crate::core::execution::trace::multitrace::Trace;
 
 use crate::core::general_context::GeneralContext;
 use crate::core::execution::trace::trace::TraceAction;
-use crate::io::file_extensions::HIBOU_TRACE_FILE_EXTENSION;
+use crate::io::file_extensions::{HibouFileFormat, HIBOU_TRACE_FILE_EXTENSION};
 use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file;
 use crate::util::slicer::Slicer;
 
@@ -32,12 +32,13 @@ pub fn get_all_slices_rec<'a>(gen_ctx : &GeneralContext,
                               co_localizations : &CoLocalizations,
                       dir_name : &str,
                       file_name_prefix : &str,
+                      file_format : HibouFileFormat,
                       id : &mut u32,
                       ok_canals : &Vec<Trace>,
                               rem_canals : &mut (impl Iterator<Item = &'a Trace> + Clone)) {
     match rem_canals.next() {
         None => {
-            let file_name = format!("{:}s{:}.{:}", file_name_prefix, id, HIBOU_TRACE_FILE_EXTENSION);
+            let file_name = format!("{:}s{:}.{:}", file_name_prefix, id, file_format.get_extension(HIBOU_TRACE_FILE_EXTENSION));
             let path : PathBuf = [dir_name, &file_name].iter().collect();
             *id = *id + 1;
             write_multi_trace_into_file(path.as_path(), gen_ctx, co_localizations,ok_canals);
@@ -52,6 +53,7 @@ pub fn get_all_slices_rec<'a>(gen_ctx : &GeneralContext,
                                    co_localizations,
                                    dir_name,
                                    file_name_prefix,
+                                   file_format,
                                    id,
                                    &new_ok_canals,
                                    &mut rem_canals.clone());
@@ -64,12 +66,13 @@ pub fn get_all_prefixes_rec<'a>(gen_ctx : &GeneralContext,
                                 co_localizations : &CoLocalizations,
                               dir_name : &str,
                                 file_name_prefix : &str,
+                                file_format : HibouFileFormat,
                               id : &mut u32,
                                 ok_canals : &Vec<Trace>,
                                 rem_canals : &mut (impl Iterator<Item = &'a Trace> + Clone)) {
     match rem_canals.next() {
         None => {
-            let file_name = format!("{:}s{:}.{:}", file_name_prefix, id, HIBOU_TRACE_FILE_EXTENSION);
+            let file_name = format!("{:}s{:}.{:}", file_name_prefix, id, file_format.get_extension(HIBOU_TRACE_FILE_EXTENSION));
             let path : PathBuf = [dir_name, &file_name].iter().collect();
             *id = *id + 1;
             write_multi_trace_into_file(path.as_path(), gen_ctx, co_localizations,ok_canals);
@@ -83,6 +86,7 @@ pub fn get_all_prefixes_rec<'a>(gen_ctx : &GeneralContext,
                                      co_localizations,
                                      dir_name,
                                      file_name_prefix,
+                                     file_format,
                                      id,
                                      &new_ok_canals,
                                      &mut rem_canals.clone());
@@ -95,12 +99,13 @@ pub fn get_all_suffixes_rec<'a>(gen_ctx : &GeneralContext,
                                 co_localizations : &CoLocalizations,
                                 dir_name : &str,
                                 file_name_prefix : &str,
+                                file_format : HibouFileFormat,
                                 id : &mut u32,
                                 ok_canals : &Vec<Trace>,
                                 rem_canals : &mut (impl Iterator<Item = &'a Trace> + Clone)) {
     match rem_canals.next() {
         None => {
-            let file_name = format!("{:}s{:}.{:}", file_name_prefix, id, HIBOU_TRACE_FILE_EXTENSION);
+            let file_name = format!("{:}s{:}.{:}", file_name_prefix, id, file_format.get_extension(HIBOU_TRACE_FILE_EXTENSION));
             let path : PathBuf = [dir_name, &file_name].iter().collect();
             *id = *id + 1;
             write_multi_trace_into_file(path.as_path(), gen_ctx, co_localizations,ok_canals);
@@ -114,6 +119,7 @@ pub fn get_all_suffixes_rec<'a>(gen_ctx : &GeneralContext,
                                      co_localizations,
                                      dir_name,
                                      file_name_prefix,
+                                     file_format,
                                      id,
                                      &new_ok_canals,
                                      &mut rem_canals.clone());
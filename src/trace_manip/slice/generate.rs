@@ -20,6 +20,7 @@ use std::fs;
 use crate::core::colocalizations::CoLocalizations;
 use crate::core::execution::trace::multitrace::{MultiTrace, Trace};
 use crate::core::general_context::GeneralContext;
+use crate::io::file_extensions::HibouFileFormat;
 use crate::trace_manip::slice::conf::*;
 use crate::trace_manip::slice::exhaustive::{get_all_prefixes_rec, get_all_slices_rec, get_all_suffixes_rec};
 use crate::trace_manip::slice::random::{get_random_slicing};
@@ -32,12 +33,13 @@ fn get_exhaustive_slicing<'a>(gen_ctx : &GeneralContext,
                           kind : &SliceKind,
                           dir_name : &String,
                               file_name_prefix : &String,
+                              file_format : HibouFileFormat,
                           rem_canals : &mut (impl Iterator<Item = &'a Trace> + Clone)) {
     match kind {
         &SliceKind::Prefix => {
             get_all_prefixes_rec(gen_ctx,
                                  co_localizations,
-                                 dir_name,file_name_prefix,
+                                 dir_name,file_name_prefix,file_format,
                                  &mut 1,
                                  &vec![],
                                  rem_canals);
@@ -45,7 +47,7 @@ fn get_exhaustive_slicing<'a>(gen_ctx : &GeneralContext,
         &SliceKind::Suffix => {
             get_all_suffixes_rec(gen_ctx,
                                  co_localizations,
-                                 dir_name,file_name_prefix,
+                                 dir_name,file_name_prefix,file_format,
                                  &mut 1,
                                  &vec![],
                                  rem_canals);
@@ -53,7 +55,7 @@ fn get_exhaustive_slicing<'a>(gen_ctx : &GeneralContext,
         &SliceKind::Slice => {
             get_all_slices_rec(gen_ctx,
                                co_localizations,
-                                 dir_name,file_name_prefix,
+                                 dir_name,file_name_prefix,file_format,
                                  &mut 1,
                                  &vec![],
                                rem_canals);
@@ -67,6 +69,7 @@ pub fn generate_slices(gen_ctx : &GeneralContext,
                        multi_trace : &MultiTrace,
                        parent_folder : Option<&str>,
                        file_name_prefix_opt : Option<&str>,
+                       file_format : HibouFileFormat,
                        select : &SliceGenerationSelection,
                        kind : &SliceKind) {
     let dir_name : String;
@@ -106,17 +109,18 @@ pub fn generate_slices(gen_ctx : &GeneralContext,
             get_exhaustive_slicing(gen_ctx,
                                    co_localizations,
                                    kind,
-                                   &dir_name,&file_name_prefix,
+                                   &dir_name,&file_name_prefix,file_format,
                                    &mut multi_trace.iter());
         },
-        &SliceGenerationSelection::Random( mut num_slices, wide ) => {
+        &SliceGenerationSelection::Random( mut num_slices, wide, seed ) => {
             get_random_slicing(gen_ctx,
                                co_localizations,
-                               &dir_name,&file_name_prefix,
+                               &dir_name,&file_name_prefix,file_format,
                                &mut num_slices,
                                &multi_trace,
                                kind,
-                               wide);
+                               wide,
+                               seed);
         }
     }
 }
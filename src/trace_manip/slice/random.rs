@@ -21,13 +21,14 @@ use std::collections::HashSet;
 use std::path::PathBuf;
 
 use rand::distributions::{Distribution, Uniform};
-use rand::rngs::ThreadRng;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
 
 use crate::core::colocalizations::CoLocalizations;
 use crate::core::execution::trace::multitrace::{MultiTrace, Trace};
 use crate::core::general_context::GeneralContext;
-use crate::io::file_extensions::HIBOU_TRACE_FILE_EXTENSION;
-use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file;
+use crate::io::file_extensions::{HibouFileFormat, HIBOU_TRACE_FILE_EXTENSION};
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file_with_comment;
 use crate::trace_manip::slice::conf::SliceKind;
 
 
@@ -36,12 +37,14 @@ pub fn get_random_slicing(gen_ctx : &GeneralContext,
                           co_localizations : &CoLocalizations,
                          dir_name : &str,
                           file_name_prefix : &str,
+                          file_format : HibouFileFormat,
                          num_slices : &mut u32,
                          multi_trace : &MultiTrace,
                          kind : &SliceKind,
-                         wide : bool) {
+                         wide : bool,
+                         seed : u64) {
     let mut slices : HashSet< Vec<(usize,usize)> > = hashset!{};
-    let mut rng = rand::thread_rng();
+    let mut rng = StdRng::seed_from_u64(seed);
     while *num_slices > 0 {
         let mut new_canals_ids : Vec<(usize,usize)> = vec![];
         let mut new_multi_trace : MultiTrace = vec![];
@@ -55,23 +58,24 @@ pub fn get_random_slicing(gen_ctx : &GeneralContext,
             new_canals_ids.push( ids );
         }
         // ***
-        let file_name = format!("{:}s{:}.{:}", file_name_prefix, num_slices, HIBOU_TRACE_FILE_EXTENSION);
+        let file_name = format!("{:}s{:}.{:}", file_name_prefix, num_slices, file_format.get_extension(HIBOU_TRACE_FILE_EXTENSION));
         let path : PathBuf = [dir_name, &file_name].iter().collect();
         //let file_path = format!("{:}/{:}s{:}", dir_name, file_name_prefix, num_slices);
         *num_slices = *num_slices - 1;
         // ***
         if !slices.contains( &new_canals_ids ) {
-            write_multi_trace_into_file(path.as_path(),
-                                        gen_ctx,
-                                        co_localizations,
-                                        &new_multi_trace);
+            write_multi_trace_into_file_with_comment(path.as_path(),
+                                                     gen_ctx,
+                                                     co_localizations,
+                                                     &new_multi_trace,
+                                                     &format!("slice generated with seed {:}", seed));
             slices.insert(new_canals_ids);
         }
     }
 }
 
 
-fn get_indexes_cuts(rng : &mut ThreadRng, length : usize,kind : &SliceKind, wide : bool) -> (usize,usize) {
+fn get_indexes_cuts<R : Rng>(rng : &mut R, length : usize,kind : &SliceKind, wide : bool) -> (usize,usize) {
     if wide {
         return get_wider_cut(rng,length,kind);
     } else {
@@ -81,7 +85,7 @@ fn get_indexes_cuts(rng : &mut ThreadRng, length : usize,kind : &SliceKind, wide
 
 
 
-fn get_any_cut(rng : &mut ThreadRng, length : usize, kind : &SliceKind) -> (usize,usize) {
+fn get_any_cut<R : Rng>(rng : &mut R, length : usize, kind : &SliceKind) -> (usize,usize) {
     if length == 0 {
         return (0,0);
     }
@@ -110,7 +114,7 @@ fn get_any_cut(rng : &mut ThreadRng, length : usize, kind : &SliceKind) -> (usiz
     }
 }
 
-fn get_wider_cut(rng : &mut ThreadRng, length : usize, kind : &SliceKind) -> (usize,usize) {
+fn get_wider_cut<R : Rng>(rng : &mut R, length : usize, kind : &SliceKind) -> (usize,usize) {
     if length <= 4 {
         return get_any_cut(rng,length,kind);
     }
@@ -137,3 +141,75 @@ fn get_wider_cut(rng : &mut ThreadRng, length : usize, kind : &SliceKind) -> (us
 }
 
 
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::path::PathBuf;
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::core::execution::trace::multitrace::MultiTrace;
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::core::message::MessageTypeExpression;
+    use crate::io::file_extensions::HibouFileFormat;
+    use crate::trace_manip::slice::conf::SliceKind;
+    use super::get_random_slicing;
+
+    fn get_slices_contents(gen_ctx : &GeneralContext,
+                           multi_trace : &MultiTrace,
+                           kind : &SliceKind,
+                           wide : bool,
+                           seed : u64,
+                           run : u32) -> BTreeMap<String,String> {
+        let dir : PathBuf = std::env::temp_dir().join(format!("hibou_slice_seed_test_{:}_{:}", std::process::id(), run));
+        fs::create_dir_all(&dir).unwrap();
+        let mut num_slices : u32 = 5;
+        get_random_slicing(gen_ctx,
+                           &CoLocalizations::get_discrete_partition(2),
+                           dir.to_str().unwrap(),
+                           "slice",
+                           HibouFileFormat::Native,
+                           &mut num_slices,
+                           multi_trace,
+                           kind,
+                           wide,
+                           seed);
+        let mut contents = BTreeMap::new();
+        for entry in fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            contents.insert(path.file_name().unwrap().to_str().unwrap().to_string(),
+                            fs::read_to_string(&path).unwrap());
+        }
+        fs::remove_dir_all(&dir).unwrap();
+        contents
+    }
+
+    #[test]
+    fn same_seed_same_slices_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        let emission = TraceAction::new(0,TraceActionKind::Emission,MessageTypeExpression::Singleton(0));
+        let reception = TraceAction::new(1,TraceActionKind::Reception,MessageTypeExpression::Singleton(0));
+        let multi_trace : MultiTrace = vec![vec![emission;6],vec![reception;6]];
+        let mut run = 0;
+        for kind in [SliceKind::Prefix,SliceKind::Suffix,SliceKind::Slice] {
+            for wide in [false,true] {
+                for seed in 0..5 {
+                    let slices1 = get_slices_contents(&gen_ctx,&multi_trace,&kind,wide,seed,run);
+                    let slices2 = get_slices_contents(&gen_ctx,&multi_trace,&kind,wide,seed,run + 1);
+                    assert!(!slices1.is_empty());
+                    assert_eq!(slices1,slices2);
+                    run += 2;
+                }
+            }
+        }
+    }
+
+}
@@ -0,0 +1,339 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::core::execution::semantics::position::{position_as_text, Position};
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::coverage::model::{CoverageElement, CoverageElementKind, InteractionCoverageModel};
+use crate::process::ana::loggers::plog::logger::AcceptingExplanation;
+
+
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum TestGenerationCriterion {
+    AllActions,
+    AllAltBranches,
+    AllLoopIterations,
+    AllSyncChoices
+}
+
+impl TestGenerationCriterion {
+
+    pub fn from_name(name : &str) -> Option<TestGenerationCriterion> {
+        match name {
+            "actions" => Some(TestGenerationCriterion::AllActions),
+            "alt" => Some(TestGenerationCriterion::AllAltBranches),
+            "loops" => Some(TestGenerationCriterion::AllLoopIterations),
+            "sync" => Some(TestGenerationCriterion::AllSyncChoices),
+            _ => None
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            TestGenerationCriterion::AllActions => "actions",
+            TestGenerationCriterion::AllAltBranches => "alt",
+            TestGenerationCriterion::AllLoopIterations => "loops",
+            TestGenerationCriterion::AllSyncChoices => "sync"
+        }
+    }
+
+}
+
+
+/**
+Items to cover :
+- Action(leaf_id) for an action leaf of the interaction
+- AltBranch(element_id) for an alternative branch (indexed in the elements of the coverage model)
+- LoopIterations(element_id,num) for a loop taken 0, 1 or at least 2 times (num being 0, 1 or 2)
+- SyncChoice(leaves) for a set of action leaves which are executed together to consume a single action
+**/
+#[derive(Clone, PartialEq, Debug, Eq, Hash, PartialOrd, Ord)]
+pub enum TestGenerationItem {
+    Action(usize),
+    AltBranch(usize),
+    LoopIterations(usize,u32),
+    SyncChoice(BTreeSet<usize>)
+}
+
+
+/**
+Returns the items of the criterion which can be covered independently of the exploration,
+i.e. all of them except the sync choices, which are only known once they are reached.
+**/
+pub fn get_static_criterion_items(model : &InteractionCoverageModel,
+                                  criterion : &TestGenerationCriterion) -> BTreeSet<TestGenerationItem> {
+    let mut items = BTreeSet::new();
+    match criterion {
+        TestGenerationCriterion::AllActions => {
+            for leaf_id in 0..model.leaves_num {
+                items.insert(TestGenerationItem::Action(leaf_id));
+            }
+        },
+        TestGenerationCriterion::AllAltBranches => {
+            for (elt_id,element) in model.elements.iter().enumerate() {
+                if element.kind == CoverageElementKind::AltBranch {
+                    items.insert(TestGenerationItem::AltBranch(elt_id));
+                }
+            }
+        },
+        TestGenerationCriterion::AllLoopIterations => {
+            for (elt_id,element) in model.elements.iter().enumerate() {
+                if element.kind == CoverageElementKind::LoopBody {
+                    for num in 0..3 {
+                        items.insert(TestGenerationItem::LoopIterations(elt_id,num));
+                    }
+                }
+            }
+        },
+        TestGenerationCriterion::AllSyncChoices => {}
+    }
+    items
+}
+
+
+/**
+Returns the items of the criterion covered by an accepting explanation of a multi-trace.
+The number of times a loop is taken is the maximum number of executions of the leaves it directly contains
+(i.e. not within a nested loop), a leaf being executed as many times as it consumes actions on a same lifeline.
+**/
+pub fn get_covered_criterion_items(model : &InteractionCoverageModel,
+                                   criterion : &TestGenerationCriterion,
+                                   multi_trace : &MultiTrace,
+                                   explanation : &AcceptingExplanation) -> BTreeSet<TestGenerationItem> {
+    let mut items = BTreeSet::new();
+    match criterion {
+        TestGenerationCriterion::AllActions => {
+            for leaves in explanation.values() {
+                for leaf_id in leaves {
+                    items.insert(TestGenerationItem::Action(*leaf_id));
+                }
+            }
+        },
+        TestGenerationCriterion::AllAltBranches => {
+            let executed : HashSet<usize> = explanation.values().flatten().cloned().collect();
+            for (elt_id,element) in model.elements.iter().enumerate() {
+                if element.kind != CoverageElementKind::AltBranch {
+                    continue;
+                }
+                let taken = if element.leaves.is_empty() {
+                    is_empty_alt_branch_taken(model,elt_id,&executed)
+                } else {
+                    !element.leaves.is_disjoint(&executed)
+                };
+                if taken {
+                    items.insert(TestGenerationItem::AltBranch(elt_id));
+                }
+            }
+        },
+        TestGenerationCriterion::AllLoopIterations => {
+            let executions = get_leaves_executions(multi_trace,explanation);
+            for (elt_id,element) in model.elements.iter().enumerate() {
+                if element.kind != CoverageElementKind::LoopBody {
+                    continue;
+                }
+                let direct_leaves = get_loop_direct_leaves(model,elt_id);
+                let taken = direct_leaves.iter()
+                    .map(|leaf_id| *executions.get(leaf_id).unwrap_or(&0))
+                    .max()
+                    .unwrap_or(0);
+                items.insert(TestGenerationItem::LoopIterations(elt_id,taken.min(2)));
+            }
+        },
+        TestGenerationCriterion::AllSyncChoices => {
+            for leaves in explanation.values() {
+                if leaves.len() > 1 {
+                    items.insert(TestGenerationItem::SyncChoice(leaves.clone()));
+                }
+            }
+        }
+    }
+    items
+}
+
+/**
+A branch without actions leaves no trace of being taken.
+It is considered taken if the scope of its alternative (the innermost enclosing loop body or alt branch) is reached
+while no action of the other branches of the alternative is executed.
+Within a loop, this does not detect the branch being taken in some iterations and not in others.
+**/
+fn is_empty_alt_branch_taken(model : &InteractionCoverageModel,
+                             branch_elt_id : usize,
+                             executed : &HashSet<usize>) -> bool {
+    // the alternative which directly contains the branch, enlarged to the alternatives it is nested in
+    // given that alt(i1,alt(i2,i3)) is a single alternative with three branches
+    let mut alt_path = get_element_path(&model.elements.get(branch_elt_id).unwrap().position);
+    alt_path.pop();
+    while let Some((_,parent_path)) = alt_path.split_last() {
+        match get_sub_interaction(&model.tagged_interaction,parent_path) {
+            Some( Interaction::Alt(_,_) ) => {
+                alt_path = parent_path.to_vec();
+            },
+            _ => {
+                break;
+            }
+        }
+    }
+    // ***
+    let mut scope : Option<(&CoverageElement,usize)> = None;
+    for element in &model.elements {
+        let elt_path = get_element_path(&element.position);
+        match element.kind {
+            CoverageElementKind::Action => {
+                if elt_path.starts_with(&alt_path) && !element.leaves.is_disjoint(executed) {
+                    // another branch of the alternative is taken
+                    return false;
+                }
+            },
+            _ => {
+                if elt_path.len() < alt_path.len() && alt_path.starts_with(&elt_path) {
+                    let is_innermost = match scope {
+                        None => {true},
+                        Some( (_,scope_depth) ) => {scope_depth < elt_path.len()}
+                    };
+                    if is_innermost {
+                        scope = Some( (element,elt_path.len()) );
+                    }
+                }
+            }
+        }
+    }
+    match scope {
+        None => {
+            true
+        },
+        Some( (got_scope,_) ) => {
+            !got_scope.leaves.is_disjoint(executed)
+        }
+    }
+}
+
+/**
+The positions of the elements of the coverage model only go left or right
+and are therefore identified by the sequence of these moves (true when going right).
+**/
+fn get_element_path(position : &Position) -> Vec<bool> {
+    let mut path = vec![];
+    let mut sub_pos = position;
+    loop {
+        match sub_pos {
+            Position::Left(in_self) => {
+                path.push(false);
+                sub_pos = in_self;
+            },
+            Position::Right(in_self) => {
+                path.push(true);
+                sub_pos = in_self;
+            },
+            _ => {
+                return path;
+            }
+        }
+    }
+}
+
+fn get_sub_interaction<'a>(interaction : &'a Interaction,
+                           path : &[bool]) -> Option<&'a Interaction> {
+    let mut sub_int = interaction;
+    for go_right in path {
+        sub_int = match (go_right,sub_int) {
+            (false,Interaction::Alt(i1,_)) => {i1},
+            (true,Interaction::Alt(_,i2)) => {i2},
+            (false,Interaction::CoReg(_,i1,_)) => {i1},
+            (true,Interaction::CoReg(_,_,i2)) => {i2},
+            (false,Interaction::Sync(_,i1,_)) => {i1},
+            (true,Interaction::Sync(_,_,i2)) => {i2},
+            (false,Interaction::Loop(_,i1)) => {i1},
+            _ => {
+                return None;
+            }
+        };
+    }
+    Some(sub_int)
+}
+
+fn get_leaves_executions(multi_trace : &MultiTrace,
+                         explanation : &AcceptingExplanation) -> HashMap<usize,u32> {
+    let mut per_lifeline : HashMap<(usize,usize),u32> = HashMap::new();
+    for ((canal_id,act_idx),leaves) in explanation {
+        let lf_id = multi_trace.get(*canal_id).unwrap().get(*act_idx).unwrap().lf_id;
+        for leaf_id in leaves {
+            *per_lifeline.entry((*leaf_id,lf_id)).or_insert(0) += 1;
+        }
+    }
+    let mut executions : HashMap<usize,u32> = HashMap::new();
+    for ((leaf_id,_),num) in per_lifeline {
+        let got = executions.entry(leaf_id).or_insert(0);
+        *got = (*got).max(num);
+    }
+    executions
+}
+
+fn get_loop_direct_leaves(model : &InteractionCoverageModel,
+                          loop_elt_id : usize) -> HashSet<usize> {
+    let loop_leaves = &model.elements.get(loop_elt_id).unwrap().leaves;
+    let mut direct_leaves = loop_leaves.clone();
+    for (elt_id,element) in model.elements.iter().enumerate() {
+        if elt_id != loop_elt_id
+            && element.kind == CoverageElementKind::LoopBody
+            && element.leaves.is_subset(loop_leaves)
+            && element.leaves.len() < loop_leaves.len() {
+            for leaf_id in &element.leaves {
+                direct_leaves.remove(leaf_id);
+            }
+        }
+    }
+    if direct_leaves.is_empty() {
+        // the body of the loop is itself a loop
+        return loop_leaves.clone();
+    }
+    direct_leaves
+}
+
+
+pub fn criterion_item_as_text(model : &InteractionCoverageModel,
+                              item : &TestGenerationItem) -> String {
+    match item {
+        TestGenerationItem::Action(leaf_id) => {
+            for element in &model.elements {
+                if element.kind == CoverageElementKind::Action && element.leaves.contains(leaf_id) {
+                    return format!("action '{:}' at position {:}", element.description, position_as_text(&element.position));
+                }
+            }
+            format!("action leaf {:}", leaf_id)
+        },
+        TestGenerationItem::AltBranch(elt_id) => {
+            format!("alt branch at position {:}", position_as_text(&model.elements.get(*elt_id).unwrap().position))
+        },
+        TestGenerationItem::LoopIterations(elt_id,num) => {
+            let position = position_as_text(&model.elements.get(*elt_id).unwrap().position);
+            match num {
+                0 => format!("loop at position {:} not taken", position),
+                1 => format!("loop at position {:} taken once", position),
+                _ => format!("loop at position {:} taken at least twice", position)
+            }
+        },
+        TestGenerationItem::SyncChoice(leaves) => {
+            let leaves_texts : Vec<String> = leaves.iter()
+                .map(|leaf_id| criterion_item_as_text(model,&TestGenerationItem::Action(*leaf_id)))
+                .collect();
+            format!("sync of [{:}]", leaves_texts.join(" & "))
+        }
+    }
+}
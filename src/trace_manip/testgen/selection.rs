@@ -0,0 +1,93 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+
+use crate::core::execution::trace::multitrace::{multi_trace_length, MultiTrace};
+use crate::trace_manip::testgen::criteria::TestGenerationItem;
+
+
+/**
+Greedy set-cover selection : candidates are selected one at a time, each time picking the one
+which covers the most items not yet covered (the shortest one in case of a tie, then the first one),
+until no candidate covers any new item.
+Returns the indices of the selected candidates along with the new items each of them covers.
+**/
+pub fn select_covering_candidates(candidates : &Vec<(MultiTrace,BTreeSet<TestGenerationItem>)>) -> Vec<(usize,BTreeSet<TestGenerationItem>)> {
+    let mut covered : BTreeSet<TestGenerationItem> = BTreeSet::new();
+    let mut selected = vec![];
+    loop {
+        let mut best : Option<(usize,BTreeSet<TestGenerationItem>)> = None;
+        for (cand_id,(multi_trace,items)) in candidates.iter().enumerate() {
+            let new_items : BTreeSet<TestGenerationItem> = items.difference(&covered).cloned().collect();
+            if new_items.is_empty() {
+                continue;
+            }
+            let is_better = match &best {
+                None => {
+                    true
+                },
+                Some( (best_id,best_items) ) => {
+                    new_items.len() > best_items.len()
+                        || (new_items.len() == best_items.len()
+                            && multi_trace_length(multi_trace) < multi_trace_length(&candidates.get(*best_id).unwrap().0))
+                }
+            };
+            if is_better {
+                best = Some( (cand_id,new_items) );
+            }
+        }
+        match best {
+            None => {
+                return selected;
+            },
+            Some( (cand_id,new_items) ) => {
+                covered.extend(new_items.iter().cloned());
+                selected.push( (cand_id,new_items) );
+            }
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use crate::core::execution::trace::multitrace::MultiTrace;
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::message::MessageTypeExpression;
+    use crate::trace_manip::testgen::criteria::TestGenerationItem;
+    use super::select_covering_candidates;
+
+    #[test]
+    fn greedy_selection_test() {
+        let act = TraceAction::new(0,TraceActionKind::Emission,MessageTypeExpression::Singleton(0));
+        let short : MultiTrace = vec![vec![act.clone()]];
+        let long : MultiTrace = vec![vec![act.clone(),act.clone()]];
+        let items = |ids : Vec<usize>| -> BTreeSet<TestGenerationItem> {
+            ids.into_iter().map(TestGenerationItem::Action).collect()
+        };
+        let candidates = vec![(long.clone(),items(vec![0,1])),
+                              (short.clone(),items(vec![1,2])),
+                              (long.clone(),items(vec![0,1,2])),
+                              (short.clone(),items(vec![3]))];
+        let selected : Vec<usize> = select_covering_candidates(&candidates).into_iter().map(|(id,_)| id).collect();
+        assert_eq!(selected, vec![2,3]);
+    }
+}
@@ -0,0 +1,268 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::PathBuf;
+
+use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
+use graph_process_manager_core::manager::manager::GenericProcessManager;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::coverage::model::InteractionCoverageModel;
+use crate::io::file_extensions::{HibouFileFormat, HIBOU_TRACE_FILE_EXTENSION};
+use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+use crate::io::input::hcf::explo::options::HibouExploreOptions;
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file_with_comment;
+use crate::process::ana::interface::make_analysis_manager;
+use crate::process::ana::loggers::plog::logger::AnalysisAcceptingPathsLogger;
+use crate::process::explo::conf::ExplorationConfig;
+use crate::process::explo::context::{ExplorationContext, ExplorationParameterization};
+use crate::process::explo::loggers::alog::logger::ExplorationAcceptedMultiTracesLogger;
+use crate::process::explo::node::ExplorationNodeKind;
+use crate::process::explo::priorities::ExplorationPriorities;
+use crate::process::explo::step::ExplorationStepKind;
+use crate::trace_manip::testgen::criteria::{get_covered_criterion_items, get_static_criterion_items, TestGenerationCriterion, TestGenerationItem};
+use crate::trace_manip::testgen::selection::select_covering_candidates;
+
+
+// beyond this number, the accepting paths of an ambiguously accepted candidate are not considered
+const MAX_ACCEPTING_PATHS_PER_CANDIDATE : usize = 100;
+
+
+pub struct TestSuiteGeneration {
+    pub candidates_num : usize,
+    // path of each written multi-trace along with the new items it covers
+    pub written : Vec<(String,BTreeSet<TestGenerationItem>)>,
+    pub covered : BTreeSet<TestGenerationItem>,
+    pub uncovered : BTreeSet<TestGenerationItem>
+}
+
+
+/**
+Explores the semantics of the interaction with the given options
+and returns the distinct accepted multi-traces which are reached.
+The loggers of the options are replaced by a logger keeping the accepted multi-traces in memory.
+The exploration must be concrete for these multi-traces to be made of concrete actions, and is therefore made so.
+**/
+pub fn explore_accepted_multi_traces(gen_ctx : &GeneralContext,
+                                     co_localizations : &CoLocalizations,
+                                     interaction : &Interaction,
+                                     mut explo_opts : HibouExploreOptions) -> Vec<MultiTrace> {
+    explo_opts.param = ExplorationParameterization::new(true);
+    explo_opts.loggers = vec![Box::new(ExplorationAcceptedMultiTracesLogger::new(co_localizations.clone()))];
+    let explo_ctx = ExplorationContext::new(gen_ctx.clone());
+    let delegate : GenericProcessDelegate<ExplorationStepKind,ExplorationNodeKind,ExplorationPriorities> = GenericProcessDelegate::new(explo_opts.strategy,explo_opts.priorities);
+    let mut exploration_manager : GenericProcessManager<ExplorationConfig> = GenericProcessManager::new(explo_ctx,
+                                                                                                        explo_opts.param,
+                                                                                                        delegate,
+                                                                                                        explo_opts.filters,
+                                                                                                        explo_opts.loggers,
+                                                                                                        None,
+                                                                                                        explo_opts.use_memoization);
+    exploration_manager.start_process(ExplorationNodeKind::new(interaction.clone(),0));
+    // ***
+    exploration_manager.get_logger(0).unwrap()
+        .as_any().downcast_ref::<ExplorationAcceptedMultiTracesLogger>().unwrap()
+        .accepted.clone()
+}
+
+/**
+Returns the items of the criterion covered by a multi-trace,
+i.e. those covered by at least one of its accepting paths, up to a maximum number of distinct paths.
+**/
+fn get_multi_trace_criterion_items(gen_ctx : &GeneralContext,
+                                   co_localizations : &CoLocalizations,
+                                   interaction : &Interaction,
+                                   model : &InteractionCoverageModel,
+                                   criterion : &TestGenerationCriterion,
+                                   multi_trace : &MultiTrace) -> BTreeSet<TestGenerationItem> {
+    let mut ana_opts = HibouAnalyzeOptions::default();
+    ana_opts.goal = None;
+    ana_opts.loggers.push( Box::new(AnalysisAcceptingPathsLogger::new(model.clone(),MAX_ACCEPTING_PATHS_PER_CANDIDATE)) );
+    let (mut analysis_manager,init_node) = make_analysis_manager(gen_ctx,
+                                                                 co_localizations,
+                                                                 multi_trace,
+                                                                 interaction,
+                                                                 ana_opts);
+    analysis_manager.start_process(init_node);
+    let paths_logger = analysis_manager.get_logger(0).unwrap()
+        .as_any().downcast_ref::<AnalysisAcceptingPathsLogger>().unwrap();
+    // ***
+    let mut items = BTreeSet::new();
+    for explanation in &paths_logger.explanations {
+        items.extend( get_covered_criterion_items(model,criterion,multi_trace,explanation) );
+    }
+    items
+}
+
+/**
+Selects among the accepted multi-traces reached by the exploration a small set achieving the criterion
+and writes them into the parent folder.
+**/
+pub fn generate_test_suite(gen_ctx : &GeneralContext,
+                           co_localizations : &CoLocalizations,
+                           interaction : &Interaction,
+                           criterion : &TestGenerationCriterion,
+                           explo_opts : HibouExploreOptions,
+                           parent_folder : &str,
+                           file_name_prefix : &str,
+                           file_format : HibouFileFormat) -> TestSuiteGeneration {
+    let accepted = explore_accepted_multi_traces(gen_ctx,
+                                                 co_localizations,
+                                                 interaction,
+                                                 explo_opts);
+    // ***
+    let model = InteractionCoverageModel::new(gen_ctx,interaction);
+    let mut all_items = get_static_criterion_items(&model,criterion);
+    let mut candidates : Vec<(MultiTrace,BTreeSet<TestGenerationItem>)> = vec![];
+    for multi_trace in accepted {
+        let items = get_multi_trace_criterion_items(gen_ctx,co_localizations,interaction,&model,criterion,&multi_trace);
+        all_items.extend(items.iter().cloned());
+        candidates.push( (multi_trace,items) );
+    }
+    // ***
+    fs::create_dir_all(parent_folder).unwrap();
+    let mut written = vec![];
+    let mut covered : BTreeSet<TestGenerationItem> = BTreeSet::new();
+    for (test_id,(cand_id,new_items)) in select_covering_candidates(&candidates).into_iter().enumerate() {
+        let file_name = format!("{:}{:}.{:}", file_name_prefix, test_id + 1, file_format.get_extension(HIBOU_TRACE_FILE_EXTENSION));
+        let path : PathBuf = [parent_folder, &file_name].iter().collect();
+        write_multi_trace_into_file_with_comment(path.as_path(),
+                                                 gen_ctx,
+                                                 co_localizations,
+                                                 &candidates.get(cand_id).unwrap().0,
+                                                 &format!("test generated for criterion '{:}' covering {:} new item(s)", criterion.get_name(), new_items.len()));
+        covered.extend(new_items.iter().cloned());
+        written.push( (path.into_os_string().to_str().unwrap().to_string(),new_items) );
+    }
+    let uncovered = all_items.difference(&covered).cloned().collect();
+    TestSuiteGeneration{candidates_num:candidates.len(),written,covered,uncovered}
+}
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::core::execution::trace::multitrace::MultiTrace;
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::BroadcastPrimitive;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::core::message::MessageTypeExpression;
+    use crate::coverage::model::{CoverageElementKind, InteractionCoverageModel};
+    use crate::io::input::hcf::explo::options::HibouExploreOptions;
+    use crate::trace_manip::testgen::criteria::{TestGenerationCriterion, TestGenerationItem};
+    use super::{explore_accepted_multi_traces, get_multi_trace_criterion_items};
+
+    fn message(orig_lf_id : usize, ms : MessageTypeExpression, tar_lf_id : usize) -> Box<Interaction> {
+        Box::new(Interaction::Action(BroadcastPrimitive::new(Some(orig_lf_id),ms,vec![tar_lf_id])))
+    }
+
+    fn action(lf_id : usize, act_kind : TraceActionKind, ms_id : usize) -> TraceAction {
+        TraceAction::new(lf_id,act_kind,MessageTypeExpression::Singleton(ms_id))
+    }
+
+    fn get_context() -> GeneralContext {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        gen_ctx
+    }
+
+    fn get_alt_branches(model : &InteractionCoverageModel) -> Vec<TestGenerationItem> {
+        model.elements.iter().enumerate()
+            .filter(|(_,element)| element.kind == CoverageElementKind::AltBranch)
+            .map(|(elt_id,_)| TestGenerationItem::AltBranch(elt_id))
+            .collect()
+    }
+
+    #[test]
+    fn explore_alt_branches_test() {
+        let gen_ctx = get_context();
+        let co_localizations = CoLocalizations::get_discrete_partition(2);
+        // alt(l1 -- m1 -> l2, seq(l1 -- m2 -> l2, l1 -- m1 -> l2))
+        let int = Interaction::Alt(message(0,MessageTypeExpression::Singleton(0),1),
+                                   Box::new(Interaction::CoReg(vec![],
+                                                               message(0,MessageTypeExpression::Singleton(1),1),
+                                                               message(0,MessageTypeExpression::Singleton(0),1))));
+        let accepted = explore_accepted_multi_traces(&gen_ctx,&co_localizations,&int,HibouExploreOptions::default());
+        let first : MultiTrace = vec![vec![action(0,TraceActionKind::Emission,0)],
+                                      vec![action(1,TraceActionKind::Reception,0)]];
+        let second : MultiTrace = vec![vec![action(0,TraceActionKind::Emission,1),action(0,TraceActionKind::Emission,0)],
+                                       vec![action(1,TraceActionKind::Reception,1),action(1,TraceActionKind::Reception,0)]];
+        assert_eq!(accepted.len(),2);
+        assert!(accepted.contains(&first));
+        assert!(accepted.contains(&second));
+        // ***
+        let model = InteractionCoverageModel::new(&gen_ctx,&int);
+        let branches = get_alt_branches(&model);
+        assert_eq!(branches.len(),2);
+        let criterion = TestGenerationCriterion::AllAltBranches;
+        assert_eq!(get_multi_trace_criterion_items(&gen_ctx,&co_localizations,&int,&model,&criterion,&first),
+                   btreeset!{branches.get(0).unwrap().clone()});
+        assert_eq!(get_multi_trace_criterion_items(&gen_ctx,&co_localizations,&int,&model,&criterion,&second),
+                   btreeset!{branches.get(1).unwrap().clone()});
+    }
+
+    #[test]
+    fn explore_empty_alt_branch_test() {
+        let gen_ctx = get_context();
+        let co_localizations = CoLocalizations::get_discrete_partition(2);
+        // alt(l1 -- m1 -> l2, ∅)
+        let int = Interaction::Alt(message(0,MessageTypeExpression::Singleton(0),1),
+                                   Box::new(Interaction::Empty));
+        let accepted = explore_accepted_multi_traces(&gen_ctx,&co_localizations,&int,HibouExploreOptions::default());
+        let empty : MultiTrace = vec![vec![],vec![]];
+        assert_eq!(accepted.len(),2);
+        assert!(accepted.contains(&empty));
+        // ***
+        let model = InteractionCoverageModel::new(&gen_ctx,&int);
+        let branches = get_alt_branches(&model);
+        let criterion = TestGenerationCriterion::AllAltBranches;
+        assert_eq!(get_multi_trace_criterion_items(&gen_ctx,&co_localizations,&int,&model,&criterion,&empty),
+                   btreeset!{branches.get(1).unwrap().clone()});
+    }
+
+    #[test]
+    fn ambiguous_candidate_covers_all_its_paths_test() {
+        let gen_ctx = get_context();
+        let co_localizations = CoLocalizations::get_discrete_partition(2);
+        // alt(l1 -- {m1,m2} -> l2, l1 -- m1 -> l2)
+        let m1_or_m2 = MessageTypeExpression::Union(Box::new(MessageTypeExpression::Singleton(0)),
+                                                    Box::new(MessageTypeExpression::Singleton(1)));
+        let int = Interaction::Alt(message(0,m1_or_m2,1),
+                                   message(0,MessageTypeExpression::Singleton(0),1));
+        let multi_trace : MultiTrace = vec![vec![action(0,TraceActionKind::Emission,0)],
+                                            vec![action(1,TraceActionKind::Reception,0)]];
+        let model = InteractionCoverageModel::new(&gen_ctx,&int);
+        // both actions are credited given that either one of them may have been executed
+        let covered = get_multi_trace_criterion_items(&gen_ctx,&co_localizations,&int,&model,&TestGenerationCriterion::AllActions,&multi_trace);
+        let expected : BTreeSet<TestGenerationItem> = btreeset!{TestGenerationItem::Action(0),TestGenerationItem::Action(1)};
+        assert_eq!(covered,expected);
+    }
+
+}
@@ -0,0 +1,127 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use clap::ArgMatches;
+
+use crate::io::file_extensions::{HibouFileFormat, HIBOU_TRACE_FILE_EXTENSION};
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::htf::interface::parse_htf_file;
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file;
+use crate::trace_manip::align::search::align_multi_trace;
+
+
+pub fn cli_align(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let htf_file_path = matches.value_of("htf").unwrap();
+                    match parse_htf_file(&gen_ctx,htf_file_path) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( (co_localizations,multi_trace) ) => {
+                            let parent_folder : Option<&str> = matches.value_of("parent_folder");
+                            // ***
+                            let max_cost : Option<u32> = if matches.is_present("max_cost") {
+                                let extracted = matches.value_of("max_cost").unwrap();
+                                let content_str : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+                                Some( content_str.parse::<u32>().unwrap() )
+                            } else {
+                                None
+                            };
+                            // ***
+                            let repaired_name : String = if matches.is_present("name") {
+                                let extracted = matches.value_of("name").unwrap();
+                                extracted.chars().filter(|c| !c.is_whitespace()).collect()
+                            } else {
+                                let mu_name : &str = Path::new(htf_file_path).file_stem().unwrap().to_str().unwrap();
+                                format!("{}_repaired",mu_name)
+                            };
+                            // ***
+                            let mut ret_print = vec![];
+                            ret_print.push( "ALIGNING TRACE".to_string());
+                            ret_print.push( format!("from file '{}'",htf_file_path) );
+                            ret_print.push( "W.R.T. INTERACTION".to_string());
+                            ret_print.push( format!("from file '{}'",hif_file_path) );
+                            ret_print.push( "".to_string());
+                            // ***
+                            let now = Instant::now();
+                            let got_alignment = align_multi_trace(&gen_ctx,
+                                                                  &co_localizations,
+                                                                  &multi_trace,
+                                                                  &int,
+                                                                  max_cost);
+                            let elapsed_time = now.elapsed();
+                            match got_alignment {
+                                None => {
+                                    match max_cost {
+                                        None => {
+                                            ret_print.push( "no alignment found within unbounded cost".to_string() );
+                                        },
+                                        Some( max ) => {
+                                            ret_print.push( format!("no alignment found within maximum cost {:?}", max) );
+                                        }
+                                    }
+                                },
+                                Some( alignment ) => {
+                                    ret_print.push( "EDIT SCRIPT".to_string());
+                                    for edit in &alignment.edit_script {
+                                        ret_print.push( edit.as_text(&gen_ctx) );
+                                    }
+                                    ret_print.push( "".to_string());
+                                    // ***
+                                    let file_name = format!("{:}.{:}", repaired_name, HibouFileFormat::from_option_value(matches.value_of("format")).get_extension(HIBOU_TRACE_FILE_EXTENSION));
+                                    let path : PathBuf = match parent_folder {
+                                        None => {
+                                            [&file_name].iter().collect()
+                                        },
+                                        Some( parent ) => {
+                                            [parent, &file_name].iter().collect()
+                                        }
+                                    };
+                                    write_multi_trace_into_file(path.as_path(),
+                                                                &gen_ctx,
+                                                                &co_localizations,
+                                                                &alignment.repaired);
+                                    ret_print.push( format!("repaired multi-trace written into file '{}'",path.to_str().unwrap()) );
+                                    ret_print.push( format!("edit cost  : {:?}", alignment.cost ) );
+                                    ret_print.push( format!("node count : {:?}", alignment.explored_states ) );
+                                }
+                            }
+                            ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64() ) );
+                            return (ret_print,0);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -14,24 +14,25 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
+use std::path::PathBuf;
 use std::time::Instant;
 
 use clap::ArgMatches;
-use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
-use graph_process_manager_core::manager::manager::GenericProcessManager;
-use crate::core::execution::trace::flags::MultiTraceAnalysisFlags;
 
 use crate::core::execution::trace::multitrace::multi_trace_length;
+use crate::coverage::model::InteractionCoverageModel;
+use crate::io::file_extensions::{HibouFileFormat, HIBOU_JSON_FILE_EXTENSION, HIBOU_WITNESS_FILE_EXTENSION};
 use crate::io::input::hcf::ana::interface::parse_hcf_file_for_ana;
 use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
 use crate::io::input::hsf::interface::parse_hsf_file;
 use crate::io::input::hif::interface::parse_hif_file;
 use crate::io::input::htf::interface::parse_htf_file;
-use crate::process::ana::conf::AnalysisConfig;
-use crate::process::ana::context::AnalysisContext;
-use crate::process::ana::node::node::AnalysisNodeKind;
-use crate::process::ana::priorities::AnalysisPriorities;
-use crate::process::ana::step::AnalysisStepKind;
+use crate::io::output::to_hfiles::witness::to_hwf::write_witness_into_file;
+use crate::io::output::to_json::analysis::write_analysis_results_into_file;
+use crate::process::ana::ambiguity::report::ambiguity_as_text_lines;
+use crate::process::ana::interface::make_analysis_manager;
+use crate::process::ana::loggers::plog::logger::AnalysisAcceptingPathsLogger;
+use crate::process::ana::loggers::wlog::logger::AnalysisWitnessLogger;
 
 
 pub fn cli_analyze(matches : &ArgMatches) -> (Vec<String>,u32) {
@@ -53,7 +54,7 @@ pub fn cli_analyze(matches : &ArgMatches) -> (Vec<String>,u32) {
                             return (vec![e.to_string()],1);
                         },
                         Ok( (co_localizations,multi_trace) ) => {
-                            let ana_opts : HibouAnalyzeOptions;
+                            let mut ana_opts : HibouAnalyzeOptions;
                             if matches.is_present("hcf") {
                                 let hcf_file_path = matches.value_of("hcf").unwrap();
                                 match parse_hcf_file_for_ana(&gen_ctx,hcf_file_path) {
@@ -68,6 +69,28 @@ pub fn cli_analyze(matches : &ArgMatches) -> (Vec<String>,u32) {
                                 ana_opts = HibouAnalyzeOptions::default();
                             }
                             // ***
+                            let witness_logger_id : Option<(usize,String)> = if matches.is_present("witness") {
+                                let extracted = matches.value_of("witness").unwrap();
+                                let witness_name : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+                                ana_opts.loggers.push( Box::new(AnalysisWitnessLogger::new()) );
+                                Some( (ana_opts.loggers.len() - 1, witness_name) )
+                            } else {
+                                None
+                            };
+                            // ***
+                            // the ambiguity goal lets the analysis continue after the first accepting path
+                            // so that the accepting paths logger retrieves all the distinct ones up to the maximum
+                            let ambiguity_logger_id : Option<(usize,InteractionCoverageModel,usize)> = match ana_opts.ambiguity {
+                                None => {
+                                    None
+                                },
+                                Some( max_num ) => {
+                                    let model = InteractionCoverageModel::new(&gen_ctx,&int);
+                                    ana_opts.loggers.push( Box::new(AnalysisAcceptingPathsLogger::new(model.clone(),max_num as usize)) );
+                                    Some( (ana_opts.loggers.len() - 1, model, max_num as usize) )
+                                }
+                            };
+                            // ***
                             let multi_trace_length = multi_trace_length(&multi_trace);
                             let mut ret_print = vec![];
                             ret_print.push( "ANALYZING TRACE".to_string());
@@ -77,32 +100,11 @@ pub fn cli_analyze(matches : &ArgMatches) -> (Vec<String>,u32) {
                             ret_print.push( format!("from file '{}'",hsf_file_path) );
                             ret_print.push( "".to_string());
                             // ***
-                            let ana_ctx = AnalysisContext::new(gen_ctx,co_localizations,multi_trace,multi_trace_length);
-                            let delegate : GenericProcessDelegate<AnalysisStepKind,AnalysisNodeKind,AnalysisPriorities> = GenericProcessDelegate::new(ana_opts.strategy,ana_opts.priorities);
-
-                            let init_flags : MultiTraceAnalysisFlags;
-                            match &ana_opts.ana_param.use_simulation {
-                                None => {
-                                    init_flags = MultiTraceAnalysisFlags::new_init(ana_ctx.co_localizations.num_colocs(),
-                                                                                   0,
-                                                                                   0);
-                                },
-                                Some( sim_config ) => {
-                                    init_flags = MultiTraceAnalysisFlags::new_init(ana_ctx.co_localizations.num_colocs(),
-                                                                                   sim_config.get_reset_rem_loop(multi_trace_length,&int),
-                                                                                   sim_config.get_reset_rem_act(multi_trace_length,&int));
-                                }
-                            }
-                            let mut analysis_manager : GenericProcessManager<AnalysisConfig> = GenericProcessManager::new(ana_ctx,
-                                                                                                                          ana_opts.ana_param,
-                                                                                                                                delegate,
-                                                                                                                          ana_opts.filters,
-                                                                                                                          ana_opts.loggers,
-                                                                                                                          ana_opts.goal,
-                                                                                                                          ana_opts.use_memoization);
-
-
-                            let init_node = AnalysisNodeKind::new(int,init_flags,0);
+                            let (mut analysis_manager,init_node) = make_analysis_manager(&gen_ctx,
+                                                                                         &co_localizations,
+                                                                                         &multi_trace,
+                                                                                         &int,
+                                                                                         ana_opts);
                             // ***
                             let now = Instant::now();
                             let (node_count,verdict) = analysis_manager.start_process(init_node);
@@ -110,6 +112,48 @@ pub fn cli_analyze(matches : &ArgMatches) -> (Vec<String>,u32) {
                             ret_print.push( format!("verdict    : '{}'", verdict.to_string() ) );
                             ret_print.push( format!("node count : {:?}", node_count ) );
                             ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64() ) );
+                            // ***
+                            if let Some((logger_id,model,max_num)) = ambiguity_logger_id {
+                                let paths_logger = analysis_manager.get_logger(logger_id).unwrap()
+                                    .as_any().downcast_ref::<AnalysisAcceptingPathsLogger>().unwrap();
+                                ret_print.append( &mut ambiguity_as_text_lines(&gen_ctx,&model,&multi_trace,&paths_logger.explanations,max_num) );
+                            }
+                            // ***
+                            let mut found_witness = None;
+                            if let Some((logger_id,witness_name)) = witness_logger_id {
+                                let witness_logger = analysis_manager.get_logger(logger_id).unwrap()
+                                    .as_any().downcast_ref::<AnalysisWitnessLogger>().unwrap();
+                                match &witness_logger.witness {
+                                    None => {
+                                        ret_print.push( "no accepting path found : no witness written".to_string() );
+                                    },
+                                    Some( witness ) => {
+                                        let file_format = HibouFileFormat::from_option_value(matches.value_of("format"));
+                                        let witness_path : PathBuf = [format!("{}.{}", witness_name, file_format.get_extension(HIBOU_WITNESS_FILE_EXTENSION))].iter().collect();
+                                        write_witness_into_file(witness_path.as_path(), &gen_ctx, witness);
+                                        ret_print.push( format!("witness of length {:} written into file '{}'", witness.len(), witness_path.to_str().unwrap()) );
+                                        found_witness = Some(witness);
+                                    }
+                                }
+                            }
+                            // ***
+                            if let Some( extracted ) = matches.value_of("report") {
+                                let report_name : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+                                let report_path : PathBuf = [format!("{}.{}", report_name, HIBOU_JSON_FILE_EXTENSION)].iter().collect();
+                                let mut input_files = vec![("hsf",hsf_file_path),("hif",hif_file_path),("htf",htf_file_path)];
+                                if let Some( hcf_file_path ) = matches.value_of("hcf") {
+                                    input_files.push( ("hcf",hcf_file_path) );
+                                }
+                                write_analysis_results_into_file(report_path.as_path(),
+                                                                 &gen_ctx,
+                                                                 &input_files,
+                                                                 multi_trace_length,
+                                                                 &verdict.to_string(),
+                                                                 node_count,
+                                                                 elapsed_time.as_secs_f64(),
+                                                                 found_witness);
+                                ret_print.push( format!("results written into file '{}'", report_path.to_str().unwrap()) );
+                            }
                             return (ret_print,0);
                         }
                     }
@@ -0,0 +1,129 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::path::Path;
+
+use clap::ArgMatches;
+
+use crate::core::execution::witness::replay::replay_witness;
+use crate::io::input::hcf::draw::interface::parse_hcf_file_for_drawing;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::htf::interface::parse_htf_file;
+use crate::io::input::hwf::interface::parse_hwf_file;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
+use crate::io::output::draw_witness::interface::draw_witness_animation;
+
+
+pub fn cli_animate(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let htf_file_path = matches.value_of("htf").unwrap();
+                    match parse_htf_file(&gen_ctx,htf_file_path) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( (co_localizations,multi_trace) ) => {
+                            let hwf_file_path = matches.value_of("hwf").unwrap();
+                            match parse_hwf_file(&gen_ctx,hwf_file_path) {
+                                Err(e) => {
+                                    return (vec![e.to_string()],1);
+                                },
+                                Ok( witness ) => {
+                                    // only a valid execution path can be rendered
+                                    if let Err(e) = replay_witness(&gen_ctx,
+                                                                   &co_localizations,
+                                                                   &multi_trace,
+                                                                   &int,
+                                                                   &witness) {
+                                        return (vec![e.to_string()],1);
+                                    }
+                                    // ***
+                                    let draw_conf : DrawingConfiguration;
+                                    if matches.is_present("hcf") {
+                                        let hcf_file_path = matches.value_of("hcf").unwrap();
+                                        match parse_hcf_file_for_drawing(hcf_file_path) {
+                                            Err(e) => {
+                                                return (vec![e.to_string()],1);
+                                            },
+                                            Ok( got_conf ) => {
+                                                draw_conf = got_conf;
+                                            }
+                                        }
+                                    } else {
+                                        draw_conf = DrawingConfiguration::default();
+                                    }
+                                    // ***
+                                    let frame_delay : u32 = if matches.is_present("delay") {
+                                        let extracted = matches.value_of("delay").unwrap();
+                                        let content_str : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+                                        content_str.parse::<u32>().unwrap()
+                                    } else {
+                                        1000
+                                    };
+                                    let output_file_name : String;
+                                    if matches.is_present("output") {
+                                        let extracted = matches.value_of("output").unwrap();
+                                        output_file_name = extracted.to_string();
+                                    } else {
+                                        let file_name = Path::new(hwf_file_path).file_stem().unwrap().to_str().unwrap();
+                                        output_file_name = format!("{}_anim", file_name);
+                                    }
+                                    let parent_folder : String = matches.value_of("parent_folder").unwrap_or("").to_string();
+                                    // ***
+                                    let frames_num = draw_witness_animation(&gen_ctx,
+                                                                            &draw_conf,
+                                                                            &co_localizations,
+                                                                            &multi_trace,
+                                                                            &int,
+                                                                            &witness,
+                                                                            frame_delay,
+                                                                            &parent_folder,
+                                                                            &output_file_name);
+                                    // ***
+                                    let mut ret_print = vec![];
+                                    ret_print.push( "".to_string());
+                                    ret_print.push( "ANIMATING WITNESS".to_string());
+                                    ret_print.push( format!("from file '{}'",hwf_file_path) );
+                                    ret_print.push( "OF TRACE".to_string());
+                                    ret_print.push( format!("from file '{}'",htf_file_path) );
+                                    ret_print.push( "W.R.T. INTERACTION".to_string());
+                                    ret_print.push( format!("from file '{}'",hif_file_path) );
+                                    ret_print.push( "".to_string());
+                                    ret_print.push( format!("{:} images drawn on files : {}_000.png ...", frames_num, output_file_name) );
+                                    ret_print.push( format!("animation drawn on file : {}.gif", output_file_name) );
+                                    ret_print.push( "".to_string());
+                                    return (ret_print,0);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,205 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use clap::ArgMatches;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::io::file_extensions::{HibouFileFormat, HIBOU_TRACE_FILE_EXTENSION};
+use crate::io::input::hcf::ana::interface::parse_hcf_file_for_ana;
+use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::htf::interface::parse_htf_file;
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file_with_comment;
+use crate::process::ana::interface::get_analysis_verdict;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::trace_manip::campaign::campaign::{MutationCampaignConfiguration, run_mutation_campaign};
+use crate::trace_manip::campaign::operator::CampaignMutationOperator;
+
+
+pub fn cli_campaign(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let operators : Vec<CampaignMutationOperator>;
+    match matches.value_of("operators") {
+        None => {
+            operators = CampaignMutationOperator::get_all_operators();
+        },
+        Some( extracted ) => {
+            let mut got_operators = vec![];
+            let content_str : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+            for operator_name in content_str.split(',') {
+                match CampaignMutationOperator::from_name(operator_name) {
+                    None => {
+                        return (vec![format!("unknown mutation operator '{}'",operator_name)],1);
+                    },
+                    Some( operator ) => {
+                        got_operators.push(operator);
+                    }
+                }
+            }
+            operators = got_operators;
+        }
+    }
+    // ***
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    // the options are parsed once and each analysis is given a copy without loggers
+                    // so as not to log every mutant analysis
+                    let hcf_file_path = matches.value_of("hcf").unwrap();
+                    let ana_opts : HibouAnalyzeOptions = match parse_hcf_file_for_ana(&gen_ctx,hcf_file_path) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( got_ana_opts ) => {
+                            got_ana_opts
+                        }
+                    };
+                    // ***
+                    let mut ret_print = vec![];
+                    ret_print.push( "MUTATION CAMPAIGN".to_string());
+                    ret_print.push( "W.R.T. INTERACTION".to_string());
+                    ret_print.push( format!("from file '{}'",hif_file_path) );
+                    ret_print.push( "ON MULTI-TRACES".to_string());
+                    // ***
+                    let mut htf_stems : Vec<String> = vec![];
+                    let mut multi_traces : Vec<(CoLocalizations,MultiTrace)> = vec![];
+                    for htf_file_path in matches.values_of("htf").unwrap() {
+                        match parse_htf_file(&gen_ctx,htf_file_path) {
+                            Err(e) => {
+                                return (vec![e.to_string()],1);
+                            },
+                            Ok( (co_localizations,multi_trace) ) => {
+                                let (_,verdict) = get_analysis_verdict(&gen_ctx,&co_localizations,&multi_trace,&int,ana_opts.clone_without_loggers());
+                                match verdict {
+                                    AnalysisGlobalVerdict::Pass | AnalysisGlobalVerdict::WeakPass => {},
+                                    _ => {
+                                        ret_print.push( format!("from file '{}' : verdict '{}' ; only accepted multi-traces can be mutated",htf_file_path,verdict.to_string()) );
+                                        return (ret_print,1);
+                                    }
+                                }
+                                ret_print.push( format!("from file '{}'",htf_file_path) );
+                                htf_stems.push( Path::new(htf_file_path).file_stem().unwrap().to_str().unwrap().to_string() );
+                                multi_traces.push( (co_localizations,multi_trace) );
+                            }
+                        }
+                    }
+                    // ***
+                    let num_mutants : u32;
+                    if matches.is_present("num_mutants") {
+                        let extracted = matches.value_of("num_mutants").unwrap();
+                        let content_str : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+                        num_mutants = content_str.parse::<u32>().unwrap();
+                    } else {
+                        num_mutants = 10;
+                    }
+                    let seed : u64 = if matches.is_present("seed") {
+                        let extracted = matches.value_of("seed").unwrap();
+                        let content_str : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+                        content_str.parse::<u64>().unwrap()
+                    } else {
+                        rand::random::<u64>()
+                    };
+                    let config = MutationCampaignConfiguration{operators,num_mutants,seed};
+                    ret_print.push( format!("with {} mutant(s) per multi-trace per operator",num_mutants) );
+                    ret_print.push( format!("with seed {:?}",seed) );
+                    ret_print.push( "".to_string());
+                    // ***
+                    let parent_folder : Option<&str> = matches.value_of("parent_folder");
+                    let file_format = HibouFileFormat::from_option_value(matches.value_of("format"));
+                    if let Some( parent ) = parent_folder {
+                        fs::create_dir_all(parent).unwrap();
+                    }
+                    let mut num_survivors : u32 = 0;
+                    let mut on_survivor = |mt_idx : usize, operator : &CampaignMutationOperator, co_localizations : &CoLocalizations, mutant : &MultiTrace| {
+                        if let Some( parent ) = parent_folder {
+                            num_survivors += 1;
+                            let file_name = format!("{:}_{:}_survivor{:}.{:}",
+                                                    htf_stems.get(mt_idx).unwrap(),
+                                                    operator.get_name(),
+                                                    num_survivors,
+                                                    file_format.get_extension(HIBOU_TRACE_FILE_EXTENSION));
+                            let path : PathBuf = [parent, &file_name].iter().collect();
+                            write_multi_trace_into_file_with_comment(path.as_path(),
+                                                                     &gen_ctx,
+                                                                     co_localizations,
+                                                                     mutant,
+                                                                     &format!("surviving mutant of campaign with seed {:}", seed));
+                        }
+                    };
+                    let mut analyze = |co_localizations : &CoLocalizations, mutant : &MultiTrace| -> AnalysisGlobalVerdict {
+                        let (_,verdict) = get_analysis_verdict(&gen_ctx,co_localizations,mutant,&int,ana_opts.clone_without_loggers());
+                        verdict
+                    };
+                    // ***
+                    let now = Instant::now();
+                    let statistics = run_mutation_campaign(&gen_ctx,&int,&multi_traces,&config,&mut analyze,&mut on_survivor);
+                    let elapsed_time = now.elapsed();
+                    // ***
+                    let mut total_killed : u32 = 0;
+                    let mut total_analyzed : u32 = 0;
+                    for stats in &statistics {
+                        total_killed += stats.killed;
+                        total_analyzed += stats.get_analyzed_num();
+                        ret_print.push( format!("operator   : '{}'", stats.operator.get_name()) );
+                        ret_print.push( format!("killed     : {:?}", stats.killed) );
+                        ret_print.push( format!("survived   : {:?}", stats.survived) );
+                        ret_print.push( format!("inconc     : {:?}", stats.inconclusive) );
+                        ret_print.push( format!("n/a        : {:?}", stats.not_applicable) );
+                        match stats.get_kill_rate() {
+                            None => {
+                                ret_print.push( "kill rate  : -".to_string() );
+                            },
+                            Some( rate ) => {
+                                ret_print.push( format!("kill rate  : {:.3}", rate) );
+                            }
+                        }
+                        match stats.get_mean_time() {
+                            None => {
+                                ret_print.push( "mean time  : -".to_string() );
+                            },
+                            Some( mean ) => {
+                                ret_print.push( format!("mean time  : {:?}", mean.as_secs_f64()) );
+                            }
+                        }
+                        ret_print.push( format!("max time   : {:?}", stats.max_time.as_secs_f64()) );
+                        ret_print.push( "".to_string());
+                    }
+                    ret_print.push( format!("killed     : {:?} out of {:?} analyzed mutants", total_killed, total_analyzed) );
+                    if let Some( parent ) = parent_folder {
+                        ret_print.push( format!("survivors  : {:?} written into folder '{}'", num_survivors, parent) );
+                    }
+                    ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64()) );
+                    return (ret_print,0);
+                }
+            }
+        }
+    }
+}
+
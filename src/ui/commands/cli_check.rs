@@ -0,0 +1,104 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::time::Instant;
+
+use clap::ArgMatches;
+
+use crate::io::input::hcf::explo::interface::parse_hcf_file_for_explore;
+use crate::io::input::hcf::explo::options::HibouExploreOptions;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::ltl::interface::parse_ltl_formula;
+use crate::io::output::draw_traces::interface::text_repr_trace;
+use crate::ltl::check::{check_ltl_formula, LtlCheckVerdict};
+use crate::ltl::graph::explore_interaction_graph;
+
+
+pub fn cli_check(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let formula_str = matches.value_of("formula").unwrap();
+                    let formula = match parse_ltl_formula(&gen_ctx,formula_str) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( got_formula ) => {
+                            got_formula
+                        }
+                    };
+                    // ***
+                    let explo_opts : HibouExploreOptions;
+                    if matches.is_present("hcf") {
+                        let hcf_file_path = matches.value_of("hcf").unwrap();
+                        match parse_hcf_file_for_explore(&gen_ctx,hcf_file_path) {
+                            Err(e) => {
+                                return (vec![e.to_string()],1);
+                            },
+                            Ok( got_explo_opt) => {
+                                explo_opts = got_explo_opt;
+                            }
+                        }
+                    } else {
+                        explo_opts = HibouExploreOptions::default();
+                    }
+                    // ***
+                    let mut ret_print = vec![];
+                    ret_print.push( "CHECKING LTL PROPERTY".to_string());
+                    ret_print.push( format!("'{}'",formula_str) );
+                    ret_print.push( "ON INTERACTION".to_string());
+                    ret_print.push( format!("from file '{}'",hif_file_path) );
+                    ret_print.push( "".to_string());
+                    // ***
+                    let now = Instant::now();
+                    let graph = explore_interaction_graph(&gen_ctx,&int,explo_opts);
+                    let verdict = check_ltl_formula(&gen_ctx,&graph,&formula);
+                    let elapsed_time = now.elapsed();
+                    // ***
+                    ret_print.push( format!("verdict    : {}", verdict) );
+                    if let LtlCheckVerdict::Violated(counter_example) = &verdict {
+                        if counter_example.is_finite() {
+                            ret_print.push( "behaviour  : finite accepted trace".to_string() );
+                            ret_print.push( format!("trace      : {}", text_repr_trace(&counter_example.prefix,&gen_ctx)) );
+                        } else {
+                            ret_print.push( "behaviour  : infinite lasso, i.e. the prefix followed by the cycle repeated forever".to_string() );
+                            ret_print.push( format!("prefix     : {}", text_repr_trace(&counter_example.prefix,&gen_ctx)) );
+                            ret_print.push( format!("cycle      : {}", text_repr_trace(&counter_example.cycle,&gen_ctx)) );
+                        }
+                    }
+                    ret_print.push( format!("graph      : {:?} states and {:?} transitions", graph.get_states_num(), graph.get_transitions_num()) );
+                    if graph.filtered_num > 0 {
+                        ret_print.push( format!("bounded    : {:?} step(s) eliminated by filters ; behaviours beyond them were not checked", graph.filtered_num) );
+                    }
+                    ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64()) );
+                    return (ret_print,0);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::path::Path;
+use std::time::Instant;
+
+use clap::ArgMatches;
+
+use crate::coverage::model::InteractionCoverageModel;
+use crate::coverage::report::InteractionCoverageReport;
+use crate::io::input::hcf::ana::interface::parse_hcf_file_for_ana;
+use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::htf::interface::parse_htf_file;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
+use crate::io::output::draw_interactions::interface::draw_interaction_coverage;
+use crate::process::ana::interface::make_analysis_manager;
+use crate::process::ana::loggers::plog::logger::AnalysisAcceptingPathsLogger;
+
+
+pub fn cli_coverage(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let ana_opts : HibouAnalyzeOptions = match matches.value_of("hcf") {
+                        None => {
+                            HibouAnalyzeOptions::default()
+                        },
+                        Some( hcf_file_path ) => {
+                            match parse_hcf_file_for_ana(&gen_ctx,hcf_file_path) {
+                                Err(e) => {
+                                    return (vec![e.to_string()],1);
+                                },
+                                Ok( got_ana_opt ) => {
+                                    got_ana_opt
+                                }
+                            }
+                        }
+                    };
+                    let model = InteractionCoverageModel::new(&gen_ctx,&int);
+                    let mut report = InteractionCoverageReport::new(&model);
+                    // ***
+                    let mut ret_print = vec![];
+                    ret_print.push( "MEASURING COVERAGE OF INTERACTION".to_string());
+                    ret_print.push( format!("from file '{}'",hif_file_path) );
+                    ret_print.push( "BY MULTI-TRACES".to_string());
+                    // ***
+                    let now = Instant::now();
+                    for htf_file_path in matches.values_of("htf").unwrap() {
+                        match parse_htf_file(&gen_ctx,htf_file_path) {
+                            Err(e) => {
+                                return (vec![e.to_string()],1);
+                            },
+                            Ok( (co_localizations,multi_trace) ) => {
+                                // the analysis is pursued after the first accepting path so as to find them all
+                                let mut trace_opts = ana_opts.clone_without_loggers();
+                                trace_opts.goal = None;
+                                trace_opts.loggers.push( Box::new(AnalysisAcceptingPathsLogger::new(model.clone(),1)) );
+                                let (mut analysis_manager,init_node) = make_analysis_manager(&gen_ctx,
+                                                                                             &co_localizations,
+                                                                                             &multi_trace,
+                                                                                             &int,
+                                                                                             trace_opts);
+                                analysis_manager.start_process(init_node);
+                                let paths_logger = analysis_manager.get_logger(0).unwrap()
+                                    .as_any().downcast_ref::<AnalysisAcceptingPathsLogger>().unwrap();
+                                let covered = paths_logger.covered_leaves.clone();
+                                match &covered {
+                                    None => {
+                                        ret_print.push( format!("from file '{}' (not accepted)",htf_file_path) );
+                                    },
+                                    Some(_) => {
+                                        ret_print.push( format!("from file '{}'",htf_file_path) );
+                                    }
+                                }
+                                report.add_trace_coverage(&model,&covered);
+                            }
+                        }
+                    }
+                    let elapsed_time = now.elapsed();
+                    ret_print.push( "".to_string());
+                    ret_print.append( &mut report.as_text_lines(&model) );
+                    ret_print.push( "".to_string());
+                    // ***
+                    let output_file_name : String;
+                    if matches.is_present("output") {
+                        let extracted = matches.value_of("output").unwrap();
+                        output_file_name = extracted.to_string();
+                    } else {
+                        let file_name = Path::new(hif_file_path).file_stem().unwrap().to_str().unwrap();
+                        output_file_name = format!("{}_coverage", file_name);
+                    }
+                    draw_interaction_coverage(&gen_ctx,
+                                              &DrawingConfiguration::default(),
+                                              &int,
+                                              &report.get_covered_leaves(),
+                                              &"".to_string(),
+                                              &output_file_name);
+                    ret_print.push( format!("coverage drawn on file : {}",output_file_name) );
+                    ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64() ) );
+                    return (ret_print,0);
+                }
+            }
+        }
+    }
+}
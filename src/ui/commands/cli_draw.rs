@@ -21,6 +21,8 @@ use clap::ArgMatches;
 
 use crate::io::input::hsf::interface::parse_hsf_file;
 use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hcf::draw::interface::parse_hcf_file_for_drawing;
+use crate::io::output::draw_commons::drawing_configuration::DrawingConfiguration;
 use crate::io::output::draw_interactions::interface::{InteractionGraphicalRepresentation,draw_interaction};
 
 
@@ -48,6 +50,15 @@ pub fn cli_draw(matches : &ArgMatches) -> (Vec<String>,u32) {
                             "tt" => {
                                 rep_kind = InteractionGraphicalRepresentation::AsTerm;
                             },
+                            "svg" => {
+                                rep_kind = InteractionGraphicalRepresentation::AsSvgSequenceDiagram;
+                            },
+                            "plantuml" => {
+                                rep_kind = InteractionGraphicalRepresentation::AsPlantUml;
+                            },
+                            "mermaid" => {
+                                rep_kind = InteractionGraphicalRepresentation::AsMermaid;
+                            },
                             _ => {
                                 return (vec![format!("unknown representation kind : {:}",extracted)], 1);
                             }
@@ -65,7 +76,22 @@ pub fn cli_draw(matches : &ArgMatches) -> (Vec<String>,u32) {
                         output_file_name = format!("{}_repr", file_name);
                     }
                     // ***
-                    draw_interaction(&gen_ctx, &int, &rep_kind, &"temp".to_string(), &"".to_string(), &output_file_name);
+                    let draw_conf : DrawingConfiguration;
+                    if matches.is_present("hcf") {
+                        let hcf_file_path = matches.value_of("hcf").unwrap();
+                        match parse_hcf_file_for_drawing(hcf_file_path) {
+                            Err(e) => {
+                                return (vec![e.to_string()],1);
+                            },
+                            Ok( got_conf ) => {
+                                draw_conf = got_conf;
+                            }
+                        }
+                    } else {
+                        draw_conf = DrawingConfiguration::default();
+                    }
+                    // ***
+                    draw_interaction(&gen_ctx, &draw_conf, &int, &rep_kind, &"temp".to_string(), &"".to_string(), &output_file_name);
                     // ***
                     let mut ret_print = vec![];
                     ret_print.push( "".to_string());
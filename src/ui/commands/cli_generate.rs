@@ -0,0 +1,115 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::path::Path;
+use std::time::Instant;
+
+use clap::ArgMatches;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::io::file_extensions::HibouFileFormat;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::trace_manip::generate::generate::{generate_random_walk_traces, RandomWalkGenerationConfiguration};
+use crate::ui::util::partition::parse_partition;
+
+
+pub fn cli_generate(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let int_name : &str = Path::new(hif_file_path).file_stem().unwrap().to_str().unwrap();
+                    let parent_folder : Option<&str> = matches.value_of("parent_folder");
+                    let file_name_prefix_opt : Option<&str> = matches.value_of("name");
+                    // ***
+                    let num_traces : u32 = if matches.is_present("random") {
+                        let extracted = matches.value_of("random").unwrap();
+                        let content_str : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+                        content_str.parse::<u32>().unwrap()
+                    } else {
+                        10
+                    };
+                    let target_length : u32 = if matches.is_present("length") {
+                        let extracted = matches.value_of("length").unwrap();
+                        let content_str : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+                        content_str.parse::<u32>().unwrap()
+                    } else {
+                        10
+                    };
+                    let seed : u64 = if matches.is_present("seed") {
+                        let extracted = matches.value_of("seed").unwrap();
+                        let content_str : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+                        content_str.parse::<u64>().unwrap()
+                    } else {
+                        rand::random::<u64>()
+                    };
+                    let bias = matches.is_present("bias");
+                    // ***
+                    let co_localizations : CoLocalizations = if matches.is_present("partition") {
+                        let extracted = matches.value_of("partition").unwrap();
+                        let content_str : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+                        match parse_partition(&gen_ctx,&content_str) {
+                            Err(e) => {
+                                return (vec![e],1);
+                            },
+                            Ok( got_colocs ) => {
+                                got_colocs
+                            }
+                        }
+                    } else {
+                        CoLocalizations::get_trivial_partition(gen_ctx.get_lf_num())
+                    };
+                    // ***
+                    let mut ret_print = vec![];
+                    ret_print.push( "GENERATING TRACES".to_string());
+                    ret_print.push( "VIA RANDOM WALKS OVER INTERACTION".to_string());
+                    ret_print.push( format!("from file '{}'",hif_file_path) );
+                    ret_print.push( "".to_string());
+                    // ***
+                    let config = RandomWalkGenerationConfiguration::new(num_traces,target_length,bias,seed);
+                    let now = Instant::now();
+                    let accepted_num = generate_random_walk_traces(&gen_ctx,
+                                                                   &co_localizations,
+                                                                   &int,
+                                                                   int_name,
+                                                                   parent_folder,
+                                                                   file_name_prefix_opt,
+                                                                   HibouFileFormat::from_option_value(matches.value_of("format")),
+                                                                   &config);
+                    let elapsed_time = now.elapsed();
+                    ret_print.push( format!("generated  : {:?}", num_traces ) );
+                    ret_print.push( format!("accepted   : {:?}", accepted_num ) );
+                    ret_print.push( format!("seed       : {:?}", seed ) );
+                    ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64() ) );
+                    return (ret_print,0);
+                }
+            }
+        }
+    }
+}
+
+
@@ -0,0 +1,71 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::path::Path;
+
+use clap::ArgMatches;
+
+use crate::io::file_extensions::{HibouFileFormat, HIBOU_INTERACTION_FILE_EXTENSION, HIBOU_SIGNATURE_FILE_EXTENSION};
+use crate::io::input::puml::interface::parse_puml_file;
+use crate::io::output::to_hfiles::interaction::to_hif::write_interaction_into_file;
+use crate::io::output::to_hfiles::signature::to_hsf::write_signature_into_file;
+
+
+pub fn cli_import_puml(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let puml_file_path = matches.value_of("puml").unwrap();
+    match parse_puml_file(puml_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( (gen_ctx,interaction,renamings) ) => {
+            let output_name : String = match matches.value_of("output") {
+                None => {
+                    Path::new(puml_file_path).file_stem().unwrap().to_str().unwrap().to_string()
+                },
+                Some( output ) => {
+                    output.to_string()
+                }
+            };
+            let file_format = HibouFileFormat::from_option_value(matches.value_of("format"));
+            // both files would otherwise have the same name when written as JSON
+            let (hsf_file_path,hif_file_path) = match file_format {
+                HibouFileFormat::Native => {
+                    (format!("{:}.{:}", output_name, HIBOU_SIGNATURE_FILE_EXTENSION),
+                     format!("{:}.{:}", output_name, HIBOU_INTERACTION_FILE_EXTENSION))
+                },
+                HibouFileFormat::Json => {
+                    (format!("{:}_signature.{:}", output_name, file_format.get_extension(HIBOU_SIGNATURE_FILE_EXTENSION)),
+                     format!("{:}_interaction.{:}", output_name, file_format.get_extension(HIBOU_INTERACTION_FILE_EXTENSION)))
+                }
+            };
+            write_signature_into_file(Path::new(&hsf_file_path),&gen_ctx);
+            write_interaction_into_file(Path::new(&hif_file_path),&gen_ctx,&interaction);
+            // ***
+            let mut ret_print = vec![];
+            ret_print.push( "IMPORTING PLANTUML SEQUENCE DIAGRAM".to_string());
+            ret_print.push( format!("from file '{}'",puml_file_path) );
+            ret_print.push( "".to_string());
+            ret_print.push( format!("lifelines  : {:?}", gen_ctx.get_lf_num()) );
+            ret_print.push( format!("messages   : {:?}", gen_ctx.get_ms_num()) );
+            for (original,renamed) in &renamings {
+                ret_print.push( format!("renamed    : '{}' as '{}'", original, renamed) );
+            }
+            ret_print.push( format!("signature written into file '{}'", hsf_file_path) );
+            ret_print.push( format!("interaction written into file '{}'", hif_file_path) );
+            return (ret_print,0);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::path::Path;
+
+use clap::ArgMatches;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::io::file_extensions::{HibouFileFormat, HIBOU_TRACE_FILE_EXTENSION};
+use crate::io::input::hmf::interface::{parse_hmf_file, parse_log_file};
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file_with_comment;
+use crate::trace_manip::import::import::import_multi_trace;
+use crate::trace_manip::import::mapping::TraceImportMapping;
+
+
+pub fn cli_import_trace(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let mapping = match matches.value_of("mapping") {
+                None => {
+                    TraceImportMapping::new_default(CoLocalizations::get_discrete_partition(gen_ctx.get_lf_num()))
+                },
+                Some( hmf_file_path ) => {
+                    match parse_hmf_file(&gen_ctx,hmf_file_path) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( got_mapping ) => {
+                            got_mapping
+                        }
+                    }
+                }
+            };
+            // ***
+            let log_file_path = matches.value_of("log").unwrap();
+            let records = match parse_log_file(&mapping,log_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( got_records ) => {
+                    got_records
+                }
+            };
+            let (multi_trace,report) = match import_multi_trace(&gen_ctx,&mapping,records) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( got ) => {
+                    got
+                }
+            };
+            // ***
+            let output_path : String = match matches.value_of("output") {
+                None => {
+                    let file_format = HibouFileFormat::from_option_value(matches.value_of("format"));
+                    let log_name = Path::new(log_file_path).file_stem().unwrap().to_str().unwrap();
+                    format!("{:}.{:}", log_name, file_format.get_extension(HIBOU_TRACE_FILE_EXTENSION))
+                },
+                Some( output ) => {
+                    output.to_string()
+                }
+            };
+            write_multi_trace_into_file_with_comment(Path::new(&output_path),
+                                                     &gen_ctx,
+                                                     &mapping.co_localizations,
+                                                     &multi_trace,
+                                                     &format!("imported from log '{}'", log_file_path));
+            // ***
+            let mut ret_print = vec![];
+            ret_print.push( "IMPORTING MULTI-TRACE".to_string());
+            ret_print.push( format!("from log '{}'",log_file_path) );
+            ret_print.push( "".to_string());
+            ret_print.push( format!("imported   : {:?} action(s) in {:?} component(s)", report.imported_num, multi_trace.len()) );
+            for (lf_name,lines) in &report.unknown_lifelines {
+                ret_print.push( format!("unknown lf : '{}' on line(s) {:?}", lf_name, lines) );
+            }
+            for (ms_name,lines) in &report.unknown_messages {
+                ret_print.push( format!("unknown ms : '{}' on line(s) {:?}", ms_name, lines) );
+            }
+            if !report.is_complete() {
+                ret_print.push( "events with undeclared lifelines or messages were not imported".to_string() );
+            }
+            ret_print.push( format!("written into file '{}'", output_path) );
+            return (ret_print,0);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::path::Path;
+
+use clap::ArgMatches;
+
+use crate::core::general_context::{GeneralContext, merge_signatures};
+use crate::io::file_extensions::{HibouFileFormat, HIBOU_SIGNATURE_FILE_EXTENSION};
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::to_hfiles::signature::to_hsf::write_signature_into_file;
+
+
+pub fn cli_merge_signatures(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let mut ret_print = vec![];
+    ret_print.push( "MERGING SIGNATURES".to_string());
+    let mut signatures : Vec<GeneralContext> = vec![];
+    for (sig_id,hsf_file_path) in matches.values_of("hsf").unwrap().enumerate() {
+        match parse_hsf_file(hsf_file_path) {
+            Err(e) => {
+                return (vec![e.to_string()],1);
+            },
+            Ok( gen_ctx ) => {
+                ret_print.push( format!("signature {:?} from file '{}'", sig_id, hsf_file_path) );
+                signatures.push(gen_ctx);
+            }
+        }
+    }
+    ret_print.push( "".to_string());
+    // ***
+    let output_path : String = match matches.value_of("output") {
+        None => {
+            let file_format = HibouFileFormat::from_option_value(matches.value_of("format"));
+            format!("merged.{:}", file_format.get_extension(HIBOU_SIGNATURE_FILE_EXTENSION))
+        },
+        Some( output ) => {
+            output.to_string()
+        }
+    };
+    // ***
+    let (merged,conflicts) = merge_signatures(&signatures);
+    write_signature_into_file(Path::new(&output_path),&merged);
+    // ***
+    ret_print.push( format!("lifelines  : {:?}", merged.get_lf_num()) );
+    ret_print.push( format!("messages   : {:?}", merged.get_ms_num()) );
+    ret_print.push( format!("types      : {:?}", merged.get_mt_num()) );
+    for conflict in &conflicts {
+        ret_print.push( format!("conflict   : {}", conflict) );
+    }
+    ret_print.push( format!("written into file '{}'", output_path) );
+    return (ret_print,0);
+}
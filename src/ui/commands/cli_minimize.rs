@@ -0,0 +1,130 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use clap::ArgMatches;
+
+use crate::core::execution::trace::multitrace::multi_trace_length;
+use crate::io::file_extensions::{HibouFileFormat, HIBOU_TRACE_FILE_EXTENSION};
+use crate::io::input::hcf::ana::interface::parse_hcf_file_for_ana;
+use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::htf::interface::parse_htf_file;
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file;
+use crate::process::ana::interface::get_analysis_verdict;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::trace_manip::minimize::ddmin::minimize_multi_trace;
+
+
+pub fn cli_minimize(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let htf_file_path = matches.value_of("htf").unwrap();
+                    match parse_htf_file(&gen_ctx,htf_file_path) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( (co_localizations,multi_trace) ) => {
+                            let ana_opts : HibouAnalyzeOptions = match matches.value_of("hcf") {
+                                None => {
+                                    HibouAnalyzeOptions::default()
+                                },
+                                Some( hcf_file_path ) => {
+                                    match parse_hcf_file_for_ana(&gen_ctx,hcf_file_path) {
+                                        Err(e) => {
+                                            return (vec![e.to_string()],1);
+                                        },
+                                        Ok( got_ana_opt ) => {
+                                            got_ana_opt
+                                        }
+                                    }
+                                }
+                            };
+                            // ***
+                            let parent_folder : Option<&str> = matches.value_of("parent_folder");
+                            let minimized_name : String = if matches.is_present("name") {
+                                let extracted = matches.value_of("name").unwrap();
+                                extracted.chars().filter(|c| !c.is_whitespace()).collect()
+                            } else {
+                                let mu_name : &str = Path::new(htf_file_path).file_stem().unwrap().to_str().unwrap();
+                                format!("{}_minimized",mu_name)
+                            };
+                            // ***
+                            let mut ret_print = vec![];
+                            ret_print.push( "MINIMIZING TRACE".to_string());
+                            ret_print.push( format!("from file '{}'",htf_file_path) );
+                            ret_print.push( format!("of length '{:?}'", multi_trace_length(&multi_trace)) );
+                            ret_print.push( "W.R.T. INTERACTION".to_string());
+                            ret_print.push( format!("from file '{}'",hif_file_path) );
+                            ret_print.push( "".to_string());
+                            // ***
+                            let now = Instant::now();
+                            let (_,init_verdict) = get_analysis_verdict(&gen_ctx,&co_localizations,&multi_trace,&int,ana_opts.clone_without_loggers());
+                            match init_verdict {
+                                AnalysisGlobalVerdict::Fail | AnalysisGlobalVerdict::WeakFail => {},
+                                _ => {
+                                    ret_print.push( format!("verdict    : '{}' ; only failing multi-traces can be minimized", init_verdict.to_string()) );
+                                    return (ret_print,1);
+                                }
+                            }
+                            let mut still_fails = |candidate : &_| -> bool {
+                                let (_,verdict) = get_analysis_verdict(&gen_ctx,&co_localizations,candidate,&int,ana_opts.clone_without_loggers());
+                                verdict == init_verdict
+                            };
+                            let minimization = minimize_multi_trace(&multi_trace,&mut still_fails);
+                            let elapsed_time = now.elapsed();
+                            // ***
+                            let file_name = format!("{:}.{:}", minimized_name, HibouFileFormat::from_option_value(matches.value_of("format")).get_extension(HIBOU_TRACE_FILE_EXTENSION));
+                            let path : PathBuf = match parent_folder {
+                                None => {
+                                    [&file_name].iter().collect()
+                                },
+                                Some( parent ) => {
+                                    [parent, &file_name].iter().collect()
+                                }
+                            };
+                            write_multi_trace_into_file(path.as_path(),
+                                                        &gen_ctx,
+                                                        &co_localizations,
+                                                        &minimization.minimized);
+                            ret_print.push( format!("minimized multi-trace written into file '{}'",path.to_str().unwrap()) );
+                            ret_print.push( format!("verdict    : '{}'", init_verdict.to_string() ) );
+                            ret_print.push( format!("length     : {:?}", multi_trace_length(&minimization.minimized) ) );
+                            ret_print.push( format!("analyses   : {:?}", minimization.tested_candidates + 1 ) );
+                            ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64() ) );
+                            return (ret_print,0);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
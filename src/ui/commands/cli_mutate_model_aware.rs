@@ -0,0 +1,119 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::path::Path;
+
+use clap::ArgMatches;
+
+
+use crate::io::file_extensions::HibouFileFormat;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::htf::interface::parse_htf_file;
+use crate::trace_manip::mutate::model_aware::{generate_model_aware_mutant, ModelAwareMutationOperator};
+
+
+pub fn cli_mutate_model_aware(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let operator_name = matches.value_of("operator").unwrap();
+    let operator : ModelAwareMutationOperator;
+    match ModelAwareMutationOperator::from_name(operator_name) {
+        None => {
+            return (vec![format!("unknown mutation operator '{}'",operator_name),
+                         "expected one of replace_same_type, replace_other_type, flip_kind, move_lifeline, duplicate, model_noise".to_string()],1);
+        },
+        Some( got_operator ) => {
+            operator = got_operator;
+        }
+    }
+    // ***
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let htf_file_path = matches.value_of("htf").unwrap();
+                    match parse_htf_file(&gen_ctx, htf_file_path) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( (co_localizations,multi_trace) ) => {
+                            // ***
+                            let parent_folder : Option<&str> = matches.value_of("parent_folder");
+                            // ***
+                            let max_num_mutations : u32;
+                            if matches.is_present("max_num_mutations") {
+                                let extracted = matches.value_of("max_num_mutations").unwrap();
+                                let content_str : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+                                max_num_mutations = content_str.parse::<u32>().unwrap();
+                            } else {
+                                max_num_mutations = 1;
+                            }
+                            // ***
+                            let mutant_name : String = if matches.is_present("name") {
+                                let extracted = matches.value_of("name").unwrap();
+                                extracted.chars().filter(|c| !c.is_whitespace()).collect()
+                            } else {
+                                let mu_name : &str = Path::new(htf_file_path).file_stem().unwrap().to_str().unwrap();
+                                format!("{}_{}_mutant",mu_name,operator.get_name())
+                            };
+                            // ***
+                            let seed : u64 = if matches.is_present("seed") {
+                                let extracted = matches.value_of("seed").unwrap();
+                                let content_str : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+                                content_str.parse::<u64>().unwrap()
+                            } else {
+                                rand::random::<u64>()
+                            };
+                            // ***
+                            let file_format = HibouFileFormat::from_option_value(matches.value_of("format"));
+                            let (mutant_file_path,num_applied) = generate_model_aware_mutant(&gen_ctx,
+                                                                                             &co_localizations,
+                                                                                             &multi_trace,
+                                                                                             &int,
+                                                                                             parent_folder,
+                                                                                             &mutant_name,
+                                                                                             file_format,
+                                                                                             &operator,
+                                                                                             max_num_mutations,
+                                                                                             seed);
+                            // ***
+                            let mut ret_print = vec![];
+                            ret_print.push( format!("GENERATED '{}' MUTANT", operator.get_name()));
+                            ret_print.push( "FOR MULTITRACE".to_string());
+                            ret_print.push( format!("from file '{}'",htf_file_path) );
+                            ret_print.push( format!("into file '{}'",mutant_file_path) );
+                            ret_print.push( format!("with seed {:?}",seed) );
+                            ret_print.push( format!("applied {} mutation(s) out of {}",num_applied,max_num_mutations) );
+                            ret_print.push( "".to_string());
+                            if num_applied == 0 {
+                                return (ret_print,1);
+                            }
+                            return (ret_print,0);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
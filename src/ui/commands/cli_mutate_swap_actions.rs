@@ -19,6 +19,7 @@ use std::path::Path;
 
 use clap::ArgMatches;
 
+use crate::io::file_extensions::HibouFileFormat;
 use crate::io::input::hsf::interface::parse_hsf_file;
 use crate::io::input::htf::interface::parse_htf_file;
 use crate::trace_manip::mutate::swap_actions::generate_swap_actions_mutant;
@@ -68,13 +69,23 @@ pub fn cli_mutate_swap_actions(matches : &ArgMatches) -> (Vec<String>,u32) {
                         mutant_name = format!("{}_swap_act_mutant",mu_name);
                     }
                     // ***
-                    let mutant_file_path = generate_swap_actions_mutant(&gen_ctx,&co_localizations,&multi_trace,parent_folder,&mutant_name,max_num_swap);
+                    let seed : u64 = if matches.is_present("seed") {
+                        let extracted = matches.value_of("seed").unwrap();
+                        let content_str : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+                        content_str.parse::<u64>().unwrap()
+                    } else {
+                        rand::random::<u64>()
+                    };
+                    // ***
+                    let file_format = HibouFileFormat::from_option_value(matches.value_of("format"));
+                    let mutant_file_path = generate_swap_actions_mutant(&gen_ctx,&co_localizations,&multi_trace,parent_folder,&mutant_name,file_format,max_num_swap,seed);
                     // ***
                     let mut ret_print = vec![];
                     ret_print.push( "GENERATED SWAP ACTIONS MUTANT".to_string());
                     ret_print.push( "FOR MULTITRACE".to_string());
                     ret_print.push( format!("from file '{}'",htf_file_path) );
                     ret_print.push( format!("into file '{}'",mutant_file_path) );
+                    ret_print.push( format!("with seed {:?}",seed) );
                     ret_print.push( "".to_string());
                     return (ret_print,0);
                 }
@@ -0,0 +1,182 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+use std::path::Path;
+
+use clap::ArgMatches;
+use itertools::Itertools;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::automaton::build::interaction_as_automaton;
+use crate::core::language::eliminate_lf::eliminable::LifelineEliminable;
+use crate::io::file_extensions::{HibouFileFormat, HIBOU_INTERACTION_FILE_EXTENSION};
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::output::draw_automata::interface::write_interaction_automaton_as_dot;
+use crate::io::output::to_hfiles::interaction::to_hif::write_interaction_into_file;
+use crate::ui::util::partition::parse_partition;
+
+
+pub fn cli_project(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let max_states : usize = match matches.value_of("max_states") {
+        None => {
+            1000
+        },
+        Some( extracted ) => {
+            match extracted.trim().parse::<usize>() {
+                Err(_) => {
+                    return (vec![format!("could not parse maximum number of states '{}'",extracted)],1);
+                },
+                Ok( got_max ) => {
+                    got_max
+                }
+            }
+        }
+    };
+    // ***
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let co_localizations : CoLocalizations = if matches.is_present("partition") {
+                        let extracted = matches.value_of("partition").unwrap();
+                        let content_str : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+                        match parse_partition(&gen_ctx,&content_str) {
+                            Err(e) => {
+                                return (vec![e],1);
+                            },
+                            Ok( got_colocs ) => {
+                                got_colocs
+                            }
+                        }
+                    } else {
+                        CoLocalizations::get_discrete_partition(gen_ctx.get_lf_num())
+                    };
+                    // ***
+                    let int_name : &str = Path::new(hif_file_path).file_stem().unwrap().to_str().unwrap();
+                    let parent_folder : String = match matches.value_of("parent_folder") {
+                        None => {
+                            format!("./{:}_projections", int_name)
+                        },
+                        Some( parent ) => {
+                            parent.to_string()
+                        }
+                    };
+                    fs::create_dir_all(&parent_folder).unwrap();
+                    let file_format = HibouFileFormat::from_option_value(matches.value_of("format"));
+                    // ***
+                    let mut ret_print = vec![];
+                    ret_print.push( "PROJECTING INTERACTION".to_string());
+                    ret_print.push( format!("from file '{}'",hif_file_path) );
+                    ret_print.push( format!("ONTO {:?} CO-LOCALIZATION(S)",co_localizations.num_colocs()) );
+                    ret_print.push( "".to_string());
+                    // ***
+                    for coloc_lfs in &co_localizations.locs_lf_ids {
+                        let mut lfs_to_remove : HashSet<usize> = gen_ctx.get_all_lfs_ids();
+                        for lf_id in coloc_lfs {
+                            lfs_to_remove.remove( lf_id );
+                        }
+                        let local_int = int.clone().eliminate_lifelines(&lfs_to_remove,&gen_ctx);
+                        let lfs_names : String = coloc_lfs.iter().sorted()
+                            .map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap())
+                            .join("_");
+                        let file_name = format!("{:}_{:}", int_name, lfs_names);
+                        // ***
+                        let hif_path : PathBuf = [&parent_folder, &format!("{:}.{:}",file_name,file_format.get_extension(HIBOU_INTERACTION_FILE_EXTENSION))].iter().collect();
+                        write_interaction_into_file(hif_path.as_path(),&gen_ctx,&local_int);
+                        // ***
+                        let automaton = interaction_as_automaton(&gen_ctx,&local_int,max_states);
+                        let dot_path : PathBuf = [&parent_folder, &format!("{:}.dot",file_name)].iter().collect();
+                        write_interaction_automaton_as_dot(dot_path.as_path(),&gen_ctx,&automaton);
+                        // ***
+                        let transitions_num : usize = automaton.nfa.transitions.iter()
+                            .map(|trans| trans.values().map(|targets| targets.len()).sum::<usize>())
+                            .sum();
+                        ret_print.push( format!("lifelines  : {}", lfs_names) );
+                        ret_print.push( format!("interaction: written into file '{}'", hif_path.display()) );
+                        ret_print.push( format!("automaton  : written into file '{}'", dot_path.display()) );
+                        ret_print.push( format!("states     : {:?}", automaton.states.len()) );
+                        ret_print.push( format!("transitions: {:?}", transitions_num) );
+                        if !automaton.complete {
+                            ret_print.push( format!("warning    : automaton truncated at {:?} states", max_states) );
+                        }
+                        ret_print.push( "".to_string());
+                    }
+                    return (ret_print,0);
+                }
+            }
+        }
+    }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use clap::App;
+    use super::cli_project;
+
+    #[test]
+    fn truncation_reported_test() {
+        let dir : PathBuf = std::env::temp_dir().join(format!("hibou_project_test_{:}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let hsf_path = dir.join("sig.hsf");
+        let hif_path = dir.join("int.hif");
+        fs::write(&hsf_path,"@lifeline{l1;l2}\n@message{m1;m2;m3}").unwrap();
+        fs::write(&hif_path,"seq(l1 -- m1 -> |, l1 -- m2 -> |, l1 -- m3 -> |)").unwrap();
+        // ***
+        let yaml = load_yaml!("../hibou_cli.yml");
+        let run = |max_states : &str| {
+            let matches = App::from_yaml(yaml).get_matches_from(vec!["hibou_passing",
+                                                                      "project",
+                                                                      hsf_path.to_str().unwrap(),
+                                                                      hif_path.to_str().unwrap(),
+                                                                      "-p",
+                                                                      dir.to_str().unwrap(),
+                                                                      "-m",
+                                                                      max_states]);
+            cli_project(matches.subcommand_matches("project").unwrap())
+        };
+        // the automaton of l1 has 4 states and that of l2 a single one
+        let (ret_print,ret_code) = run("3");
+        assert_eq!(ret_code,0);
+        assert!(ret_print.contains(&"states     : 3".to_string()));
+        assert!(ret_print.contains(&"states     : 1".to_string()));
+        assert_eq!(ret_print.iter().filter(|line| line.starts_with("warning")).collect::<Vec<&String>>(),
+                   vec!["warning    : automaton truncated at 3 states"]);
+        // ***
+        let (ret_print,_) = run("4");
+        assert!(ret_print.contains(&"states     : 4".to_string()));
+        assert!(!ret_print.iter().any(|line| line.starts_with("warning")));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+}
@@ -0,0 +1,125 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::time::Instant;
+
+use clap::ArgMatches;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::general_context::GeneralContext;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::htf::interface::parse_htf_file;
+use crate::io::input::query::interface::parse_trace_queries;
+use crate::io::output::draw_traces::interface::text_repr_trace_action;
+use crate::trace_manip::query::eval::evaluate_trace_query;
+use crate::trace_manip::query::query::{TraceQuery, TraceQueryActions, TraceQueryPattern, TraceQueryScope};
+
+
+fn query_actions_as_text(gen_ctx : &GeneralContext, query_actions : &TraceQueryActions) -> String {
+    query_actions.iter().map(|action| text_repr_trace_action(action,gen_ctx)).collect::<Vec<String>>().join(" | ")
+}
+
+fn query_as_text(gen_ctx : &GeneralContext, query : &TraceQuery) -> String {
+    let pattern_str = match &query.pattern {
+        TraceQueryPattern::Response(triggers,responses) => {
+            format!("response({}, {})", query_actions_as_text(gen_ctx,triggers), query_actions_as_text(gen_ctx,responses))
+        },
+        TraceQueryPattern::Precedence(causes,effects) => {
+            format!("precedence({}, {})", query_actions_as_text(gen_ctx,causes), query_actions_as_text(gen_ctx,effects))
+        },
+        TraceQueryPattern::Absence(forbidden) => {
+            format!("absence({})", query_actions_as_text(gen_ctx,forbidden))
+        },
+        TraceQueryPattern::BoundedExistence(bounded,max_occurrences) => {
+            format!("bounded_existence({}, {})", query_actions_as_text(gen_ctx,bounded), max_occurrences)
+        }
+    };
+    match query.scope {
+        TraceQueryScope::Component => {
+            format!("{} per component", pattern_str)
+        },
+        TraceQueryScope::Lifeline => {
+            format!("{} per lifeline", pattern_str)
+        }
+    }
+}
+
+fn canal_as_text(gen_ctx : &GeneralContext, co_localizations : &CoLocalizations, canal_id : usize) -> String {
+    let mut lf_ids : Vec<usize> = co_localizations.locs_lf_ids[canal_id].iter().cloned().collect();
+    lf_ids.sort();
+    let lf_names : Vec<String> = lf_ids.iter().map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap()).collect();
+    format!("[{}]", lf_names.join(","))
+}
+
+
+pub fn cli_query(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let htf_file_path = matches.value_of("htf").unwrap();
+            match parse_htf_file(&gen_ctx,htf_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( (co_localizations,multi_trace) ) => {
+                    let queries = match parse_trace_queries(&gen_ctx,matches.value_of("query").unwrap()) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( got_queries ) => {
+                            got_queries
+                        }
+                    };
+                    // ***
+                    let mut ret_print = vec![];
+                    ret_print.push( "QUERYING MULTI-TRACE".to_string());
+                    ret_print.push( format!("from file '{}'",htf_file_path) );
+                    ret_print.push( "".to_string());
+                    // ***
+                    let now = Instant::now();
+                    let mut violated_num : u32 = 0;
+                    for query in &queries {
+                        let violations = evaluate_trace_query(&gen_ctx,query,&multi_trace);
+                        ret_print.push( format!("query      : {}", query_as_text(&gen_ctx,query)) );
+                        if violations.is_empty() {
+                            ret_print.push( "verdict    : satisfied".to_string() );
+                        } else {
+                            violated_num += 1;
+                            ret_print.push( format!("verdict    : violated by {:?} action(s)", violations.len()) );
+                            for violation in &violations {
+                                let action = &multi_trace[violation.canal_id][violation.position];
+                                ret_print.push( format!("violation  : {} at index {:?} : {}",
+                                                        canal_as_text(&gen_ctx,&co_localizations,violation.canal_id),
+                                                        violation.position,
+                                                        text_repr_trace_action(action,&gen_ctx)) );
+                            }
+                        }
+                        ret_print.push( "".to_string());
+                    }
+                    let elapsed_time = now.elapsed();
+                    ret_print.push( format!("violated   : {:?} out of {:?} queries", violated_num, queries.len()) );
+                    ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64()) );
+                    return (ret_print,0);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::time::Instant;
+
+use clap::ArgMatches;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::realizability::check::check_realizability;
+use crate::core::execution::realizability::verdict::{RealizabilityVerdict, RealizabilityViolationKind};
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::output::draw_traces::interface::text_repr_trace;
+use crate::ui::util::partition::parse_partition;
+
+
+pub fn cli_realizability(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let max_states : usize = match matches.value_of("max_states") {
+        None => {
+            1000
+        },
+        Some( extracted ) => {
+            match extracted.trim().parse::<usize>() {
+                Err(_) => {
+                    return (vec![format!("could not parse maximum number of states '{}'",extracted)],1);
+                },
+                Ok( got_max ) => {
+                    got_max
+                }
+            }
+        }
+    };
+    // ***
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let co_localizations : CoLocalizations = if matches.is_present("partition") {
+                        let extracted = matches.value_of("partition").unwrap();
+                        let content_str : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+                        match parse_partition(&gen_ctx,&content_str) {
+                            Err(e) => {
+                                return (vec![e],1);
+                            },
+                            Ok( got_colocs ) => {
+                                got_colocs
+                            }
+                        }
+                    } else {
+                        CoLocalizations::get_discrete_partition(gen_ctx.get_lf_num())
+                    };
+                    // ***
+                    let mut ret_print = vec![];
+                    ret_print.push( "CHECKING REALIZABILITY OF INTERACTION".to_string());
+                    ret_print.push( format!("from file '{}'",hif_file_path) );
+                    ret_print.push( format!("BY {:?} COMPONENT(S)",co_localizations.num_colocs()) );
+                    ret_print.push( "".to_string());
+                    // ***
+                    let now = Instant::now();
+                    let (verdict,explored) = check_realizability(&gen_ctx,&co_localizations,&int,max_states);
+                    let elapsed_time = now.elapsed();
+                    // ***
+                    ret_print.push( format!("verdict    : {}", verdict) );
+                    if let RealizabilityVerdict::NotRealizable(violation) = &verdict {
+                        ret_print.push( format!("violation  : {}", violation.kind) );
+                        ret_print.push( format!("witness    : {}", text_repr_trace(&violation.witness,&gen_ctx)) );
+                        if violation.on_termination {
+                            ret_print.push( "             is a complete behaviour of the components but not of the interaction".to_string() );
+                        } else if violation.kind == RealizabilityViolationKind::Deadlock {
+                            ret_print.push( "             after which the components cannot progress".to_string() );
+                        } else {
+                            ret_print.push( "             whose last action cannot occur at this point in the interaction".to_string() );
+                        }
+                    }
+                    ret_print.push( format!("explored   : {:?} states of the composition", explored) );
+                    ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64()) );
+                    return (ret_print,0);
+                }
+            }
+        }
+    }
+}
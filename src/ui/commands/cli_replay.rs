@@ -0,0 +1,92 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::time::Instant;
+
+use clap::ArgMatches;
+
+use crate::core::execution::witness::replay::replay_witness;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::htf::interface::parse_htf_file;
+use crate::io::input::hwf::interface::parse_hwf_file;
+
+
+pub fn cli_replay(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let htf_file_path = matches.value_of("htf").unwrap();
+                    match parse_htf_file(&gen_ctx,htf_file_path) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( (co_localizations,multi_trace) ) => {
+                            let hwf_file_path = matches.value_of("hwf").unwrap();
+                            match parse_hwf_file(&gen_ctx,hwf_file_path) {
+                                Err(e) => {
+                                    return (vec![e.to_string()],1);
+                                },
+                                Ok( witness ) => {
+                                    let mut ret_print = vec![];
+                                    ret_print.push( "REPLAYING WITNESS".to_string());
+                                    ret_print.push( format!("from file '{}'",hwf_file_path) );
+                                    ret_print.push( "OF TRACE".to_string());
+                                    ret_print.push( format!("from file '{}'",htf_file_path) );
+                                    ret_print.push( "W.R.T. INTERACTION".to_string());
+                                    ret_print.push( format!("from file '{}'",hif_file_path) );
+                                    ret_print.push( "".to_string());
+                                    // ***
+                                    let now = Instant::now();
+                                    let replayed = replay_witness(&gen_ctx,
+                                                                  &co_localizations,
+                                                                  &multi_trace,
+                                                                  &int,
+                                                                  &witness);
+                                    let elapsed_time = now.elapsed();
+                                    match replayed {
+                                        Err(e) => {
+                                            ret_print.push( e.to_string() );
+                                            ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64() ) );
+                                            return (ret_print,1);
+                                        },
+                                        Ok( () ) => {
+                                            ret_print.push( format!("witness of length {:} replayed successfully", witness.len()) );
+                                            ret_print.push( "verdict    : 'Pass' confirmed".to_string() );
+                                            ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64() ) );
+                                            return (ret_print,0);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -18,6 +18,7 @@ use std::path::Path;
 
 use clap::ArgMatches;
 
+use crate::io::file_extensions::HibouFileFormat;
 use crate::io::input::hsf::interface::parse_hsf_file;
 use crate::io::input::htf::interface::parse_htf_file;
 use crate::trace_manip::slice::conf::{SliceGenerationSelection, SliceKind};
@@ -77,7 +78,16 @@ pub fn cli_slice(matches : &ArgMatches) -> (Vec<String>,u32) {
                             wide_only = false;
                         }
                         // ***
-                        generation_selection = SliceGenerationSelection::Random(num_random_slices,wide_only);
+                        let seed : u64 = if matches.is_present("seed") {
+                            let extracted = matches.value_of("seed").unwrap();
+                            let content_str : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+                            content_str.parse::<u64>().unwrap()
+                        } else {
+                            rand::random::<u64>()
+                        };
+                        ret_print.push( format!("with seed {:?}",seed) );
+                        // ***
+                        generation_selection = SliceGenerationSelection::Random(num_random_slices,wide_only,seed);
                     } else {
                         generation_selection = SliceGenerationSelection::Exhaustive;
                     }
@@ -105,7 +115,8 @@ pub fn cli_slice(matches : &ArgMatches) -> (Vec<String>,u32) {
                         generation_kind = SliceKind::Slice;
                     }
                     // ***
-                    generate_slices(&gen_ctx,&co_localizations,mu_name,&multi_trace,parent_folder,file_name_prefix_opt,&generation_selection,&generation_kind);
+                    let file_format = HibouFileFormat::from_option_value(matches.value_of("format"));
+                    generate_slices(&gen_ctx,&co_localizations,mu_name,&multi_trace,parent_folder,file_name_prefix_opt,file_format,&generation_selection,&generation_kind);
                     return (ret_print,0);
                 }
             }
@@ -0,0 +1,146 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::path::Path;
+use std::time::Instant;
+
+use clap::ArgMatches;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::coverage::model::InteractionCoverageModel;
+use crate::io::input::hcf::explo::interface::parse_hcf_file_for_explore;
+use crate::io::input::hcf::explo::options::HibouExploreOptions;
+use crate::io::file_extensions::HibouFileFormat;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::trace_manip::testgen::criteria::{criterion_item_as_text, TestGenerationCriterion};
+use crate::trace_manip::testgen::testgen::generate_test_suite;
+use crate::ui::util::partition::parse_partition;
+
+
+pub fn cli_testgen(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let criterion : TestGenerationCriterion;
+    match matches.value_of("criterion") {
+        None => {
+            criterion = TestGenerationCriterion::AllActions;
+        },
+        Some( extracted ) => {
+            let content_str : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+            match TestGenerationCriterion::from_name(&content_str) {
+                None => {
+                    return (vec![format!("unknown coverage criterion '{}' ; expected one of actions, alt, loops, sync",content_str)],1);
+                },
+                Some( got_criterion ) => {
+                    criterion = got_criterion;
+                }
+            }
+        }
+    }
+    // ***
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let explo_opts : HibouExploreOptions;
+                    if matches.is_present("hcf") {
+                        let hcf_file_path = matches.value_of("hcf").unwrap();
+                        match parse_hcf_file_for_explore(&gen_ctx,hcf_file_path) {
+                            Err(e) => {
+                                return (vec![e.to_string()],1);
+                            },
+                            Ok( got_explo_opt) => {
+                                explo_opts = got_explo_opt;
+                            }
+                        }
+                    } else {
+                        explo_opts = HibouExploreOptions::default();
+                    }
+                    // ***
+                    let co_localizations : CoLocalizations = if matches.is_present("partition") {
+                        let extracted = matches.value_of("partition").unwrap();
+                        let content_str : String = extracted.chars().filter(|c| !c.is_whitespace()).collect();
+                        match parse_partition(&gen_ctx,&content_str) {
+                            Err(e) => {
+                                return (vec![e],1);
+                            },
+                            Ok( got_colocs ) => {
+                                got_colocs
+                            }
+                        }
+                    } else {
+                        CoLocalizations::get_trivial_partition(gen_ctx.get_lf_num())
+                    };
+                    // ***
+                    let int_name : &str = Path::new(hif_file_path).file_stem().unwrap().to_str().unwrap();
+                    let parent_folder : String = match matches.value_of("parent_folder") {
+                        None => {
+                            format!("./{:}_testgen", int_name)
+                        },
+                        Some( parent ) => {
+                            parent.to_string()
+                        }
+                    };
+                    let file_name_prefix : &str = matches.value_of("name").unwrap_or("test");
+                    // ***
+                    let mut ret_print = vec![];
+                    ret_print.push( "GENERATING TEST MULTI-TRACES".to_string());
+                    ret_print.push( format!("FOR CRITERION '{}'",criterion.get_name()));
+                    ret_print.push( "FROM INTERACTION".to_string());
+                    ret_print.push( format!("from file '{}'",hif_file_path) );
+                    ret_print.push( "".to_string());
+                    // ***
+                    let now = Instant::now();
+                    let generation = generate_test_suite(&gen_ctx,
+                                                         &co_localizations,
+                                                         &int,
+                                                         &criterion,
+                                                         explo_opts,
+                                                         &parent_folder,
+                                                         file_name_prefix,
+                                                         HibouFileFormat::from_option_value(matches.value_of("format")));
+                    let elapsed_time = now.elapsed();
+                    // ***
+                    let model = InteractionCoverageModel::new(&gen_ctx,&int);
+                    for (path,new_items) in &generation.written {
+                        ret_print.push( format!("written into file '{}' covering {:?} new item(s)", path, new_items.len()) );
+                        for item in new_items {
+                            ret_print.push( format!("  {}", criterion_item_as_text(&model,item)) );
+                        }
+                    }
+                    ret_print.push( "".to_string());
+                    let total = generation.covered.len() + generation.uncovered.len();
+                    ret_print.push( format!("candidates : {:?} accepted multi-traces explored", generation.candidates_num) );
+                    ret_print.push( format!("selected   : {:?}", generation.written.len()) );
+                    ret_print.push( format!("covered    : {:?} out of {:?} items", generation.covered.len(), total) );
+                    for item in &generation.uncovered {
+                        ret_print.push( format!("uncovered  : {}", criterion_item_as_text(&model,item)) );
+                    }
+                    ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64()) );
+                    return (ret_print,0);
+                }
+            }
+        }
+    }
+}
+
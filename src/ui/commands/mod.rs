@@ -27,3 +27,20 @@ pub mod cli_mutate_remove_actions;
 
 
 
+
+pub mod cli_align;
+pub mod cli_coverage;
+pub mod cli_replay;
+pub mod cli_animate;
+pub mod cli_minimize;
+pub mod cli_generate;
+pub mod cli_mutate_model_aware;
+pub mod cli_campaign;
+pub mod cli_testgen;
+pub mod cli_project;
+pub mod cli_realizability;
+pub mod cli_check;
+pub mod cli_query;
+pub mod cli_merge_signatures;
+pub mod cli_import_trace;
+pub mod cli_import_puml;
\ No newline at end of file
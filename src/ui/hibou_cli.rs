@@ -17,14 +17,30 @@ limitations under the License.
 
 use clap::App;
 
+use crate::ui::commands::cli_align::cli_align;
+use crate::ui::commands::cli_animate::cli_animate;
 use crate::ui::commands::cli_analyze::cli_analyze;
+use crate::ui::commands::cli_campaign::cli_campaign;
+use crate::ui::commands::cli_check::cli_check;
+use crate::ui::commands::cli_coverage::cli_coverage;
 use crate::ui::commands::cli_draw::cli_draw;
 use crate::ui::commands::cli_explore::cli_explore;
+use crate::ui::commands::cli_generate::cli_generate;
+use crate::ui::commands::cli_import_puml::cli_import_puml;
+use crate::ui::commands::cli_import_trace::cli_import_trace;
+use crate::ui::commands::cli_merge_signatures::cli_merge_signatures;
+use crate::ui::commands::cli_minimize::cli_minimize;
 use crate::ui::commands::cli_mutate_insert_noise::cli_mutate_insert_noise;
+use crate::ui::commands::cli_mutate_model_aware::cli_mutate_model_aware;
 use crate::ui::commands::cli_mutate_remove_actions::cli_mutate_remove_actions;
 use crate::ui::commands::cli_mutate_swap_actions::cli_mutate_swap_actions;
 use crate::ui::commands::cli_mutate_swap_components::cli_mutate_swap_components;
+use crate::ui::commands::cli_project::cli_project;
+use crate::ui::commands::cli_query::cli_query;
+use crate::ui::commands::cli_realizability::cli_realizability;
+use crate::ui::commands::cli_replay::cli_replay;
 use crate::ui::commands::cli_slice::cli_slice;
+use crate::ui::commands::cli_testgen::cli_testgen;
 use crate::ui::util::printing::print_on_hibou_cli;
 
 
@@ -48,6 +64,66 @@ pub fn hibou_cli() -> i32 {
         let mut got = cli_analyze(matches);
         ret_print = got.0;
         ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("replay") {
+        let mut got = cli_replay(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("animate") {
+        let mut got = cli_animate(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("align") {
+        let mut got = cli_align(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("minimize") {
+        let mut got = cli_minimize(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("coverage") {
+        let mut got = cli_coverage(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("generate") {
+        let mut got = cli_generate(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("testgen") {
+        let got = cli_testgen(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("campaign") {
+        let got = cli_campaign(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("project") {
+        let got = cli_project(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("realizability") {
+        let got = cli_realizability(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("check") {
+        let got = cli_check(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("query") {
+        let got = cli_query(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("merge_signatures") {
+        let got = cli_merge_signatures(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("import_trace") {
+        let got = cli_import_trace(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("import_puml") {
+        let got = cli_import_puml(matches);
+        ret_print = got.0;
+        ret_code = got.1;
     } else if let Some(matches) = matches.subcommand_matches("slice") {
         let mut got = cli_slice(matches);
         ret_print = got.0;
@@ -68,6 +144,10 @@ pub fn hibou_cli() -> i32 {
         let mut got = cli_mutate_swap_components(matches);
         ret_print = got.0;
         ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("mutate_model_aware") {
+        let mut got = cli_mutate_model_aware(matches);
+        ret_print = got.0;
+        ret_code = got.1;
     } else {
         ret_print = vec!["".to_string(),"TYPE help or -h to get a summary of the utilities".to_string()];
         ret_code = 0
@@ -77,3 +157,33 @@ pub fn hibou_cli() -> i32 {
     return 0;
 }
 
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use clap::App;
+
+    #[test]
+    fn format_possible_values_test() {
+        let yaml = load_yaml!("hibou_cli.yml");
+        let app = App::from_yaml(yaml);
+        let mut checked : u32 = 0;
+        for subcommand in app.get_subcommands() {
+            for arg in subcommand.get_arguments() {
+                if arg.get_id() != "format" {
+                    continue;
+                }
+                let values : Vec<&str> = arg.get_possible_values()
+                    .expect("the format option must restrict its values")
+                    .iter().map(|pv| pv.get_name())
+                    .collect();
+                assert_eq!(values,vec!["hibou","json"],"{}",subcommand.get_name());
+                checked += 1;
+            }
+        }
+        assert_eq!(checked,16);
+    }
+
+}
@@ -15,4 +15,5 @@ limitations under the License.
 */
 
 
-pub mod printing;
\ No newline at end of file
+pub mod printing;
+pub mod partition;
@@ -0,0 +1,65 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashSet;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::general_context::GeneralContext;
+
+
+/**
+Parses either 'trivial', 'discrete' or a list of co-localizations such as 'l1,l2;l3'.
+Lifelines which do not appear in the list are each put in their own co-localization.
+**/
+pub fn parse_partition(gen_ctx : &GeneralContext,
+                       partition_str : &str) -> Result<CoLocalizations,String> {
+    match partition_str {
+        "trivial" => {
+            return Ok( CoLocalizations::get_trivial_partition(gen_ctx.get_lf_num()) );
+        },
+        "discrete" => {
+            return Ok( CoLocalizations::get_discrete_partition(gen_ctx.get_lf_num()) );
+        },
+        _ => {
+            let mut colocs : Vec<HashSet<usize>> = vec![];
+            let mut got_lfs : HashSet<usize> = hashset!{};
+            for coloc_str in partition_str.split(";") {
+                let mut coloc : HashSet<usize> = hashset!{};
+                for lf_name in coloc_str.split(",") {
+                    match gen_ctx.get_lf_id(lf_name) {
+                        None => {
+                            return Err( format!("unknown lifeline '{:}' in partition", lf_name) );
+                        },
+                        Some( lf_id ) => {
+                            if !got_lfs.insert(lf_id) {
+                                return Err( format!("lifeline '{:}' appears twice in partition", lf_name) );
+                            }
+                            coloc.insert(lf_id);
+                        }
+                    }
+                }
+                colocs.push(coloc);
+            }
+            for lf_id in 0..gen_ctx.get_lf_num() {
+                if !got_lfs.contains(&lf_id) {
+                    colocs.push( hashset!{lf_id} );
+                }
+            }
+            return Ok( CoLocalizations::new(colocs) );
+        }
+    }
+}